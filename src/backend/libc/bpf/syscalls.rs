@@ -0,0 +1,77 @@
+//! libc syscalls supporting `rustix::bpf`.
+
+use crate::backend::c;
+use crate::backend::conv::{ret, ret_owned_fd};
+use crate::bpf::{
+    MapCreateAttr, MapElemAttr, ObjAttr, ProgAttachAttr, ProgLoadAttr, BPF_MAP_CREATE, BPF_OBJ_GET,
+    BPF_OBJ_PIN, BPF_PROG_LOAD,
+};
+use crate::fd::OwnedFd;
+use crate::io;
+use core::mem::size_of;
+
+syscall! {
+    fn bpf(
+        cmd: c::c_int,
+        attr: *const c::c_void,
+        size: c::c_uint
+    ) via SYS_bpf -> c::c_int
+}
+
+#[inline]
+pub(crate) fn bpf_map_create(attr: &MapCreateAttr) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(bpf(
+            BPF_MAP_CREATE as _,
+            (attr as *const MapCreateAttr).cast(),
+            size_of::<MapCreateAttr>() as _,
+        ))
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn bpf_map_elem(cmd: u32, attr: &MapElemAttr) -> io::Result<()> {
+    ret(bpf(
+        cmd as _,
+        (attr as *const MapElemAttr).cast(),
+        size_of::<MapElemAttr>() as _,
+    ))
+}
+
+#[inline]
+pub(crate) unsafe fn bpf_prog_load(attr: &ProgLoadAttr) -> io::Result<OwnedFd> {
+    ret_owned_fd(bpf(
+        BPF_PROG_LOAD as _,
+        (attr as *const ProgLoadAttr).cast(),
+        size_of::<ProgLoadAttr>() as _,
+    ))
+}
+
+#[inline]
+pub(crate) unsafe fn bpf_obj_pin(attr: &ObjAttr) -> io::Result<()> {
+    ret(bpf(
+        BPF_OBJ_PIN as _,
+        (attr as *const ObjAttr).cast(),
+        size_of::<ObjAttr>() as _,
+    ))
+}
+
+#[inline]
+pub(crate) unsafe fn bpf_obj_get(attr: &ObjAttr) -> io::Result<OwnedFd> {
+    ret_owned_fd(bpf(
+        BPF_OBJ_GET as _,
+        (attr as *const ObjAttr).cast(),
+        size_of::<ObjAttr>() as _,
+    ))
+}
+
+#[inline]
+pub(crate) fn bpf_prog_attach(cmd: u32, attr: &ProgAttachAttr) -> io::Result<()> {
+    unsafe {
+        ret(bpf(
+            cmd as _,
+            (attr as *const ProgAttachAttr).cast(),
+            size_of::<ProgAttachAttr>() as _,
+        ))
+    }
+}
@@ -63,6 +63,49 @@ pub(crate) const ETH_P_XDSA: c_int = linux_raw_sys::if_ether::ETH_P_XDSA as _;
 pub(crate) const ETH_P_MAP: c_int = linux_raw_sys::if_ether::ETH_P_MAP as _;
 #[cfg(all(linux_kernel, feature = "net"))]
 pub(crate) const ETH_P_MCTP: c_int = linux_raw_sys::if_ether::ETH_P_MCTP as _;
+#[cfg(all(linux_kernel, feature = "net"))]
+pub(crate) const SO_TIMESTAMP_OLD: c_int = linux_raw_sys::net::SO_TIMESTAMP_OLD as _;
+#[cfg(all(linux_kernel, feature = "net"))]
+pub(crate) const SO_TIMESTAMP_NEW: c_int = linux_raw_sys::net::SO_TIMESTAMP_NEW as _;
+#[cfg(all(linux_kernel, feature = "net"))]
+pub(crate) const SO_TIMESTAMPNS_OLD: c_int = linux_raw_sys::net::SO_TIMESTAMPNS_OLD as _;
+#[cfg(all(linux_kernel, feature = "net"))]
+pub(crate) const SO_TIMESTAMPNS_NEW: c_int = linux_raw_sys::net::SO_TIMESTAMPNS_NEW as _;
+#[cfg(all(linux_kernel, feature = "net"))]
+pub(crate) const SO_TIMESTAMPING_OLD: c_int = linux_raw_sys::net::SO_TIMESTAMPING_OLD as _;
+#[cfg(all(linux_kernel, feature = "net"))]
+pub(crate) const SO_TIMESTAMPING_NEW: c_int = linux_raw_sys::net::SO_TIMESTAMPING_NEW as _;
+#[cfg(all(linux_kernel, feature = "net"))]
+pub(crate) const UDP_SEGMENT: c_int = 103;
+#[cfg(all(linux_kernel, feature = "net"))]
+pub(crate) const UDP_GRO: c_int = 104;
+#[cfg(all(linux_kernel, feature = "net"))]
+pub(crate) const SO_ZEROCOPY: c_int = linux_raw_sys::net::SO_ZEROCOPY as _;
+#[cfg(all(linux_kernel, feature = "net"))]
+pub(crate) const MSG_ZEROCOPY: c_int = 0x400_0000;
+#[cfg(all(linux_kernel, feature = "net"))]
+pub(crate) const SO_BINDTOIFINDEX: c_int = linux_raw_sys::net::SO_BINDTOIFINDEX as _;
+#[cfg(all(linux_kernel, feature = "net"))]
+pub(crate) const SO_ATTACH_FILTER: c_int = linux_raw_sys::net::SO_ATTACH_FILTER as _;
+#[cfg(all(linux_kernel, feature = "net"))]
+pub(crate) const SO_DETACH_FILTER: c_int = linux_raw_sys::net::SO_DETACH_FILTER as _;
+#[cfg(all(linux_kernel, feature = "net"))]
+pub(crate) const SO_LOCK_FILTER: c_int = linux_raw_sys::net::SO_LOCK_FILTER as _;
+#[cfg(all(linux_kernel, feature = "net"))]
+pub(crate) const SO_ATTACH_REUSEPORT_CBPF: c_int =
+    linux_raw_sys::net::SO_ATTACH_REUSEPORT_CBPF as _;
+#[cfg(all(linux_kernel, feature = "net"))]
+pub(crate) const SO_DETACH_REUSEPORT_BPF: c_int = linux_raw_sys::net::SO_DETACH_REUSEPORT_BPF as _;
+#[cfg(all(linux_kernel, feature = "net"))]
+pub(crate) const SOL_TLS: c_int = linux_raw_sys::net::SOL_TLS as _;
+#[cfg(all(linux_kernel, feature = "net"))]
+pub(crate) const TLS_TX: c_int = 1;
+#[cfg(all(linux_kernel, feature = "net"))]
+pub(crate) const TLS_RX: c_int = 2;
+#[cfg(all(linux_kernel, feature = "net"))]
+pub(crate) const TLS_SET_RECORD_TYPE: c_int = 1;
+#[cfg(all(linux_kernel, feature = "net"))]
+pub(crate) const TLS_GET_RECORD_TYPE: c_int = 2;
 
 #[cfg(all(
     linux_kernel,
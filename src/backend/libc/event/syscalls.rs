@@ -4,6 +4,8 @@ use crate::backend::c;
 #[cfg(any(linux_kernel, solarish, target_os = "redox"))]
 use crate::backend::conv::ret;
 use crate::backend::conv::ret_c_int;
+#[cfg(linux_kernel)]
+use crate::backend::conv::ret_discarded_fd;
 #[cfg(feature = "alloc")]
 #[cfg(any(linux_kernel, target_os = "illumos", target_os = "redox"))]
 use crate::backend::conv::ret_u32;
@@ -19,6 +21,8 @@ use crate::event::EventfdFlags;
 #[cfg(any(bsd, linux_kernel, target_os = "wasi"))]
 use crate::event::FdSetElement;
 use crate::event::PollFd;
+#[cfg(linux_kernel)]
+use crate::event::{SigSet, SignalfdFlags};
 use crate::io;
 #[cfg(solarish)]
 use crate::utils::as_mut_ptr;
@@ -90,6 +94,18 @@ pub(crate) fn eventfd(initval: u32, flags: EventfdFlags) -> io::Result<OwnedFd>
     }
 }
 
+#[cfg(linux_kernel)]
+pub(crate) fn signalfd(mask: &SigSet, flags: SignalfdFlags) -> io::Result<OwnedFd> {
+    unsafe { ret_owned_fd(c::signalfd(-1, mask.as_raw(), bitflags_bits!(flags))) }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn signalfd_set_mask(fd: BorrowedFd<'_>, mask: &SigSet) -> io::Result<()> {
+    // When passed an existing signalfd, `signalfd` replaces its mask and
+    // returns the same file descriptor, which we already own.
+    unsafe { ret_discarded_fd(c::signalfd(borrowed_fd(fd), mask.as_raw(), 0)) }
+}
+
 #[cfg(all(feature = "alloc", bsd))]
 pub(crate) fn kqueue() -> io::Result<OwnedFd> {
     unsafe { ret_owned_fd(c::kqueue()) }
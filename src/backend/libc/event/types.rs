@@ -35,3 +35,21 @@ bitflags! {
         const _ = !0;
     }
 }
+
+#[cfg(linux_kernel)]
+bitflags! {
+    /// `SFD_*` flags for use with [`signalfd`].
+    ///
+    /// [`signalfd`]: crate::event::signalfd
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct SignalfdFlags: u32 {
+        /// `SFD_CLOEXEC`
+        const CLOEXEC = bitcast!(c::SFD_CLOEXEC);
+        /// `SFD_NONBLOCK`
+        const NONBLOCK = bitcast!(c::SFD_NONBLOCK);
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
@@ -1770,6 +1770,57 @@ const SYS_OPENAT2: i32 = 437;
 #[cfg(all(linux_kernel, target_pointer_width = "64"))]
 const SYS_OPENAT2: i64 = 437;
 
+#[cfg(all(linux_kernel, feature = "alloc"))]
+pub(crate) unsafe fn name_to_handle_at(
+    dirfd: BorrowedFd<'_>,
+    path: &CStr,
+    handle: *mut c::c_void,
+    mount_id: *mut c::c_void,
+    flags: AtFlags,
+) -> io::Result<()> {
+    syscall! {
+        fn name_to_handle_at(
+            base_dirfd: c::c_int,
+            pathname: *const c::c_char,
+            handle: *mut c::c_void,
+            mount_id: *mut c::c_void,
+            flags: c::c_int
+        ) via SYS_name_to_handle_at -> c::c_int
+    }
+
+    ret(name_to_handle_at(
+        borrowed_fd(dirfd),
+        c_str(path),
+        handle,
+        mount_id,
+        bitflags_bits!(flags),
+    ))
+}
+
+#[cfg(all(linux_kernel, feature = "alloc"))]
+pub(crate) unsafe fn open_by_handle_at(
+    mount_fd: BorrowedFd<'_>,
+    handle: *const c::c_void,
+    flags: OFlags,
+) -> io::Result<OwnedFd> {
+    syscall! {
+        fn open_by_handle_at(
+            mount_fd: c::c_int,
+            handle: *const c::c_void,
+            flags: c::c_int
+        ) via SYS_open_by_handle_at -> c::c_int
+    }
+
+    // Always enable support for large files, as the libc `openat` does.
+    let flags = flags | OFlags::LARGEFILE;
+
+    ret_owned_fd(open_by_handle_at(
+        borrowed_fd(mount_fd),
+        handle,
+        bitflags_bits!(flags),
+    ))
+}
+
 #[cfg(target_os = "linux")]
 pub(crate) fn sendfile(
     out_fd: BorrowedFd<'_>,
@@ -2586,3 +2637,47 @@ pub(crate) fn inotify_rm_watch(inot: BorrowedFd<'_>, wd: i32) -> io::Result<()>
     // SAFETY: The fd is valid and closing an arbitrary wd is valid.
     unsafe { ret(c::inotify_rm_watch(borrowed_fd(inot), wd)) }
 }
+
+#[cfg(linux_kernel)]
+pub(crate) fn fanotify_init(flags: u32, event_flags: OFlags) -> io::Result<OwnedFd> {
+    syscall! {
+        fn fanotify_init(
+            flags: c::c_uint,
+            event_f_flags: c::c_uint
+        ) via SYS_fanotify_init -> c::c_int
+    }
+
+    unsafe { ret_owned_fd(fanotify_init(flags, bitflags_bits!(event_flags))) }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn fanotify_mark(
+    fan: BorrowedFd<'_>,
+    flags: u32,
+    mask: u64,
+    dirfd: BorrowedFd<'_>,
+    path: Option<&CStr>,
+) -> io::Result<()> {
+    // `libc::syscall` can't pass the 64-bit `mask` on 32-bit platforms, so
+    // use the libc function.
+    weakcall! {
+        fn fanotify_mark(
+            fan: c::c_int,
+            flags: c::c_uint,
+            mask: u64,
+            dirfd: c::c_int,
+            path: *const c::c_char
+        ) -> c::c_int
+    }
+
+    let path = path.map_or(core::ptr::null(), c_str);
+    unsafe {
+        ret(fanotify_mark(
+            borrowed_fd(fan),
+            flags,
+            mask,
+            borrowed_fd(dirfd),
+            path,
+        ))
+    }
+}
@@ -102,6 +102,9 @@ pub(crate) mod fd {
 #[cfg_attr(windows, path = "winsock_c.rs")]
 pub(crate) mod c;
 
+#[cfg(linux_kernel)]
+#[cfg(feature = "bpf")]
+pub(crate) mod bpf;
 #[cfg(feature = "event")]
 pub(crate) mod event;
 #[cfg(not(windows))]
@@ -128,6 +131,9 @@ pub(crate) mod net;
     target_arch = "x86",
 ))]
 pub(crate) mod param;
+#[cfg(linux_kernel)]
+#[cfg(feature = "perf_event")]
+pub(crate) mod perf_event;
 #[cfg(not(windows))]
 #[cfg(feature = "pipe")]
 pub(crate) mod pipe;
@@ -190,6 +196,8 @@ pub(crate) mod prctl;
 )))]
 #[cfg(feature = "shm")]
 pub(crate) mod shm;
+#[cfg(all(linux_kernel, any(feature = "event", feature = "signal")))]
+pub(crate) mod signal;
 #[cfg(any(feature = "fs", feature = "thread", feature = "process"))]
 #[cfg(not(any(windows, target_os = "wasi")))]
 pub(crate) mod ugid;
@@ -1,6 +1,8 @@
 use crate::backend::c;
 use crate::backend::conv::ret;
 #[cfg(feature = "mount")]
+use crate::backend::conv::ret_usize;
+#[cfg(feature = "mount")]
 use crate::backend::conv::{borrowed_fd, c_str, ret_owned_fd};
 #[cfg(feature = "mount")]
 use crate::fd::{BorrowedFd, OwnedFd};
@@ -270,3 +272,85 @@ pub(crate) fn fsconfig_reconfigure(fs_fd: BorrowedFd<'_>) -> io::Result<()> {
         ))
     }
 }
+
+#[cfg(linux_kernel)]
+#[cfg(feature = "mount")]
+pub(crate) fn mount_setattr(
+    dfd: BorrowedFd<'_>,
+    path: &CStr,
+    flags: super::types::MountSetattrFlags,
+    attr: &linux_raw_sys::general::mount_attr,
+) -> io::Result<()> {
+    syscall! {
+        fn mount_setattr(
+            dfd: c::c_int,
+            path: *const c::c_char,
+            flags: c::c_uint,
+            uattr: *const linux_raw_sys::general::mount_attr,
+            usize: c::size_t
+        ) via SYS_mount_setattr -> c::c_int
+    }
+
+    unsafe {
+        ret(mount_setattr(
+            borrowed_fd(dfd),
+            c_str(path),
+            flags.bits(),
+            attr,
+            core::mem::size_of::<linux_raw_sys::general::mount_attr>(),
+        ))
+    }
+}
+
+#[cfg(linux_kernel)]
+#[cfg(feature = "mount")]
+pub(crate) fn statmount(
+    req: &linux_raw_sys::general::mnt_id_req,
+    buf: &mut [u64],
+    flags: c::c_uint,
+) -> io::Result<()> {
+    // TODO: Upstream this to the libc crate.
+    #[allow(non_upper_case_globals)]
+    const SYS_statmount: c::c_long = linux_raw_sys::general::__NR_statmount as c::c_long;
+
+    syscall! {
+        fn statmount(
+            req: *const linux_raw_sys::general::mnt_id_req,
+            buf: *mut u64,
+            bufsize: c::size_t,
+            flags: c::c_uint
+        ) via SYS_statmount -> c::c_int
+    }
+
+    unsafe {
+        ret(statmount(
+            req,
+            buf.as_mut_ptr(),
+            core::mem::size_of_val(buf),
+            flags,
+        ))
+    }
+}
+
+#[cfg(linux_kernel)]
+#[cfg(feature = "mount")]
+pub(crate) fn listmount(
+    req: &linux_raw_sys::general::mnt_id_req,
+    buf: &mut [u64],
+    flags: c::c_uint,
+) -> io::Result<usize> {
+    // TODO: Upstream this to the libc crate.
+    #[allow(non_upper_case_globals)]
+    const SYS_listmount: c::c_long = linux_raw_sys::general::__NR_listmount as c::c_long;
+
+    syscall! {
+        fn listmount(
+            req: *const linux_raw_sys::general::mnt_id_req,
+            mnt_ids: *mut u64,
+            nr_mnt_ids: c::size_t,
+            flags: c::c_uint
+        ) via SYS_listmount -> c::ssize_t
+    }
+
+    unsafe { ret_usize(listmount(req, buf.as_mut_ptr(), buf.len(), flags)) }
+}
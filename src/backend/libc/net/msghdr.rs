@@ -7,11 +7,23 @@ use crate::backend::c;
 use crate::backend::conv::{msg_control_len, msg_iov_len};
 #[cfg(target_os = "linux")]
 use crate::backend::net::write_sockaddr::encode_sockaddr_xdp;
+#[cfg(linux_kernel)]
+use crate::backend::net::write_sockaddr::{
+    encode_sockaddr_link, encode_sockaddr_netlink, encode_sockaddr_vsock,
+};
 use crate::backend::net::write_sockaddr::{encode_sockaddr_v4, encode_sockaddr_v6};
 
 use crate::io::{self, IoSlice, IoSliceMut};
+#[cfg(linux_kernel)]
+use crate::net::netlink::SocketAddrNetlink;
+#[cfg(linux_kernel)]
+use crate::net::packet::SocketAddrLink;
+#[cfg(linux_kernel)]
+use crate::net::vsock::SocketAddrVsock;
 #[cfg(target_os = "linux")]
 use crate::net::xdp::SocketAddrXdp;
+#[cfg(linux_kernel)]
+use crate::net::{MMsgHdr, RecvMMsgHdr};
 use crate::net::{RecvAncillaryBuffer, SendAncillaryBuffer, SocketAddrV4, SocketAddrV6};
 use crate::utils::as_ptr;
 
@@ -78,7 +90,7 @@ pub(crate) fn with_v4_msghdr<R>(
     f({
         let mut h = zero_msghdr();
         h.msg_name = as_ptr(&encoded) as _;
-        h.msg_namelen = size_of::<SocketAddrV4>() as _;
+        h.msg_namelen = size_of::<c::sockaddr_in>() as _;
         h.msg_iov = iov.as_ptr() as _;
         h.msg_iovlen = msg_iov_len(iov.len());
         h.msg_control = control.as_control_ptr().cast();
@@ -99,7 +111,7 @@ pub(crate) fn with_v6_msghdr<R>(
     f({
         let mut h = zero_msghdr();
         h.msg_name = as_ptr(&encoded) as _;
-        h.msg_namelen = size_of::<SocketAddrV6>() as _;
+        h.msg_namelen = size_of::<c::sockaddr_in6>() as _;
         h.msg_iov = iov.as_ptr() as _;
         h.msg_iovlen = msg_iov_len(iov.len());
         h.msg_control = control.as_control_ptr().cast();
@@ -150,6 +162,118 @@ pub(crate) fn with_xdp_msghdr<R>(
     })
 }
 
+/// Create a message header intended to send with a netlink address.
+#[cfg(linux_kernel)]
+pub(crate) fn with_netlink_msghdr<R>(
+    addr: &SocketAddrNetlink,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    f: impl FnOnce(c::msghdr) -> R,
+) -> R {
+    let encoded = encode_sockaddr_netlink(addr);
+
+    f({
+        let mut h = zero_msghdr();
+        h.msg_name = as_ptr(&encoded) as _;
+        h.msg_namelen = size_of::<c::sockaddr_nl>() as _;
+        h.msg_iov = iov.as_ptr() as _;
+        h.msg_iovlen = msg_iov_len(iov.len());
+        h.msg_control = control.as_control_ptr().cast();
+        h.msg_controllen = msg_control_len(control.control_len());
+        h
+    })
+}
+
+/// Create a message header intended to send with a link-layer address.
+#[cfg(linux_kernel)]
+pub(crate) fn with_link_msghdr<R>(
+    addr: &SocketAddrLink,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    f: impl FnOnce(c::msghdr) -> R,
+) -> R {
+    let encoded = encode_sockaddr_link(addr);
+
+    f({
+        let mut h = zero_msghdr();
+        h.msg_name = as_ptr(&encoded) as _;
+        h.msg_namelen = size_of::<c::sockaddr_ll>() as _;
+        h.msg_iov = iov.as_ptr() as _;
+        h.msg_iovlen = msg_iov_len(iov.len());
+        h.msg_control = control.as_control_ptr().cast();
+        h.msg_controllen = msg_control_len(control.control_len());
+        h
+    })
+}
+
+/// Create a message header intended to send with a VM socket address.
+#[cfg(linux_kernel)]
+pub(crate) fn with_vsock_msghdr<R>(
+    addr: &SocketAddrVsock,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    f: impl FnOnce(c::msghdr) -> R,
+) -> R {
+    let encoded = encode_sockaddr_vsock(addr);
+
+    f({
+        let mut h = zero_msghdr();
+        h.msg_name = as_ptr(&encoded) as _;
+        h.msg_namelen = size_of::<c::sockaddr_vm>() as _;
+        h.msg_iov = iov.as_ptr() as _;
+        h.msg_iovlen = msg_iov_len(iov.len());
+        h.msg_control = control.as_control_ptr().cast();
+        h.msg_controllen = msg_control_len(control.control_len());
+        h
+    })
+}
+
+/// Create a message header for a message to send with `sendmmsg`.
+#[cfg(linux_kernel)]
+pub(crate) fn send_mmsghdr(msg: &MMsgHdr<'_>) -> c::mmsghdr {
+    c::mmsghdr {
+        msg_hdr: {
+            let mut h = zero_msghdr();
+            if msg.name_len != 0 {
+                h.msg_name = msg.name.as_ptr() as _;
+                h.msg_namelen = msg.name_len as _;
+            }
+            h.msg_iov = msg.iov.as_ptr() as _;
+            h.msg_iovlen = msg_iov_len(msg.iov.len());
+            h.msg_control = msg.control.cast();
+            h.msg_controllen = msg_control_len(msg.control_len);
+            h
+        },
+        msg_len: 0,
+    }
+}
+
+/// Create a message header for a message to receive with `recvmmsg`.
+///
+/// # Safety
+///
+/// `msg` must have been created by `RecvMMsgHdr::new`, and so must
+/// reference live buffers.
+#[cfg(linux_kernel)]
+pub(crate) unsafe fn recv_mmsghdr(msg: &mut RecvMMsgHdr<'_>) -> c::mmsghdr {
+    let control = &mut *msg.control;
+    control.clear();
+
+    c::mmsghdr {
+        msg_hdr: {
+            let mut h = zero_msghdr();
+            h.msg_name = msg.name.as_mut_ptr().cast();
+            h.msg_namelen = size_of::<c::sockaddr_storage>() as _;
+            h.msg_iov = msg.iov.cast();
+            h.msg_iovlen = msg_iov_len(msg.iov_len);
+            h.msg_control = control.as_control_ptr().cast();
+            h.msg_controllen = msg_control_len(control.control_len());
+            h
+        },
+        msg_len: 0,
+    }
+}
+
 /// Create a zero-initialized message header struct value.
 #[cfg(all(unix, not(target_os = "redox")))]
 pub(crate) fn zero_msghdr() -> c::msghdr {
@@ -8,9 +8,17 @@ use crate::backend::c;
 #[cfg(not(windows))]
 use crate::ffi::CStr;
 use crate::io;
+#[cfg(linux_kernel)]
+use crate::net::netlink::SocketAddrNetlink;
+#[cfg(linux_kernel)]
+use crate::net::packet::SocketAddrLink;
+#[cfg(linux_kernel)]
+use crate::net::vsock::SocketAddrVsock;
 #[cfg(target_os = "linux")]
 use crate::net::xdp::{SockaddrXdpFlags, SocketAddrXdp};
 use crate::net::{Ipv4Addr, Ipv6Addr, SocketAddrAny, SocketAddrV4, SocketAddrV6};
+#[cfg(linux_kernel)]
+use crate::net::{Ipv4PktInfo, Ipv6PktInfo};
 use core::mem::size_of;
 
 // This must match the header of `sockaddr`.
@@ -213,6 +221,36 @@ pub(crate) unsafe fn read_sockaddr(
                 u32::from_be(decode.sxdp_shared_umem_fd),
             )))
         }
+        #[cfg(linux_kernel)]
+        c::AF_NETLINK => {
+            if len < size_of::<c::sockaddr_nl>() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = &*storage.cast::<c::sockaddr_nl>();
+            Ok(SocketAddrAny::Netlink(SocketAddrNetlink::new(
+                decode.nl_pid,
+                decode.nl_groups,
+            )))
+        }
+        #[cfg(linux_kernel)]
+        c::AF_PACKET => {
+            if len < SOCKADDR_LL_ADDR_OFFSET {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = &*storage.cast::<c::sockaddr_ll>();
+            Ok(SocketAddrAny::Link(decode_sockaddr_link(decode, len)))
+        }
+        #[cfg(linux_kernel)]
+        c::AF_VSOCK => {
+            if len < size_of::<c::sockaddr_vm>() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = &*storage.cast::<c::sockaddr_vm>();
+            Ok(SocketAddrAny::Vsock(SocketAddrVsock::new(
+                decode.svm_cid,
+                decode.svm_port,
+            )))
+        }
         _ => Err(io::Errno::INVAL),
     }
 }
@@ -332,6 +370,62 @@ unsafe fn inner_read_sockaddr_os(
                 u32::from_be(decode.sxdp_shared_umem_fd),
             ))
         }
+        #[cfg(linux_kernel)]
+        c::AF_NETLINK => {
+            assert!(len >= size_of::<c::sockaddr_nl>());
+            let decode = &*storage.cast::<c::sockaddr_nl>();
+            SocketAddrAny::Netlink(SocketAddrNetlink::new(decode.nl_pid, decode.nl_groups))
+        }
+        #[cfg(linux_kernel)]
+        c::AF_PACKET => {
+            assert!(len >= SOCKADDR_LL_ADDR_OFFSET);
+            let decode = &*storage.cast::<c::sockaddr_ll>();
+            SocketAddrAny::Link(decode_sockaddr_link(decode, len))
+        }
+        #[cfg(linux_kernel)]
+        c::AF_VSOCK => {
+            assert!(len >= size_of::<c::sockaddr_vm>());
+            let decode = &*storage.cast::<c::sockaddr_vm>();
+            SocketAddrAny::Vsock(SocketAddrVsock::new(decode.svm_cid, decode.svm_port))
+        }
         other => unimplemented!("{:?}", other),
     }
 }
+
+/// The offset of `sll_addr` in `struct sockaddr_ll`.
+///
+/// The kernel's `getsockname` only includes as much of the hardware address as
+/// is in use, so addresses may be shorter than `struct sockaddr_ll`.
+#[cfg(linux_kernel)]
+const SOCKADDR_LL_ADDR_OFFSET: usize = 12;
+
+#[cfg(linux_kernel)]
+fn decode_sockaddr_link(decode: &c::sockaddr_ll, len: usize) -> SocketAddrLink {
+    let halen = (decode.sll_halen as usize)
+        .min(decode.sll_addr.len())
+        .min(len - SOCKADDR_LL_ADDR_OFFSET);
+    SocketAddrLink::from_raw_parts(
+        decode.sll_protocol,
+        decode.sll_ifindex as u32,
+        decode.sll_hatype,
+        decode.sll_pkttype,
+        &decode.sll_addr[..halen],
+    )
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn decode_in_pktinfo(decode: &c::in_pktinfo) -> Ipv4PktInfo {
+    Ipv4PktInfo {
+        ifindex: decode.ipi_ifindex as u32,
+        spec_dst: Ipv4Addr::from(u32::from_be(in_addr_s_addr(decode.ipi_spec_dst))),
+        addr: Ipv4Addr::from(u32::from_be(in_addr_s_addr(decode.ipi_addr))),
+    }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn decode_in6_pktinfo(decode: &c::in6_pktinfo) -> Ipv6PktInfo {
+    Ipv6PktInfo {
+        addr: Ipv6Addr::from(in6_addr_s6_addr(decode.ipi6_addr)),
+        ifindex: decode.ipi6_ifindex as u32,
+    }
+}
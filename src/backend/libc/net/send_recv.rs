@@ -48,6 +48,9 @@ bitflags! {
         const NOSIGNAL = bitcast!(c::MSG_NOSIGNAL);
         /// `MSG_OOB`
         const OOB = bitcast!(c::MSG_OOB);
+        /// `MSG_ZEROCOPY`
+        #[cfg(linux_kernel)]
+        const ZEROCOPY = bitcast!(c::MSG_ZEROCOPY);
 
         /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
         const _ = !0;
@@ -99,6 +102,9 @@ bitflags! {
         const TRUNC = bitcast!(c::MSG_TRUNC);
         /// `MSG_WAITALL`
         const WAITALL = bitcast!(c::MSG_WAITALL);
+        /// `MSG_WAITFORONE`
+        #[cfg(linux_kernel)]
+        const WAITFORONE = bitcast!(c::MSG_WAITFORONE);
 
         /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
         const _ = !0;
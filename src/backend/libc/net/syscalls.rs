@@ -4,17 +4,36 @@
 use super::addr::SocketAddrUnix;
 #[cfg(target_os = "linux")]
 use super::msghdr::with_xdp_msghdr;
+#[cfg(linux_kernel)]
+use super::msghdr::{with_link_msghdr, with_netlink_msghdr, with_vsock_msghdr};
 #[cfg(target_os = "linux")]
 use super::write_sockaddr::encode_sockaddr_xdp;
+#[cfg(linux_kernel)]
+use super::write_sockaddr::{encode_sockaddr_link, encode_sockaddr_netlink, encode_sockaddr_vsock};
 use crate::backend::c;
 use crate::backend::conv::{borrowed_fd, ret, ret_owned_fd, ret_send_recv, send_recv_len};
 use crate::fd::{BorrowedFd, OwnedFd};
 use crate::io;
+#[cfg(linux_kernel)]
+use crate::net::netlink::SocketAddrNetlink;
+#[cfg(linux_kernel)]
+use crate::net::packet::SocketAddrLink;
+#[cfg(linux_kernel)]
+use crate::net::vsock::SocketAddrVsock;
 #[cfg(target_os = "linux")]
 use crate::net::xdp::SocketAddrXdp;
 use crate::net::{SocketAddrAny, SocketAddrV4, SocketAddrV6};
 use crate::utils::as_ptr;
 use core::mem::{size_of, MaybeUninit};
+#[cfg(linux_kernel)]
+use {
+    super::msghdr::{recv_mmsghdr, send_mmsghdr},
+    crate::backend::conv::ret_c_int,
+    crate::net::{MMsgHdr, RecvMMsgHdr, Timespec, MMSG_MAX},
+    crate::utils::as_mut_ptr,
+    core::cmp::min,
+    core::slice,
+};
 #[cfg(not(any(
     windows,
     target_os = "espidf",
@@ -170,6 +189,63 @@ pub(crate) fn sendto_xdp(
     }
 }
 
+#[cfg(linux_kernel)]
+pub(crate) fn sendto_netlink(
+    fd: BorrowedFd<'_>,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrNetlink,
+) -> io::Result<usize> {
+    unsafe {
+        ret_send_recv(c::sendto(
+            borrowed_fd(fd),
+            buf.as_ptr().cast(),
+            send_recv_len(buf.len()),
+            bitflags_bits!(flags),
+            as_ptr(&encode_sockaddr_netlink(addr)).cast::<c::sockaddr>(),
+            size_of::<c::sockaddr_nl>() as _,
+        ))
+    }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn sendto_link(
+    fd: BorrowedFd<'_>,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrLink,
+) -> io::Result<usize> {
+    unsafe {
+        ret_send_recv(c::sendto(
+            borrowed_fd(fd),
+            buf.as_ptr().cast(),
+            send_recv_len(buf.len()),
+            bitflags_bits!(flags),
+            as_ptr(&encode_sockaddr_link(addr)).cast::<c::sockaddr>(),
+            size_of::<c::sockaddr_ll>() as _,
+        ))
+    }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn sendto_vsock(
+    fd: BorrowedFd<'_>,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrVsock,
+) -> io::Result<usize> {
+    unsafe {
+        ret_send_recv(c::sendto(
+            borrowed_fd(fd),
+            buf.as_ptr().cast(),
+            send_recv_len(buf.len()),
+            bitflags_bits!(flags),
+            as_ptr(&encode_sockaddr_vsock(addr)).cast::<c::sockaddr>(),
+            size_of::<c::sockaddr_vm>() as _,
+        ))
+    }
+}
+
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub(crate) fn socket(
     domain: AddressFamily,
@@ -253,6 +329,39 @@ pub(crate) fn bind_xdp(sockfd: BorrowedFd<'_>, addr: &SocketAddrXdp) -> io::Resu
     }
 }
 
+#[cfg(linux_kernel)]
+pub(crate) fn bind_netlink(sockfd: BorrowedFd<'_>, addr: &SocketAddrNetlink) -> io::Result<()> {
+    unsafe {
+        ret(c::bind(
+            borrowed_fd(sockfd),
+            as_ptr(&encode_sockaddr_netlink(addr)).cast(),
+            size_of::<c::sockaddr_nl>() as c::socklen_t,
+        ))
+    }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn bind_link(sockfd: BorrowedFd<'_>, addr: &SocketAddrLink) -> io::Result<()> {
+    unsafe {
+        ret(c::bind(
+            borrowed_fd(sockfd),
+            as_ptr(&encode_sockaddr_link(addr)).cast(),
+            size_of::<c::sockaddr_ll>() as c::socklen_t,
+        ))
+    }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn bind_vsock(sockfd: BorrowedFd<'_>, addr: &SocketAddrVsock) -> io::Result<()> {
+    unsafe {
+        ret(c::bind(
+            borrowed_fd(sockfd),
+            as_ptr(&encode_sockaddr_vsock(addr)).cast(),
+            size_of::<c::sockaddr_vm>() as c::socklen_t,
+        ))
+    }
+}
+
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub(crate) fn connect_v4(sockfd: BorrowedFd<'_>, addr: &SocketAddrV4) -> io::Result<()> {
     unsafe {
@@ -286,6 +395,28 @@ pub(crate) fn connect_unix(sockfd: BorrowedFd<'_>, addr: &SocketAddrUnix) -> io:
     }
 }
 
+#[cfg(linux_kernel)]
+pub(crate) fn connect_netlink(sockfd: BorrowedFd<'_>, addr: &SocketAddrNetlink) -> io::Result<()> {
+    unsafe {
+        ret(c::connect(
+            borrowed_fd(sockfd),
+            as_ptr(&encode_sockaddr_netlink(addr)).cast(),
+            size_of::<c::sockaddr_nl>() as c::socklen_t,
+        ))
+    }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn connect_vsock(sockfd: BorrowedFd<'_>, addr: &SocketAddrVsock) -> io::Result<()> {
+    unsafe {
+        ret(c::connect(
+            borrowed_fd(sockfd),
+            as_ptr(&encode_sockaddr_vsock(addr)).cast(),
+            size_of::<c::sockaddr_vm>() as c::socklen_t,
+        ))
+    }
+}
+
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub(crate) fn connect_unspec(sockfd: BorrowedFd<'_>) -> io::Result<()> {
     debug_assert_eq!(c::AF_UNSPEC, 0);
@@ -312,6 +443,90 @@ pub(crate) fn accept(sockfd: BorrowedFd<'_>) -> io::Result<OwnedFd> {
     }
 }
 
+#[cfg(linux_kernel)]
+pub(crate) fn sendmmsg(
+    sockfd: BorrowedFd<'_>,
+    msgs: &mut [MMsgHdr<'_>],
+    msg_flags: SendFlags,
+) -> io::Result<usize> {
+    let len = min(msgs.len(), MMSG_MAX);
+    let mut raw = [MaybeUninit::<c::mmsghdr>::uninit(); MMSG_MAX];
+    for (raw, msg) in raw.iter_mut().zip(msgs.iter()) {
+        raw.write(send_mmsghdr(msg));
+    }
+    // SAFETY: We initialized the first `len` elements above.
+    let raw = unsafe { slice::from_raw_parts_mut(raw.as_mut_ptr().cast::<c::mmsghdr>(), len) };
+
+    let nmsgs = unsafe {
+        ret_c_int(c::sendmmsg(
+            borrowed_fd(sockfd),
+            raw.as_mut_ptr(),
+            len as c::c_uint,
+            bitflags_bits!(msg_flags),
+        ))?
+    } as usize;
+
+    for (msg, raw) in msgs.iter_mut().zip(&raw[..nmsgs]) {
+        msg.bytes = raw.msg_len as usize;
+    }
+    Ok(nmsgs)
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn recvmmsg(
+    sockfd: BorrowedFd<'_>,
+    msgs: &mut [RecvMMsgHdr<'_>],
+    msg_flags: RecvFlags,
+    timeout: Option<&Timespec>,
+) -> io::Result<usize> {
+    let len = min(msgs.len(), MMSG_MAX);
+    let mut raw = [MaybeUninit::<c::mmsghdr>::uninit(); MMSG_MAX];
+    for (raw, msg) in raw.iter_mut().zip(msgs.iter_mut()) {
+        // SAFETY: `RecvMMsgHdr::new` is the only way to create a
+        // `RecvMMsgHdr`.
+        raw.write(unsafe { recv_mmsghdr(msg) });
+    }
+    // SAFETY: We initialized the first `len` elements above.
+    let raw = unsafe { slice::from_raw_parts_mut(raw.as_mut_ptr().cast::<c::mmsghdr>(), len) };
+
+    // Convert from `Timespec` to `c::timespec`. This also hides the fact that
+    // Linux's `recvmmsg` mutates the timeout argument.
+    let mut timeout_data;
+    let timeout_ptr = match timeout {
+        Some(timeout) => {
+            timeout_data = c::timespec {
+                tv_sec: timeout.tv_sec.try_into().map_err(|_| io::Errno::OVERFLOW)?,
+                tv_nsec: timeout.tv_nsec as _,
+            };
+            as_mut_ptr(&mut timeout_data)
+        }
+        None => null_mut(),
+    };
+
+    let nmsgs = unsafe {
+        ret_c_int(c::recvmmsg(
+            borrowed_fd(sockfd),
+            raw.as_mut_ptr(),
+            len as c::c_uint,
+            bitflags_bits!(msg_flags) as _,
+            timeout_ptr,
+        ))?
+    } as usize;
+
+    for (msg, raw) in msgs.iter_mut().zip(&raw[..nmsgs]) {
+        let hdr = &raw.msg_hdr;
+        msg.bytes = raw.msg_len as usize;
+        msg.flags = RecvFlags::from_bits_retain(bitcast!(hdr.msg_flags));
+        // Get the address of the sender, if any.
+        msg.address = unsafe { maybe_read_sockaddr_os(hdr.msg_name as _, hdr.msg_namelen as _) };
+        // Reset the control length.
+        unsafe {
+            (*msg.control).set_control_len(hdr.msg_controllen.try_into().unwrap_or(usize::MAX));
+        }
+    }
+    Ok(nmsgs)
+}
+
 #[cfg(not(any(
     windows,
     target_os = "espidf",
@@ -455,6 +670,57 @@ pub(crate) fn sendmsg_xdp(
     })
 }
 
+#[cfg(linux_kernel)]
+pub(crate) fn sendmsg_netlink(
+    sockfd: BorrowedFd<'_>,
+    addr: &SocketAddrNetlink,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    msg_flags: SendFlags,
+) -> io::Result<usize> {
+    with_netlink_msghdr(addr, iov, control, |msghdr| unsafe {
+        ret_send_recv(c::sendmsg(
+            borrowed_fd(sockfd),
+            &msghdr,
+            bitflags_bits!(msg_flags),
+        ))
+    })
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn sendmsg_link(
+    sockfd: BorrowedFd<'_>,
+    addr: &SocketAddrLink,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    msg_flags: SendFlags,
+) -> io::Result<usize> {
+    with_link_msghdr(addr, iov, control, |msghdr| unsafe {
+        ret_send_recv(c::sendmsg(
+            borrowed_fd(sockfd),
+            &msghdr,
+            bitflags_bits!(msg_flags),
+        ))
+    })
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn sendmsg_vsock(
+    sockfd: BorrowedFd<'_>,
+    addr: &SocketAddrVsock,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    msg_flags: SendFlags,
+) -> io::Result<usize> {
+    with_vsock_msghdr(addr, iov, control, |msghdr| unsafe {
+        ret_send_recv(c::sendmsg(
+            borrowed_fd(sockfd),
+            &msghdr,
+            bitflags_bits!(msg_flags),
+        ))
+    })
+}
+
 #[cfg(not(any(
     apple,
     windows,
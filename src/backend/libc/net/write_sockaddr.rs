@@ -6,8 +6,16 @@ use super::addr::SocketAddrStorage;
 use super::addr::SocketAddrUnix;
 use super::ext::{in6_addr_new, in_addr_new, sockaddr_in6_new};
 use crate::backend::c;
+#[cfg(linux_kernel)]
+use crate::net::netlink::SocketAddrNetlink;
+#[cfg(linux_kernel)]
+use crate::net::packet::SocketAddrLink;
+#[cfg(linux_kernel)]
+use crate::net::vsock::SocketAddrVsock;
 #[cfg(target_os = "linux")]
 use crate::net::xdp::SocketAddrXdp;
+#[cfg(linux_kernel)]
+use crate::net::{Ipv4PktInfo, Ipv6PktInfo};
 use crate::net::{SocketAddrAny, SocketAddrV4, SocketAddrV6};
 use core::mem::size_of;
 
@@ -22,6 +30,12 @@ pub(crate) unsafe fn write_sockaddr(
         SocketAddrAny::Unix(unix) => write_sockaddr_unix(unix, storage),
         #[cfg(target_os = "linux")]
         SocketAddrAny::Xdp(xdp) => write_sockaddr_xdp(xdp, storage),
+        #[cfg(linux_kernel)]
+        SocketAddrAny::Netlink(netlink) => write_sockaddr_netlink(netlink, storage),
+        #[cfg(linux_kernel)]
+        SocketAddrAny::Link(link) => write_sockaddr_link(link, storage),
+        #[cfg(linux_kernel)]
+        SocketAddrAny::Vsock(vsock) => write_sockaddr_vsock(vsock, storage),
     }
 }
 
@@ -126,3 +140,83 @@ unsafe fn write_sockaddr_xdp(xdp: &SocketAddrXdp, storage: *mut SocketAddrStorag
     core::ptr::write(storage.cast(), encoded);
     size_of::<c::sockaddr_xdp>()
 }
+
+#[cfg(linux_kernel)]
+pub(crate) fn encode_sockaddr_netlink(netlink: &SocketAddrNetlink) -> c::sockaddr_nl {
+    // libc's `sockaddr_nl` has a private padding field, so start from zeros.
+    let mut encoded: c::sockaddr_nl = unsafe { core::mem::zeroed() };
+    encoded.nl_family = c::AF_NETLINK as _;
+    encoded.nl_pid = netlink.pid();
+    encoded.nl_groups = netlink.groups();
+    encoded
+}
+
+#[cfg(linux_kernel)]
+unsafe fn write_sockaddr_netlink(
+    netlink: &SocketAddrNetlink,
+    storage: *mut SocketAddrStorage,
+) -> usize {
+    let encoded = encode_sockaddr_netlink(netlink);
+    core::ptr::write(storage.cast(), encoded);
+    size_of::<c::sockaddr_nl>()
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn encode_sockaddr_link(link: &SocketAddrLink) -> c::sockaddr_ll {
+    let address = link.address();
+    let mut sll_addr = [0; 8];
+    sll_addr[..address.len()].copy_from_slice(address);
+    c::sockaddr_ll {
+        sll_family: c::AF_PACKET as _,
+        sll_protocol: link
+            .protocol()
+            .map_or(0, |protocol| protocol.as_raw().get() as u16),
+        sll_ifindex: link.interface_index() as _,
+        sll_hatype: link.hardware_type(),
+        sll_pkttype: link.packet_type().as_raw(),
+        sll_halen: address.len() as _,
+        sll_addr,
+    }
+}
+
+#[cfg(linux_kernel)]
+unsafe fn write_sockaddr_link(link: &SocketAddrLink, storage: *mut SocketAddrStorage) -> usize {
+    let encoded = encode_sockaddr_link(link);
+    core::ptr::write(storage.cast(), encoded);
+    size_of::<c::sockaddr_ll>()
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn encode_sockaddr_vsock(vsock: &SocketAddrVsock) -> c::sockaddr_vm {
+    // The layout of the trailing bytes of `sockaddr_vm` varies between libc
+    // versions, so start from zeros.
+    let mut encoded: c::sockaddr_vm = unsafe { core::mem::zeroed() };
+    encoded.svm_family = c::AF_VSOCK as _;
+    encoded.svm_port = vsock.port();
+    encoded.svm_cid = vsock.cid();
+    encoded
+}
+
+#[cfg(linux_kernel)]
+unsafe fn write_sockaddr_vsock(vsock: &SocketAddrVsock, storage: *mut SocketAddrStorage) -> usize {
+    let encoded = encode_sockaddr_vsock(vsock);
+    core::ptr::write(storage.cast(), encoded);
+    size_of::<c::sockaddr_vm>()
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn encode_in_pktinfo(info: &Ipv4PktInfo) -> c::in_pktinfo {
+    c::in_pktinfo {
+        ipi_ifindex: info.ifindex as _,
+        ipi_spec_dst: in_addr_new(u32::from_ne_bytes(info.spec_dst.octets())),
+        ipi_addr: in_addr_new(u32::from_ne_bytes(info.addr.octets())),
+    }
+}
+
+#[cfg(linux_kernel)]
+pub(crate) fn encode_in6_pktinfo(info: &Ipv6PktInfo) -> c::in6_pktinfo {
+    c::in6_pktinfo {
+        ipi6_addr: in6_addr_new(info.addr.octets()),
+        ipi6_ifindex: info.ifindex as _,
+    }
+}
@@ -0,0 +1,27 @@
+//! libc syscalls supporting `rustix::perf_event`.
+
+use crate::backend::c;
+use crate::backend::conv::ret_owned_fd;
+use crate::fd::OwnedFd;
+use crate::io;
+use crate::perf_event::PerfEventAttr;
+
+#[inline]
+pub(crate) fn perf_event_open(
+    attr: &PerfEventAttr,
+    pid: c::c_int,
+    cpu: c::c_int,
+    group_fd: c::c_int,
+    flags: c::c_uint,
+) -> io::Result<OwnedFd> {
+    syscall! {
+        fn perf_event_open(
+            attr: *const PerfEventAttr,
+            pid: c::pid_t,
+            cpu: c::c_int,
+            group_fd: c::c_int,
+            flags: c::c_ulong
+        ) via SYS_perf_event_open -> c::c_int
+    }
+    unsafe { ret_owned_fd(perf_event_open(attr, pid, cpu, group_fd, flags.into())) }
+}
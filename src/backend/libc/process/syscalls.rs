@@ -23,7 +23,7 @@ use crate::backend::conv::{ret, ret_c_int};
 #[cfg(not(any(target_os = "wasi", target_os = "fuchsia")))]
 use crate::fd::BorrowedFd;
 #[cfg(target_os = "linux")]
-use crate::fd::{AsRawFd, OwnedFd, RawFd};
+use crate::fd::{AsRawFd, RawFd};
 #[cfg(any(target_os = "linux", feature = "fs"))]
 use crate::ffi::CStr;
 #[cfg(feature = "fs")]
@@ -61,10 +61,14 @@ use crate::process::{Resource, Rlimit};
 )))]
 use crate::process::{WaitId, WaitIdOptions, WaitIdStatus};
 use core::mem::MaybeUninit;
-#[cfg(target_os = "linux")]
+#[cfg(linux_kernel)]
 use {
-    super::super::conv::ret_owned_fd, crate::process::PidfdFlags, crate::process::PidfdGetfdFlags,
+    crate::backend::conv::{ret_owned_fd, ret_u32},
+    crate::fd::OwnedFd,
+    crate::process::landlock,
 };
+#[cfg(target_os = "linux")]
+use {crate::process::PidfdFlags, crate::process::PidfdGetfdFlags};
 
 #[cfg(feature = "fs")]
 #[cfg(not(target_os = "wasi"))]
@@ -646,3 +650,101 @@ pub(crate) fn getgroups(buf: &mut [Gid]) -> io::Result<usize> {
 
     unsafe { ret_usize(c::getgroups(len, buf.as_mut_ptr().cast()) as isize) }
 }
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn landlock_abi_version() -> io::Result<u32> {
+    syscall! {
+        fn landlock_create_ruleset(
+            attr: *const landlock::RulesetAttr,
+            size: c::size_t,
+            flags: u32
+        ) via SYS_landlock_create_ruleset -> c::c_int
+    }
+    unsafe {
+        ret_u32(landlock_create_ruleset(
+            core::ptr::null(),
+            0,
+            landlock::CREATE_RULESET_VERSION,
+        ))
+    }
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn landlock_create_ruleset(attr: &landlock::RulesetAttr) -> io::Result<OwnedFd> {
+    syscall! {
+        fn landlock_create_ruleset(
+            attr: *const landlock::RulesetAttr,
+            size: c::size_t,
+            flags: u32
+        ) via SYS_landlock_create_ruleset -> c::c_int
+    }
+    unsafe {
+        ret_owned_fd(landlock_create_ruleset(
+            attr,
+            core::mem::size_of::<landlock::RulesetAttr>(),
+            0,
+        ))
+    }
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn landlock_add_path_beneath_rule(
+    ruleset: BorrowedFd<'_>,
+    attr: &landlock::PathBeneathAttr,
+) -> io::Result<()> {
+    syscall! {
+        fn landlock_add_rule(
+            ruleset_fd: c::c_int,
+            rule_type: u32,
+            rule_attr: *const landlock::PathBeneathAttr,
+            flags: u32
+        ) via SYS_landlock_add_rule -> c::c_int
+    }
+    unsafe {
+        ret(landlock_add_rule(
+            borrowed_fd(ruleset),
+            landlock::RULE_PATH_BENEATH,
+            attr,
+            0,
+        ))
+    }
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn landlock_add_net_port_rule(
+    ruleset: BorrowedFd<'_>,
+    attr: &landlock::NetPortAttr,
+) -> io::Result<()> {
+    syscall! {
+        fn landlock_add_rule(
+            ruleset_fd: c::c_int,
+            rule_type: u32,
+            rule_attr: *const landlock::NetPortAttr,
+            flags: u32
+        ) via SYS_landlock_add_rule -> c::c_int
+    }
+    unsafe {
+        ret(landlock_add_rule(
+            borrowed_fd(ruleset),
+            landlock::RULE_NET_PORT,
+            attr,
+            0,
+        ))
+    }
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn landlock_restrict_self(ruleset: BorrowedFd<'_>) -> io::Result<()> {
+    syscall! {
+        fn landlock_restrict_self(
+            ruleset_fd: c::c_int,
+            flags: u32
+        ) via SYS_landlock_restrict_self -> c::c_int
+    }
+    unsafe { ret(landlock_restrict_self(borrowed_fd(ruleset), 0)) }
+}
@@ -0,0 +1,3 @@
+#[cfg(feature = "signal")]
+pub(crate) mod syscalls;
+pub(crate) mod types;
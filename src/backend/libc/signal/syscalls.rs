@@ -0,0 +1,144 @@
+//! libc syscalls supporting `rustix::signal`.
+
+use super::types::{RawSigSet, RawSiginfo};
+use crate::backend::c;
+use crate::backend::conv::{ret, ret_c_int};
+use crate::io;
+use crate::signal::{SigAction, SigHandler, SigSet, SigactionFlags, Siginfo, SigmaskHow, Signal};
+use crate::timespec::Timespec;
+use crate::utils::option_as_ptr;
+use core::mem::{transmute, zeroed, MaybeUninit};
+
+#[inline]
+pub(crate) fn thread_sigmask(how: SigmaskHow, set: Option<&SigSet>) -> io::Result<SigSet> {
+    let mut old = MaybeUninit::<RawSigSet>::uninit();
+    let new = option_as_ptr(set.map(SigSet::as_raw));
+    unsafe {
+        // `pthread_sigmask` returns the error code rather than setting
+        // `errno`.
+        match c::pthread_sigmask(how as c::c_int, new, old.as_mut_ptr()) {
+            0 => Ok(SigSet::from_raw(old.assume_init())),
+            err => Err(io::Errno(err)),
+        }
+    }
+}
+
+#[inline]
+pub(crate) fn sigpending() -> SigSet {
+    let mut pending = MaybeUninit::<RawSigSet>::uninit();
+    unsafe {
+        // `sigpending` only fails if given an invalid pointer.
+        let _ = c::sigpending(pending.as_mut_ptr());
+        SigSet::from_raw(pending.assume_init())
+    }
+}
+
+#[inline]
+pub(crate) fn sigwait(set: &SigSet) -> io::Result<Signal> {
+    let mut signum = MaybeUninit::<c::c_int>::uninit();
+    unsafe {
+        // Like `pthread_sigmask`, `sigwait` returns the error code.
+        match c::sigwait(set.as_raw(), signum.as_mut_ptr()) {
+            0 => Signal::from_raw(signum.assume_init()).ok_or(io::Errno::NOTSUP),
+            err => Err(io::Errno(err)),
+        }
+    }
+}
+
+#[inline]
+pub(crate) fn sigwaitinfo(set: &SigSet) -> io::Result<RawSiginfo> {
+    let mut info = MaybeUninit::<RawSiginfo>::uninit();
+    unsafe {
+        let _signum = ret_c_int(c::sigwaitinfo(set.as_raw(), info.as_mut_ptr().cast()))?;
+        Ok(info.assume_init())
+    }
+}
+
+#[inline]
+pub(crate) fn sigtimedwait(set: &SigSet, timeout: Option<&Timespec>) -> io::Result<RawSiginfo> {
+    let mut info = MaybeUninit::<RawSiginfo>::uninit();
+
+    // The libc `sigtimedwait` is not y2038-compatible on these platforms, so
+    // convert the timeout, failing if it doesn't fit.
+    #[cfg(fix_y2038)]
+    let timeout = match timeout {
+        Some(timeout) => Some(c::timespec {
+            tv_sec: timeout.tv_sec.try_into().map_err(|_| io::Errno::OVERFLOW)?,
+            tv_nsec: timeout.tv_nsec as _,
+        }),
+        None => None,
+    };
+    #[cfg(fix_y2038)]
+    let timeout = timeout.as_ref();
+
+    unsafe {
+        let _signum = ret_c_int(c::sigtimedwait(
+            set.as_raw(),
+            info.as_mut_ptr().cast(),
+            option_as_ptr(timeout),
+        ))?;
+        Ok(info.assume_init())
+    }
+}
+
+pub(crate) unsafe fn sigaction(signal: Signal, new: Option<&SigAction>) -> io::Result<SigAction> {
+    let new = new.map(encode_sigaction);
+    let mut old = MaybeUninit::<c::sigaction>::uninit();
+    ret(c::sigaction(
+        signal as c::c_int,
+        option_as_ptr(new.as_ref()),
+        old.as_mut_ptr(),
+    ))?;
+    Ok(decode_sigaction(&old.assume_init()))
+}
+
+type HandlerFn = extern "C" fn(c::c_int);
+type SigInfoFn = extern "C" fn(c::c_int, *mut Siginfo, *mut c::c_void);
+
+fn encode_sigaction(action: &SigAction) -> c::sigaction {
+    // The layout of `struct sigaction` varies between platforms, so start
+    // from zeros and fill in the fields that are common to all of them.
+    let mut raw: c::sigaction = unsafe { zeroed() };
+    let mut flags = action.flags.bits() as c::c_int;
+
+    raw.sa_sigaction = match action.handler {
+        SigHandler::Default => c::SIG_DFL,
+        SigHandler::Ignore => c::SIG_IGN,
+        SigHandler::Handler(handler) => handler as c::sighandler_t,
+        SigHandler::SigInfo(handler) => {
+            flags |= c::SA_SIGINFO;
+            handler as c::sighandler_t
+        }
+    };
+
+    raw.sa_flags = flags as _;
+    raw.sa_mask = *action.mask.as_raw();
+    raw
+}
+
+unsafe fn decode_sigaction(raw: &c::sigaction) -> SigAction {
+    let flags = raw.sa_flags as c::c_int;
+    let handler = match raw.sa_sigaction {
+        c::SIG_DFL => SigHandler::Default,
+        c::SIG_IGN => SigHandler::Ignore,
+        handler if flags & c::SA_SIGINFO != 0 => {
+            SigHandler::SigInfo(transmute::<c::sighandler_t, SigInfoFn>(handler))
+        }
+        handler => SigHandler::Handler(transmute::<c::sighandler_t, HandlerFn>(handler)),
+    };
+
+    SigAction {
+        handler,
+        // `SA_SIGINFO` is expressed by the handler, and libc may add flags of
+        // its own, such as `SA_RESTORER`, so only report the flags we know.
+        flags: SigactionFlags::from_bits_retain(flags as u32).intersection(
+            SigactionFlags::NOCLDSTOP
+                | SigactionFlags::NOCLDWAIT
+                | SigactionFlags::ONSTACK
+                | SigactionFlags::RESTART
+                | SigactionFlags::NODEFER
+                | SigactionFlags::RESETHAND,
+        ),
+        mask: SigSet::from_raw(raw.sa_mask),
+    }
+}
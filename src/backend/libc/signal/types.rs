@@ -0,0 +1,133 @@
+use crate::backend::c;
+#[cfg(feature = "signal")]
+use crate::ugid::Uid;
+#[cfg(feature = "signal")]
+use bitflags::bitflags;
+use core::mem::MaybeUninit;
+
+/// The libc signal set type.
+pub(crate) type RawSigSet = c::sigset_t;
+
+#[inline]
+pub(crate) fn sigemptyset() -> RawSigSet {
+    let mut set = MaybeUninit::<RawSigSet>::uninit();
+    unsafe {
+        // `sigemptyset` can't fail when given a valid pointer.
+        let _ = c::sigemptyset(set.as_mut_ptr());
+        set.assume_init()
+    }
+}
+
+#[inline]
+pub(crate) fn sigaddset(set: &mut RawSigSet, sig: c::c_int) {
+    // `sigaddset` only fails if `sig` is not a valid signal number, and our
+    // callers only pass values from `Signal`.
+    unsafe {
+        let _ = c::sigaddset(set, sig);
+    }
+}
+
+#[inline]
+pub(crate) fn sigdelset(set: &mut RawSigSet, sig: c::c_int) {
+    // As with `sigaddset`, this only fails on invalid signal numbers.
+    unsafe {
+        let _ = c::sigdelset(set, sig);
+    }
+}
+
+#[inline]
+pub(crate) fn sigismember(set: &RawSigSet, sig: c::c_int) -> bool {
+    unsafe { c::sigismember(set, sig) == 1 }
+}
+
+/// The kernel's `siginfo_t`, which has the same layout as libc's on Linux.
+///
+/// libc doesn't provide accessors for all of the union fields on all
+/// platforms, so we use the kernel's definition instead.
+#[cfg(feature = "signal")]
+pub(crate) type RawSiginfo = linux_raw_sys::general::siginfo_t;
+
+// The fields of `siginfo_t` after `si_code` live in a union whose active
+// member depends on the signal and `si_code`. Each of these accessors reads
+// plain integer or pointer data, for which every bit pattern is valid.
+
+#[cfg(feature = "signal")]
+#[inline]
+pub(crate) fn siginfo_signo(info: &RawSiginfo) -> c::c_int {
+    unsafe { info.__bindgen_anon_1.__bindgen_anon_1.si_signo }
+}
+
+#[cfg(feature = "signal")]
+#[inline]
+pub(crate) fn siginfo_errno(info: &RawSiginfo) -> c::c_int {
+    unsafe { info.__bindgen_anon_1.__bindgen_anon_1.si_errno }
+}
+
+#[cfg(feature = "signal")]
+#[inline]
+pub(crate) fn siginfo_code(info: &RawSiginfo) -> c::c_int {
+    unsafe { info.__bindgen_anon_1.__bindgen_anon_1.si_code }
+}
+
+#[cfg(feature = "signal")]
+#[inline]
+pub(crate) fn siginfo_pid(info: &RawSiginfo) -> c::pid_t {
+    unsafe { info.__bindgen_anon_1.__bindgen_anon_1._sifields._kill._pid }
+}
+
+#[cfg(feature = "signal")]
+#[inline]
+pub(crate) fn siginfo_uid(info: &RawSiginfo) -> Uid {
+    unsafe { Uid::from_raw(info.__bindgen_anon_1.__bindgen_anon_1._sifields._kill._uid) }
+}
+
+#[cfg(feature = "signal")]
+#[inline]
+pub(crate) fn siginfo_status(info: &RawSiginfo) -> c::c_int {
+    unsafe {
+        info.__bindgen_anon_1
+            .__bindgen_anon_1
+            ._sifields
+            ._sigchld
+            ._status
+    }
+}
+
+#[cfg(feature = "signal")]
+#[inline]
+pub(crate) fn siginfo_addr(info: &RawSiginfo) -> *mut c::c_void {
+    unsafe {
+        info.__bindgen_anon_1
+            .__bindgen_anon_1
+            ._sifields
+            ._sigfault
+            ._addr
+            .cast()
+    }
+}
+
+#[cfg(feature = "signal")]
+bitflags! {
+    /// `SA_*` flags for use with [`SigAction`].
+    ///
+    /// [`SigAction`]: crate::signal::SigAction
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct SigactionFlags: u32 {
+        /// `SA_NOCLDSTOP`
+        const NOCLDSTOP = bitcast!(c::SA_NOCLDSTOP);
+        /// `SA_NOCLDWAIT`
+        const NOCLDWAIT = bitcast!(c::SA_NOCLDWAIT);
+        /// `SA_ONSTACK`
+        const ONSTACK = bitcast!(c::SA_ONSTACK);
+        /// `SA_RESTART`
+        const RESTART = bitcast!(c::SA_RESTART);
+        /// `SA_NODEFER`
+        const NODEFER = bitcast!(c::SA_NODEFER);
+        /// `SA_RESETHAND`
+        const RESETHAND = bitcast!(c::SA_RESETHAND);
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
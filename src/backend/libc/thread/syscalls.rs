@@ -36,9 +36,10 @@ use core::mem::MaybeUninit;
 use core::sync::atomic::AtomicU32;
 #[cfg(linux_kernel)]
 use {
-    crate::backend::conv::{borrowed_fd, ret_c_int, ret_u32, ret_usize},
-    crate::fd::BorrowedFd,
-    crate::thread::futex,
+    crate::backend::conv::{borrowed_fd, ret_c_int, ret_owned_fd, ret_u32, ret_usize},
+    crate::bpf_filter::SockFprog,
+    crate::fd::{BorrowedFd, OwnedFd},
+    crate::thread::{futex, seccomp},
     crate::utils::as_mut_ptr,
 };
 
@@ -741,3 +742,45 @@ pub(crate) fn membarrier_cpu(cmd: MembarrierCommand, cpu: Cpuid) -> io::Result<(
         ))
     }
 }
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn seccomp_set_mode_filter(flags: u32, prog: &SockFprog) -> io::Result<c::c_int> {
+    syscall! {
+        fn seccomp(
+            operation: u32,
+            flags: u32,
+            args: *const SockFprog
+        ) via SYS_seccomp -> c::c_int
+    }
+    unsafe { ret_c_int(seccomp(seccomp::SET_MODE_FILTER, flags, prog)) }
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn seccomp_set_mode_filter_listener(
+    flags: u32,
+    prog: &SockFprog,
+) -> io::Result<OwnedFd> {
+    syscall! {
+        fn seccomp(
+            operation: u32,
+            flags: u32,
+            args: *const SockFprog
+        ) via SYS_seccomp -> c::c_int
+    }
+    unsafe { ret_owned_fd(seccomp(seccomp::SET_MODE_FILTER, flags, prog)) }
+}
+
+#[cfg(linux_kernel)]
+#[inline]
+pub(crate) fn seccomp_get_action_avail(action: &u32) -> io::Result<()> {
+    syscall! {
+        fn seccomp(
+            operation: u32,
+            flags: u32,
+            args: *const u32
+        ) via SYS_seccomp -> c::c_int
+    }
+    unsafe { ret(seccomp(seccomp::GET_ACTION_AVAIL, 0, action)) }
+}
@@ -0,0 +1,78 @@
+//! linux_raw syscalls supporting `rustix::bpf`.
+//!
+//! # Safety
+//!
+//! See the `rustix::backend::syscalls` module documentation for details.
+#![allow(unsafe_code, clippy::undocumented_unsafe_blocks)]
+
+use crate::backend::conv::{by_ref, c_uint, ret, ret_owned_fd, size_of};
+use crate::bpf::{
+    MapCreateAttr, MapElemAttr, ObjAttr, ProgAttachAttr, ProgLoadAttr, BPF_MAP_CREATE, BPF_OBJ_GET,
+    BPF_OBJ_PIN, BPF_PROG_LOAD,
+};
+use crate::fd::OwnedFd;
+use crate::io;
+
+#[inline]
+pub(crate) fn bpf_map_create(attr: &MapCreateAttr) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_bpf,
+            c_uint(BPF_MAP_CREATE),
+            by_ref(attr),
+            size_of::<MapCreateAttr, _>()
+        ))
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn bpf_map_elem(cmd: u32, attr: &MapElemAttr) -> io::Result<()> {
+    ret(syscall!(
+        __NR_bpf,
+        c_uint(cmd),
+        by_ref(attr),
+        size_of::<MapElemAttr, _>()
+    ))
+}
+
+#[inline]
+pub(crate) unsafe fn bpf_prog_load(attr: &ProgLoadAttr) -> io::Result<OwnedFd> {
+    ret_owned_fd(syscall!(
+        __NR_bpf,
+        c_uint(BPF_PROG_LOAD),
+        by_ref(attr),
+        size_of::<ProgLoadAttr, _>()
+    ))
+}
+
+#[inline]
+pub(crate) unsafe fn bpf_obj_pin(attr: &ObjAttr) -> io::Result<()> {
+    ret(syscall_readonly!(
+        __NR_bpf,
+        c_uint(BPF_OBJ_PIN),
+        by_ref(attr),
+        size_of::<ObjAttr, _>()
+    ))
+}
+
+#[inline]
+pub(crate) unsafe fn bpf_obj_get(attr: &ObjAttr) -> io::Result<OwnedFd> {
+    ret_owned_fd(syscall_readonly!(
+        __NR_bpf,
+        c_uint(BPF_OBJ_GET),
+        by_ref(attr),
+        size_of::<ObjAttr, _>()
+    ))
+}
+
+#[inline]
+pub(crate) fn bpf_prog_attach(cmd: u32, attr: &ProgAttachAttr) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_bpf,
+            c_uint(cmd),
+            by_ref(attr),
+            size_of::<ProgAttachAttr, _>()
+        ))
+    }
+}
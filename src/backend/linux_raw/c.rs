@@ -56,29 +56,42 @@ pub(crate) use linux_raw_sys::{
     cmsg_macros::*,
     general::{O_CLOEXEC as SOCK_CLOEXEC, O_NONBLOCK as SOCK_NONBLOCK},
     if_ether::*,
+    if_packet::{
+        packet_mreq, sockaddr_ll, tpacket_req3, tpacket_stats_v3, PACKET_ADD_MEMBERSHIP,
+        PACKET_DROP_MEMBERSHIP, PACKET_FANOUT, PACKET_RX_RING, PACKET_STATISTICS, PACKET_TX_RING,
+        PACKET_VERSION,
+    },
     net::{
-        linger, msghdr, sockaddr, sockaddr_in, sockaddr_in6, sockaddr_un, socklen_t, AF_DECnet,
         __kernel_sa_family_t as sa_family_t, __kernel_sockaddr_storage as sockaddr_storage,
-        cmsghdr, in6_addr, in_addr, ip_mreq, ip_mreq_source, ip_mreqn, ipv6_mreq, AF_APPLETALK,
-        AF_ASH, AF_ATMPVC, AF_ATMSVC, AF_AX25, AF_BLUETOOTH, AF_BRIDGE, AF_CAN, AF_ECONET,
-        AF_IEEE802154, AF_INET, AF_INET6, AF_IPX, AF_IRDA, AF_ISDN, AF_IUCV, AF_KEY, AF_LLC,
-        AF_NETBEUI, AF_NETLINK, AF_NETROM, AF_PACKET, AF_PHONET, AF_PPPOX, AF_RDS, AF_ROSE,
-        AF_RXRPC, AF_SECURITY, AF_SNA, AF_TIPC, AF_UNIX, AF_UNSPEC, AF_WANPIPE, AF_X25, AF_XDP,
-        IP6T_SO_ORIGINAL_DST, IPPROTO_FRAGMENT, IPPROTO_ICMPV6, IPPROTO_MH, IPPROTO_ROUTING,
-        IPV6_ADD_MEMBERSHIP, IPV6_DROP_MEMBERSHIP, IPV6_FREEBIND, IPV6_MULTICAST_HOPS,
-        IPV6_MULTICAST_LOOP, IPV6_RECVTCLASS, IPV6_TCLASS, IPV6_UNICAST_HOPS, IPV6_V6ONLY,
-        IP_ADD_MEMBERSHIP, IP_ADD_SOURCE_MEMBERSHIP, IP_DROP_MEMBERSHIP, IP_DROP_SOURCE_MEMBERSHIP,
-        IP_FREEBIND, IP_MULTICAST_LOOP, IP_MULTICAST_TTL, IP_RECVTOS, IP_TOS, IP_TTL,
-        MSG_CMSG_CLOEXEC, MSG_CONFIRM, MSG_DONTROUTE, MSG_DONTWAIT, MSG_EOR, MSG_ERRQUEUE,
-        MSG_MORE, MSG_NOSIGNAL, MSG_OOB, MSG_PEEK, MSG_TRUNC, MSG_WAITALL, SCM_CREDENTIALS,
-        SCM_RIGHTS, SHUT_RD, SHUT_RDWR, SHUT_WR, SOCK_DGRAM, SOCK_RAW, SOCK_RDM, SOCK_SEQPACKET,
-        SOCK_STREAM, SOL_SOCKET, SOL_XDP, SO_ACCEPTCONN, SO_BROADCAST, SO_COOKIE, SO_DOMAIN,
-        SO_ERROR, SO_INCOMING_CPU, SO_KEEPALIVE, SO_LINGER, SO_OOBINLINE, SO_ORIGINAL_DST,
-        SO_PASSCRED, SO_PROTOCOL, SO_RCVBUF, SO_RCVBUFFORCE, SO_RCVTIMEO_NEW,
-        SO_RCVTIMEO_NEW as SO_RCVTIMEO, SO_RCVTIMEO_OLD, SO_REUSEADDR, SO_REUSEPORT, SO_SNDBUF,
-        SO_SNDTIMEO_NEW, SO_SNDTIMEO_NEW as SO_SNDTIMEO, SO_SNDTIMEO_OLD, SO_TYPE, TCP_CONGESTION,
-        TCP_CORK, TCP_KEEPCNT, TCP_KEEPIDLE, TCP_KEEPINTVL, TCP_NODELAY, TCP_QUICKACK,
-        TCP_THIN_LINEAR_TIMEOUTS, TCP_USER_TIMEOUT,
+        cmsghdr, in6_addr, in6_pktinfo, in_addr, in_pktinfo, ip_mreq, ip_mreq_source, ip_mreqn,
+        ipv6_mreq, linger, mmsghdr, msghdr, sockaddr, sockaddr_in, sockaddr_in6, sockaddr_un,
+        socklen_t, AF_DECnet, AF_APPLETALK, AF_ASH, AF_ATMPVC, AF_ATMSVC, AF_AX25, AF_BLUETOOTH,
+        AF_BRIDGE, AF_CAN, AF_ECONET, AF_IEEE802154, AF_INET, AF_INET6, AF_IPX, AF_IRDA, AF_ISDN,
+        AF_IUCV, AF_KEY, AF_LLC, AF_NETBEUI, AF_NETLINK, AF_NETROM, AF_PACKET, AF_PHONET, AF_PPPOX,
+        AF_RDS, AF_ROSE, AF_RXRPC, AF_SECURITY, AF_SNA, AF_TIPC, AF_UNIX, AF_UNSPEC, AF_VSOCK,
+        AF_WANPIPE, AF_X25, AF_XDP, IP6T_SO_ORIGINAL_DST, IPPROTO_FRAGMENT, IPPROTO_ICMPV6,
+        IPPROTO_MH, IPPROTO_ROUTING, IPV6_ADD_MEMBERSHIP, IPV6_DROP_MEMBERSHIP, IPV6_FREEBIND,
+        IPV6_MTU, IPV6_MTU_DISCOVER, IPV6_MULTICAST_HOPS, IPV6_MULTICAST_LOOP, IPV6_PKTINFO,
+        IPV6_RECVERR, IPV6_RECVPKTINFO, IPV6_RECVTCLASS, IPV6_TCLASS, IPV6_TRANSPARENT,
+        IPV6_UNICAST_HOPS, IPV6_V6ONLY, IP_ADD_MEMBERSHIP, IP_ADD_SOURCE_MEMBERSHIP,
+        IP_BIND_ADDRESS_NO_PORT, IP_DROP_MEMBERSHIP, IP_DROP_SOURCE_MEMBERSHIP, IP_FREEBIND,
+        IP_MTU, IP_MTU_DISCOVER, IP_MULTICAST_LOOP, IP_MULTICAST_TTL, IP_PKTINFO, IP_RECVERR,
+        IP_RECVTOS, IP_TOS, IP_TRANSPARENT, IP_TTL, MSG_CMSG_CLOEXEC, MSG_CONFIRM, MSG_DONTROUTE,
+        MSG_DONTWAIT, MSG_EOR, MSG_ERRQUEUE, MSG_MORE, MSG_NOSIGNAL, MSG_OOB, MSG_PEEK, MSG_TRUNC,
+        MSG_WAITALL, SCM_CREDENTIALS, SCM_RIGHTS, SHUT_RD, SHUT_RDWR, SHUT_WR, SOCK_DGRAM,
+        SOCK_RAW, SOCK_RDM, SOCK_SEQPACKET, SOCK_STREAM, SOL_SOCKET, SOL_TLS, SOL_XDP,
+        SO_ACCEPTCONN, SO_ATTACH_FILTER, SO_ATTACH_REUSEPORT_CBPF, SO_BINDTODEVICE,
+        SO_BINDTOIFINDEX, SO_BROADCAST, SO_COOKIE, SO_DETACH_FILTER, SO_DETACH_REUSEPORT_BPF,
+        SO_DOMAIN, SO_ERROR, SO_INCOMING_CPU, SO_KEEPALIVE, SO_LINGER, SO_LOCK_FILTER, SO_MARK,
+        SO_OOBINLINE, SO_ORIGINAL_DST, SO_PASSCRED, SO_PRIORITY, SO_PROTOCOL, SO_RCVBUF,
+        SO_RCVBUFFORCE, SO_RCVTIMEO_NEW, SO_RCVTIMEO_NEW as SO_RCVTIMEO, SO_RCVTIMEO_OLD,
+        SO_REUSEADDR, SO_REUSEPORT, SO_SNDBUF, SO_SNDTIMEO_NEW, SO_SNDTIMEO_NEW as SO_SNDTIMEO,
+        SO_SNDTIMEO_OLD, SO_TIMESTAMPING_NEW, SO_TIMESTAMPING_OLD, SO_TIMESTAMPNS_NEW,
+        SO_TIMESTAMPNS_OLD, SO_TIMESTAMP_NEW, SO_TIMESTAMP_OLD, SO_TYPE, SO_ZEROCOPY,
+        TCP_CONGESTION, TCP_CORK, TCP_DEFER_ACCEPT, TCP_FASTOPEN, TCP_FASTOPEN_CONNECT, TCP_INFO,
+        TCP_KEEPCNT, TCP_KEEPIDLE, TCP_KEEPINTVL, TCP_MAXSEG, TCP_NODELAY, TCP_NOTSENT_LOWAT,
+        TCP_QUICKACK, TCP_SAVED_SYN, TCP_SAVE_SYN, TCP_THIN_LINEAR_TIMEOUTS, TCP_ULP,
+        TCP_USER_TIMEOUT, TCP_WINDOW_CLAMP,
     },
     netlink::*,
     xdp::{
@@ -150,6 +163,68 @@ pub(crate) const IPPROTO_RAW: u32 = linux_raw_sys::net::IPPROTO_RAW as _;
 #[cfg(feature = "net")]
 pub(crate) const IPPROTO_MPTCP: u32 = linux_raw_sys::net::IPPROTO_MPTCP as _;
 
+// linux-raw-sys doesn't provide `SOL_PACKET` in all versions, so define it
+// here.
+#[cfg(feature = "net")]
+pub(crate) const SOL_PACKET: u32 = 263;
+
+// linux-raw-sys doesn't provide `MSG_WAITFORONE`, so define it here.
+#[cfg(feature = "net")]
+pub(crate) const MSG_WAITFORONE: u32 = 0x1_0000;
+
+// linux-raw-sys doesn't provide `UDP_SEGMENT` or `UDP_GRO`, so define them
+// here.
+// <https://github.com/torvalds/linux/blob/v6.6/include/uapi/linux/udp.h#L34-L35>
+#[cfg(feature = "net")]
+pub(crate) const UDP_SEGMENT: u32 = 103;
+#[cfg(feature = "net")]
+pub(crate) const UDP_GRO: u32 = 104;
+
+// linux-raw-sys doesn't provide `MSG_ZEROCOPY`, so define it here.
+#[cfg(feature = "net")]
+pub(crate) const MSG_ZEROCOPY: u32 = 0x400_0000;
+
+// linux-raw-sys doesn't provide the `SOL_TLS` options and control messages,
+// so define them here.
+// <https://github.com/torvalds/linux/blob/v6.6/include/uapi/linux/tls.h#L39-L45>
+#[cfg(feature = "net")]
+pub(crate) const TLS_TX: u32 = 1;
+#[cfg(feature = "net")]
+pub(crate) const TLS_RX: u32 = 2;
+#[cfg(feature = "net")]
+pub(crate) const TLS_SET_RECORD_TYPE: u32 = 1;
+#[cfg(feature = "net")]
+pub(crate) const TLS_GET_RECORD_TYPE: u32 = 2;
+
+// linux-raw-sys doesn't provide `struct sock_extended_err`, so define it here.
+// <https://github.com/torvalds/linux/blob/v6.6/include/uapi/linux/errqueue.h#L20-L28>
+#[cfg(feature = "net")]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub(crate) struct sock_extended_err {
+    pub(crate) ee_errno: u32,
+    pub(crate) ee_origin: u8,
+    pub(crate) ee_type: u8,
+    pub(crate) ee_code: u8,
+    pub(crate) ee_pad: u8,
+    pub(crate) ee_info: u32,
+    pub(crate) ee_data: u32,
+}
+
+// linux-raw-sys doesn't provide `struct sockaddr_vm`, so define it here.
+// <https://github.com/torvalds/linux/blob/v6.6/include/uapi/linux/vm_sockets.h#L180-L189>
+#[cfg(feature = "net")]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub(crate) struct sockaddr_vm {
+    pub(crate) svm_family: sa_family_t,
+    pub(crate) svm_reserved1: u16,
+    pub(crate) svm_port: u32,
+    pub(crate) svm_cid: u32,
+    pub(crate) svm_flags: u8,
+    pub(crate) svm_zero: [u8; 3],
+}
+
 #[cfg(any(feature = "process", feature = "runtime"))]
 pub(crate) use linux_raw_sys::general::siginfo_t;
 
@@ -256,6 +331,13 @@ pub(crate) const SIGSYS: c_int = linux_raw_sys::general::SIGSYS as _;
 ))]
 pub(crate) const SIGEMT: c_int = linux_raw_sys::general::SIGEMT as _;
 
+#[cfg(feature = "signal")]
+pub(crate) const SIG_BLOCK: c_int = linux_raw_sys::general::SIG_BLOCK as _;
+#[cfg(feature = "signal")]
+pub(crate) const SIG_UNBLOCK: c_int = linux_raw_sys::general::SIG_UNBLOCK as _;
+#[cfg(feature = "signal")]
+pub(crate) const SIG_SETMASK: c_int = linux_raw_sys::general::SIG_SETMASK as _;
+
 #[cfg(feature = "stdio")]
 pub(crate) const STDIN_FILENO: c_int = linux_raw_sys::general::STDIN_FILENO as _;
 #[cfg(feature = "stdio")]
@@ -39,7 +39,7 @@ use crate::io;
 use crate::pid::Pid;
 #[cfg(feature = "process")]
 use crate::process::Resource;
-#[cfg(any(feature = "process", feature = "runtime"))]
+#[cfg(any(feature = "process", feature = "runtime", feature = "signal"))]
 use crate::signal::Signal;
 use crate::utils::{as_mut_ptr, as_ptr};
 use core::mem::MaybeUninit;
@@ -495,6 +495,14 @@ impl<'a, Num: ArgNumber> From<crate::backend::mount::types::OpenTreeFlags> for A
     }
 }
 
+#[cfg(feature = "mount")]
+impl<'a, Num: ArgNumber> From<crate::backend::mount::types::MountSetattrFlags> for ArgReg<'a, Num> {
+    #[inline]
+    fn from(flags: crate::backend::mount::types::MountSetattrFlags) -> Self {
+        c_uint(flags.bits())
+    }
+}
+
 #[cfg(feature = "mount")]
 impl<'a, Num: ArgNumber> From<crate::backend::mount::types::FsPickFlags> for ArgReg<'a, Num> {
     #[inline]
@@ -580,6 +588,14 @@ impl<'a, Num: ArgNumber> From<crate::event::EventfdFlags> for ArgReg<'a, Num> {
     }
 }
 
+#[cfg(feature = "event")]
+impl<'a, Num: ArgNumber> From<crate::event::SignalfdFlags> for ArgReg<'a, Num> {
+    #[inline]
+    fn from(flags: crate::event::SignalfdFlags) -> Self {
+        c_uint(flags.bits())
+    }
+}
+
 #[cfg(feature = "event")]
 impl<'a, Num: ArgNumber> From<crate::event::epoll::CreateFlags> for ArgReg<'a, Num> {
     #[inline]
@@ -705,7 +721,7 @@ pub(super) fn negative_pid<'a, Num: ArgNumber>(pid: Pid) -> ArgReg<'a, Num> {
     pass_usize(pid.as_raw_nonzero().get().wrapping_neg() as usize)
 }
 
-#[cfg(any(feature = "process", feature = "runtime"))]
+#[cfg(any(feature = "process", feature = "runtime", feature = "signal"))]
 impl<'a, Num: ArgNumber> From<Signal> for ArgReg<'a, Num> {
     #[inline]
     fn from(sig: Signal) -> Self {
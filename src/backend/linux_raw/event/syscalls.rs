@@ -7,21 +7,19 @@
 
 use crate::backend::c;
 use crate::backend::conv::{
-    by_ref, c_int, c_uint, ret, ret_c_int, ret_error, ret_owned_fd, ret_usize, slice_mut, zero,
+    by_ref, c_int, c_uint, no_fd, ret, ret_c_int, ret_discarded_fd, ret_error, ret_owned_fd,
+    ret_usize, size_of, slice_mut, zero,
 };
-use crate::event::{epoll, EventfdFlags, FdSetElement, PollFd};
+use crate::event::{epoll, EventfdFlags, FdSetElement, PollFd, SigSet, SignalfdFlags};
 use crate::fd::{BorrowedFd, OwnedFd};
 use crate::io;
 use crate::utils::as_mut_ptr;
 #[cfg(feature = "alloc")]
 use core::mem::MaybeUninit;
 use core::ptr::null_mut;
-use linux_raw_sys::general::{EPOLL_CTL_ADD, EPOLL_CTL_DEL, EPOLL_CTL_MOD};
+use linux_raw_sys::general::{kernel_sigset_t, EPOLL_CTL_ADD, EPOLL_CTL_DEL, EPOLL_CTL_MOD};
 #[cfg(any(target_arch = "aarch64", target_arch = "riscv64"))]
-use {
-    crate::backend::conv::{opt_ref, size_of},
-    linux_raw_sys::general::{__kernel_timespec, kernel_sigset_t},
-};
+use {crate::backend::conv::opt_ref, linux_raw_sys::general::__kernel_timespec};
 
 #[inline]
 pub(crate) fn poll(fds: &mut [PollFd<'_>], timeout: c::c_int) -> io::Result<usize> {
@@ -230,6 +228,34 @@ pub(crate) fn eventfd(initval: u32, flags: EventfdFlags) -> io::Result<OwnedFd>
     unsafe { ret_owned_fd(syscall_readonly!(__NR_eventfd2, c_uint(initval), flags)) }
 }
 
+#[inline]
+pub(crate) fn signalfd(mask: &SigSet, flags: SignalfdFlags) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_signalfd4,
+            no_fd(),
+            by_ref(mask.as_raw()),
+            size_of::<kernel_sigset_t, _>(),
+            flags
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn signalfd_set_mask(fd: BorrowedFd<'_>, mask: &SigSet) -> io::Result<()> {
+    // When passed an existing signalfd, `signalfd4` replaces its mask and
+    // returns the same file descriptor, which we already own.
+    unsafe {
+        ret_discarded_fd(syscall_readonly!(
+            __NR_signalfd4,
+            fd,
+            by_ref(mask.as_raw()),
+            size_of::<kernel_sigset_t, _>(),
+            c_uint(0)
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn pause() {
     unsafe {
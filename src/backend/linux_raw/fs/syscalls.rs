@@ -112,6 +112,43 @@ pub(crate) fn openat2(
     }
 }
 
+#[cfg(feature = "alloc")]
+#[inline]
+pub(crate) unsafe fn name_to_handle_at(
+    dirfd: BorrowedFd<'_>,
+    path: &CStr,
+    handle: *mut c::c_void,
+    mount_id: *mut c::c_void,
+    flags: AtFlags,
+) -> io::Result<()> {
+    ret(syscall!(
+        __NR_name_to_handle_at,
+        dirfd,
+        path,
+        handle,
+        mount_id,
+        flags
+    ))
+}
+
+#[cfg(feature = "alloc")]
+#[inline]
+pub(crate) unsafe fn open_by_handle_at(
+    mount_fd: BorrowedFd<'_>,
+    handle: *const c::c_void,
+    flags: OFlags,
+) -> io::Result<OwnedFd> {
+    // Always enable support for large files.
+    let flags = flags | OFlags::LARGEFILE;
+
+    ret_owned_fd(syscall_readonly!(
+        __NR_open_by_handle_at,
+        mount_fd,
+        handle,
+        flags
+    ))
+}
+
 #[inline]
 pub(crate) fn chmod(path: &CStr, mode: Mode) -> io::Result<()> {
     unsafe {
@@ -1524,6 +1561,50 @@ pub(crate) fn inotify_rm_watch(infd: BorrowedFd<'_>, wfd: i32) -> io::Result<()>
     unsafe { ret(syscall_readonly!(__NR_inotify_rm_watch, infd, c_int(wfd))) }
 }
 
+#[inline]
+pub(crate) fn fanotify_init(flags: u32, event_flags: OFlags) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_fanotify_init,
+            c_uint(flags),
+            event_flags
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn fanotify_mark(
+    fan: BorrowedFd<'_>,
+    flags: u32,
+    mask: u64,
+    dirfd: BorrowedFd<'_>,
+    path: Option<&CStr>,
+) -> io::Result<()> {
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fanotify_mark,
+            fan,
+            c_uint(flags),
+            hi(mask),
+            lo(mask),
+            dirfd,
+            path
+        ))
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fanotify_mark,
+            fan,
+            c_uint(flags),
+            pass_usize(mask as usize),
+            dirfd,
+            path
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn getxattr(path: &CStr, name: &CStr, value: &mut [u8]) -> io::Result<usize> {
     let (value_addr_mut, value_len) = slice_mut(value);
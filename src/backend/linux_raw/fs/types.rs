@@ -61,6 +61,20 @@ bitflags! {
         /// `AT_STATX_DONT_SYNC`
         const STATX_DONT_SYNC = linux_raw_sys::general::AT_STATX_DONT_SYNC;
 
+        /// `AT_HANDLE_FID`—For [`name_to_handle_at`], return a handle which
+        /// identifies the file, but which may not be usable with
+        /// [`open_by_handle_at`].
+        ///
+        /// [`name_to_handle_at`]: crate::fs::name_to_handle_at
+        /// [`open_by_handle_at`]: crate::fs::open_by_handle_at
+        const HANDLE_FID = linux_raw_sys::general::AT_HANDLE_FID;
+
+        /// `AT_HANDLE_MNT_ID_UNIQUE` (since Linux 6.12)—For
+        /// [`name_to_handle_at`], return the unique 64-bit mount ID.
+        ///
+        /// [`name_to_handle_at`]: crate::fs::name_to_handle_at
+        const HANDLE_MNT_ID_UNIQUE = 0x001;
+
         /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
         const _ = !0;
     }
@@ -259,6 +259,8 @@ bitflags! {
         const CLOEXEC = linux_raw_sys::general::O_CLOEXEC;
         /// `O_NONBLOCK`
         const NONBLOCK = linux_raw_sys::general::O_NONBLOCK;
+        /// `UFFD_USER_MODE_ONLY`—Only handle faults from user mode.
+        const USER_MODE_ONLY = linux_raw_sys::general::UFFD_USER_MODE_ONLY;
 
         /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
         const _ = !0;
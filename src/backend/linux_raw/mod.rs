@@ -23,6 +23,8 @@ mod vdso;
 #[cfg(any(feature = "time", feature = "process", target_arch = "x86"))]
 mod vdso_wrappers;
 
+#[cfg(feature = "bpf")]
+pub(crate) mod bpf;
 #[cfg(feature = "event")]
 pub(crate) mod event;
 #[cfg(any(
@@ -57,6 +59,8 @@ pub(crate) mod net;
     target_arch = "x86",
 ))]
 pub(crate) mod param;
+#[cfg(feature = "perf_event")]
+pub(crate) mod perf_event;
 #[cfg(feature = "pipe")]
 pub(crate) mod pipe;
 #[cfg(feature = "process")]
@@ -93,6 +97,8 @@ pub(crate) mod c;
 pub(crate) mod pid;
 #[cfg(any(feature = "process", feature = "thread"))]
 pub(crate) mod prctl;
+#[cfg(any(feature = "event", feature = "signal"))]
+pub(crate) mod signal;
 #[cfg(any(
     feature = "fs",
     feature = "process",
@@ -6,9 +6,14 @@
 #![allow(unsafe_code)]
 #![allow(clippy::undocumented_unsafe_blocks)]
 
+#[cfg(feature = "mount")]
+use crate::backend::c;
 use crate::backend::conv::ret;
 #[cfg(feature = "mount")]
-use crate::backend::conv::{ret_owned_fd, slice, zero};
+use crate::backend::conv::{
+    by_ref, c_uint, pass_usize, ret_owned_fd, ret_usize, size_of, slice, slice_just_addr_mut,
+    slice_mut, zero,
+};
 #[cfg(feature = "mount")]
 use crate::fd::{BorrowedFd, OwnedFd};
 use crate::ffi::CStr;
@@ -237,3 +242,61 @@ pub(crate) fn fsconfig_reconfigure(fs_fd: BorrowedFd<'_>) -> io::Result<()> {
         ))
     }
 }
+
+#[cfg(feature = "mount")]
+#[inline]
+pub(crate) fn mount_setattr(
+    dfd: BorrowedFd<'_>,
+    path: &CStr,
+    flags: super::types::MountSetattrFlags,
+    attr: &linux_raw_sys::general::mount_attr,
+) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_mount_setattr,
+            dfd,
+            path,
+            flags,
+            by_ref(attr),
+            size_of::<linux_raw_sys::general::mount_attr, _>()
+        ))
+    }
+}
+
+#[cfg(feature = "mount")]
+#[inline]
+pub(crate) fn statmount(
+    req: &linux_raw_sys::general::mnt_id_req,
+    buf: &mut [u64],
+    flags: c::c_uint,
+) -> io::Result<()> {
+    let buf_size = core::mem::size_of_val(buf);
+    unsafe {
+        ret(syscall!(
+            __NR_statmount,
+            by_ref(req),
+            slice_just_addr_mut(buf),
+            pass_usize(buf_size),
+            c_uint(flags)
+        ))
+    }
+}
+
+#[cfg(feature = "mount")]
+#[inline]
+pub(crate) fn listmount(
+    req: &linux_raw_sys::general::mnt_id_req,
+    buf: &mut [u64],
+    flags: c::c_uint,
+) -> io::Result<usize> {
+    let (buf_addr_mut, buf_len) = slice_mut(buf);
+    unsafe {
+        ret_usize(syscall!(
+            __NR_listmount,
+            by_ref(req),
+            buf_addr_mut,
+            buf_len,
+            c_uint(flags)
+        ))
+    }
+}
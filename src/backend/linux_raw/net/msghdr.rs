@@ -8,12 +8,20 @@
 use crate::backend::c;
 #[cfg(target_os = "linux")]
 use crate::backend::net::write_sockaddr::encode_sockaddr_xdp;
+use crate::backend::net::write_sockaddr::{
+    encode_sockaddr_link, encode_sockaddr_netlink, encode_sockaddr_vsock,
+};
 use crate::backend::net::write_sockaddr::{encode_sockaddr_v4, encode_sockaddr_v6};
 
 use crate::io::{self, IoSlice, IoSliceMut};
+use crate::net::netlink::SocketAddrNetlink;
+use crate::net::packet::SocketAddrLink;
+use crate::net::vsock::SocketAddrVsock;
 #[cfg(target_os = "linux")]
 use crate::net::xdp::SocketAddrXdp;
-use crate::net::{RecvAncillaryBuffer, SendAncillaryBuffer, SocketAddrV4, SocketAddrV6};
+use crate::net::{
+    MMsgHdr, RecvAncillaryBuffer, RecvMMsgHdr, SendAncillaryBuffer, SocketAddrV4, SocketAddrV6,
+};
 use crate::utils::as_ptr;
 
 use core::mem::{size_of, MaybeUninit};
@@ -89,7 +97,7 @@ pub(crate) fn with_v4_msghdr<R>(
 
     f(c::msghdr {
         msg_name: as_ptr(&encoded) as _,
-        msg_namelen: size_of::<SocketAddrV4>() as _,
+        msg_namelen: size_of::<c::sockaddr_in>() as _,
         msg_iov: iov.as_ptr() as _,
         msg_iovlen: msg_iov_len(iov.len()),
         msg_control: control.as_control_ptr().cast(),
@@ -109,7 +117,7 @@ pub(crate) fn with_v6_msghdr<R>(
 
     f(c::msghdr {
         msg_name: as_ptr(&encoded) as _,
-        msg_namelen: size_of::<SocketAddrV6>() as _,
+        msg_namelen: size_of::<c::sockaddr_in6>() as _,
         msg_iov: iov.as_ptr() as _,
         msg_iovlen: msg_iov_len(iov.len()),
         msg_control: control.as_control_ptr().cast(),
@@ -157,6 +165,110 @@ pub(crate) fn with_xdp_msghdr<R>(
     })
 }
 
+/// Create a message header intended to send with a netlink address.
+pub(crate) fn with_netlink_msghdr<R>(
+    addr: &SocketAddrNetlink,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    f: impl FnOnce(c::msghdr) -> R,
+) -> R {
+    let encoded = encode_sockaddr_netlink(addr);
+
+    f(c::msghdr {
+        msg_name: as_ptr(&encoded) as _,
+        msg_namelen: size_of::<c::sockaddr_nl>() as _,
+        msg_iov: iov.as_ptr() as _,
+        msg_iovlen: msg_iov_len(iov.len()),
+        msg_control: control.as_control_ptr().cast(),
+        msg_controllen: msg_control_len(control.control_len()),
+        msg_flags: 0,
+    })
+}
+
+/// Create a message header intended to send with a link-layer address.
+pub(crate) fn with_link_msghdr<R>(
+    addr: &SocketAddrLink,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    f: impl FnOnce(c::msghdr) -> R,
+) -> R {
+    let encoded = encode_sockaddr_link(addr);
+
+    f(c::msghdr {
+        msg_name: as_ptr(&encoded) as _,
+        msg_namelen: size_of::<c::sockaddr_ll>() as _,
+        msg_iov: iov.as_ptr() as _,
+        msg_iovlen: msg_iov_len(iov.len()),
+        msg_control: control.as_control_ptr().cast(),
+        msg_controllen: msg_control_len(control.control_len()),
+        msg_flags: 0,
+    })
+}
+
+/// Create a message header intended to send with a VM socket address.
+pub(crate) fn with_vsock_msghdr<R>(
+    addr: &SocketAddrVsock,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    f: impl FnOnce(c::msghdr) -> R,
+) -> R {
+    let encoded = encode_sockaddr_vsock(addr);
+
+    f(c::msghdr {
+        msg_name: as_ptr(&encoded) as _,
+        msg_namelen: size_of::<c::sockaddr_vm>() as _,
+        msg_iov: iov.as_ptr() as _,
+        msg_iovlen: msg_iov_len(iov.len()),
+        msg_control: control.as_control_ptr().cast(),
+        msg_controllen: msg_control_len(control.control_len()),
+        msg_flags: 0,
+    })
+}
+
+/// Create a message header for a message to send with `sendmmsg`.
+pub(crate) fn send_mmsghdr(msg: &MMsgHdr<'_>) -> c::mmsghdr {
+    c::mmsghdr {
+        msg_hdr: c::msghdr {
+            msg_name: if msg.name_len == 0 {
+                null_mut()
+            } else {
+                msg.name.as_ptr() as _
+            },
+            msg_namelen: msg.name_len as _,
+            msg_iov: msg.iov.as_ptr() as _,
+            msg_iovlen: msg_iov_len(msg.iov.len()),
+            msg_control: msg.control.cast(),
+            msg_controllen: msg_control_len(msg.control_len),
+            msg_flags: 0,
+        },
+        msg_len: 0,
+    }
+}
+
+/// Create a message header for a message to receive with `recvmmsg`.
+///
+/// # Safety
+///
+/// `msg` must have been created by `RecvMMsgHdr::new`, and so must
+/// reference live buffers.
+pub(crate) unsafe fn recv_mmsghdr(msg: &mut RecvMMsgHdr<'_>) -> c::mmsghdr {
+    let control = &mut *msg.control;
+    control.clear();
+
+    c::mmsghdr {
+        msg_hdr: c::msghdr {
+            msg_name: msg.name.as_mut_ptr().cast(),
+            msg_namelen: size_of::<c::sockaddr_storage>() as _,
+            msg_iov: msg.iov.cast(),
+            msg_iovlen: msg_iov_len(msg.iov_len),
+            msg_control: control.as_control_ptr().cast(),
+            msg_controllen: msg_control_len(control.control_len()),
+            msg_flags: 0,
+        },
+        msg_len: 0,
+    }
+}
+
 /// Create a zero-initialized message header struct value.
 pub(crate) fn zero_msghdr() -> c::msghdr {
     c::msghdr {
@@ -4,9 +4,15 @@
 
 use crate::backend::c;
 use crate::io;
+use crate::net::netlink::SocketAddrNetlink;
+use crate::net::packet::SocketAddrLink;
+use crate::net::vsock::SocketAddrVsock;
 #[cfg(target_os = "linux")]
 use crate::net::xdp::{SockaddrXdpFlags, SocketAddrXdp};
-use crate::net::{Ipv4Addr, Ipv6Addr, SocketAddrAny, SocketAddrUnix, SocketAddrV4, SocketAddrV6};
+use crate::net::{
+    Ipv4Addr, Ipv4PktInfo, Ipv6Addr, Ipv6PktInfo, SocketAddrAny, SocketAddrUnix, SocketAddrV4,
+    SocketAddrV6,
+};
 use core::mem::size_of;
 use core::slice;
 
@@ -127,6 +133,33 @@ pub(crate) unsafe fn read_sockaddr(
                 u32::from_be(decode.sxdp_shared_umem_fd),
             )))
         }
+        c::AF_NETLINK => {
+            if len < size_of::<c::sockaddr_nl>() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = &*storage.cast::<c::sockaddr_nl>();
+            Ok(SocketAddrAny::Netlink(SocketAddrNetlink::new(
+                decode.nl_pid,
+                decode.nl_groups,
+            )))
+        }
+        c::AF_PACKET => {
+            if len < SOCKADDR_LL_ADDR_OFFSET {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = &*storage.cast::<c::sockaddr_ll>();
+            Ok(SocketAddrAny::Link(decode_sockaddr_link(decode, len)))
+        }
+        c::AF_VSOCK => {
+            if len < size_of::<c::sockaddr_vm>() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = &*storage.cast::<c::sockaddr_vm>();
+            Ok(SocketAddrAny::Vsock(SocketAddrVsock::new(
+                decode.svm_cid,
+                decode.svm_port,
+            )))
+        }
         _ => Err(io::Errno::NOTSUP),
     }
 }
@@ -216,6 +249,55 @@ pub(crate) unsafe fn read_sockaddr_os(storage: *const c::sockaddr, len: usize) -
                 u32::from_be(decode.sxdp_shared_umem_fd),
             ))
         }
+        c::AF_NETLINK => {
+            assert!(len >= size_of::<c::sockaddr_nl>());
+            let decode = &*storage.cast::<c::sockaddr_nl>();
+            SocketAddrAny::Netlink(SocketAddrNetlink::new(decode.nl_pid, decode.nl_groups))
+        }
+        c::AF_PACKET => {
+            assert!(len >= SOCKADDR_LL_ADDR_OFFSET);
+            let decode = &*storage.cast::<c::sockaddr_ll>();
+            SocketAddrAny::Link(decode_sockaddr_link(decode, len))
+        }
+        c::AF_VSOCK => {
+            assert!(len >= size_of::<c::sockaddr_vm>());
+            let decode = &*storage.cast::<c::sockaddr_vm>();
+            SocketAddrAny::Vsock(SocketAddrVsock::new(decode.svm_cid, decode.svm_port))
+        }
         other => unimplemented!("{:?}", other),
     }
 }
+
+/// The offset of `sll_addr` in `struct sockaddr_ll`.
+///
+/// The kernel's `getsockname` only includes as much of the hardware address as
+/// is in use, so addresses may be shorter than `struct sockaddr_ll`.
+const SOCKADDR_LL_ADDR_OFFSET: usize = 12;
+
+fn decode_sockaddr_link(decode: &c::sockaddr_ll, len: usize) -> SocketAddrLink {
+    let halen = (decode.sll_halen as usize)
+        .min(decode.sll_addr.len())
+        .min(len - SOCKADDR_LL_ADDR_OFFSET);
+    SocketAddrLink::from_raw_parts(
+        decode.sll_protocol,
+        decode.sll_ifindex as u32,
+        decode.sll_hatype,
+        decode.sll_pkttype,
+        &decode.sll_addr[..halen],
+    )
+}
+
+pub(crate) fn decode_in_pktinfo(decode: &c::in_pktinfo) -> Ipv4PktInfo {
+    Ipv4PktInfo {
+        ifindex: decode.ipi_ifindex as u32,
+        spec_dst: Ipv4Addr::from(u32::from_be(decode.ipi_spec_dst.s_addr)),
+        addr: Ipv4Addr::from(u32::from_be(decode.ipi_addr.s_addr)),
+    }
+}
+
+pub(crate) fn decode_in6_pktinfo(decode: &c::in6_pktinfo) -> Ipv6PktInfo {
+    Ipv6PktInfo {
+        addr: Ipv6Addr::from(unsafe { decode.ipi6_addr.in6_u.u6_addr8 }),
+        ifindex: decode.ipi6_ifindex as u32,
+    }
+}
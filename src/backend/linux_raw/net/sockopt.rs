@@ -7,21 +7,30 @@
 
 use crate::backend::c;
 use crate::backend::conv::{by_mut, c_uint, ret, socklen_t};
+use crate::bpf_filter::SockFprog;
 use crate::fd::BorrowedFd;
 #[cfg(feature = "alloc")]
 use crate::ffi::CStr;
 use crate::io;
+#[cfg(target_os = "linux")]
+use crate::net::packet::{
+    PacketFanout, PacketFanoutFlags, PacketFanoutMode, PacketMreq, PacketStats, PacketVersion,
+    TpacketReq3,
+};
 use crate::net::sockopt::Timeout;
+use crate::net::tls::TlsCryptoInfo;
 #[cfg(target_os = "linux")]
 use crate::net::xdp::{XdpMmapOffsets, XdpOptionsFlags, XdpRingOffset, XdpStatistics, XdpUmemReg};
 use crate::net::{
-    AddressFamily, Ipv4Addr, Ipv6Addr, Protocol, RawProtocol, SocketAddrAny, SocketAddrStorage,
-    SocketAddrV4, SocketAddrV6, SocketType, UCred,
+    AddressFamily, Ipv4Addr, Ipv6Addr, PmtuDiscovery, Protocol, RawProtocol, SocketAddrAny,
+    SocketAddrStorage, SocketAddrV4, SocketAddrV6, SocketType, TcpInfo, TimestampingFlags, UCred,
 };
 #[cfg(feature = "alloc")]
 use alloc::borrow::ToOwned;
 #[cfg(feature = "alloc")]
 use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::mem::MaybeUninit;
 use core::time::Duration;
 use linux_raw_sys::general::{__kernel_old_timeval, __kernel_sock_timeval};
@@ -61,9 +70,44 @@ fn getsockopt_raw<T>(
     optname: u32,
     value: &mut MaybeUninit<T>,
     optlen: &mut c::socklen_t,
+) -> io::Result<()> {
+    assert!(
+        *optlen as usize <= core::mem::size_of::<T>(),
+        "getsockopt length exceeds the buffer"
+    );
+    // SAFETY: `value` has room for `optlen` bytes.
+    unsafe { getsockopt_unchecked(fd, level, optname, value.as_mut_ptr().cast(), optlen) }
+}
+
+/// Like `getsockopt_raw`, but for a buffer of bytes. `optlen` is set to the
+/// length reported by the kernel.
+#[cfg(feature = "alloc")]
+#[inline]
+fn getsockopt_raw_slice(
+    fd: BorrowedFd<'_>,
+    level: u32,
+    optname: u32,
+    value: &mut [MaybeUninit<u8>],
+    optlen: &mut c::socklen_t,
+) -> io::Result<()> {
+    *optlen = value.len().try_into().unwrap_or(c::socklen_t::MAX);
+    // SAFETY: `value` has room for `optlen` bytes.
+    unsafe { getsockopt_unchecked(fd, level, optname, value.as_mut_ptr().cast(), optlen) }
+}
+
+/// # Safety
+///
+/// `value` must point to at least `*optlen` writable bytes.
+#[inline]
+unsafe fn getsockopt_unchecked(
+    fd: BorrowedFd<'_>,
+    level: u32,
+    optname: u32,
+    value: *mut c::c_void,
+    optlen: &mut c::socklen_t,
 ) -> io::Result<()> {
     #[cfg(not(target_arch = "x86"))]
-    unsafe {
+    {
         ret(syscall!(
             __NR_getsockopt,
             fd,
@@ -74,7 +118,7 @@ fn getsockopt_raw<T>(
         ))
     }
     #[cfg(target_arch = "x86")]
-    unsafe {
+    {
         ret(syscall!(
             __NR_socketcall,
             x86_sys(SYS_GETSOCKOPT),
@@ -425,6 +469,182 @@ pub(crate) fn set_socket_incoming_cpu(fd: BorrowedFd<'_>, value: u32) -> io::Res
     setsockopt(fd, c::SOL_SOCKET, c::SO_INCOMING_CPU, value)
 }
 
+#[inline]
+pub(crate) fn set_socket_timestamp(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    match setsockopt(fd, c::SOL_SOCKET, c::SO_TIMESTAMP_NEW, from_bool(value)) {
+        Err(io::Errno::NOPROTOOPT) if c::SO_TIMESTAMP_NEW != c::SO_TIMESTAMP_OLD => {
+            setsockopt(fd, c::SOL_SOCKET, c::SO_TIMESTAMP_OLD, from_bool(value))
+        }
+        otherwise => otherwise,
+    }
+}
+
+#[inline]
+pub(crate) fn socket_timestamp(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    // The kernel only reports the option as set when queried with the same
+    // variant that enabled it, so check both.
+    match getsockopt(fd, c::SOL_SOCKET, c::SO_TIMESTAMP_NEW).map(to_bool) {
+        Ok(false) | Err(io::Errno::NOPROTOOPT) => {
+            getsockopt(fd, c::SOL_SOCKET, c::SO_TIMESTAMP_OLD).map(to_bool)
+        }
+        otherwise => otherwise,
+    }
+}
+
+#[inline]
+pub(crate) fn set_socket_timestampns(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    match setsockopt(fd, c::SOL_SOCKET, c::SO_TIMESTAMPNS_NEW, from_bool(value)) {
+        Err(io::Errno::NOPROTOOPT) if c::SO_TIMESTAMPNS_NEW != c::SO_TIMESTAMPNS_OLD => {
+            setsockopt(fd, c::SOL_SOCKET, c::SO_TIMESTAMPNS_OLD, from_bool(value))
+        }
+        otherwise => otherwise,
+    }
+}
+
+#[inline]
+pub(crate) fn socket_timestampns(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    // As with `SO_TIMESTAMP`, check both variants.
+    match getsockopt(fd, c::SOL_SOCKET, c::SO_TIMESTAMPNS_NEW).map(to_bool) {
+        Ok(false) | Err(io::Errno::NOPROTOOPT) => {
+            getsockopt(fd, c::SOL_SOCKET, c::SO_TIMESTAMPNS_OLD).map(to_bool)
+        }
+        otherwise => otherwise,
+    }
+}
+
+#[inline]
+pub(crate) fn set_socket_timestamping(
+    fd: BorrowedFd<'_>,
+    value: TimestampingFlags,
+) -> io::Result<()> {
+    match setsockopt(fd, c::SOL_SOCKET, c::SO_TIMESTAMPING_NEW, value.bits()) {
+        Err(io::Errno::NOPROTOOPT) if c::SO_TIMESTAMPING_NEW != c::SO_TIMESTAMPING_OLD => {
+            setsockopt(fd, c::SOL_SOCKET, c::SO_TIMESTAMPING_OLD, value.bits())
+        }
+        otherwise => otherwise,
+    }
+}
+
+#[inline]
+pub(crate) fn socket_timestamping(fd: BorrowedFd<'_>) -> io::Result<TimestampingFlags> {
+    // `SO_TIMESTAMPING_OLD` reports the flags regardless of which variant set
+    // them, while `SO_TIMESTAMPING_NEW` isn't supported for `getsockopt` on
+    // some kernel versions.
+    let flags: u32 = getsockopt(fd, c::SOL_SOCKET, c::SO_TIMESTAMPING_OLD)?;
+    Ok(TimestampingFlags::from_bits_retain(flags))
+}
+
+#[inline]
+pub(crate) fn set_socket_zerocopy(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::SOL_SOCKET, c::SO_ZEROCOPY, from_bool(value))
+}
+
+#[inline]
+pub(crate) fn socket_zerocopy(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::SOL_SOCKET, c::SO_ZEROCOPY).map(to_bool)
+}
+
+#[inline]
+pub(crate) fn set_socket_bindtodevice(fd: BorrowedFd<'_>, value: &str) -> io::Result<()> {
+    let level = c::SOL_SOCKET;
+    let optname = c::SO_BINDTODEVICE;
+    let optlen = value.len().try_into().unwrap();
+    setsockopt_raw(fd, level, optname, value.as_ptr(), optlen)
+}
+
+#[cfg(feature = "alloc")]
+#[inline]
+pub(crate) fn socket_bindtodevice(fd: BorrowedFd<'_>) -> io::Result<String> {
+    // `IFNAMSIZ`
+    const OPTLEN: c::socklen_t = 16;
+
+    let level = c::SOL_SOCKET;
+    let optname = c::SO_BINDTODEVICE;
+    let mut value = MaybeUninit::<[u8; OPTLEN as usize]>::zeroed();
+    let mut optlen = OPTLEN;
+    getsockopt_raw(fd, level, optname, &mut value, &mut optlen)?;
+    // SAFETY: The buffer was zero-initialized.
+    let value = unsafe { value.assume_init() };
+    let slice = &value[..optlen as usize];
+    let len = slice
+        .iter()
+        .position(|b| *b == b'\0')
+        .unwrap_or(slice.len());
+    // Interface names can hold any bytes except `/`, `:`, whitespace, and
+    // NUL, so they aren't necessarily UTF-8.
+    let name = core::str::from_utf8(&slice[..len]).map_err(|_| io::Errno::INVAL)?;
+    Ok(name.to_owned())
+}
+
+#[inline]
+pub(crate) fn set_socket_bindtoifindex(fd: BorrowedFd<'_>, value: u32) -> io::Result<()> {
+    setsockopt(fd, c::SOL_SOCKET, c::SO_BINDTOIFINDEX, value)
+}
+
+#[inline]
+pub(crate) fn socket_bindtoifindex(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    getsockopt(fd, c::SOL_SOCKET, c::SO_BINDTOIFINDEX)
+}
+
+#[inline]
+pub(crate) fn set_socket_mark(fd: BorrowedFd<'_>, value: u32) -> io::Result<()> {
+    setsockopt(fd, c::SOL_SOCKET, c::SO_MARK, value)
+}
+
+#[inline]
+pub(crate) fn socket_mark(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    getsockopt(fd, c::SOL_SOCKET, c::SO_MARK)
+}
+
+#[inline]
+pub(crate) fn set_socket_priority(fd: BorrowedFd<'_>, value: u32) -> io::Result<()> {
+    setsockopt(fd, c::SOL_SOCKET, c::SO_PRIORITY, value)
+}
+
+#[inline]
+pub(crate) fn socket_priority(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    getsockopt(fd, c::SOL_SOCKET, c::SO_PRIORITY)
+}
+
+#[inline]
+pub(crate) fn set_socket_attach_filter(fd: BorrowedFd<'_>, prog: &SockFprog) -> io::Result<()> {
+    set_socket_fprog(fd, c::SO_ATTACH_FILTER, prog)
+}
+
+#[inline]
+pub(crate) fn set_socket_detach_filter(fd: BorrowedFd<'_>) -> io::Result<()> {
+    setsockopt(fd, c::SOL_SOCKET, c::SO_DETACH_FILTER, 0 as c::c_int)
+}
+
+#[inline]
+pub(crate) fn set_socket_lock_filter(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::SOL_SOCKET, c::SO_LOCK_FILTER, from_bool(value))
+}
+
+#[inline]
+pub(crate) fn socket_lock_filter(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::SOL_SOCKET, c::SO_LOCK_FILTER).map(to_bool)
+}
+
+#[inline]
+pub(crate) fn set_socket_attach_reuseport_cbpf(
+    fd: BorrowedFd<'_>,
+    prog: &SockFprog,
+) -> io::Result<()> {
+    set_socket_fprog(fd, c::SO_ATTACH_REUSEPORT_CBPF, prog)
+}
+
+#[inline]
+pub(crate) fn set_socket_detach_reuseport_bpf(fd: BorrowedFd<'_>) -> io::Result<()> {
+    setsockopt(fd, c::SOL_SOCKET, c::SO_DETACH_REUSEPORT_BPF, 0 as c::c_int)
+}
+
+#[inline]
+fn set_socket_fprog(fd: BorrowedFd<'_>, optname: u32, prog: &SockFprog) -> io::Result<()> {
+    let optlen = core::mem::size_of::<SockFprog>().try_into().unwrap();
+    setsockopt_raw(fd, c::SOL_SOCKET, optname, prog, optlen)
+}
+
 #[inline]
 pub(crate) fn set_ip_ttl(fd: BorrowedFd<'_>, ttl: u32) -> io::Result<()> {
     setsockopt(fd, c::IPPROTO_IP, c::IP_TTL, ttl)
@@ -624,6 +844,123 @@ pub(crate) fn ipv6_recvtclass(fd: BorrowedFd<'_>) -> io::Result<bool> {
     getsockopt(fd, c::IPPROTO_IPV6, c::IPV6_RECVTCLASS).map(to_bool)
 }
 
+#[inline]
+pub(crate) fn set_ip_pktinfo(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IP, c::IP_PKTINFO, from_bool(value))
+}
+
+#[inline]
+pub(crate) fn ip_pktinfo(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_IP, c::IP_PKTINFO).map(to_bool)
+}
+
+#[inline]
+pub(crate) fn set_ipv6_recvpktinfo(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IPV6, c::IPV6_RECVPKTINFO, from_bool(value))
+}
+
+#[inline]
+pub(crate) fn ipv6_recvpktinfo(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_IPV6, c::IPV6_RECVPKTINFO).map(to_bool)
+}
+
+#[inline]
+pub(crate) fn set_ip_recverr(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IP, c::IP_RECVERR, from_bool(value))
+}
+
+#[inline]
+pub(crate) fn ip_recverr(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_IP, c::IP_RECVERR).map(to_bool)
+}
+
+#[inline]
+pub(crate) fn set_ipv6_recverr(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IPV6, c::IPV6_RECVERR, from_bool(value))
+}
+
+#[inline]
+pub(crate) fn ipv6_recverr(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_IPV6, c::IPV6_RECVERR).map(to_bool)
+}
+
+#[inline]
+pub(crate) fn set_ip_transparent(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IP, c::IP_TRANSPARENT, from_bool(value))
+}
+
+#[inline]
+pub(crate) fn ip_transparent(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_IP, c::IP_TRANSPARENT).map(to_bool)
+}
+
+#[inline]
+pub(crate) fn set_ip_bind_address_no_port(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(
+        fd,
+        c::IPPROTO_IP,
+        c::IP_BIND_ADDRESS_NO_PORT,
+        from_bool(value),
+    )
+}
+
+#[inline]
+pub(crate) fn ip_bind_address_no_port(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_IP, c::IP_BIND_ADDRESS_NO_PORT).map(to_bool)
+}
+
+#[inline]
+pub(crate) fn set_ip_mtu_discover(fd: BorrowedFd<'_>, value: PmtuDiscovery) -> io::Result<()> {
+    setsockopt(
+        fd,
+        c::IPPROTO_IP,
+        c::IP_MTU_DISCOVER,
+        value.as_raw() as c::c_int,
+    )
+}
+
+#[inline]
+pub(crate) fn ip_mtu_discover(fd: BorrowedFd<'_>) -> io::Result<PmtuDiscovery> {
+    let value: c::c_int = getsockopt(fd, c::IPPROTO_IP, c::IP_MTU_DISCOVER)?;
+    Ok(PmtuDiscovery::from_raw(value as u32))
+}
+
+#[inline]
+pub(crate) fn ip_mtu(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    getsockopt(fd, c::IPPROTO_IP, c::IP_MTU)
+}
+
+#[inline]
+pub(crate) fn set_ipv6_transparent(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_IPV6, c::IPV6_TRANSPARENT, from_bool(value))
+}
+
+#[inline]
+pub(crate) fn ipv6_transparent(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_IPV6, c::IPV6_TRANSPARENT).map(to_bool)
+}
+
+#[inline]
+pub(crate) fn set_ipv6_mtu_discover(fd: BorrowedFd<'_>, value: PmtuDiscovery) -> io::Result<()> {
+    setsockopt(
+        fd,
+        c::IPPROTO_IPV6,
+        c::IPV6_MTU_DISCOVER,
+        value.as_raw() as c::c_int,
+    )
+}
+
+#[inline]
+pub(crate) fn ipv6_mtu_discover(fd: BorrowedFd<'_>) -> io::Result<PmtuDiscovery> {
+    let value: c::c_int = getsockopt(fd, c::IPPROTO_IPV6, c::IPV6_MTU_DISCOVER)?;
+    Ok(PmtuDiscovery::from_raw(value as u32))
+}
+
+#[inline]
+pub(crate) fn ipv6_mtu(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    getsockopt(fd, c::IPPROTO_IPV6, c::IPV6_MTU)
+}
+
 #[inline]
 pub(crate) fn set_ip_freebind(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
     setsockopt(fd, c::IPPROTO_IP, c::IP_FREEBIND, from_bool(value))
@@ -805,6 +1142,191 @@ pub(crate) fn tcp_cork(fd: BorrowedFd<'_>) -> io::Result<bool> {
     getsockopt(fd, c::IPPROTO_TCP, c::TCP_CORK).map(to_bool)
 }
 
+#[inline]
+pub(crate) fn tcp_info(fd: BorrowedFd<'_>) -> io::Result<(TcpInfo, usize)> {
+    // Older kernels write only a prefix of `struct tcp_info`, so start with
+    // zeros and return the length the kernel wrote.
+    let mut value = MaybeUninit::<TcpInfo>::zeroed();
+    let mut optlen = core::mem::size_of::<TcpInfo>().try_into().unwrap();
+    getsockopt_raw(fd, c::IPPROTO_TCP, c::TCP_INFO, &mut value, &mut optlen)?;
+    // SAFETY: All the fields of `TcpInfo` are integers, so it's valid when
+    // zero-initialized.
+    unsafe { Ok((value.assume_init(), optlen as usize)) }
+}
+
+#[inline]
+pub(crate) fn set_tcp_maxseg(fd: BorrowedFd<'_>, value: u32) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_TCP, c::TCP_MAXSEG, value)
+}
+
+#[inline]
+pub(crate) fn tcp_maxseg(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    getsockopt(fd, c::IPPROTO_TCP, c::TCP_MAXSEG)
+}
+
+#[inline]
+pub(crate) fn set_tcp_defer_accept(fd: BorrowedFd<'_>, duration: Duration) -> io::Result<()> {
+    let secs: c::c_uint = duration_to_secs(duration)?;
+    setsockopt(fd, c::IPPROTO_TCP, c::TCP_DEFER_ACCEPT, secs)
+}
+
+#[inline]
+pub(crate) fn tcp_defer_accept(fd: BorrowedFd<'_>) -> io::Result<Duration> {
+    let secs: c::c_uint = getsockopt(fd, c::IPPROTO_TCP, c::TCP_DEFER_ACCEPT)?;
+    Ok(Duration::from_secs(secs.into()))
+}
+
+#[inline]
+pub(crate) fn set_tcp_window_clamp(fd: BorrowedFd<'_>, value: u32) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_TCP, c::TCP_WINDOW_CLAMP, value)
+}
+
+#[inline]
+pub(crate) fn tcp_window_clamp(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    getsockopt(fd, c::IPPROTO_TCP, c::TCP_WINDOW_CLAMP)
+}
+
+#[inline]
+pub(crate) fn set_tcp_fastopen(fd: BorrowedFd<'_>, value: u32) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_TCP, c::TCP_FASTOPEN, value)
+}
+
+#[inline]
+pub(crate) fn tcp_fastopen(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    getsockopt(fd, c::IPPROTO_TCP, c::TCP_FASTOPEN)
+}
+
+#[inline]
+pub(crate) fn set_tcp_fastopen_connect(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(
+        fd,
+        c::IPPROTO_TCP,
+        c::TCP_FASTOPEN_CONNECT,
+        from_bool(value),
+    )
+}
+
+#[inline]
+pub(crate) fn tcp_fastopen_connect(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_TCP, c::TCP_FASTOPEN_CONNECT).map(to_bool)
+}
+
+#[inline]
+pub(crate) fn set_tcp_notsent_lowat(fd: BorrowedFd<'_>, value: u32) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_TCP, c::TCP_NOTSENT_LOWAT, value)
+}
+
+#[inline]
+pub(crate) fn tcp_notsent_lowat(fd: BorrowedFd<'_>) -> io::Result<u32> {
+    getsockopt(fd, c::IPPROTO_TCP, c::TCP_NOTSENT_LOWAT)
+}
+
+#[inline]
+pub(crate) fn set_tcp_save_syn(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_TCP, c::TCP_SAVE_SYN, from_bool(value))
+}
+
+#[inline]
+pub(crate) fn tcp_save_syn(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_TCP, c::TCP_SAVE_SYN).map(to_bool)
+}
+
+#[cfg(feature = "alloc")]
+#[inline]
+pub(crate) fn tcp_saved_syn(fd: BorrowedFd<'_>) -> io::Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    // IPv6 and TCP headers, with room for some options.
+    let mut capacity = 256;
+    loop {
+        buf.reserve(capacity);
+        let spare = buf.spare_capacity_mut();
+        capacity = spare.len();
+        let mut optlen = 0;
+        match getsockopt_raw_slice(fd, c::IPPROTO_TCP, c::TCP_SAVED_SYN, spare, &mut optlen) {
+            Ok(()) => {
+                unsafe {
+                    buf.set_len(optlen as usize);
+                }
+                return Ok(buf);
+            }
+            // If the buffer is too small, the kernel fails with `EINVAL` and
+            // sets the length to the size it needs.
+            Err(io::Errno::INVAL) if optlen as usize > capacity => capacity = optlen as usize,
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+#[inline]
+pub(crate) fn set_tcp_ulp(fd: BorrowedFd<'_>, value: &str) -> io::Result<()> {
+    let level = c::IPPROTO_TCP;
+    let optname = c::TCP_ULP;
+    let optlen = value.len().try_into().unwrap();
+    setsockopt_raw(fd, level, optname, value.as_ptr(), optlen)
+}
+
+#[cfg(feature = "alloc")]
+#[inline]
+pub(crate) fn tcp_ulp(fd: BorrowedFd<'_>) -> io::Result<String> {
+    // `TCP_ULP_NAME_MAX`
+    const OPTLEN: c::socklen_t = 16;
+
+    let level = c::IPPROTO_TCP;
+    let optname = c::TCP_ULP;
+    let mut value = MaybeUninit::<[u8; OPTLEN as usize]>::zeroed();
+    let mut optlen = OPTLEN;
+    getsockopt_raw(fd, level, optname, &mut value, &mut optlen)?;
+    // SAFETY: The buffer was zero-initialized.
+    let value = unsafe { value.assume_init() };
+    let slice = &value[..optlen as usize];
+    let len = slice
+        .iter()
+        .position(|b| *b == b'\0')
+        .unwrap_or(slice.len());
+    let name = core::str::from_utf8(&slice[..len]).map_err(|_| io::Errno::INVAL)?;
+    Ok(name.to_owned())
+}
+
+#[inline]
+pub(crate) fn set_udp_segment(fd: BorrowedFd<'_>, value: u16) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_UDP, c::UDP_SEGMENT, c::c_int::from(value))
+}
+
+#[inline]
+pub(crate) fn udp_segment(fd: BorrowedFd<'_>) -> io::Result<u16> {
+    let value: c::c_int = getsockopt(fd, c::IPPROTO_UDP, c::UDP_SEGMENT)?;
+    Ok(value as u16)
+}
+
+#[inline]
+pub(crate) fn set_udp_gro(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+    setsockopt(fd, c::IPPROTO_UDP, c::UDP_GRO, from_bool(value))
+}
+
+#[inline]
+pub(crate) fn udp_gro(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    getsockopt(fd, c::IPPROTO_UDP, c::UDP_GRO).map(to_bool)
+}
+
+#[inline]
+pub(crate) fn set_tls_tx(fd: BorrowedFd<'_>, value: &TlsCryptoInfo) -> io::Result<()> {
+    set_tls_crypto_info(fd, c::TLS_TX, value)
+}
+
+#[inline]
+pub(crate) fn set_tls_rx(fd: BorrowedFd<'_>, value: &TlsCryptoInfo) -> io::Result<()> {
+    set_tls_crypto_info(fd, c::TLS_RX, value)
+}
+
+#[inline]
+fn set_tls_crypto_info(fd: BorrowedFd<'_>, optname: u32, value: &TlsCryptoInfo) -> io::Result<()> {
+    match value {
+        TlsCryptoInfo::AesGcm128(info) => setsockopt(fd, c::SOL_TLS, optname, *info),
+        TlsCryptoInfo::AesGcm256(info) => setsockopt(fd, c::SOL_TLS, optname, *info),
+        TlsCryptoInfo::Chacha20Poly1305(info) => setsockopt(fd, c::SOL_TLS, optname, *info),
+    }
+}
+
 #[inline]
 pub(crate) fn socket_peercred(fd: BorrowedFd<'_>) -> io::Result<UCred> {
     getsockopt(fd, c::SOL_SOCKET, linux_raw_sys::net::SO_PEERCRED)
@@ -976,6 +1498,107 @@ pub(crate) fn xdp_options(fd: BorrowedFd<'_>) -> io::Result<XdpOptionsFlags> {
     getsockopt(fd, c::SOL_XDP, c::XDP_OPTIONS)
 }
 
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn set_packet_add_membership(fd: BorrowedFd<'_>, value: PacketMreq) -> io::Result<()> {
+    setsockopt(fd, c::SOL_PACKET, c::PACKET_ADD_MEMBERSHIP, value)
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn set_packet_drop_membership(fd: BorrowedFd<'_>, value: PacketMreq) -> io::Result<()> {
+    setsockopt(fd, c::SOL_PACKET, c::PACKET_DROP_MEMBERSHIP, value)
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn set_packet_version(fd: BorrowedFd<'_>, value: PacketVersion) -> io::Result<()> {
+    setsockopt(fd, c::SOL_PACKET, c::PACKET_VERSION, value as c::c_int)
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn packet_version(fd: BorrowedFd<'_>) -> io::Result<PacketVersion> {
+    match getsockopt::<c::c_int>(fd, c::SOL_PACKET, c::PACKET_VERSION)? {
+        0 => Ok(PacketVersion::V1),
+        1 => Ok(PacketVersion::V2),
+        2 => Ok(PacketVersion::V3),
+        _ => Err(io::Errno::INVAL),
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn set_packet_fanout(fd: BorrowedFd<'_>, value: PacketFanout) -> io::Result<()> {
+    // The group ID is in the low 16 bits, and the mode and flags share the
+    // high 16 bits.
+    let type_flags = value.mode.as_raw() | value.flags.bits();
+    let value = u32::from(value.group_id) | (u32::from(type_flags) << 16);
+    setsockopt(fd, c::SOL_PACKET, c::PACKET_FANOUT, value as c::c_int)
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn packet_fanout(fd: BorrowedFd<'_>) -> io::Result<Option<PacketFanout>> {
+    let value = getsockopt::<c::c_int>(fd, c::SOL_PACKET, c::PACKET_FANOUT)? as u32;
+    if value == 0 {
+        return Ok(None);
+    }
+    let type_flags = (value >> 16) as u16;
+    Ok(Some(PacketFanout {
+        group_id: value as u16,
+        mode: PacketFanoutMode::from_raw(type_flags & 0xff),
+        flags: PacketFanoutFlags::from_bits_retain(type_flags & !0xff),
+    }))
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn set_packet_rx_ring(fd: BorrowedFd<'_>, value: TpacketReq3) -> io::Result<()> {
+    setsockopt(fd, c::SOL_PACKET, c::PACKET_RX_RING, value)
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn set_packet_tx_ring(fd: BorrowedFd<'_>, value: TpacketReq3) -> io::Result<()> {
+    setsockopt(fd, c::SOL_PACKET, c::PACKET_TX_RING, value)
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn packet_statistics(fd: BorrowedFd<'_>) -> io::Result<PacketStats> {
+    let mut optlen = core::mem::size_of::<c::tpacket_stats_v3>()
+        .try_into()
+        .unwrap();
+    let mut value = MaybeUninit::<c::tpacket_stats_v3>::zeroed();
+    getsockopt_raw(
+        fd,
+        c::SOL_PACKET,
+        c::PACKET_STATISTICS,
+        &mut value,
+        &mut optlen,
+    )?;
+
+    // Safety: All members of `tpacket_stats_v3` are integers and thus are
+    // correctly initialized by `MaybeUninit::<tpacket_stats_v3>::zeroed()`.
+    let stats = unsafe { value.assume_init() };
+    if optlen as usize == core::mem::size_of::<c::tpacket_stats_v3>() {
+        Ok(PacketStats {
+            packets: stats.tp_packets,
+            drops: stats.tp_drops,
+            freeze_queue_count: Some(stats.tp_freeze_q_cnt),
+        })
+    } else {
+        // `TPACKET_V1` and `TPACKET_V2` sockets return the shorter
+        // `struct tpacket_stats`.
+        Ok(PacketStats {
+            packets: stats.tp_packets,
+            drops: stats.tp_drops,
+            freeze_queue_count: None,
+        })
+    }
+}
+
 #[inline]
 fn to_ip_mreq(multiaddr: &Ipv4Addr, interface: &Ipv4Addr) -> c::ip_mreq {
     c::ip_mreq {
@@ -8,13 +8,17 @@
 #[cfg(target_os = "linux")]
 use super::msghdr::with_xdp_msghdr;
 use super::msghdr::{
-    with_noaddr_msghdr, with_recv_msghdr, with_unix_msghdr, with_v4_msghdr, with_v6_msghdr,
+    recv_mmsghdr, send_mmsghdr, with_link_msghdr, with_netlink_msghdr, with_noaddr_msghdr,
+    with_recv_msghdr, with_unix_msghdr, with_v4_msghdr, with_v6_msghdr, with_vsock_msghdr,
 };
 use super::read_sockaddr::{initialize_family_to_unspec, maybe_read_sockaddr_os, read_sockaddr_os};
 use super::send_recv::{RecvFlags, SendFlags};
 #[cfg(target_os = "linux")]
 use super::write_sockaddr::encode_sockaddr_xdp;
-use super::write_sockaddr::{encode_sockaddr_v4, encode_sockaddr_v6};
+use super::write_sockaddr::{
+    encode_sockaddr_link, encode_sockaddr_netlink, encode_sockaddr_v4, encode_sockaddr_v6,
+    encode_sockaddr_vsock,
+};
 use crate::backend::c;
 use crate::backend::conv::{
     by_mut, by_ref, c_int, c_uint, pass_usize, ret, ret_owned_fd, ret_usize, size_of, slice,
@@ -22,24 +26,34 @@ use crate::backend::conv::{
 };
 use crate::fd::{BorrowedFd, OwnedFd};
 use crate::io::{self, IoSlice, IoSliceMut};
+use crate::net::netlink::SocketAddrNetlink;
+use crate::net::packet::SocketAddrLink;
+use crate::net::vsock::SocketAddrVsock;
 #[cfg(target_os = "linux")]
 use crate::net::xdp::SocketAddrXdp;
 use crate::net::{
-    AddressFamily, Protocol, RecvAncillaryBuffer, RecvMsgReturn, SendAncillaryBuffer, Shutdown,
-    SocketAddrAny, SocketAddrUnix, SocketAddrV4, SocketAddrV6, SocketFlags, SocketType,
+    AddressFamily, MMsgHdr, Protocol, RecvAncillaryBuffer, RecvMMsgHdr, RecvMsgReturn,
+    SendAncillaryBuffer, Shutdown, SocketAddrAny, SocketAddrUnix, SocketAddrV4, SocketAddrV6,
+    SocketFlags, SocketType, Timespec, MMSG_MAX,
 };
+use crate::utils::as_mut_ptr;
 use c::{sockaddr, sockaddr_in, sockaddr_in6, socklen_t};
+use core::cmp::min;
 use core::mem::MaybeUninit;
+use core::ptr::null_mut;
+use core::slice;
 #[cfg(target_arch = "x86")]
 use {
     crate::backend::conv::{slice_just_addr, x86_sys},
     crate::backend::reg::{ArgReg, SocketArg},
     linux_raw_sys::net::{
         SYS_ACCEPT, SYS_ACCEPT4, SYS_BIND, SYS_CONNECT, SYS_GETPEERNAME, SYS_GETSOCKNAME,
-        SYS_LISTEN, SYS_RECV, SYS_RECVFROM, SYS_RECVMSG, SYS_SEND, SYS_SENDMSG, SYS_SENDTO,
-        SYS_SHUTDOWN, SYS_SOCKET, SYS_SOCKETPAIR,
+        SYS_LISTEN, SYS_RECV, SYS_RECVFROM, SYS_RECVMSG, SYS_SEND, SYS_SENDMMSG, SYS_SENDMSG,
+        SYS_SENDTO, SYS_SHUTDOWN, SYS_SOCKET, SYS_SOCKETPAIR,
     },
 };
+#[cfg(target_pointer_width = "32")]
+use {crate::utils::option_as_mut_ptr, linux_raw_sys::general::__kernel_old_timespec};
 
 #[inline]
 pub(crate) fn socket(
@@ -299,6 +313,150 @@ pub(crate) fn recvmsg(
     })
 }
 
+#[inline]
+pub(crate) fn sendmmsg(
+    sockfd: BorrowedFd<'_>,
+    msgs: &mut [MMsgHdr<'_>],
+    msg_flags: SendFlags,
+) -> io::Result<usize> {
+    let len = min(msgs.len(), MMSG_MAX);
+    let mut raw = [MaybeUninit::<c::mmsghdr>::uninit(); MMSG_MAX];
+    for (raw, msg) in raw.iter_mut().zip(msgs.iter()) {
+        raw.write(send_mmsghdr(msg));
+    }
+    // SAFETY: We initialized the first `len` elements above.
+    let raw = unsafe { slice::from_raw_parts_mut(raw.as_mut_ptr().cast::<c::mmsghdr>(), len) };
+
+    #[cfg(not(target_arch = "x86"))]
+    let nmsgs = unsafe {
+        ret_usize(syscall!(
+            __NR_sendmmsg,
+            sockfd,
+            raw.as_mut_ptr(),
+            c_uint(len as c::c_uint),
+            msg_flags
+        ))?
+    };
+
+    #[cfg(target_arch = "x86")]
+    let nmsgs = unsafe {
+        ret_usize(syscall!(
+            __NR_socketcall,
+            x86_sys(SYS_SENDMMSG),
+            slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
+                sockfd.into(),
+                raw.as_mut_ptr().into(),
+                c_uint(len as c::c_uint),
+                msg_flags.into(),
+            ])
+        ))?
+    };
+
+    for (msg, raw) in msgs.iter_mut().zip(&raw[..nmsgs]) {
+        msg.bytes = raw.msg_len as usize;
+    }
+    Ok(nmsgs)
+}
+
+#[inline]
+pub(crate) fn recvmmsg(
+    sockfd: BorrowedFd<'_>,
+    msgs: &mut [RecvMMsgHdr<'_>],
+    msg_flags: RecvFlags,
+    timeout: Option<&Timespec>,
+) -> io::Result<usize> {
+    let len = min(msgs.len(), MMSG_MAX);
+    let mut raw = [MaybeUninit::<c::mmsghdr>::uninit(); MMSG_MAX];
+    for (raw, msg) in raw.iter_mut().zip(msgs.iter_mut()) {
+        // SAFETY: `RecvMMsgHdr::new` is the only way to create a
+        // `RecvMMsgHdr`.
+        raw.write(unsafe { recv_mmsghdr(msg) });
+    }
+    // SAFETY: We initialized the first `len` elements above.
+    let raw = unsafe { slice::from_raw_parts_mut(raw.as_mut_ptr().cast::<c::mmsghdr>(), len) };
+
+    // Linux's `recvmmsg` mutates the timeout argument. Our public interface
+    // does not do this, so we create a temporary value to hide this behavior.
+    let mut timeout_data;
+    let timeout_ptr = match timeout {
+        Some(timeout) => {
+            timeout_data = *timeout;
+            as_mut_ptr(&mut timeout_data)
+        }
+        None => null_mut(),
+    };
+
+    // `recvmmsg_time64` was introduced in Linux 5.1. The old `recvmmsg`
+    // syscall is not y2038-compatible on 32-bit architectures.
+    #[cfg(target_pointer_width = "32")]
+    let nmsgs = unsafe {
+        match ret_usize(syscall!(
+            __NR_recvmmsg_time64,
+            sockfd,
+            raw.as_mut_ptr(),
+            c_uint(len as c::c_uint),
+            msg_flags,
+            timeout_ptr
+        )) {
+            Ok(nmsgs) => nmsgs,
+            Err(io::Errno::NOSYS) => recvmmsg_old(sockfd, raw, msg_flags, timeout)?,
+            Err(err) => return Err(err),
+        }
+    };
+
+    #[cfg(target_pointer_width = "64")]
+    let nmsgs = unsafe {
+        ret_usize(syscall!(
+            __NR_recvmmsg,
+            sockfd,
+            raw.as_mut_ptr(),
+            c_uint(len as c::c_uint),
+            msg_flags,
+            timeout_ptr
+        ))?
+    };
+
+    for (msg, raw) in msgs.iter_mut().zip(&raw[..nmsgs]) {
+        let hdr = &raw.msg_hdr;
+        msg.bytes = raw.msg_len as usize;
+        msg.flags = RecvFlags::from_bits_retain(hdr.msg_flags);
+        // Get the address of the sender, if any.
+        msg.address = unsafe { maybe_read_sockaddr_os(hdr.msg_name as _, hdr.msg_namelen as _) };
+        // Reset the control length.
+        unsafe {
+            (*msg.control).set_control_len(hdr.msg_controllen.try_into().unwrap_or(usize::MAX));
+        }
+    }
+    Ok(nmsgs)
+}
+
+#[cfg(target_pointer_width = "32")]
+unsafe fn recvmmsg_old(
+    sockfd: BorrowedFd<'_>,
+    raw: &mut [c::mmsghdr],
+    msg_flags: RecvFlags,
+    timeout: Option<&Timespec>,
+) -> io::Result<usize> {
+    let mut old_timeout = match timeout {
+        Some(timeout) => Some(__kernel_old_timespec {
+            tv_sec: timeout.tv_sec.try_into().map_err(|_| io::Errno::OVERFLOW)?,
+            tv_nsec: timeout.tv_nsec as _,
+        }),
+        None => None,
+    };
+
+    let old_timeout_ptr = option_as_mut_ptr(old_timeout.as_mut());
+
+    ret_usize(syscall!(
+        __NR_recvmmsg,
+        sockfd,
+        raw.as_mut_ptr(),
+        c_uint(raw.len() as c::c_uint),
+        msg_flags,
+        old_timeout_ptr
+    ))
+}
+
 #[inline]
 pub(crate) fn sendmsg(
     sockfd: BorrowedFd<'_>,
@@ -449,6 +607,96 @@ pub(crate) fn sendmsg_xdp(
     })
 }
 
+#[inline]
+pub(crate) fn sendmsg_netlink(
+    sockfd: BorrowedFd<'_>,
+    addr: &SocketAddrNetlink,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    msg_flags: SendFlags,
+) -> io::Result<usize> {
+    with_netlink_msghdr(addr, iov, control, |msghdr| {
+        #[cfg(not(target_arch = "x86"))]
+        let result =
+            unsafe { ret_usize(syscall!(__NR_sendmsg, sockfd, by_ref(&msghdr), msg_flags)) };
+
+        #[cfg(target_arch = "x86")]
+        let result = unsafe {
+            ret_usize(syscall!(
+                __NR_socketcall,
+                x86_sys(SYS_SENDMSG),
+                slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
+                    sockfd.into(),
+                    by_ref(&msghdr),
+                    msg_flags.into()
+                ])
+            ))
+        };
+
+        result
+    })
+}
+
+#[inline]
+pub(crate) fn sendmsg_link(
+    sockfd: BorrowedFd<'_>,
+    addr: &SocketAddrLink,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    msg_flags: SendFlags,
+) -> io::Result<usize> {
+    with_link_msghdr(addr, iov, control, |msghdr| {
+        #[cfg(not(target_arch = "x86"))]
+        let result =
+            unsafe { ret_usize(syscall!(__NR_sendmsg, sockfd, by_ref(&msghdr), msg_flags)) };
+
+        #[cfg(target_arch = "x86")]
+        let result = unsafe {
+            ret_usize(syscall!(
+                __NR_socketcall,
+                x86_sys(SYS_SENDMSG),
+                slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
+                    sockfd.into(),
+                    by_ref(&msghdr),
+                    msg_flags.into()
+                ])
+            ))
+        };
+
+        result
+    })
+}
+
+#[inline]
+pub(crate) fn sendmsg_vsock(
+    sockfd: BorrowedFd<'_>,
+    addr: &SocketAddrVsock,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    msg_flags: SendFlags,
+) -> io::Result<usize> {
+    with_vsock_msghdr(addr, iov, control, |msghdr| {
+        #[cfg(not(target_arch = "x86"))]
+        let result =
+            unsafe { ret_usize(syscall!(__NR_sendmsg, sockfd, by_ref(&msghdr), msg_flags)) };
+
+        #[cfg(target_arch = "x86")]
+        let result = unsafe {
+            ret_usize(syscall!(
+                __NR_socketcall,
+                x86_sys(SYS_SENDMSG),
+                slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
+                    sockfd.into(),
+                    by_ref(&msghdr),
+                    msg_flags.into()
+                ])
+            ))
+        };
+
+        result
+    })
+}
+
 #[inline]
 pub(crate) fn shutdown(fd: BorrowedFd<'_>, how: Shutdown) -> io::Result<()> {
     #[cfg(not(target_arch = "x86"))]
@@ -672,6 +920,120 @@ pub(crate) fn sendto_xdp(
     }
 }
 
+#[inline]
+pub(crate) fn sendto_netlink(
+    fd: BorrowedFd<'_>,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrNetlink,
+) -> io::Result<usize> {
+    let (buf_addr, buf_len) = slice(buf);
+
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_sendto,
+            fd,
+            buf_addr,
+            buf_len,
+            flags,
+            by_ref(&encode_sockaddr_netlink(addr)),
+            size_of::<c::sockaddr_nl, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_SENDTO),
+            slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
+                fd.into(),
+                buf_addr,
+                buf_len,
+                flags.into(),
+                by_ref(&encode_sockaddr_netlink(addr)),
+                size_of::<c::sockaddr_nl, _>(),
+            ])
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn sendto_link(
+    fd: BorrowedFd<'_>,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrLink,
+) -> io::Result<usize> {
+    let (buf_addr, buf_len) = slice(buf);
+
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_sendto,
+            fd,
+            buf_addr,
+            buf_len,
+            flags,
+            by_ref(&encode_sockaddr_link(addr)),
+            size_of::<c::sockaddr_ll, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_SENDTO),
+            slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
+                fd.into(),
+                buf_addr,
+                buf_len,
+                flags.into(),
+                by_ref(&encode_sockaddr_link(addr)),
+                size_of::<c::sockaddr_ll, _>(),
+            ])
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn sendto_vsock(
+    fd: BorrowedFd<'_>,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrVsock,
+) -> io::Result<usize> {
+    let (buf_addr, buf_len) = slice(buf);
+
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_sendto,
+            fd,
+            buf_addr,
+            buf_len,
+            flags,
+            by_ref(&encode_sockaddr_vsock(addr)),
+            size_of::<c::sockaddr_vm, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_SENDTO),
+            slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
+                fd.into(),
+                buf_addr,
+                buf_len,
+                flags.into(),
+                by_ref(&encode_sockaddr_vsock(addr)),
+                size_of::<c::sockaddr_vm, _>(),
+            ])
+        ))
+    }
+}
+
 #[inline]
 pub(crate) unsafe fn recv(
     fd: BorrowedFd<'_>,
@@ -945,6 +1307,81 @@ pub(crate) fn bind_xdp(fd: BorrowedFd<'_>, addr: &SocketAddrXdp) -> io::Result<(
     }
 }
 
+#[inline]
+pub(crate) fn bind_netlink(fd: BorrowedFd<'_>, addr: &SocketAddrNetlink) -> io::Result<()> {
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_bind,
+            fd,
+            by_ref(&encode_sockaddr_netlink(addr)),
+            size_of::<c::sockaddr_nl, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_BIND),
+            slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
+                fd.into(),
+                by_ref(&encode_sockaddr_netlink(addr)),
+                size_of::<c::sockaddr_nl, _>(),
+            ])
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn bind_link(fd: BorrowedFd<'_>, addr: &SocketAddrLink) -> io::Result<()> {
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_bind,
+            fd,
+            by_ref(&encode_sockaddr_link(addr)),
+            size_of::<c::sockaddr_ll, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_BIND),
+            slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
+                fd.into(),
+                by_ref(&encode_sockaddr_link(addr)),
+                size_of::<c::sockaddr_ll, _>(),
+            ])
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn bind_vsock(fd: BorrowedFd<'_>, addr: &SocketAddrVsock) -> io::Result<()> {
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_bind,
+            fd,
+            by_ref(&encode_sockaddr_vsock(addr)),
+            size_of::<c::sockaddr_vm, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_BIND),
+            slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
+                fd.into(),
+                by_ref(&encode_sockaddr_vsock(addr)),
+                size_of::<c::sockaddr_vm, _>(),
+            ])
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn connect_v4(fd: BorrowedFd<'_>, addr: &SocketAddrV4) -> io::Result<()> {
     #[cfg(not(target_arch = "x86"))]
@@ -1020,6 +1457,56 @@ pub(crate) fn connect_unix(fd: BorrowedFd<'_>, addr: &SocketAddrUnix) -> io::Res
     }
 }
 
+#[inline]
+pub(crate) fn connect_netlink(fd: BorrowedFd<'_>, addr: &SocketAddrNetlink) -> io::Result<()> {
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_connect,
+            fd,
+            by_ref(&encode_sockaddr_netlink(addr)),
+            size_of::<c::sockaddr_nl, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_CONNECT),
+            slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
+                fd.into(),
+                by_ref(&encode_sockaddr_netlink(addr)),
+                size_of::<c::sockaddr_nl, _>(),
+            ])
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn connect_vsock(fd: BorrowedFd<'_>, addr: &SocketAddrVsock) -> io::Result<()> {
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_connect,
+            fd,
+            by_ref(&encode_sockaddr_vsock(addr)),
+            size_of::<c::sockaddr_vm, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_CONNECT),
+            slice_just_addr::<ArgReg<'_, SocketArg>, _>(&[
+                fd.into(),
+                by_ref(&encode_sockaddr_vsock(addr)),
+                size_of::<c::sockaddr_vm, _>(),
+            ])
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn connect_unspec(fd: BorrowedFd<'_>) -> io::Result<()> {
     debug_assert_eq!(c::AF_UNSPEC, 0);
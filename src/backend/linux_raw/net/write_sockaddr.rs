@@ -3,9 +3,15 @@
 #![allow(unsafe_code)]
 
 use crate::backend::c;
+use crate::net::netlink::SocketAddrNetlink;
+use crate::net::packet::SocketAddrLink;
+use crate::net::vsock::SocketAddrVsock;
 #[cfg(target_os = "linux")]
 use crate::net::xdp::SocketAddrXdp;
-use crate::net::{SocketAddrAny, SocketAddrStorage, SocketAddrUnix, SocketAddrV4, SocketAddrV6};
+use crate::net::{
+    Ipv4PktInfo, Ipv6PktInfo, SocketAddrAny, SocketAddrStorage, SocketAddrUnix, SocketAddrV4,
+    SocketAddrV6,
+};
 use core::mem::size_of;
 
 pub(crate) unsafe fn write_sockaddr(
@@ -18,6 +24,9 @@ pub(crate) unsafe fn write_sockaddr(
         SocketAddrAny::Unix(unix) => write_sockaddr_unix(unix, storage),
         #[cfg(target_os = "linux")]
         SocketAddrAny::Xdp(xdp) => write_sockaddr_xdp(xdp, storage),
+        SocketAddrAny::Netlink(netlink) => write_sockaddr_netlink(netlink, storage),
+        SocketAddrAny::Link(link) => write_sockaddr_link(link, storage),
+        SocketAddrAny::Vsock(vsock) => write_sockaddr_vsock(vsock, storage),
     }
 }
 
@@ -80,3 +89,84 @@ unsafe fn write_sockaddr_xdp(xdp: &SocketAddrXdp, storage: *mut SocketAddrStorag
     core::ptr::write(storage.cast(), encoded);
     size_of::<c::sockaddr_xdp>()
 }
+
+pub(crate) fn encode_sockaddr_netlink(netlink: &SocketAddrNetlink) -> c::sockaddr_nl {
+    c::sockaddr_nl {
+        nl_family: c::AF_NETLINK as _,
+        nl_pad: 0,
+        nl_pid: netlink.pid(),
+        nl_groups: netlink.groups(),
+    }
+}
+
+unsafe fn write_sockaddr_netlink(
+    netlink: &SocketAddrNetlink,
+    storage: *mut SocketAddrStorage,
+) -> usize {
+    let encoded = encode_sockaddr_netlink(netlink);
+    core::ptr::write(storage.cast(), encoded);
+    size_of::<c::sockaddr_nl>()
+}
+
+pub(crate) fn encode_sockaddr_link(link: &SocketAddrLink) -> c::sockaddr_ll {
+    let address = link.address();
+    let mut sll_addr = [0; 8];
+    sll_addr[..address.len()].copy_from_slice(address);
+    c::sockaddr_ll {
+        sll_family: c::AF_PACKET as _,
+        sll_protocol: link
+            .protocol()
+            .map_or(0, |protocol| protocol.as_raw().get() as u16),
+        sll_ifindex: link.interface_index() as _,
+        sll_hatype: link.hardware_type(),
+        sll_pkttype: link.packet_type().as_raw(),
+        sll_halen: address.len() as _,
+        sll_addr,
+    }
+}
+
+unsafe fn write_sockaddr_link(link: &SocketAddrLink, storage: *mut SocketAddrStorage) -> usize {
+    let encoded = encode_sockaddr_link(link);
+    core::ptr::write(storage.cast(), encoded);
+    size_of::<c::sockaddr_ll>()
+}
+
+pub(crate) fn encode_sockaddr_vsock(vsock: &SocketAddrVsock) -> c::sockaddr_vm {
+    c::sockaddr_vm {
+        svm_family: c::AF_VSOCK as _,
+        svm_reserved1: 0,
+        svm_port: vsock.port(),
+        svm_cid: vsock.cid(),
+        svm_flags: 0,
+        svm_zero: [0; 3],
+    }
+}
+
+unsafe fn write_sockaddr_vsock(vsock: &SocketAddrVsock, storage: *mut SocketAddrStorage) -> usize {
+    let encoded = encode_sockaddr_vsock(vsock);
+    core::ptr::write(storage.cast(), encoded);
+    size_of::<c::sockaddr_vm>()
+}
+
+pub(crate) fn encode_in_pktinfo(info: &Ipv4PktInfo) -> c::in_pktinfo {
+    c::in_pktinfo {
+        ipi_ifindex: info.ifindex as _,
+        ipi_spec_dst: c::in_addr {
+            s_addr: u32::from_ne_bytes(info.spec_dst.octets()),
+        },
+        ipi_addr: c::in_addr {
+            s_addr: u32::from_ne_bytes(info.addr.octets()),
+        },
+    }
+}
+
+pub(crate) fn encode_in6_pktinfo(info: &Ipv6PktInfo) -> c::in6_pktinfo {
+    c::in6_pktinfo {
+        ipi6_addr: c::in6_addr {
+            in6_u: linux_raw_sys::net::in6_addr__bindgen_ty_1 {
+                u6_addr8: info.addr.octets(),
+            },
+        },
+        ipi6_ifindex: info.ifindex as _,
+    }
+}
@@ -0,0 +1,32 @@
+//! linux_raw syscalls supporting `rustix::perf_event`.
+//!
+//! # Safety
+//!
+//! See the `rustix::backend::syscalls` module documentation for details.
+#![allow(unsafe_code, clippy::undocumented_unsafe_blocks)]
+
+use crate::backend::c;
+use crate::backend::conv::{by_ref, c_int, c_uint, ret_owned_fd};
+use crate::fd::OwnedFd;
+use crate::io;
+use crate::perf_event::PerfEventAttr;
+
+#[inline]
+pub(crate) fn perf_event_open(
+    attr: &PerfEventAttr,
+    pid: c::c_int,
+    cpu: c::c_int,
+    group_fd: c::c_int,
+    flags: c::c_uint,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall!(
+            __NR_perf_event_open,
+            by_ref(attr),
+            c_int(pid),
+            c_int(cpu),
+            c_int(group_fd),
+            c_uint(flags)
+        ))
+    }
+}
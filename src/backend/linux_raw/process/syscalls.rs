@@ -10,7 +10,7 @@ use crate::backend::c;
 use crate::backend::conv::slice_mut;
 use crate::backend::conv::{
     by_mut, by_ref, c_int, c_uint, negative_pid, pass_usize, raw_fd, ret, ret_c_int,
-    ret_c_int_infallible, ret_infallible, ret_owned_fd, zero,
+    ret_c_int_infallible, ret_c_uint, ret_infallible, ret_owned_fd, size_of, zero,
 };
 use crate::fd::{AsRawFd, BorrowedFd, OwnedFd, RawFd};
 #[cfg(feature = "fs")]
@@ -18,8 +18,8 @@ use crate::ffi::CStr;
 use crate::io;
 use crate::pid::RawPid;
 use crate::process::{
-    Pid, PidfdFlags, PidfdGetfdFlags, Resource, Rlimit, Uid, WaitId, WaitIdOptions, WaitIdStatus,
-    WaitOptions, WaitStatus,
+    landlock, Pid, PidfdFlags, PidfdGetfdFlags, Resource, Rlimit, Uid, WaitId, WaitIdOptions,
+    WaitIdStatus, WaitOptions, WaitStatus,
 };
 use crate::signal::Signal;
 use core::mem::MaybeUninit;
@@ -519,3 +519,70 @@ pub(crate) fn getgroups(buf: &mut [Gid]) -> io::Result<usize> {
         ))
     }
 }
+
+#[inline]
+pub(crate) fn landlock_abi_version() -> io::Result<u32> {
+    unsafe {
+        ret_c_uint(syscall_readonly!(
+            __NR_landlock_create_ruleset,
+            zero(),
+            zero(),
+            c_uint(landlock::CREATE_RULESET_VERSION)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn landlock_create_ruleset(attr: &landlock::RulesetAttr) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_landlock_create_ruleset,
+            by_ref(attr),
+            size_of::<landlock::RulesetAttr, _>(),
+            c_uint(0)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn landlock_add_path_beneath_rule(
+    ruleset: BorrowedFd<'_>,
+    attr: &landlock::PathBeneathAttr,
+) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_landlock_add_rule,
+            ruleset,
+            c_uint(landlock::RULE_PATH_BENEATH),
+            by_ref(attr),
+            c_uint(0)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn landlock_add_net_port_rule(
+    ruleset: BorrowedFd<'_>,
+    attr: &landlock::NetPortAttr,
+) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_landlock_add_rule,
+            ruleset,
+            c_uint(landlock::RULE_NET_PORT),
+            by_ref(attr),
+            c_uint(0)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn landlock_restrict_self(ruleset: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_landlock_restrict_self,
+            ruleset,
+            c_uint(0)
+        ))
+    }
+}
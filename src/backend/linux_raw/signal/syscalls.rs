@@ -0,0 +1,253 @@
+//! linux_raw syscalls supporting `rustix::signal`.
+//!
+//! # Safety
+//!
+//! See the `rustix::backend` module documentation for details.
+#![allow(unsafe_code, clippy::undocumented_unsafe_blocks)]
+
+use super::types::{RawSigSet, RawSiginfo};
+use crate::backend::c;
+use crate::backend::conv::{by_ref, c_int, ret, ret_c_int, ret_infallible, size_of, zero};
+use crate::io;
+use crate::signal::{SigAction, SigHandler, SigSet, SigactionFlags, Siginfo, SigmaskHow, Signal};
+use crate::timespec::Timespec;
+use crate::utils::option_as_ptr;
+#[cfg(target_arch = "x86_64")]
+use core::arch::global_asm;
+use core::mem::{transmute, zeroed, MaybeUninit};
+#[cfg(target_pointer_width = "32")]
+use linux_raw_sys::general::__kernel_old_timespec;
+#[cfg(target_arch = "x86_64")]
+use linux_raw_sys::general::SA_RESTORER;
+use linux_raw_sys::general::{kernel_sigaction, SA_SIGINFO};
+
+#[inline]
+pub(crate) fn thread_sigmask(how: SigmaskHow, set: Option<&SigSet>) -> io::Result<SigSet> {
+    let mut old = MaybeUninit::<RawSigSet>::uninit();
+    let new = option_as_ptr(set.map(SigSet::as_raw));
+    unsafe {
+        ret(syscall!(
+            __NR_rt_sigprocmask,
+            c_int(how as c::c_int),
+            new,
+            &mut old,
+            size_of::<RawSigSet, _>()
+        ))?;
+        Ok(SigSet::from_raw(old.assume_init()))
+    }
+}
+
+#[inline]
+pub(crate) fn sigpending() -> SigSet {
+    let mut pending = MaybeUninit::<RawSigSet>::uninit();
+    unsafe {
+        ret_infallible(syscall!(
+            __NR_rt_sigpending,
+            &mut pending,
+            size_of::<RawSigSet, _>()
+        ));
+        SigSet::from_raw(pending.assume_init())
+    }
+}
+
+#[inline]
+pub(crate) fn sigwait(set: &SigSet) -> io::Result<Signal> {
+    unsafe {
+        match Signal::from_raw(ret_c_int(syscall_readonly!(
+            __NR_rt_sigtimedwait,
+            by_ref(set.as_raw()),
+            zero(),
+            zero(),
+            size_of::<RawSigSet, _>()
+        ))?) {
+            Some(signum) => Ok(signum),
+            None => Err(io::Errno::NOTSUP),
+        }
+    }
+}
+
+#[inline]
+pub(crate) fn sigwaitinfo(set: &SigSet) -> io::Result<RawSiginfo> {
+    let mut info = MaybeUninit::<RawSiginfo>::uninit();
+    unsafe {
+        let _signum = ret_c_int(syscall!(
+            __NR_rt_sigtimedwait,
+            by_ref(set.as_raw()),
+            &mut info,
+            zero(),
+            size_of::<RawSigSet, _>()
+        ))?;
+        Ok(info.assume_init())
+    }
+}
+
+#[inline]
+pub(crate) fn sigtimedwait(set: &SigSet, timeout: Option<&Timespec>) -> io::Result<RawSiginfo> {
+    let mut info = MaybeUninit::<RawSiginfo>::uninit();
+    let timeout_ptr = option_as_ptr(timeout);
+
+    // `rt_sigtimedwait_time64` was introduced in Linux 5.1. The old
+    // `rt_sigtimedwait` syscall is not y2038-compatible on 32-bit
+    // architectures.
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        match ret_c_int(syscall!(
+            __NR_rt_sigtimedwait_time64,
+            by_ref(set.as_raw()),
+            &mut info,
+            timeout_ptr,
+            size_of::<RawSigSet, _>()
+        )) {
+            Ok(_signum) => (),
+            Err(io::Errno::NOSYS) => sigtimedwait_old(set, timeout, &mut info)?,
+            Err(err) => return Err(err),
+        }
+        Ok(info.assume_init())
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        let _signum = ret_c_int(syscall!(
+            __NR_rt_sigtimedwait,
+            by_ref(set.as_raw()),
+            &mut info,
+            timeout_ptr,
+            size_of::<RawSigSet, _>()
+        ))?;
+        Ok(info.assume_init())
+    }
+}
+
+#[cfg(target_pointer_width = "32")]
+unsafe fn sigtimedwait_old(
+    set: &SigSet,
+    timeout: Option<&Timespec>,
+    info: &mut MaybeUninit<RawSiginfo>,
+) -> io::Result<()> {
+    let old_timeout = match timeout {
+        Some(timeout) => Some(__kernel_old_timespec {
+            tv_sec: timeout.tv_sec.try_into().map_err(|_| io::Errno::OVERFLOW)?,
+            tv_nsec: timeout.tv_nsec as _,
+        }),
+        None => None,
+    };
+
+    let old_timeout_ptr = option_as_ptr(old_timeout.as_ref());
+
+    let _signum = ret_c_int(syscall!(
+        __NR_rt_sigtimedwait,
+        by_ref(set.as_raw()),
+        info,
+        old_timeout_ptr,
+        size_of::<RawSigSet, _>()
+    ))?;
+
+    Ok(())
+}
+
+pub(crate) unsafe fn sigaction(signal: Signal, new: Option<&SigAction>) -> io::Result<SigAction> {
+    let new = new.map(|new| encode_sigaction(new));
+    let mut old = MaybeUninit::<kernel_sigaction>::uninit();
+    ret(syscall!(
+        __NR_rt_sigaction,
+        signal,
+        option_as_ptr(new.as_ref()),
+        &mut old,
+        size_of::<RawSigSet, _>()
+    ))?;
+    Ok(decode_sigaction(&old.assume_init()))
+}
+
+type HandlerFn = extern "C" fn(c::c_int);
+type SigInfoFn = extern "C" fn(c::c_int, *mut Siginfo, *mut c::c_void);
+
+// linux-raw-sys doesn't define these, because they're pointer-typed macros.
+const SIG_DFL: usize = 0;
+const SIG_IGN: usize = 1;
+
+unsafe fn encode_sigaction(action: &SigAction) -> kernel_sigaction {
+    // Not all architectures have an `sa_restorer` field, so start from zeros
+    // and fill in the fields that are common to all of them.
+    let mut raw: kernel_sigaction = zeroed();
+    let mut flags = action.flags.bits() as c::c_ulong;
+
+    let handler: usize = match action.handler {
+        SigHandler::Default => SIG_DFL,
+        SigHandler::Ignore => SIG_IGN,
+        SigHandler::Handler(handler) => handler as usize,
+        SigHandler::SigInfo(handler) => {
+            flags |= SA_SIGINFO as c::c_ulong;
+            handler as usize
+        }
+    };
+    raw.sa_handler_kernel = transmute::<usize, Option<unsafe extern "C" fn(c::c_int)>>(handler);
+
+    // On x86_64, the kernel doesn't provide a way to return from a signal
+    // handler, so we have to provide our own.
+    #[cfg(target_arch = "x86_64")]
+    {
+        raw.sa_restorer = Some(rustix_sigreturn);
+        flags |= SA_RESTORER as c::c_ulong;
+    }
+
+    raw.sa_flags = flags as _;
+    raw.sa_mask = *action.mask.as_raw();
+    raw
+}
+
+unsafe fn decode_sigaction(raw: &kernel_sigaction) -> SigAction {
+    let handler = transmute::<Option<unsafe extern "C" fn(c::c_int)>, usize>(raw.sa_handler_kernel);
+    let handler = match handler {
+        SIG_DFL => SigHandler::Default,
+        SIG_IGN => SigHandler::Ignore,
+        _ if raw.sa_flags as u32 & SA_SIGINFO != 0 => {
+            SigHandler::SigInfo(transmute::<usize, SigInfoFn>(handler))
+        }
+        _ => SigHandler::Handler(transmute::<usize, HandlerFn>(handler)),
+    };
+
+    SigAction {
+        handler,
+        // `SA_SIGINFO` is expressed by the handler, and a libc in the same
+        // process may have installed the action with flags of its own, such
+        // as `SA_RESTORER`, so only report the flags we know, as the libc
+        // backend does.
+        flags: SigactionFlags::from_bits_retain(raw.sa_flags as u32).intersection(
+            SigactionFlags::NOCLDSTOP
+                | SigactionFlags::NOCLDWAIT
+                | SigactionFlags::ONSTACK
+                | SigactionFlags::RESTART
+                | SigactionFlags::NODEFER
+                | SigactionFlags::RESETHAND,
+        ),
+        mask: SigSet::from_raw(raw.sa_mask),
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+extern "C" {
+    /// A signal restorer which calls `rt_sigreturn`, which is syscall 15.
+    ///
+    /// This is only ever called by the kernel, when a signal handler returns,
+    /// so its signature here is not its true signature.
+    ///
+    /// This extern block and the `global_asm!` below can be replaced with
+    /// `#[naked]` if it's stabilized.
+    fn rustix_sigreturn();
+}
+
+#[cfg(target_arch = "x86_64")]
+global_asm!(
+    r#"
+    .section    .text.rustix_sigreturn,"ax",@progbits
+    .p2align    4
+    .weak       rustix_sigreturn
+    .hidden     rustix_sigreturn
+    .type       rustix_sigreturn, @function
+rustix_sigreturn:
+    mov    eax, 15
+    syscall
+    ud2
+    .size rustix_sigreturn, .-rustix_sigreturn
+"#
+);
@@ -0,0 +1,140 @@
+use crate::backend::c;
+#[cfg(feature = "signal")]
+use crate::ugid::Uid;
+#[cfg(feature = "signal")]
+use bitflags::bitflags;
+use core::mem::size_of;
+use linux_raw_sys::general::kernel_sigset_t;
+
+/// The kernel's signal set type, as used by `rt_sigprocmask`, `signalfd4`,
+/// and related syscalls.
+pub(crate) type RawSigSet = kernel_sigset_t;
+
+const WORDS: usize = size_of::<RawSigSet>() / size_of::<c::c_ulong>();
+const BITS_PER_WORD: usize = c::c_ulong::BITS as usize;
+
+/// Compute the word index and bit mask for signal `sig`, which is numbered
+/// starting at 1.
+#[inline]
+fn word_and_bit(sig: c::c_int) -> (usize, c::c_ulong) {
+    let bit = (sig - 1) as usize;
+    (bit / BITS_PER_WORD, 1 << (bit % BITS_PER_WORD))
+}
+
+#[inline]
+pub(crate) fn sigemptyset() -> RawSigSet {
+    RawSigSet { sig: [0; WORDS] }
+}
+
+#[inline]
+pub(crate) fn sigaddset(set: &mut RawSigSet, sig: c::c_int) {
+    let (word, bit) = word_and_bit(sig);
+    set.sig[word] |= bit;
+}
+
+#[inline]
+pub(crate) fn sigdelset(set: &mut RawSigSet, sig: c::c_int) {
+    let (word, bit) = word_and_bit(sig);
+    set.sig[word] &= !bit;
+}
+
+#[inline]
+pub(crate) fn sigismember(set: &RawSigSet, sig: c::c_int) -> bool {
+    let (word, bit) = word_and_bit(sig);
+    (set.sig[word] & bit) != 0
+}
+
+/// The kernel's `siginfo_t`.
+#[cfg(feature = "signal")]
+pub(crate) type RawSiginfo = linux_raw_sys::general::siginfo_t;
+
+// The fields of `siginfo_t` after `si_code` live in a union whose active
+// member depends on the signal and `si_code`. Each of these accessors reads
+// plain integer or pointer data, for which every bit pattern is valid.
+
+#[cfg(feature = "signal")]
+#[allow(unsafe_code)]
+#[inline]
+pub(crate) fn siginfo_signo(info: &RawSiginfo) -> c::c_int {
+    unsafe { info.__bindgen_anon_1.__bindgen_anon_1.si_signo }
+}
+
+#[cfg(feature = "signal")]
+#[allow(unsafe_code)]
+#[inline]
+pub(crate) fn siginfo_errno(info: &RawSiginfo) -> c::c_int {
+    unsafe { info.__bindgen_anon_1.__bindgen_anon_1.si_errno }
+}
+
+#[cfg(feature = "signal")]
+#[allow(unsafe_code)]
+#[inline]
+pub(crate) fn siginfo_code(info: &RawSiginfo) -> c::c_int {
+    unsafe { info.__bindgen_anon_1.__bindgen_anon_1.si_code }
+}
+
+#[cfg(feature = "signal")]
+#[allow(unsafe_code)]
+#[inline]
+pub(crate) fn siginfo_pid(info: &RawSiginfo) -> c::pid_t {
+    unsafe { info.__bindgen_anon_1.__bindgen_anon_1._sifields._kill._pid }
+}
+
+#[cfg(feature = "signal")]
+#[allow(unsafe_code)]
+#[inline]
+pub(crate) fn siginfo_uid(info: &RawSiginfo) -> Uid {
+    unsafe { Uid::from_raw(info.__bindgen_anon_1.__bindgen_anon_1._sifields._kill._uid) }
+}
+
+#[cfg(feature = "signal")]
+#[allow(unsafe_code)]
+#[inline]
+pub(crate) fn siginfo_status(info: &RawSiginfo) -> c::c_int {
+    unsafe {
+        info.__bindgen_anon_1
+            .__bindgen_anon_1
+            ._sifields
+            ._sigchld
+            ._status
+    }
+}
+
+#[cfg(feature = "signal")]
+#[allow(unsafe_code)]
+#[inline]
+pub(crate) fn siginfo_addr(info: &RawSiginfo) -> *mut c::c_void {
+    unsafe {
+        info.__bindgen_anon_1
+            .__bindgen_anon_1
+            ._sifields
+            ._sigfault
+            ._addr
+    }
+}
+
+#[cfg(feature = "signal")]
+bitflags! {
+    /// `SA_*` flags for use with [`SigAction`].
+    ///
+    /// [`SigAction`]: crate::signal::SigAction
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct SigactionFlags: u32 {
+        /// `SA_NOCLDSTOP`
+        const NOCLDSTOP = linux_raw_sys::general::SA_NOCLDSTOP;
+        /// `SA_NOCLDWAIT`
+        const NOCLDWAIT = linux_raw_sys::general::SA_NOCLDWAIT;
+        /// `SA_ONSTACK`
+        const ONSTACK = linux_raw_sys::general::SA_ONSTACK;
+        /// `SA_RESTART`
+        const RESTART = linux_raw_sys::general::SA_RESTART;
+        /// `SA_NODEFER`
+        const NODEFER = linux_raw_sys::general::SA_NODEFER;
+        /// `SA_RESETHAND`
+        const RESETHAND = linux_raw_sys::general::SA_RESETHAND;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
@@ -8,14 +8,16 @@
 use super::types::RawCpuSet;
 use crate::backend::c;
 use crate::backend::conv::{
-    by_mut, by_ref, c_int, c_uint, ret, ret_c_int, ret_c_int_infallible, ret_c_uint, ret_usize,
-    size_of, slice, slice_just_addr, slice_just_addr_mut, zero,
+    by_mut, by_ref, c_int, c_uint, ret, ret_c_int, ret_c_int_infallible, ret_c_uint, ret_owned_fd,
+    ret_usize, size_of, slice, slice_just_addr, slice_just_addr_mut, zero,
 };
-use crate::fd::BorrowedFd;
+use crate::bpf_filter::SockFprog;
+use crate::fd::{BorrowedFd, OwnedFd};
 use crate::io;
 use crate::pid::Pid;
 use crate::thread::{
-    futex, ClockId, Cpuid, MembarrierCommand, MembarrierQuery, NanosleepRelativeResult, Timespec,
+    futex, seccomp, ClockId, Cpuid, MembarrierCommand, MembarrierQuery, NanosleepRelativeResult,
+    Timespec,
 };
 use crate::utils::as_mut_ptr;
 use core::mem::MaybeUninit;
@@ -513,3 +515,42 @@ pub(crate) fn membarrier_cpu(cmd: MembarrierCommand, cpu: Cpuid) -> io::Result<(
         ))
     }
 }
+
+#[inline]
+pub(crate) fn seccomp_set_mode_filter(flags: u32, prog: &SockFprog) -> io::Result<c::c_int> {
+    unsafe {
+        ret_c_int(syscall_readonly!(
+            __NR_seccomp,
+            c_uint(seccomp::SET_MODE_FILTER),
+            c_uint(flags),
+            by_ref(prog)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn seccomp_set_mode_filter_listener(
+    flags: u32,
+    prog: &SockFprog,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_seccomp,
+            c_uint(seccomp::SET_MODE_FILTER),
+            c_uint(flags),
+            by_ref(prog)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn seccomp_get_action_avail(action: &u32) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_seccomp,
+            c_uint(seccomp::GET_ACTION_AVAIL),
+            c_uint(0),
+            by_ref(action)
+        ))
+    }
+}
@@ -0,0 +1,826 @@
+//! Linux `bpf` syscall, for creating eBPF maps and loading eBPF programs.
+//!
+//! This covers the commonly used subset of the `bpf` commands: creating maps
+//! and accessing their elements, loading programs, pinning maps and programs
+//! in a BPF filesystem, and attaching programs to cgroups and other targets.
+//! Programs are passed as already-assembled [`Insn`] instructions; this module
+//! doesn't include an assembler or an ELF object loader.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use rustix::bpf::{self, Insn, MapFlags, MapType, ProgType};
+//! use rustix::cstr;
+//!
+//! # fn test() -> std::io::Result<()> {
+//! // A single-element array map, with 4-byte keys and 8-byte values.
+//! let map = bpf::map_create(MapType::ARRAY, 4, 8, 1, MapFlags::empty())?;
+//! let key = 0_u32.to_ne_bytes();
+//! let mut value = [0_u8; 8];
+//! unsafe { bpf::map_lookup_elem(&map, &key, &mut value)? };
+//!
+//! // A socket filter which drops everything.
+//! let insns = [Insn::mov64_imm(0, 0), Insn::exit()];
+//! let mut log = vec![0_u8; 4096];
+//! let prog = bpf::prog_load(
+//!     ProgType::SOCKET_FILTER,
+//!     &insns,
+//!     cstr!("GPL"),
+//!     None,
+//!     Some(&mut log),
+//! )?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # References
+//!  - [Linux `bpf`]
+//!  - [Linux kernel documentation]
+//!
+//! [Linux `bpf`]: https://man7.org/linux/man-pages/man2/bpf.2.html
+//! [Linux kernel documentation]: https://docs.kernel.org/userspace-api/ebpf/syscall.html
+#![allow(unsafe_code)]
+
+use crate::fd::{AsFd, AsRawFd, BorrowedFd, OwnedFd};
+use crate::ffi::CStr;
+use crate::{backend, io, path};
+use bitflags::bitflags;
+use core::ptr::{null, null_mut};
+
+// linux-raw-sys doesn't provide <linux/bpf.h>, so define what we need here.
+
+/// `BPF_MAP_CREATE`
+pub(crate) const BPF_MAP_CREATE: u32 = 0;
+/// `BPF_MAP_LOOKUP_ELEM`
+pub(crate) const BPF_MAP_LOOKUP_ELEM: u32 = 1;
+/// `BPF_MAP_UPDATE_ELEM`
+pub(crate) const BPF_MAP_UPDATE_ELEM: u32 = 2;
+/// `BPF_MAP_DELETE_ELEM`
+pub(crate) const BPF_MAP_DELETE_ELEM: u32 = 3;
+/// `BPF_MAP_GET_NEXT_KEY`
+pub(crate) const BPF_MAP_GET_NEXT_KEY: u32 = 4;
+/// `BPF_PROG_LOAD`
+pub(crate) const BPF_PROG_LOAD: u32 = 5;
+/// `BPF_OBJ_PIN`
+pub(crate) const BPF_OBJ_PIN: u32 = 6;
+/// `BPF_OBJ_GET`
+pub(crate) const BPF_OBJ_GET: u32 = 7;
+/// `BPF_PROG_ATTACH`
+pub(crate) const BPF_PROG_ATTACH: u32 = 8;
+/// `BPF_PROG_DETACH`
+pub(crate) const BPF_PROG_DETACH: u32 = 9;
+
+/// The `union bpf_attr` fields used by `BPF_MAP_CREATE`.
+#[repr(C)]
+#[derive(Default)]
+pub(crate) struct MapCreateAttr {
+    pub(crate) map_type: u32,
+    pub(crate) key_size: u32,
+    pub(crate) value_size: u32,
+    pub(crate) max_entries: u32,
+    pub(crate) map_flags: u32,
+    pub(crate) inner_map_fd: u32,
+    pub(crate) numa_node: u32,
+    pub(crate) map_name: [u8; 16],
+    pub(crate) map_ifindex: u32,
+}
+
+/// The `union bpf_attr` fields used by the `BPF_MAP_*_ELEM` and
+/// `BPF_MAP_GET_NEXT_KEY` commands.
+///
+/// `value` is `next_key` for `BPF_MAP_GET_NEXT_KEY`. The kernel declares the
+/// pointer fields `__aligned_u64`, so `_pad` keeps them 8-byte aligned on
+/// targets where `u64` is only 4-byte aligned.
+#[repr(C)]
+pub(crate) struct MapElemAttr {
+    pub(crate) map_fd: u32,
+    pub(crate) _pad: u32,
+    pub(crate) key: u64,
+    pub(crate) value: u64,
+    pub(crate) flags: u64,
+}
+
+/// The `union bpf_attr` fields used by `BPF_PROG_LOAD`.
+#[repr(C)]
+#[derive(Default)]
+pub(crate) struct ProgLoadAttr {
+    pub(crate) prog_type: u32,
+    pub(crate) insn_cnt: u32,
+    pub(crate) insns: u64,
+    pub(crate) license: u64,
+    pub(crate) log_level: u32,
+    pub(crate) log_size: u32,
+    pub(crate) log_buf: u64,
+    pub(crate) kern_version: u32,
+    pub(crate) prog_flags: u32,
+    pub(crate) prog_name: [u8; 16],
+    pub(crate) prog_ifindex: u32,
+    pub(crate) expected_attach_type: u32,
+}
+
+/// The `union bpf_attr` fields used by `BPF_OBJ_PIN` and `BPF_OBJ_GET`.
+#[repr(C)]
+pub(crate) struct ObjAttr {
+    pub(crate) pathname: u64,
+    pub(crate) bpf_fd: u32,
+    pub(crate) file_flags: u32,
+}
+
+/// The `union bpf_attr` fields used by `BPF_PROG_ATTACH` and
+/// `BPF_PROG_DETACH`.
+#[repr(C)]
+pub(crate) struct ProgAttachAttr {
+    pub(crate) target_fd: u32,
+    pub(crate) attach_bpf_fd: u32,
+    pub(crate) attach_type: u32,
+    pub(crate) attach_flags: u32,
+    pub(crate) replace_bpf_fd: u32,
+}
+
+/// `BPF_MAP_TYPE_*` constants for use with [`map_create`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct MapType(u32);
+
+#[rustfmt::skip]
+impl MapType {
+    /// `BPF_MAP_TYPE_HASH`
+    pub const HASH: Self = Self(1);
+    /// `BPF_MAP_TYPE_ARRAY`
+    pub const ARRAY: Self = Self(2);
+    /// `BPF_MAP_TYPE_PROG_ARRAY`
+    pub const PROG_ARRAY: Self = Self(3);
+    /// `BPF_MAP_TYPE_PERF_EVENT_ARRAY`
+    pub const PERF_EVENT_ARRAY: Self = Self(4);
+    /// `BPF_MAP_TYPE_PERCPU_HASH`
+    pub const PERCPU_HASH: Self = Self(5);
+    /// `BPF_MAP_TYPE_PERCPU_ARRAY`
+    pub const PERCPU_ARRAY: Self = Self(6);
+    /// `BPF_MAP_TYPE_STACK_TRACE`
+    pub const STACK_TRACE: Self = Self(7);
+    /// `BPF_MAP_TYPE_CGROUP_ARRAY`
+    pub const CGROUP_ARRAY: Self = Self(8);
+    /// `BPF_MAP_TYPE_LRU_HASH`
+    pub const LRU_HASH: Self = Self(9);
+    /// `BPF_MAP_TYPE_LRU_PERCPU_HASH`
+    pub const LRU_PERCPU_HASH: Self = Self(10);
+    /// `BPF_MAP_TYPE_LPM_TRIE`
+    pub const LPM_TRIE: Self = Self(11);
+    /// `BPF_MAP_TYPE_ARRAY_OF_MAPS`
+    pub const ARRAY_OF_MAPS: Self = Self(12);
+    /// `BPF_MAP_TYPE_HASH_OF_MAPS`
+    pub const HASH_OF_MAPS: Self = Self(13);
+    /// `BPF_MAP_TYPE_DEVMAP`
+    pub const DEVMAP: Self = Self(14);
+    /// `BPF_MAP_TYPE_SOCKMAP`
+    pub const SOCKMAP: Self = Self(15);
+    /// `BPF_MAP_TYPE_CPUMAP`
+    pub const CPUMAP: Self = Self(16);
+    /// `BPF_MAP_TYPE_XSKMAP`
+    pub const XSKMAP: Self = Self(17);
+    /// `BPF_MAP_TYPE_SOCKHASH`
+    pub const SOCKHASH: Self = Self(18);
+    /// `BPF_MAP_TYPE_REUSEPORT_SOCKARRAY`
+    pub const REUSEPORT_SOCKARRAY: Self = Self(20);
+    /// `BPF_MAP_TYPE_QUEUE`
+    pub const QUEUE: Self = Self(22);
+    /// `BPF_MAP_TYPE_STACK`
+    pub const STACK: Self = Self(23);
+    /// `BPF_MAP_TYPE_SK_STORAGE`
+    pub const SK_STORAGE: Self = Self(24);
+    /// `BPF_MAP_TYPE_DEVMAP_HASH`
+    pub const DEVMAP_HASH: Self = Self(25);
+    /// `BPF_MAP_TYPE_RINGBUF`
+    pub const RINGBUF: Self = Self(27);
+    /// `BPF_MAP_TYPE_INODE_STORAGE`
+    pub const INODE_STORAGE: Self = Self(28);
+    /// `BPF_MAP_TYPE_TASK_STORAGE`
+    pub const TASK_STORAGE: Self = Self(29);
+    /// `BPF_MAP_TYPE_BLOOM_FILTER`
+    pub const BLOOM_FILTER: Self = Self(30);
+
+    /// Constructs a `MapType` from a raw integer.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw integer for this `MapType`.
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        self.0
+    }
+}
+
+/// `BPF_PROG_TYPE_*` constants for use with [`prog_load`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct ProgType(u32);
+
+#[rustfmt::skip]
+impl ProgType {
+    /// `BPF_PROG_TYPE_SOCKET_FILTER`
+    pub const SOCKET_FILTER: Self = Self(1);
+    /// `BPF_PROG_TYPE_KPROBE`
+    pub const KPROBE: Self = Self(2);
+    /// `BPF_PROG_TYPE_SCHED_CLS`
+    pub const SCHED_CLS: Self = Self(3);
+    /// `BPF_PROG_TYPE_SCHED_ACT`
+    pub const SCHED_ACT: Self = Self(4);
+    /// `BPF_PROG_TYPE_TRACEPOINT`
+    pub const TRACEPOINT: Self = Self(5);
+    /// `BPF_PROG_TYPE_XDP`
+    pub const XDP: Self = Self(6);
+    /// `BPF_PROG_TYPE_PERF_EVENT`
+    pub const PERF_EVENT: Self = Self(7);
+    /// `BPF_PROG_TYPE_CGROUP_SKB`
+    pub const CGROUP_SKB: Self = Self(8);
+    /// `BPF_PROG_TYPE_CGROUP_SOCK`
+    pub const CGROUP_SOCK: Self = Self(9);
+    /// `BPF_PROG_TYPE_SOCK_OPS`
+    pub const SOCK_OPS: Self = Self(13);
+    /// `BPF_PROG_TYPE_SK_SKB`
+    pub const SK_SKB: Self = Self(14);
+    /// `BPF_PROG_TYPE_CGROUP_DEVICE`
+    pub const CGROUP_DEVICE: Self = Self(15);
+    /// `BPF_PROG_TYPE_SK_MSG`
+    pub const SK_MSG: Self = Self(16);
+    /// `BPF_PROG_TYPE_RAW_TRACEPOINT`
+    pub const RAW_TRACEPOINT: Self = Self(17);
+    /// `BPF_PROG_TYPE_CGROUP_SOCK_ADDR`
+    pub const CGROUP_SOCK_ADDR: Self = Self(18);
+    /// `BPF_PROG_TYPE_SK_REUSEPORT`
+    pub const SK_REUSEPORT: Self = Self(21);
+    /// `BPF_PROG_TYPE_FLOW_DISSECTOR`
+    pub const FLOW_DISSECTOR: Self = Self(22);
+    /// `BPF_PROG_TYPE_CGROUP_SYSCTL`
+    pub const CGROUP_SYSCTL: Self = Self(23);
+    /// `BPF_PROG_TYPE_CGROUP_SOCKOPT`
+    pub const CGROUP_SOCKOPT: Self = Self(25);
+    /// `BPF_PROG_TYPE_SK_LOOKUP`
+    pub const SK_LOOKUP: Self = Self(30);
+
+    /// Constructs a `ProgType` from a raw integer.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw integer for this `ProgType`.
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        self.0
+    }
+}
+
+/// `BPF_*` attach type constants for use with [`prog_load`],
+/// [`prog_attach`], and [`prog_detach`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct AttachType(u32);
+
+#[rustfmt::skip]
+impl AttachType {
+    /// `BPF_CGROUP_INET_INGRESS`
+    pub const CGROUP_INET_INGRESS: Self = Self(0);
+    /// `BPF_CGROUP_INET_EGRESS`
+    pub const CGROUP_INET_EGRESS: Self = Self(1);
+    /// `BPF_CGROUP_INET_SOCK_CREATE`
+    pub const CGROUP_INET_SOCK_CREATE: Self = Self(2);
+    /// `BPF_CGROUP_SOCK_OPS`
+    pub const CGROUP_SOCK_OPS: Self = Self(3);
+    /// `BPF_SK_SKB_STREAM_PARSER`
+    pub const SK_SKB_STREAM_PARSER: Self = Self(4);
+    /// `BPF_SK_SKB_STREAM_VERDICT`
+    pub const SK_SKB_STREAM_VERDICT: Self = Self(5);
+    /// `BPF_CGROUP_DEVICE`
+    pub const CGROUP_DEVICE: Self = Self(6);
+    /// `BPF_SK_MSG_VERDICT`
+    pub const SK_MSG_VERDICT: Self = Self(7);
+    /// `BPF_CGROUP_INET4_BIND`
+    pub const CGROUP_INET4_BIND: Self = Self(8);
+    /// `BPF_CGROUP_INET6_BIND`
+    pub const CGROUP_INET6_BIND: Self = Self(9);
+    /// `BPF_CGROUP_INET4_CONNECT`
+    pub const CGROUP_INET4_CONNECT: Self = Self(10);
+    /// `BPF_CGROUP_INET6_CONNECT`
+    pub const CGROUP_INET6_CONNECT: Self = Self(11);
+    /// `BPF_CGROUP_INET4_POST_BIND`
+    pub const CGROUP_INET4_POST_BIND: Self = Self(12);
+    /// `BPF_CGROUP_INET6_POST_BIND`
+    pub const CGROUP_INET6_POST_BIND: Self = Self(13);
+    /// `BPF_CGROUP_UDP4_SENDMSG`
+    pub const CGROUP_UDP4_SENDMSG: Self = Self(14);
+    /// `BPF_CGROUP_UDP6_SENDMSG`
+    pub const CGROUP_UDP6_SENDMSG: Self = Self(15);
+    /// `BPF_FLOW_DISSECTOR`
+    pub const FLOW_DISSECTOR: Self = Self(17);
+    /// `BPF_CGROUP_SYSCTL`
+    pub const CGROUP_SYSCTL: Self = Self(18);
+    /// `BPF_CGROUP_UDP4_RECVMSG`
+    pub const CGROUP_UDP4_RECVMSG: Self = Self(19);
+    /// `BPF_CGROUP_UDP6_RECVMSG`
+    pub const CGROUP_UDP6_RECVMSG: Self = Self(20);
+    /// `BPF_CGROUP_GETSOCKOPT`
+    pub const CGROUP_GETSOCKOPT: Self = Self(21);
+    /// `BPF_CGROUP_SETSOCKOPT`
+    pub const CGROUP_SETSOCKOPT: Self = Self(22);
+    /// `BPF_CGROUP_INET4_GETPEERNAME`
+    pub const CGROUP_INET4_GETPEERNAME: Self = Self(29);
+    /// `BPF_CGROUP_INET6_GETPEERNAME`
+    pub const CGROUP_INET6_GETPEERNAME: Self = Self(30);
+    /// `BPF_CGROUP_INET4_GETSOCKNAME`
+    pub const CGROUP_INET4_GETSOCKNAME: Self = Self(31);
+    /// `BPF_CGROUP_INET6_GETSOCKNAME`
+    pub const CGROUP_INET6_GETSOCKNAME: Self = Self(32);
+    /// `BPF_CGROUP_INET_SOCK_RELEASE`
+    pub const CGROUP_INET_SOCK_RELEASE: Self = Self(34);
+    /// `BPF_SK_SKB_VERDICT`
+    pub const SK_SKB_VERDICT: Self = Self(38);
+
+    /// Constructs an `AttachType` from a raw integer.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw integer for this `AttachType`.
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        self.0
+    }
+}
+
+bitflags! {
+    /// `BPF_F_*` flags for use with [`map_create`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct MapFlags: u32 {
+        /// `BPF_F_NO_PREALLOC`
+        const NO_PREALLOC = 1 << 0;
+        /// `BPF_F_NO_COMMON_LRU`
+        const NO_COMMON_LRU = 1 << 1;
+        /// `BPF_F_RDONLY`
+        const RDONLY = 1 << 3;
+        /// `BPF_F_WRONLY`
+        const WRONLY = 1 << 4;
+        /// `BPF_F_STACK_BUILD_ID`
+        const STACK_BUILD_ID = 1 << 5;
+        /// `BPF_F_ZERO_SEED`
+        const ZERO_SEED = 1 << 6;
+        /// `BPF_F_RDONLY_PROG`
+        const RDONLY_PROG = 1 << 7;
+        /// `BPF_F_WRONLY_PROG`
+        const WRONLY_PROG = 1 << 8;
+        /// `BPF_F_CLONE`
+        const CLONE = 1 << 9;
+        /// `BPF_F_MMAPABLE`
+        const MMAPABLE = 1 << 10;
+        /// `BPF_F_PRESERVE_ELEMS`
+        const PRESERVE_ELEMS = 1 << 11;
+        /// `BPF_F_INNER_MAP`
+        const INNER_MAP = 1 << 12;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `BPF_*` flags for use with [`map_update_elem`].
+    ///
+    /// The empty set is `BPF_ANY`.
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct MapUpdateFlags: u64 {
+        /// `BPF_NOEXIST`
+        const NOEXIST = 1;
+        /// `BPF_EXIST`
+        const EXIST = 2;
+        /// `BPF_F_LOCK`
+        const LOCK = 4;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `BPF_F_*` flags for use with [`prog_attach`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct AttachFlags: u32 {
+        /// `BPF_F_ALLOW_OVERRIDE`
+        const ALLOW_OVERRIDE = 1 << 0;
+        /// `BPF_F_ALLOW_MULTI`
+        const ALLOW_MULTI = 1 << 1;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+// Instruction classes, sizes, modes, and operations used by the `Insn`
+// helpers.
+const BPF_LD: u8 = 0x00;
+const BPF_JMP: u8 = 0x05;
+const BPF_ALU64: u8 = 0x07;
+const BPF_DW: u8 = 0x18;
+const BPF_IMM: u8 = 0x00;
+const BPF_K: u8 = 0x00;
+const BPF_MOV: u8 = 0xb0;
+const BPF_EXIT: u8 = 0x90;
+
+/// `BPF_PSEUDO_MAP_FD`
+const BPF_PSEUDO_MAP_FD: u8 = 1;
+
+/// `struct bpf_insn`—An eBPF instruction.
+#[doc(alias = "bpf_insn")]
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Insn {
+    /// The opcode.
+    pub code: u8,
+    /// The `dst_reg` and `src_reg` bitfields.
+    regs: u8,
+    /// The signed offset.
+    pub off: i16,
+    /// The signed immediate constant.
+    pub imm: i32,
+}
+
+impl Insn {
+    /// Constructs an instruction from its fields.
+    ///
+    /// Only the low 4 bits of `dst_reg` and `src_reg` are used.
+    #[inline]
+    pub const fn new(code: u8, dst_reg: u8, src_reg: u8, off: i16, imm: i32) -> Self {
+        #[cfg(target_endian = "little")]
+        let regs = (dst_reg & 0xf) | ((src_reg & 0xf) << 4);
+        #[cfg(target_endian = "big")]
+        let regs = ((dst_reg & 0xf) << 4) | (src_reg & 0xf);
+
+        Self {
+            code,
+            regs,
+            off,
+            imm,
+        }
+    }
+
+    /// The destination register.
+    #[inline]
+    pub const fn dst_reg(&self) -> u8 {
+        #[cfg(target_endian = "little")]
+        {
+            self.regs & 0xf
+        }
+        #[cfg(target_endian = "big")]
+        {
+            self.regs >> 4
+        }
+    }
+
+    /// The source register.
+    #[inline]
+    pub const fn src_reg(&self) -> u8 {
+        #[cfg(target_endian = "little")]
+        {
+            self.regs >> 4
+        }
+        #[cfg(target_endian = "big")]
+        {
+            self.regs & 0xf
+        }
+    }
+
+    /// `BPF_MOV64_IMM(dst, imm)`—Set register `dst` to `imm`.
+    #[doc(alias = "BPF_MOV64_IMM")]
+    #[inline]
+    pub const fn mov64_imm(dst: u8, imm: i32) -> Self {
+        Self::new(BPF_ALU64 | BPF_MOV | BPF_K, dst, 0, 0, imm)
+    }
+
+    /// `BPF_EXIT_INSN()`—Return from the program, with the value in
+    /// register 0.
+    #[doc(alias = "BPF_EXIT_INSN")]
+    #[inline]
+    pub const fn exit() -> Self {
+        Self::new(BPF_JMP | BPF_EXIT, 0, 0, 0, 0)
+    }
+
+    /// `BPF_LD_MAP_FD(dst, map)`—Load a reference to `map` into register
+    /// `dst`.
+    ///
+    /// This is a double-width instruction, so it occupies two slots. The file
+    /// descriptor is only used while the program is being loaded.
+    #[doc(alias = "BPF_LD_MAP_FD")]
+    #[inline]
+    pub fn ld_map_fd(dst: u8, map: BorrowedFd<'_>) -> [Self; 2] {
+        [
+            Self::new(
+                BPF_LD | BPF_DW | BPF_IMM,
+                dst,
+                BPF_PSEUDO_MAP_FD,
+                0,
+                map.as_raw_fd(),
+            ),
+            Self::new(0, 0, 0, 0, 0),
+        ]
+    }
+}
+
+/// `bpf(BPF_MAP_CREATE, …)`—Creates a map.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/bpf.2.html
+#[inline]
+#[doc(alias = "BPF_MAP_CREATE")]
+pub fn map_create(
+    map_type: MapType,
+    key_size: u32,
+    value_size: u32,
+    max_entries: u32,
+    flags: MapFlags,
+) -> io::Result<OwnedFd> {
+    let attr = MapCreateAttr {
+        map_type: map_type.0,
+        key_size,
+        value_size,
+        max_entries,
+        map_flags: flags.bits(),
+        ..Default::default()
+    };
+    backend::bpf::syscalls::bpf_map_create(&attr)
+}
+
+/// `bpf(BPF_MAP_LOOKUP_ELEM, …)`—Reads the value for `key` in `map` into
+/// `value`.
+///
+/// This fails with [`io::Errno::NOENT`] if `key` isn't present.
+///
+/// # Safety
+///
+/// `key` must be at least as long as the map's key size, and `value` must be
+/// at least as long as the map's value size. For per-CPU maps, the value size
+/// is the map's value size rounded up to a multiple of 8, times the number of
+/// possible CPUs.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/bpf.2.html
+#[inline]
+#[doc(alias = "BPF_MAP_LOOKUP_ELEM")]
+pub unsafe fn map_lookup_elem<Fd: AsFd>(map: Fd, key: &[u8], value: &mut [u8]) -> io::Result<()> {
+    let attr = MapElemAttr {
+        map_fd: map.as_fd().as_raw_fd() as u32,
+        _pad: 0,
+        key: key.as_ptr() as usize as u64,
+        value: value.as_mut_ptr() as usize as u64,
+        flags: 0,
+    };
+    backend::bpf::syscalls::bpf_map_elem(BPF_MAP_LOOKUP_ELEM, &attr)
+}
+
+/// `bpf(BPF_MAP_UPDATE_ELEM, …)`—Sets the value for `key` in `map` to
+/// `value`.
+///
+/// # Safety
+///
+/// `key` must be at least as long as the map's key size, and `value` must be
+/// at least as long as the map's value size, as described in
+/// [`map_lookup_elem`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/bpf.2.html
+#[inline]
+#[doc(alias = "BPF_MAP_UPDATE_ELEM")]
+pub unsafe fn map_update_elem<Fd: AsFd>(
+    map: Fd,
+    key: &[u8],
+    value: &[u8],
+    flags: MapUpdateFlags,
+) -> io::Result<()> {
+    let attr = MapElemAttr {
+        map_fd: map.as_fd().as_raw_fd() as u32,
+        _pad: 0,
+        key: key.as_ptr() as usize as u64,
+        value: value.as_ptr() as usize as u64,
+        flags: flags.bits(),
+    };
+    backend::bpf::syscalls::bpf_map_elem(BPF_MAP_UPDATE_ELEM, &attr)
+}
+
+/// `bpf(BPF_MAP_DELETE_ELEM, …)`—Removes `key` from `map`.
+///
+/// # Safety
+///
+/// `key` must be at least as long as the map's key size.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/bpf.2.html
+#[inline]
+#[doc(alias = "BPF_MAP_DELETE_ELEM")]
+pub unsafe fn map_delete_elem<Fd: AsFd>(map: Fd, key: &[u8]) -> io::Result<()> {
+    let attr = MapElemAttr {
+        map_fd: map.as_fd().as_raw_fd() as u32,
+        _pad: 0,
+        key: key.as_ptr() as usize as u64,
+        value: 0,
+        flags: 0,
+    };
+    backend::bpf::syscalls::bpf_map_elem(BPF_MAP_DELETE_ELEM, &attr)
+}
+
+/// `bpf(BPF_MAP_GET_NEXT_KEY, …)`—Writes the key following `key` in `map`
+/// into `next_key`, or the first key if `key` is `None`.
+///
+/// This fails with [`io::Errno::NOENT`] if `key` is the last key.
+///
+/// # Safety
+///
+/// `key` and `next_key` must be at least as long as the map's key size.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/bpf.2.html
+#[inline]
+#[doc(alias = "BPF_MAP_GET_NEXT_KEY")]
+pub unsafe fn map_get_next_key<Fd: AsFd>(
+    map: Fd,
+    key: Option<&[u8]>,
+    next_key: &mut [u8],
+) -> io::Result<()> {
+    let attr = MapElemAttr {
+        map_fd: map.as_fd().as_raw_fd() as u32,
+        _pad: 0,
+        key: key.map_or(null(), <[u8]>::as_ptr) as usize as u64,
+        value: next_key.as_mut_ptr() as usize as u64,
+        flags: 0,
+    };
+    backend::bpf::syscalls::bpf_map_elem(BPF_MAP_GET_NEXT_KEY, &attr)
+}
+
+/// `bpf(BPF_PROG_LOAD, …)`—Verifies and loads a program.
+///
+/// `license` is the program's license, such as `cstr!("GPL")`; some kernel helper
+/// functions are only available to GPL-compatible programs.
+/// `expected_attach_type` is required by some program types, such as
+/// [`ProgType::CGROUP_SOCK_ADDR`].
+///
+/// If `log` is provided, the verifier writes a NUL-terminated log of its
+/// analysis into it, which is useful for finding out why a program was
+/// rejected. The kernel requires it to be at least 128 bytes, and fails with
+/// [`io::Errno::NOSPC`] if the log doesn't fit.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/bpf.2.html
+#[inline]
+#[doc(alias = "BPF_PROG_LOAD")]
+pub fn prog_load(
+    prog_type: ProgType,
+    insns: &[Insn],
+    license: &CStr,
+    expected_attach_type: Option<AttachType>,
+    log: Option<&mut [u8]>,
+) -> io::Result<OwnedFd> {
+    let (log_level, log_buf, log_size) = match log {
+        Some(log) => (
+            1,
+            log.as_mut_ptr(),
+            log.len().try_into().map_err(|_| io::Errno::INVAL)?,
+        ),
+        None => (0, null_mut(), 0),
+    };
+    let attr = ProgLoadAttr {
+        prog_type: prog_type.0,
+        insn_cnt: insns.len().try_into().map_err(|_| io::Errno::INVAL)?,
+        insns: insns.as_ptr() as usize as u64,
+        license: license.as_ptr() as usize as u64,
+        log_level,
+        log_size,
+        log_buf: log_buf as usize as u64,
+        expected_attach_type: expected_attach_type.map_or(0, |t| t.0),
+        ..Default::default()
+    };
+    // SAFETY: All the pointers in `attr` come from live slices and their
+    // lengths.
+    unsafe { backend::bpf::syscalls::bpf_prog_load(&attr) }
+}
+
+/// `bpf(BPF_OBJ_PIN, …)`—Pins a map or program at `path` in a BPF
+/// filesystem, so that it outlives its file descriptors.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/bpf.2.html
+#[inline]
+#[doc(alias = "BPF_OBJ_PIN")]
+pub fn obj_pin<Fd: AsFd, P: path::Arg>(fd: Fd, path: P) -> io::Result<()> {
+    path.into_with_c_str(|path| {
+        let attr = ObjAttr {
+            pathname: path.as_ptr() as usize as u64,
+            bpf_fd: fd.as_fd().as_raw_fd() as u32,
+            file_flags: 0,
+        };
+        // SAFETY: `attr.pathname` points to `path`, which outlives the call.
+        unsafe { backend::bpf::syscalls::bpf_obj_pin(&attr) }
+    })
+}
+
+/// `bpf(BPF_OBJ_GET, …)`—Opens a map or program pinned at `path`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/bpf.2.html
+#[inline]
+#[doc(alias = "BPF_OBJ_GET")]
+pub fn obj_get<P: path::Arg>(path: P) -> io::Result<OwnedFd> {
+    path.into_with_c_str(|path| {
+        let attr = ObjAttr {
+            pathname: path.as_ptr() as usize as u64,
+            bpf_fd: 0,
+            file_flags: 0,
+        };
+        // SAFETY: `attr.pathname` points to `path`, which outlives the call.
+        unsafe { backend::bpf::syscalls::bpf_obj_get(&attr) }
+    })
+}
+
+/// `bpf(BPF_PROG_ATTACH, …)`—Attaches `prog` to `target`, such as a cgroup
+/// directory or a sockmap.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/bpf.2.html
+#[inline]
+#[doc(alias = "BPF_PROG_ATTACH")]
+pub fn prog_attach<Target: AsFd, Prog: AsFd>(
+    target: Target,
+    prog: Prog,
+    attach_type: AttachType,
+    flags: AttachFlags,
+) -> io::Result<()> {
+    let attr = ProgAttachAttr {
+        target_fd: target.as_fd().as_raw_fd() as u32,
+        attach_bpf_fd: prog.as_fd().as_raw_fd() as u32,
+        attach_type: attach_type.0,
+        attach_flags: flags.bits(),
+        replace_bpf_fd: 0,
+    };
+    backend::bpf::syscalls::bpf_prog_attach(BPF_PROG_ATTACH, &attr)
+}
+
+/// `bpf(BPF_PROG_DETACH, …)`—Detaches `prog` from `target`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/bpf.2.html
+#[inline]
+#[doc(alias = "BPF_PROG_DETACH")]
+pub fn prog_detach<Target: AsFd, Prog: AsFd>(
+    target: Target,
+    prog: Prog,
+    attach_type: AttachType,
+) -> io::Result<()> {
+    let attr = ProgAttachAttr {
+        target_fd: target.as_fd().as_raw_fd() as u32,
+        attach_bpf_fd: prog.as_fd().as_raw_fd() as u32,
+        attach_type: attach_type.0,
+        attach_flags: 0,
+        replace_bpf_fd: 0,
+    };
+    backend::bpf::syscalls::bpf_prog_attach(BPF_PROG_DETACH, &attr)
+}
+
+#[test]
+fn test_sizes() {
+    use core::mem::size_of;
+
+    assert_eq!(size_of::<MapCreateAttr>(), 48);
+    assert_eq!(size_of::<MapElemAttr>(), 32);
+    assert_eq!(memoffset::offset_of!(MapElemAttr, key), 8);
+    assert_eq!(memoffset::offset_of!(MapElemAttr, value), 16);
+    assert_eq!(memoffset::offset_of!(MapElemAttr, flags), 24);
+    assert_eq!(size_of::<ProgLoadAttr>(), 72);
+    assert_eq!(memoffset::offset_of!(ProgLoadAttr, insns), 8);
+    assert_eq!(memoffset::offset_of!(ProgLoadAttr, log_buf), 32);
+    assert_eq!(memoffset::offset_of!(ProgLoadAttr, prog_name), 48);
+    assert_eq!(size_of::<ObjAttr>(), 16);
+    assert_eq!(size_of::<ProgAttachAttr>(), 20);
+    assert_eq!(size_of::<Insn>(), 8);
+}
@@ -0,0 +1,146 @@
+//! Classic BPF filter programs, as used by seccomp and socket filters.
+//!
+//! # References
+//!  - [Linux Socket Filtering]
+//!
+//! [Linux Socket Filtering]: https://docs.kernel.org/networking/filter.html
+
+// linux-raw-sys doesn't provide <linux/filter.h> or <linux/bpf_common.h>, so
+// define what we need here.
+
+// Instruction classes.
+const BPF_LD: u16 = 0x00;
+const BPF_JMP: u16 = 0x05;
+const BPF_RET: u16 = 0x06;
+
+// Load sizes and modes.
+const BPF_W: u16 = 0x00;
+const BPF_ABS: u16 = 0x20;
+
+// Jump comparisons.
+const BPF_JA: u16 = 0x00;
+const BPF_JEQ: u16 = 0x10;
+const BPF_JGT: u16 = 0x20;
+const BPF_JGE: u16 = 0x30;
+const BPF_JSET: u16 = 0x40;
+
+// Operand sources.
+const BPF_K: u16 = 0x00;
+
+/// `struct sock_filter`—A classic BPF instruction.
+///
+/// The constructors correspond to the `BPF_STMT` and `BPF_JUMP` macros in
+/// `<linux/filter.h>`, plus a few helpers for the instructions that filters
+/// most commonly need.
+#[doc(alias = "sock_filter")]
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SockFilter {
+    /// The opcode.
+    pub code: u16,
+    /// The jump offset if the condition is true, for conditional jumps.
+    pub jt: u8,
+    /// The jump offset if the condition is false, for conditional jumps.
+    pub jf: u8,
+    /// The generic operand.
+    pub k: u32,
+}
+
+impl SockFilter {
+    /// `BPF_STMT(code, k)`—A non-jump instruction.
+    #[doc(alias = "BPF_STMT")]
+    #[inline]
+    pub const fn stmt(code: u16, k: u32) -> Self {
+        Self {
+            code,
+            jt: 0,
+            jf: 0,
+            k,
+        }
+    }
+
+    /// `BPF_JUMP(code, k, jt, jf)`—A jump instruction.
+    #[doc(alias = "BPF_JUMP")]
+    #[inline]
+    pub const fn jump(code: u16, k: u32, jt: u8, jf: u8) -> Self {
+        Self { code, jt, jf, k }
+    }
+
+    /// `BPF_STMT(BPF_LD | BPF_W | BPF_ABS, offset)`—Load the 32-bit word at
+    /// `offset` in the input into the accumulator.
+    #[inline]
+    pub const fn load_word(offset: u32) -> Self {
+        Self::stmt(BPF_LD | BPF_W | BPF_ABS, offset)
+    }
+
+    /// `BPF_JUMP(BPF_JMP | BPF_JA, offset, 0, 0)`—Jump forward
+    /// unconditionally by `offset` instructions.
+    #[inline]
+    pub const fn jump_always(offset: u32) -> Self {
+        Self::jump(BPF_JMP | BPF_JA, offset, 0, 0)
+    }
+
+    /// `BPF_JUMP(BPF_JMP | BPF_JEQ | BPF_K, k, jt, jf)`—If the accumulator
+    /// equals `k`, skip `jt` instructions, otherwise skip `jf` instructions.
+    #[inline]
+    pub const fn jump_eq(k: u32, jt: u8, jf: u8) -> Self {
+        Self::jump(BPF_JMP | BPF_JEQ | BPF_K, k, jt, jf)
+    }
+
+    /// `BPF_JUMP(BPF_JMP | BPF_JGT | BPF_K, k, jt, jf)`—If the accumulator is
+    /// greater than `k`, skip `jt` instructions, otherwise skip `jf`
+    /// instructions.
+    #[inline]
+    pub const fn jump_gt(k: u32, jt: u8, jf: u8) -> Self {
+        Self::jump(BPF_JMP | BPF_JGT | BPF_K, k, jt, jf)
+    }
+
+    /// `BPF_JUMP(BPF_JMP | BPF_JGE | BPF_K, k, jt, jf)`—If the accumulator is
+    /// greater than or equal to `k`, skip `jt` instructions, otherwise skip
+    /// `jf` instructions.
+    #[inline]
+    pub const fn jump_ge(k: u32, jt: u8, jf: u8) -> Self {
+        Self::jump(BPF_JMP | BPF_JGE | BPF_K, k, jt, jf)
+    }
+
+    /// `BPF_JUMP(BPF_JMP | BPF_JSET | BPF_K, k, jt, jf)`—If the accumulator
+    /// has any of the bits in `k` set, skip `jt` instructions, otherwise skip
+    /// `jf` instructions.
+    #[inline]
+    pub const fn jump_set(k: u32, jt: u8, jf: u8) -> Self {
+        Self::jump(BPF_JMP | BPF_JSET | BPF_K, k, jt, jf)
+    }
+
+    /// `BPF_STMT(BPF_RET | BPF_K, k)`—Return `k`.
+    #[inline]
+    pub const fn ret(k: u32) -> Self {
+        Self::stmt(BPF_RET | BPF_K, k)
+    }
+}
+
+/// `struct sock_fprog`
+#[repr(C)]
+pub(crate) struct SockFprog {
+    pub(crate) len: u16,
+    pub(crate) filter: *const SockFilter,
+}
+
+impl SockFprog {
+    /// Describe `filter`, failing with `INVAL` if it's too long to be a valid
+    /// program.
+    #[inline]
+    pub(crate) fn new(filter: &[SockFilter]) -> crate::io::Result<Self> {
+        Ok(Self {
+            len: filter
+                .len()
+                .try_into()
+                .map_err(|_| crate::io::Errno::INVAL)?,
+            filter: filter.as_ptr(),
+        })
+    }
+}
+
+#[test]
+fn test_sizes() {
+    assert_eq!(core::mem::size_of::<SockFilter>(), 8);
+}
@@ -18,6 +18,8 @@ mod poll;
 pub mod port;
 #[cfg(any(bsd, linux_kernel, windows, target_os = "wasi"))]
 mod select;
+#[cfg(linux_kernel)]
+mod signalfd;
 
 #[cfg(any(
     linux_kernel,
@@ -31,3 +33,7 @@ pub use pause::*;
 pub use poll::{poll, PollFd, PollFlags};
 #[cfg(any(bsd, linux_kernel, windows, target_os = "wasi"))]
 pub use select::*;
+#[cfg(linux_kernel)]
+pub use signalfd::{
+    signalfd, signalfd_read, signalfd_set_mask, SigSet, Signal, SignalfdFlags, SignalfdSiginfo,
+};
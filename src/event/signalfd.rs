@@ -0,0 +1,263 @@
+use crate::fd::{AsFd, OwnedFd};
+use crate::{backend, io};
+
+pub use crate::signal::{SigSet, Signal};
+pub use backend::event::types::SignalfdFlags;
+
+/// `signalfd4(-1, mask, flags)`—Creates a file descriptor for accepting
+/// signals.
+///
+/// Signals in `mask` become readable from the returned file descriptor, as
+/// [`SignalfdSiginfo`] records. They should also be blocked, for example with
+/// `pthread_sigmask`, so that they aren't delivered by the default mechanism.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/signalfd.2.html
+#[doc(alias = "signalfd4")]
+#[inline]
+pub fn signalfd(mask: &SigSet, flags: SignalfdFlags) -> io::Result<OwnedFd> {
+    backend::event::syscalls::signalfd(mask, flags)
+}
+
+/// `signalfd4(fd, mask, 0)`—Replaces the set of signals accepted by an
+/// existing signalfd.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/signalfd.2.html
+#[doc(alias = "signalfd4")]
+#[inline]
+pub fn signalfd_set_mask<Fd: AsFd>(fd: Fd, mask: &SigSet) -> io::Result<()> {
+    backend::event::syscalls::signalfd_set_mask(fd.as_fd(), mask)
+}
+
+/// Reads a single [`SignalfdSiginfo`] from a signalfd.
+///
+/// If no signals are pending and the file descriptor was created with
+/// [`SignalfdFlags::NONBLOCK`], this fails with [`io::Errno::AGAIN`].
+/// Otherwise it blocks until a signal in the mask is pending.
+///
+/// To read several records at once, use [`io::read`] with a buffer that is a
+/// multiple of [`SignalfdSiginfo::SIZE`] bytes and decode each record with
+/// [`SignalfdSiginfo::from_bytes`].
+///
+/// [`io::read`]: crate::io::read
+#[inline]
+pub fn signalfd_read<Fd: AsFd>(fd: Fd) -> io::Result<SignalfdSiginfo> {
+    let mut bytes = [0_u8; SignalfdSiginfo::SIZE];
+    let nread = crate::io::read(fd, &mut bytes)?;
+
+    // The kernel only ever writes whole records.
+    if nread != SignalfdSiginfo::SIZE {
+        return Err(io::Errno::INVAL);
+    }
+
+    Ok(SignalfdSiginfo::from_bytes(&bytes))
+}
+
+/// `struct signalfd_siginfo`—Information about a signal, read from a
+/// signalfd.
+#[doc(alias = "signalfd_siginfo")]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SignalfdSiginfo {
+    signo: u32,
+    errno: i32,
+    code: i32,
+    pid: u32,
+    uid: u32,
+    fd: i32,
+    tid: u32,
+    band: u32,
+    overrun: u32,
+    trapno: u32,
+    status: i32,
+    int: i32,
+    ptr: u64,
+    utime: u64,
+    stime: u64,
+    addr: u64,
+    addr_lsb: u16,
+    syscall: i32,
+    call_addr: u64,
+    arch: u32,
+}
+
+impl SignalfdSiginfo {
+    /// The size in bytes of a `struct signalfd_siginfo` record.
+    pub const SIZE: usize = 128;
+
+    /// Decodes a `struct signalfd_siginfo` record from bytes read from a
+    /// signalfd.
+    pub fn from_bytes(bytes: &[u8; Self::SIZE]) -> Self {
+        let u16_at = |offset: usize| u16::from_ne_bytes([bytes[offset], bytes[offset + 1]]);
+        let u32_at = |offset: usize| {
+            let mut buf = [0_u8; 4];
+            buf.copy_from_slice(&bytes[offset..offset + 4]);
+            u32::from_ne_bytes(buf)
+        };
+        let u64_at = |offset: usize| {
+            let mut buf = [0_u8; 8];
+            buf.copy_from_slice(&bytes[offset..offset + 8]);
+            u64::from_ne_bytes(buf)
+        };
+
+        // These offsets follow the layout in <linux/signalfd.h>, which is the
+        // same on all architectures.
+        Self {
+            signo: u32_at(0),
+            errno: u32_at(4) as i32,
+            code: u32_at(8) as i32,
+            pid: u32_at(12),
+            uid: u32_at(16),
+            fd: u32_at(20) as i32,
+            tid: u32_at(24),
+            band: u32_at(28),
+            overrun: u32_at(32),
+            trapno: u32_at(36),
+            status: u32_at(40) as i32,
+            int: u32_at(44) as i32,
+            ptr: u64_at(48),
+            utime: u64_at(56),
+            stime: u64_at(64),
+            addr: u64_at(72),
+            addr_lsb: u16_at(80),
+            syscall: u32_at(84) as i32,
+            call_addr: u64_at(88),
+            arch: u32_at(96),
+        }
+    }
+
+    /// Returns the signal, if it is one that [`Signal`] can represent.
+    #[inline]
+    pub fn signal(&self) -> Option<Signal> {
+        Signal::from_raw(self.signo as _)
+    }
+
+    /// `ssi_signo`—Returns the raw signal number.
+    #[inline]
+    pub fn signo(&self) -> u32 {
+        self.signo
+    }
+
+    /// `ssi_errno`—Returns the error number, which is usually unused.
+    #[inline]
+    pub fn errno(&self) -> i32 {
+        self.errno
+    }
+
+    /// `ssi_code`—Returns the signal code, such as `SI_USER` or `SI_QUEUE`.
+    #[inline]
+    pub fn code(&self) -> i32 {
+        self.code
+    }
+
+    /// `ssi_pid`—Returns the PID of the sender.
+    #[inline]
+    pub fn pid(&self) -> u32 {
+        self.pid
+    }
+
+    /// `ssi_uid`—Returns the real UID of the sender.
+    #[inline]
+    pub fn uid(&self) -> u32 {
+        self.uid
+    }
+
+    /// `ssi_fd`—Returns the file descriptor, for `SIGIO`.
+    #[inline]
+    pub fn fd(&self) -> i32 {
+        self.fd
+    }
+
+    /// `ssi_tid`—Returns the kernel timer ID, for POSIX timers.
+    #[inline]
+    pub fn tid(&self) -> u32 {
+        self.tid
+    }
+
+    /// `ssi_band`—Returns the band event, for `SIGIO`.
+    #[inline]
+    pub fn band(&self) -> u32 {
+        self.band
+    }
+
+    /// `ssi_overrun`—Returns the overrun count, for POSIX timers.
+    #[inline]
+    pub fn overrun(&self) -> u32 {
+        self.overrun
+    }
+
+    /// `ssi_trapno`—Returns the trap number that caused the signal.
+    #[inline]
+    pub fn trapno(&self) -> u32 {
+        self.trapno
+    }
+
+    /// `ssi_status`—Returns the exit status or signal, for `SIGCHLD`.
+    #[inline]
+    pub fn status(&self) -> i32 {
+        self.status
+    }
+
+    /// `ssi_int`—Returns the integer sent by `sigqueue`.
+    #[inline]
+    pub fn int(&self) -> i32 {
+        self.int
+    }
+
+    /// `ssi_ptr`—Returns the pointer sent by `sigqueue`.
+    #[inline]
+    pub fn ptr(&self) -> u64 {
+        self.ptr
+    }
+
+    /// `ssi_utime`—Returns the user CPU time consumed, for `SIGCHLD`.
+    #[inline]
+    pub fn utime(&self) -> u64 {
+        self.utime
+    }
+
+    /// `ssi_stime`—Returns the system CPU time consumed, for `SIGCHLD`.
+    #[inline]
+    pub fn stime(&self) -> u64 {
+        self.stime
+    }
+
+    /// `ssi_addr`—Returns the address that generated the signal, for
+    /// hardware-generated signals.
+    #[inline]
+    pub fn addr(&self) -> u64 {
+        self.addr
+    }
+
+    /// `ssi_addr_lsb`—Returns the least significant bit of the address, for
+    /// `SIGBUS`.
+    #[inline]
+    pub fn addr_lsb(&self) -> u16 {
+        self.addr_lsb
+    }
+
+    /// `ssi_syscall`—Returns the system call number, for `SIGSYS` from
+    /// seccomp.
+    #[inline]
+    pub fn syscall(&self) -> i32 {
+        self.syscall
+    }
+
+    /// `ssi_call_addr`—Returns the address of the system call instruction,
+    /// for `SIGSYS` from seccomp.
+    #[inline]
+    pub fn call_addr(&self) -> u64 {
+        self.call_addr
+    }
+
+    /// `ssi_arch`—Returns the `AUDIT_ARCH_*` value of the system call, for
+    /// `SIGSYS` from seccomp.
+    #[inline]
+    pub fn arch(&self) -> u32 {
+        self.arch
+    }
+}
@@ -0,0 +1,796 @@
+//! fanotify support for monitoring filesystem events.
+//!
+//! Unlike inotify, fanotify can watch whole mounts and filesystems, can
+//! report events with file descriptors or file handles identifying the
+//! affected files, and can ask a listener to allow or deny accesses.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use rustix::fs::{fanotify, OFlags, CWD};
+//! use rustix::io;
+//! use std::mem::MaybeUninit;
+//!
+//! # fn test() -> io::Result<()> {
+//! // Create an fanotify object which receives permission events.
+//! let fanotify = fanotify::init(
+//!     fanotify::CreateFlags::CLOEXEC | fanotify::CreateFlags::CLASS_CONTENT,
+//!     OFlags::RDONLY | OFlags::CLOEXEC,
+//! )?;
+//!
+//! // Ask to approve opens of any file on the mount containing `/srv`.
+//! fanotify::mark(
+//!     &fanotify,
+//!     fanotify::MarkFlags::ADD | fanotify::MarkFlags::MOUNT,
+//!     fanotify::EventFlags::OPEN_PERM,
+//!     CWD,
+//!     "/srv",
+//! )?;
+//!
+//! // Loop over events, allowing each open.
+//! let mut buf = [MaybeUninit::uninit(); 4096];
+//! // SAFETY: `fanotify` is an fanotify group file descriptor.
+//! let mut iter = unsafe { fanotify::Reader::new(&fanotify, &mut buf) };
+//! loop {
+//!     let event = iter.next()?;
+//!     if let Some(fd) = event.fd() {
+//!         fanotify::respond(&fanotify, fd, fanotify::Response::ALLOW)?;
+//!     }
+//! }
+//! # }
+//! ```
+//!
+//! # References
+//!  - [Linux `fanotify`]
+//!
+//! [Linux `fanotify`]: https://man7.org/linux/man-pages/man7/fanotify.7.html
+
+#![allow(unsafe_code)]
+
+use crate::backend::fs::syscalls;
+use crate::fd::{AsFd, AsRawFd, BorrowedFd, FromRawFd, OwnedFd, RawFd};
+use crate::ffi::CStr;
+use crate::fs::OFlags;
+use crate::io::{self, read_uninit, Errno};
+use bitflags::bitflags;
+use core::mem::{size_of, MaybeUninit};
+use core::ptr::read_unaligned;
+
+// linux-raw-sys doesn't provide <linux/fanotify.h>, so define what we need
+// here.
+
+/// `struct fanotify_event_metadata`
+#[repr(C)]
+struct EventMetadata {
+    event_len: u32,
+    vers: u8,
+    reserved: u8,
+    metadata_len: u16,
+    mask: u64,
+    fd: i32,
+    pid: i32,
+}
+
+/// `struct fanotify_event_info_header`
+#[repr(C)]
+struct InfoHeader {
+    info_type: u8,
+    pad: u8,
+    len: u16,
+}
+
+/// `struct fanotify_response`
+#[repr(C)]
+struct RawResponse {
+    fd: i32,
+    response: u32,
+}
+
+/// `FANOTIFY_METADATA_VERSION`
+const METADATA_VERSION: u8 = 3;
+
+const INFO_TYPE_FID: u8 = 1;
+const INFO_TYPE_DFID_NAME: u8 = 2;
+const INFO_TYPE_DFID: u8 = 3;
+const INFO_TYPE_PIDFD: u8 = 4;
+const INFO_TYPE_ERROR: u8 = 5;
+const INFO_TYPE_OLD_DFID_NAME: u8 = 10;
+const INFO_TYPE_NEW_DFID_NAME: u8 = 12;
+
+bitflags! {
+    /// `FAN_*` flags for use with [`fanotify::init`].
+    ///
+    /// [`fanotify::init`]: crate::fs::fanotify::init
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct CreateFlags: u32 {
+        /// `FAN_CLOEXEC`
+        const CLOEXEC = 0x0000_0001;
+        /// `FAN_NONBLOCK`
+        const NONBLOCK = 0x0000_0002;
+        /// `FAN_CLASS_CONTENT`—Receive permission events after a file's
+        /// content is final.
+        const CLASS_CONTENT = 0x0000_0004;
+        /// `FAN_CLASS_PRE_CONTENT`—Receive permission events before a
+        /// file's content is final.
+        const CLASS_PRE_CONTENT = 0x0000_0008;
+        /// `FAN_UNLIMITED_QUEUE`
+        const UNLIMITED_QUEUE = 0x0000_0010;
+        /// `FAN_UNLIMITED_MARKS`
+        const UNLIMITED_MARKS = 0x0000_0020;
+        /// `FAN_ENABLE_AUDIT`
+        const ENABLE_AUDIT = 0x0000_0040;
+        /// `FAN_REPORT_PIDFD`—Report a pidfd for the process which caused
+        /// each event; see [`Event::pidfd`].
+        const REPORT_PIDFD = 0x0000_0080;
+        /// `FAN_REPORT_TID`—Report thread IDs instead of process IDs.
+        const REPORT_TID = 0x0000_0100;
+        /// `FAN_REPORT_FID`—Identify objects by file handle instead of by
+        /// file descriptor.
+        const REPORT_FID = 0x0000_0200;
+        /// `FAN_REPORT_DIR_FID`—Identify the directories containing objects
+        /// by file handle.
+        const REPORT_DIR_FID = 0x0000_0400;
+        /// `FAN_REPORT_NAME`—With `REPORT_DIR_FID`, also report names
+        /// within directories.
+        const REPORT_NAME = 0x0000_0800;
+        /// `FAN_REPORT_TARGET_FID`
+        const REPORT_TARGET_FID = 0x0000_1000;
+        /// `FAN_REPORT_DFID_NAME`
+        const REPORT_DFID_NAME = Self::REPORT_DIR_FID.bits() | Self::REPORT_NAME.bits();
+        /// `FAN_REPORT_DFID_NAME_TARGET`
+        const REPORT_DFID_NAME_TARGET = Self::REPORT_DFID_NAME.bits()
+            | Self::REPORT_FID.bits()
+            | Self::REPORT_TARGET_FID.bits();
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `FAN_MARK_*` flags for use with [`fanotify::mark`].
+    ///
+    /// Exactly one of `ADD`, `REMOVE`, or `FLUSH` must be given.
+    ///
+    /// [`fanotify::mark`]: crate::fs::fanotify::mark
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct MarkFlags: u32 {
+        /// `FAN_MARK_ADD`
+        const ADD = 0x0000_0001;
+        /// `FAN_MARK_REMOVE`
+        const REMOVE = 0x0000_0002;
+        /// `FAN_MARK_DONT_FOLLOW`
+        const DONT_FOLLOW = 0x0000_0004;
+        /// `FAN_MARK_ONLYDIR`
+        const ONLYDIR = 0x0000_0008;
+        /// `FAN_MARK_MOUNT`—Mark the mount containing the path.
+        const MOUNT = 0x0000_0010;
+        /// `FAN_MARK_IGNORED_MASK`
+        const IGNORED_MASK = 0x0000_0020;
+        /// `FAN_MARK_IGNORED_SURV_MODIFY`
+        const IGNORED_SURV_MODIFY = 0x0000_0040;
+        /// `FAN_MARK_FLUSH`
+        const FLUSH = 0x0000_0080;
+        /// `FAN_MARK_FILESYSTEM`—Mark the filesystem containing the path.
+        const FILESYSTEM = 0x0000_0100;
+        /// `FAN_MARK_EVICTABLE`
+        const EVICTABLE = 0x0000_0200;
+        /// `FAN_MARK_IGNORE`
+        const IGNORE = 0x0000_0400;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `FAN_*` event flags for use with [`fanotify::mark`] and
+    /// [`Event::mask`].
+    ///
+    /// [`fanotify::mark`]: crate::fs::fanotify::mark
+    #[repr(transparent)]
+    #[derive(Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct EventFlags: u64 {
+        /// `FAN_ACCESS`
+        const ACCESS = 0x0000_0001;
+        /// `FAN_MODIFY`
+        const MODIFY = 0x0000_0002;
+        /// `FAN_ATTRIB`
+        const ATTRIB = 0x0000_0004;
+        /// `FAN_CLOSE_WRITE`
+        const CLOSE_WRITE = 0x0000_0008;
+        /// `FAN_CLOSE_NOWRITE`
+        const CLOSE_NOWRITE = 0x0000_0010;
+        /// `FAN_OPEN`
+        const OPEN = 0x0000_0020;
+        /// `FAN_MOVED_FROM`
+        const MOVED_FROM = 0x0000_0040;
+        /// `FAN_MOVED_TO`
+        const MOVED_TO = 0x0000_0080;
+        /// `FAN_CREATE`
+        const CREATE = 0x0000_0100;
+        /// `FAN_DELETE`
+        const DELETE = 0x0000_0200;
+        /// `FAN_DELETE_SELF`
+        const DELETE_SELF = 0x0000_0400;
+        /// `FAN_MOVE_SELF`
+        const MOVE_SELF = 0x0000_0800;
+        /// `FAN_OPEN_EXEC`
+        const OPEN_EXEC = 0x0000_1000;
+        /// `FAN_Q_OVERFLOW`
+        const QUEUE_OVERFLOW = 0x0000_4000;
+        /// `FAN_FS_ERROR`
+        const FS_ERROR = 0x0000_8000;
+        /// `FAN_OPEN_PERM`
+        const OPEN_PERM = 0x0001_0000;
+        /// `FAN_ACCESS_PERM`
+        const ACCESS_PERM = 0x0002_0000;
+        /// `FAN_OPEN_EXEC_PERM`
+        const OPEN_EXEC_PERM = 0x0004_0000;
+        /// `FAN_EVENT_ON_CHILD`
+        const EVENT_ON_CHILD = 0x0800_0000;
+        /// `FAN_RENAME`
+        const RENAME = 0x1000_0000;
+        /// `FAN_ONDIR`
+        const ONDIR = 0x4000_0000;
+
+        /// `FAN_CLOSE`
+        const CLOSE = Self::CLOSE_WRITE.bits() | Self::CLOSE_NOWRITE.bits();
+        /// `FAN_MOVE`
+        const MOVE = Self::MOVED_FROM.bits() | Self::MOVED_TO.bits();
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `FAN_*` responses for use with [`fanotify::respond`].
+    ///
+    /// Exactly one of `ALLOW` or `DENY` must be given.
+    ///
+    /// [`fanotify::respond`]: crate::fs::fanotify::respond
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct Response: u32 {
+        /// `FAN_ALLOW`
+        const ALLOW = 0x01;
+        /// `FAN_DENY`
+        const DENY = 0x02;
+        /// `FAN_AUDIT`—Also log the decision, if the fanotify object was
+        /// created with [`CreateFlags::ENABLE_AUDIT`].
+        const AUDIT = 0x10;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// `fanotify_init(flags, event_flags)`—Creates a new fanotify object.
+///
+/// `event_flags` gives the `O_*` flags for the file descriptors of reported
+/// files, such as [`OFlags::RDONLY`] and [`OFlags::CLOEXEC`].
+///
+/// Use the [`CreateFlags::CLOEXEC`] flag to prevent the resulting file
+/// descriptor from being implicitly passed across `exec` boundaries.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fanotify_init.2.html
+#[doc(alias = "fanotify_init")]
+#[inline]
+pub fn init(flags: CreateFlags, event_flags: OFlags) -> io::Result<OwnedFd> {
+    syscalls::fanotify_init(flags.bits(), event_flags)
+}
+
+/// `fanotify_mark(fan, flags, mask, dirfd, path)`—Adds, removes, or flushes
+/// marks on a file, directory, mount, or filesystem.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fanotify_mark.2.html
+#[doc(alias = "fanotify_mark")]
+#[inline]
+pub fn mark<FanFd: AsFd, P: crate::path::Arg, Fd: AsFd>(
+    fan: FanFd,
+    flags: MarkFlags,
+    mask: EventFlags,
+    dirfd: Fd,
+    path: P,
+) -> io::Result<()> {
+    path.into_with_c_str(|path| {
+        syscalls::fanotify_mark(
+            fan.as_fd(),
+            flags.bits(),
+            mask.bits(),
+            dirfd.as_fd(),
+            Some(path),
+        )
+    })
+}
+
+/// `fanotify_mark(fan, flags, mask, fd, NULL)`—Adds, removes, or flushes
+/// marks on the file or directory open as `fd`, or its mount or filesystem.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fanotify_mark.2.html
+#[doc(alias = "fanotify_mark")]
+#[inline]
+pub fn mark_fd<FanFd: AsFd, Fd: AsFd>(
+    fan: FanFd,
+    flags: MarkFlags,
+    mask: EventFlags,
+    fd: Fd,
+) -> io::Result<()> {
+    syscalls::fanotify_mark(fan.as_fd(), flags.bits(), mask.bits(), fd.as_fd(), None)
+}
+
+/// `write(fan, &fanotify_response { fd, response })`—Responds to a
+/// permission event.
+///
+/// `event_fd` is the file descriptor from the event, as returned by
+/// [`Event::fd`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/fanotify.7.html
+#[doc(alias = "fanotify_response")]
+#[inline]
+pub fn respond<FanFd: AsFd, Fd: AsFd>(
+    fan: FanFd,
+    event_fd: Fd,
+    response: Response,
+) -> io::Result<()> {
+    let raw = RawResponse {
+        fd: event_fd.as_fd().as_raw_fd(),
+        response: response.bits(),
+    };
+
+    // SAFETY: `RawResponse` is a `repr(C)` struct of integers with no
+    // padding.
+    let bytes = unsafe {
+        core::slice::from_raw_parts(
+            (&raw as *const RawResponse).cast::<u8>(),
+            size_of::<RawResponse>(),
+        )
+    };
+    match io::write(fan, bytes)? {
+        n if n == bytes.len() => Ok(()),
+        _ => Err(Errno::IO),
+    }
+}
+
+/// An fanotify event iterator implemented with the read syscall.
+///
+/// See the [`RawDir`] API for more details and usage examples as this API is
+/// based on it.
+///
+/// [`RawDir`]: crate::fs::raw_dir::RawDir
+pub struct Reader<'buf, Fd: AsFd> {
+    fd: Fd,
+    buf: &'buf mut [MaybeUninit<u8>],
+    initialized: usize,
+    offset: usize,
+}
+
+impl<'buf, Fd: AsFd> Reader<'buf, Fd> {
+    /// Create a new iterator from the given file descriptor and buffer.
+    ///
+    /// The buffer must be large enough to hold at least one event, including
+    /// any information records.
+    ///
+    /// # Safety
+    ///
+    /// `fd` must be an fanotify group file descriptor, such as one returned
+    /// by [`init`]. Events read from it contain file descriptor numbers which
+    /// the reader takes ownership of and closes.
+    pub unsafe fn new(fd: Fd, buf: &'buf mut [MaybeUninit<u8>]) -> Self {
+        Self {
+            fd,
+            buf,
+            initialized: 0,
+            offset: 0,
+        }
+    }
+}
+
+/// An fanotify event.
+///
+/// The event owns the file descriptors the kernel opened for it, and closes
+/// them when dropped, unless they're taken with [`Event::take_fd`] or
+/// [`Event::take_pidfd`].
+#[derive(Debug)]
+pub struct Event<'a> {
+    mask: EventFlags,
+    pid: i32,
+    fd: Option<OwnedFd>,
+    pidfd: Option<OwnedFd>,
+    info: &'a [u8],
+}
+
+impl<'a> Event<'a> {
+    /// Returns a description of the events.
+    #[inline]
+    pub fn mask(&self) -> EventFlags {
+        self.mask
+    }
+
+    /// Returns the ID of the process, or with [`CreateFlags::REPORT_TID`]
+    /// the thread, which caused the event.
+    #[inline]
+    pub fn pid(&self) -> i32 {
+        self.pid
+    }
+
+    /// Returns the file descriptor open on the affected file, if any.
+    ///
+    /// There is no file descriptor when the fanotify object was created with
+    /// [`CreateFlags::REPORT_FID`] or similar, or for
+    /// [`EventFlags::QUEUE_OVERFLOW`] events.
+    #[inline]
+    pub fn fd(&self) -> Option<BorrowedFd<'_>> {
+        self.fd.as_ref().map(AsFd::as_fd)
+    }
+
+    /// Takes ownership of the file descriptor open on the affected file, if
+    /// any.
+    #[inline]
+    pub fn take_fd(&mut self) -> Option<OwnedFd> {
+        self.fd.take()
+    }
+
+    /// Returns the pidfd for the process which caused the event, if the
+    /// fanotify object was created with [`CreateFlags::REPORT_PIDFD`] and the
+    /// process still exists.
+    #[inline]
+    pub fn pidfd(&self) -> Option<BorrowedFd<'_>> {
+        self.pidfd.as_ref().map(AsFd::as_fd)
+    }
+
+    /// Takes ownership of the pidfd for the process which caused the event,
+    /// if any.
+    #[inline]
+    pub fn take_pidfd(&mut self) -> Option<OwnedFd> {
+        self.pidfd.take()
+    }
+
+    /// Returns an iterator over the information records attached to the
+    /// event.
+    ///
+    /// Pidfd records aren't included; use [`Event::pidfd`] instead.
+    #[inline]
+    pub fn info(&self) -> InfoIter<'a> {
+        InfoIter { buf: self.info }
+    }
+}
+
+/// An information record attached to an fanotify [`Event`].
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum Info<'a> {
+    /// `FAN_EVENT_INFO_TYPE_FID`—The affected object.
+    Fid(FileId<'a>),
+    /// `FAN_EVENT_INFO_TYPE_DFID`—The directory containing the affected
+    /// object.
+    Dfid(FileId<'a>),
+    /// `FAN_EVENT_INFO_TYPE_DFID_NAME`—The directory containing the
+    /// affected object, and its name within the directory.
+    DfidName(FileId<'a>, &'a CStr),
+    /// `FAN_EVENT_INFO_TYPE_OLD_DFID_NAME`—For [`EventFlags::RENAME`], the
+    /// directory and name the object was renamed from.
+    OldDfidName(FileId<'a>, &'a CStr),
+    /// `FAN_EVENT_INFO_TYPE_NEW_DFID_NAME`—For [`EventFlags::RENAME`], the
+    /// directory and name the object was renamed to.
+    NewDfidName(FileId<'a>, &'a CStr),
+    /// `FAN_EVENT_INFO_TYPE_ERROR`—For [`EventFlags::FS_ERROR`], the first
+    /// error and the number of errors since the last event.
+    Error {
+        /// The error.
+        error: i32,
+        /// The number of errors.
+        error_count: u32,
+    },
+    /// A record of a type this version of rustix doesn't know about.
+    Unknown {
+        /// The record type.
+        info_type: u8,
+        /// The record contents, after the header.
+        data: &'a [u8],
+    },
+}
+
+/// A file identifier reported in an fanotify [`Info`] record.
+#[derive(Debug, Clone, Copy)]
+pub struct FileId<'a> {
+    fsid: [i32; 2],
+    handle_type: i32,
+    handle: &'a [u8],
+}
+
+impl<'a> FileId<'a> {
+    /// Returns the filesystem ID of the filesystem containing the object, as
+    /// in the `f_fsid` field of `statfs`.
+    #[inline]
+    pub fn fsid(&self) -> [i32; 2] {
+        self.fsid
+    }
+
+    /// Returns the type of the file handle.
+    #[inline]
+    pub fn handle_type(&self) -> i32 {
+        self.handle_type
+    }
+
+    /// Returns the bytes of the file handle.
+    #[inline]
+    pub fn handle(&self) -> &'a [u8] {
+        self.handle
+    }
+
+    /// Returns an owned copy of the file handle, for use with
+    /// [`open_by_handle_at`] or for comparing with the result of
+    /// [`name_to_handle_at`].
+    ///
+    /// [`open_by_handle_at`]: crate::fs::open_by_handle_at
+    /// [`name_to_handle_at`]: crate::fs::name_to_handle_at
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn to_file_handle(&self) -> crate::fs::FileHandle {
+        crate::fs::FileHandle::new(self.handle_type, self.handle)
+    }
+}
+
+/// An iterator over the information records attached to an fanotify
+/// [`Event`].
+#[derive(Debug, Clone)]
+pub struct InfoIter<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Iterator for InfoIter<'a> {
+    type Item = Info<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (info_type, data) = next_record(&mut self.buf)?;
+            if info_type != INFO_TYPE_PIDFD {
+                return Some(parse_info(info_type, data));
+            }
+        }
+    }
+}
+
+/// Split the first information record from `buf`, returning its type and
+/// the contents after its header.
+fn next_record<'a>(buf: &mut &'a [u8]) -> Option<(u8, &'a [u8])> {
+    if buf.len() < size_of::<InfoHeader>() {
+        return None;
+    }
+    // SAFETY: We checked that `buf` is long enough, and `read_unaligned`
+    // doesn't require alignment.
+    let header = unsafe { read_unaligned(buf.as_ptr().cast::<InfoHeader>()) };
+    let len = usize::from(header.len);
+    if len < size_of::<InfoHeader>() || len > buf.len() {
+        *buf = &[];
+        return None;
+    }
+    let data = &buf[size_of::<InfoHeader>()..len];
+    *buf = &buf[len..];
+    Some((header.info_type, data))
+}
+
+fn parse_info(info_type: u8, data: &[u8]) -> Info<'_> {
+    let unknown = Info::Unknown { info_type, data };
+    match info_type {
+        INFO_TYPE_FID
+        | INFO_TYPE_DFID
+        | INFO_TYPE_DFID_NAME
+        | INFO_TYPE_OLD_DFID_NAME
+        | INFO_TYPE_NEW_DFID_NAME => {
+            // The record contains a `__kernel_fsid_t`, then a `struct
+            // file_handle`, then for the `*_NAME` types a NUL-terminated name.
+            match parse_file_id(data) {
+                Some((id, name)) => match info_type {
+                    INFO_TYPE_FID => Info::Fid(id),
+                    INFO_TYPE_DFID => Info::Dfid(id),
+                    _ => match parse_name(name) {
+                        Some(name) if info_type == INFO_TYPE_DFID_NAME => Info::DfidName(id, name),
+                        Some(name) if info_type == INFO_TYPE_OLD_DFID_NAME => {
+                            Info::OldDfidName(id, name)
+                        }
+                        Some(name) => Info::NewDfidName(id, name),
+                        None => unknown,
+                    },
+                },
+                None => unknown,
+            }
+        }
+        INFO_TYPE_ERROR => match split_array::<4>(data) {
+            Some((error, data)) => match split_array::<4>(data) {
+                Some((error_count, _)) => Info::Error {
+                    error: i32::from_ne_bytes(error),
+                    error_count: u32::from_ne_bytes(error_count),
+                },
+                None => unknown,
+            },
+            None => unknown,
+        },
+        _ => unknown,
+    }
+}
+
+/// Parse a `__kernel_fsid_t` and a `struct file_handle`, returning the
+/// identifier and the remaining data.
+fn parse_file_id(data: &[u8]) -> Option<(FileId<'_>, &[u8])> {
+    let (fsid0, data) = split_array::<4>(data)?;
+    let (fsid1, data) = split_array::<4>(data)?;
+    let (handle_bytes, data) = split_array::<4>(data)?;
+    let (handle_type, data) = split_array::<4>(data)?;
+    let handle_bytes = u32::from_ne_bytes(handle_bytes) as usize;
+    if handle_bytes > data.len() {
+        return None;
+    }
+    let (handle, rest) = data.split_at(handle_bytes);
+    let id = FileId {
+        fsid: [i32::from_ne_bytes(fsid0), i32::from_ne_bytes(fsid1)],
+        handle_type: i32::from_ne_bytes(handle_type),
+        handle,
+    };
+    Some((id, rest))
+}
+
+/// Parse a NUL-terminated name, which may be followed by padding.
+fn parse_name(data: &[u8]) -> Option<&CStr> {
+    let len = data.iter().position(|b| *b == 0)?;
+    CStr::from_bytes_with_nul(&data[..=len]).ok()
+}
+
+fn split_array<const N: usize>(data: &[u8]) -> Option<([u8; N], &[u8])> {
+    if data.len() < N {
+        return None;
+    }
+    let (head, tail) = data.split_at(N);
+    Some((head.try_into().unwrap(), tail))
+}
+
+impl<'buf, Fd: AsFd> Reader<'buf, Fd> {
+    /// Read the next fanotify event.
+    ///
+    /// This is similar to `[Iterator::next`] except that it doesn't return an
+    /// `Option`, because the stream doesn't have an ending. It always returns
+    /// events or errors.
+    ///
+    /// If there are no events in the buffer and none ready to be read:
+    ///  - If the file descriptor was opened with
+    ///    [`fanotify::CreateFlags::NONBLOCK`], this will fail with
+    ///    [`Errno::AGAIN`].
+    ///  - Otherwise this will block until at least one event is ready or an
+    ///    error occurs.
+    ///
+    /// [`fanotify::CreateFlags::NONBLOCK`]: CreateFlags::NONBLOCK
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> io::Result<Event<'_>> {
+        if self.is_buffer_empty() {
+            match read_uninit(self.fd.as_fd(), self.buf).map(|(init, _)| init.len()) {
+                Ok(0) => return Err(Errno::INVAL),
+                Ok(bytes_read) => {
+                    self.initialized = bytes_read;
+                    self.offset = 0;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        self.next_buffered()
+    }
+
+    /// Parse the next event in the buffer.
+    ///
+    /// This takes ownership of the event's file descriptors as soon as the
+    /// event header is recognized, so that they're closed if the rest of the
+    /// event is malformed.
+    fn next_buffered(&mut self) -> io::Result<Event<'_>> {
+        // SAFETY: This data is initialized by `next`.
+        let buf = unsafe {
+            core::slice::from_raw_parts(
+                self.buf[self.offset..].as_ptr().cast::<u8>(),
+                self.initialized - self.offset,
+            )
+        };
+        if buf.len() < size_of::<EventMetadata>() {
+            self.offset = self.initialized;
+            return Err(Errno::INVAL);
+        }
+
+        // SAFETY: We checked that `buf` is long enough. Events following
+        // information records may not be aligned, so use `read_unaligned`.
+        let metadata = unsafe { read_unaligned(buf.as_ptr().cast::<EventMetadata>()) };
+        if metadata.vers != METADATA_VERSION {
+            self.offset = self.initialized;
+            return Err(Errno::INVAL);
+        }
+
+        let fd = owned_fd(metadata.fd);
+        let event_len = metadata.event_len as usize;
+        let metadata_len = usize::from(metadata.metadata_len);
+        let info_end = event_len.min(buf.len());
+        let mut pidfd = None;
+        if metadata_len >= size_of::<EventMetadata>() && metadata_len <= info_end {
+            let mut records = &buf[metadata_len..info_end];
+            while let Some((info_type, data)) = next_record(&mut records) {
+                if info_type == INFO_TYPE_PIDFD {
+                    if let Some((raw, _)) = split_array::<4>(data) {
+                        pidfd = owned_fd(i32::from_ne_bytes(raw));
+                    }
+                }
+            }
+        }
+
+        if metadata_len < size_of::<EventMetadata>()
+            || event_len < metadata_len
+            || event_len > buf.len()
+        {
+            // `fd` and `pidfd` are closed when they're dropped here.
+            self.offset = self.initialized;
+            return Err(Errno::INVAL);
+        }
+        self.offset += event_len;
+
+        Ok(Event {
+            mask: EventFlags::from_bits_retain(metadata.mask),
+            pid: metadata.pid,
+            fd,
+            pidfd,
+            info: &buf[metadata_len..event_len],
+        })
+    }
+
+    /// Returns true if the internal buffer is empty and will be refilled when
+    /// calling [`next`]. This is useful to avoid further blocking reads.
+    ///
+    /// [`next`]: Self::next
+    pub fn is_buffer_empty(&self) -> bool {
+        self.offset >= self.initialized
+    }
+}
+
+impl<'buf, Fd: AsFd> Drop for Reader<'buf, Fd> {
+    fn drop(&mut self) {
+        // Close the file descriptors of any events which were read but never
+        // returned.
+        while !self.is_buffer_empty() {
+            if self.next_buffered().is_err() {
+                break;
+            }
+        }
+    }
+}
+
+/// Take ownership of a file descriptor from an event, if it's valid.
+///
+/// Negative values, such as `FAN_NOFD`, `FAN_NOPIDFD`, and `FAN_EPIDFD`,
+/// mean there is no file descriptor.
+fn owned_fd(raw: RawFd) -> Option<OwnedFd> {
+    if raw < 0 {
+        None
+    } else {
+        // SAFETY: `Reader::new` requires its file descriptor to be an
+        // fanotify group, so the kernel opened this file descriptor for the
+        // reader of the event, and we own it.
+        Some(unsafe { OwnedFd::from_raw_fd(raw) })
+    }
+}
+
+#[test]
+fn test_sizes() {
+    assert_eq!(size_of::<EventMetadata>(), 24);
+    assert_eq!(size_of::<InfoHeader>(), 4);
+    assert_eq!(size_of::<RawResponse>(), 8);
+}
@@ -0,0 +1,243 @@
+//! `name_to_handle_at` and `open_by_handle_at`.
+#![allow(unsafe_code)]
+
+use crate::backend::c;
+use crate::fd::{AsFd, OwnedFd};
+use crate::fs::{AtFlags, OFlags};
+use crate::{backend, io, path};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::mem::size_of;
+use core::ptr::addr_of_mut;
+
+/// `MAX_HANDLE_SZ`—The maximum size of the bytes of a file handle returned by
+/// the kernel.
+pub const MAX_HANDLE_SZ: usize = 128;
+
+/// A mount ID returned by [`name_to_handle_at`].
+///
+/// With [`AtFlags::HANDLE_MNT_ID_UNIQUE`], this is the unique 64-bit mount ID
+/// used by `statx` with `STATX_MNT_ID_UNIQUE` and `statmount`. Otherwise, it's
+/// the ID used in `/proc/<pid>/mountinfo`, which may be reused after the mount
+/// is unmounted.
+pub type MountId = u64;
+
+/// The size of the `handle_bytes` and `handle_type` fields at the start of a
+/// `struct file_handle`.
+const HEADER_SIZE: usize = 2 * size_of::<u32>();
+
+/// An owned file handle, as returned by [`name_to_handle_at`] and used by
+/// [`open_by_handle_at`].
+///
+/// A file handle identifies a file within a filesystem persistently, so it
+/// can be stored and used later, even after a reboot, as long as the
+/// filesystem supports it.
+#[doc(alias = "file_handle")]
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct FileHandle {
+    /// A `struct file_handle`, stored in `u32`s so that it's aligned.
+    buf: Vec<u32>,
+}
+
+impl FileHandle {
+    /// Construct a `FileHandle` from a handle type and the bytes of a
+    /// handle, such as those reported by fanotify.
+    pub fn new(handle_type: i32, handle: &[u8]) -> Self {
+        let mut this = Self::with_capacity(handle.len());
+        this.buf[1] = handle_type as u32;
+        this.handle_mut().copy_from_slice(handle);
+        this
+    }
+
+    /// Construct a zeroed `FileHandle` with room for `len` bytes.
+    fn with_capacity(len: usize) -> Self {
+        let words = (HEADER_SIZE + len + size_of::<u32>() - 1) / size_of::<u32>();
+        let mut buf = vec![0_u32; words];
+        buf[0] = len as u32;
+        Self { buf }
+    }
+
+    /// The value of the `handle_bytes` field.
+    #[inline]
+    fn handle_bytes(&self) -> usize {
+        self.buf[0] as usize
+    }
+
+    /// Returns the filesystem-specific type of the handle.
+    #[inline]
+    pub fn handle_type(&self) -> i32 {
+        self.buf[1] as i32
+    }
+
+    /// Returns the bytes of the handle.
+    #[inline]
+    pub fn handle(&self) -> &[u8] {
+        // SAFETY: `buf` holds initialized `u32`s, followed by at least
+        // `handle_bytes` bytes after the header.
+        unsafe {
+            core::slice::from_raw_parts(
+                self.buf.as_ptr().cast::<u8>().add(HEADER_SIZE),
+                self.handle_bytes(),
+            )
+        }
+    }
+
+    #[inline]
+    fn handle_mut(&mut self) -> &mut [u8] {
+        // SAFETY: As in `handle`.
+        unsafe {
+            core::slice::from_raw_parts_mut(
+                self.buf.as_mut_ptr().cast::<u8>().add(HEADER_SIZE),
+                self.handle_bytes(),
+            )
+        }
+    }
+
+    /// Serialize the handle, in the layout of a `struct file_handle` in
+    /// native byte order: a 4-byte length, a 4-byte handle type, and then
+    /// the bytes of the handle.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_SIZE + self.handle_bytes());
+        bytes.extend_from_slice(&self.buf[0].to_ne_bytes());
+        bytes.extend_from_slice(&self.buf[1].to_ne_bytes());
+        bytes.extend_from_slice(self.handle());
+        bytes
+    }
+
+    /// Deserialize a handle serialized by [`FileHandle::to_bytes`].
+    ///
+    /// This fails with [`io::Errno::INVAL`] if `bytes` is too short, or if
+    /// its length field doesn't match the number of bytes which follow it.
+    pub fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        if bytes.len() < HEADER_SIZE {
+            return Err(io::Errno::INVAL);
+        }
+        let (header, handle) = bytes.split_at(HEADER_SIZE);
+        let handle_bytes = u32::from_ne_bytes([header[0], header[1], header[2], header[3]]);
+        let handle_type = i32::from_ne_bytes([header[4], header[5], header[6], header[7]]);
+        if handle_bytes as usize != handle.len() {
+            return Err(io::Errno::INVAL);
+        }
+        Ok(Self::new(handle_type, handle))
+    }
+}
+
+impl fmt::Debug for FileHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileHandle")
+            .field("handle_type", &self.handle_type())
+            .field("handle", &self.handle())
+            .finish()
+    }
+}
+
+/// The `mount_id` argument of `name_to_handle_at`, which is an `int` unless
+/// `AT_HANDLE_MNT_ID_UNIQUE` is used.
+#[repr(C)]
+union MountIdArg {
+    old: c::c_int,
+    unique: u64,
+}
+
+/// `name_to_handle_at(dirfd, path, handle, &mount_id, flags)`—Returns a file
+/// handle for a file, and the ID of the mount containing it.
+///
+/// Pass [`AtFlags::HANDLE_FID`] to get a handle which only identifies the
+/// file, such as for comparing with handles reported by fanotify, on
+/// filesystems which don't support opening files by handle.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/name_to_handle_at.2.html
+pub fn name_to_handle_at<Fd: AsFd, P: path::Arg>(
+    dirfd: Fd,
+    path: P,
+    flags: AtFlags,
+) -> io::Result<(FileHandle, MountId)> {
+    path.into_with_c_str(|path| {
+        let mut len = MAX_HANDLE_SZ;
+        loop {
+            let mut handle = FileHandle::with_capacity(len);
+            let mut mount_id = MountIdArg { unique: 0 };
+
+            // SAFETY: `handle` has room for `handle_bytes` bytes after its
+            // header, and `mount_id` has room for either kind of mount ID.
+            match unsafe {
+                backend::fs::syscalls::name_to_handle_at(
+                    dirfd.as_fd(),
+                    path,
+                    handle.buf.as_mut_ptr().cast(),
+                    addr_of_mut!(mount_id).cast(),
+                    flags,
+                )
+            } {
+                Ok(()) => {
+                    // SAFETY: The kernel wrote the field selected by `flags`.
+                    let mount_id = unsafe {
+                        if flags.contains(AtFlags::HANDLE_MNT_ID_UNIQUE) {
+                            mount_id.unique
+                        } else {
+                            mount_id.old as u32 as u64
+                        }
+                    };
+
+                    // Trim any unused space, so that equal handles compare
+                    // equal.
+                    let handle = FileHandle::new(handle.handle_type(), handle.handle());
+                    return Ok((handle, mount_id));
+                }
+                // On `EOVERFLOW`, the kernel sets `handle_bytes` to the size
+                // it needs.
+                Err(io::Errno::OVERFLOW) if handle.handle_bytes() > len => {
+                    len = handle.handle_bytes();
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    })
+}
+
+/// `open_by_handle_at(mount_fd, handle, flags)`—Opens a file by its handle.
+///
+/// `mount_fd` may be any file descriptor on the mount containing the file.
+/// This requires the `CAP_DAC_READ_SEARCH` capability.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/open_by_handle_at.2.html
+#[inline]
+pub fn open_by_handle_at<Fd: AsFd>(
+    mount_fd: Fd,
+    handle: &FileHandle,
+    flags: OFlags,
+) -> io::Result<OwnedFd> {
+    // SAFETY: `handle.buf` is a `struct file_handle` with `handle_bytes`
+    // bytes after its header.
+    unsafe {
+        backend::fs::syscalls::open_by_handle_at(
+            mount_fd.as_fd(),
+            handle.buf.as_ptr().cast(),
+            flags,
+        )
+    }
+}
+
+#[test]
+fn test_file_handle_bytes() {
+    let handle = FileHandle::new(-3, &[1, 2, 3, 4, 5]);
+    assert_eq!(handle.handle_type(), -3);
+    assert_eq!(handle.handle(), &[1, 2, 3, 4, 5]);
+
+    let bytes = handle.to_bytes();
+    assert_eq!(bytes.len(), HEADER_SIZE + 5);
+    assert_eq!(FileHandle::from_bytes(&bytes).unwrap(), handle);
+
+    assert_eq!(
+        FileHandle::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(io::Errno::INVAL)
+    );
+    assert_eq!(FileHandle::from_bytes(&[0; 4]), Err(io::Errno::INVAL));
+}
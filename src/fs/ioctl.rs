@@ -11,6 +11,9 @@ use {
 
 use bitflags::bitflags;
 
+#[cfg(all(linux_kernel, feature = "alloc"))]
+use alloc::vec::Vec;
+
 #[cfg(all(linux_kernel, not(any(target_arch = "sparc", target_arch = "sparc64"))))]
 use crate::fd::{AsRawFd, BorrowedFd};
 
@@ -57,6 +60,152 @@ pub fn ioctl_ficlone<Fd: AsFd, SrcFd: AsFd>(fd: Fd, src_fd: SrcFd) -> io::Result
     unsafe { ioctl::ioctl(fd, Ficlone(src_fd.as_fd())) }
 }
 
+/// `ioctl(fd, FICLONERANGE, range)`—Share a range of data between open
+/// files.
+///
+/// This shares `src_length` bytes at `src_offset` in `src_fd` into `fd` at
+/// `dest_offset`. A `src_length` of zero means to share all the data up to
+/// the end of `src_fd`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_ficlonerange.2.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "FICLONERANGE")]
+pub fn ioctl_ficlonerange<Fd: AsFd, SrcFd: AsFd>(
+    fd: Fd,
+    src_fd: SrcFd,
+    src_offset: u64,
+    src_length: u64,
+    dest_offset: u64,
+) -> io::Result<()> {
+    use linux_raw_sys::general::file_clone_range;
+
+    let range = file_clone_range {
+        src_fd: crate::fd::AsRawFd::as_raw_fd(&src_fd.as_fd()).into(),
+        src_offset,
+        src_length,
+        dest_offset,
+    };
+
+    // SAFETY: `FICLONERANGE` is a pointer setter opcode for a
+    // `struct file_clone_range`.
+    unsafe {
+        let ctl = ioctl::Setter::<ioctl::WriteOpcode<0x94, 13, file_clone_range>, _>::new(range);
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// The outcome of deduplicating one destination range with
+/// [`ioctl_fideduperange`].
+#[cfg(all(linux_kernel, feature = "alloc"))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum FileDedupeRangeStatus {
+    /// `FILE_DEDUPE_RANGE_SAME`—The contents matched and the range was
+    /// deduplicated.
+    #[doc(alias = "FILE_DEDUPE_RANGE_SAME")]
+    Same,
+
+    /// `FILE_DEDUPE_RANGE_DIFFERS`—The contents differed, so nothing was
+    /// deduplicated.
+    #[doc(alias = "FILE_DEDUPE_RANGE_DIFFERS")]
+    Differs,
+}
+
+/// The result for one destination of [`ioctl_fideduperange`].
+#[cfg(all(linux_kernel, feature = "alloc"))]
+#[doc(alias = "file_dedupe_range_info")]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct FileDedupeRangeInfo {
+    /// Whether the range was deduplicated, or the error which prevented it.
+    pub status: io::Result<FileDedupeRangeStatus>,
+
+    /// The number of bytes which were deduplicated.
+    pub bytes_deduped: u64,
+}
+
+/// `ioctl(src_fd, FIDEDUPERANGE, range)`—Share a range of data between open
+/// files, if their contents are identical.
+///
+/// This compares `src_length` bytes at `src_offset` in `src_fd` with each of
+/// the `(fd, offset)` ranges in `dests`, and shares the data of the ranges
+/// which match. It returns the outcome for each destination, in the same
+/// order as `dests`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_fideduperange.2.html
+#[cfg(all(linux_kernel, feature = "alloc"))]
+#[doc(alias = "FIDEDUPERANGE")]
+pub fn ioctl_fideduperange<Fd: AsFd>(
+    src_fd: Fd,
+    src_offset: u64,
+    src_length: u64,
+    dests: &[(crate::fd::BorrowedFd<'_>, u64)],
+) -> io::Result<Vec<FileDedupeRangeInfo>> {
+    use core::mem::size_of;
+    use linux_raw_sys::general::{file_dedupe_range, file_dedupe_range_info};
+
+    const HEADER_WORDS: usize = size_of::<file_dedupe_range>() / size_of::<u64>();
+    const INFO_WORDS: usize = size_of::<file_dedupe_range_info>() / size_of::<u64>();
+
+    let dest_count = u16::try_from(dests.len()).map_err(|_| io::Errno::INVAL)?;
+
+    // Build a `struct file_dedupe_range` followed by its array of
+    // `struct file_dedupe_range_info`, in `u64`s so that it's aligned.
+    let mut buf = alloc::vec![0_u64; HEADER_WORDS + dests.len() * INFO_WORDS];
+    let header = buf.as_mut_ptr().cast::<file_dedupe_range>();
+    // SAFETY: `buf` is big enough and aligned enough for the header and
+    // `dests.len()` info records, and is zero-initialized.
+    let infos = unsafe {
+        (*header).src_offset = src_offset;
+        (*header).src_length = src_length;
+        (*header).dest_count = dest_count;
+
+        let infos = buf
+            .as_mut_ptr()
+            .add(HEADER_WORDS)
+            .cast::<file_dedupe_range_info>();
+        for (i, (dest_fd, dest_offset)) in dests.iter().enumerate() {
+            infos.add(i).write(file_dedupe_range_info {
+                dest_fd: crate::fd::AsRawFd::as_raw_fd(dest_fd).into(),
+                dest_offset: *dest_offset,
+                bytes_deduped: 0,
+                status: 0,
+                reserved: 0,
+            });
+        }
+        infos
+    };
+
+    // SAFETY: `buf` holds a `struct file_dedupe_range` with `dest_count`
+    // info records.
+    unsafe { ioctl::ioctl(src_fd, Fideduperange(header.cast()))? };
+
+    Ok((0..dests.len())
+        .map(|i| {
+            // SAFETY: The kernel has filled in each info record.
+            let info = unsafe { infos.add(i).read() };
+            let status = match info.status {
+                status if status < 0 => Err(io::Errno::from_raw_os_error(-status)),
+                status if status == linux_raw_sys::general::FILE_DEDUPE_RANGE_SAME as i32 => {
+                    Ok(FileDedupeRangeStatus::Same)
+                }
+                _ => Ok(FileDedupeRangeStatus::Differs),
+            };
+            FileDedupeRangeInfo {
+                status,
+                bytes_deduped: info.bytes_deduped,
+            }
+        })
+        .collect())
+}
+
 /// `ioctl(fd, EXT4_IOC_RESIZE_FS, blocks)`—Resize ext4 filesystem on fd.
 #[cfg(linux_kernel)]
 #[inline]
@@ -93,6 +242,29 @@ unsafe impl ioctl::Ioctl for Ficlone<'_> {
     }
 }
 
+#[cfg(all(linux_kernel, feature = "alloc"))]
+struct Fideduperange(*mut c::c_void);
+
+#[cfg(all(linux_kernel, feature = "alloc"))]
+unsafe impl ioctl::Ioctl for Fideduperange {
+    type Output = ();
+
+    const IS_MUTATING: bool = true;
+    const OPCODE: ioctl::Opcode =
+        ioctl::Opcode::read_write::<linux_raw_sys::general::file_dedupe_range>(0x94, 54);
+
+    fn as_ptr(&mut self) -> *mut c::c_void {
+        self.0
+    }
+
+    unsafe fn output_from_ptr(
+        _: ioctl::IoctlOutput,
+        _: *mut c::c_void,
+    ) -> io::Result<Self::Output> {
+        Ok(())
+    }
+}
+
 #[cfg(linux_kernel)]
 bitflags! {
     /// `FS_*` constants for use with [`ioctl_getflags`].
@@ -19,12 +19,16 @@ mod dir;
     target_os = "vita",
 )))]
 mod fadvise;
+#[cfg(linux_kernel)]
+pub mod fanotify;
 pub(crate) mod fcntl;
 #[cfg(apple)]
 mod fcntl_apple;
 #[cfg(apple)]
 mod fcopyfile;
 pub(crate) mod fd;
+#[cfg(all(linux_kernel, feature = "alloc"))]
+mod file_handle;
 #[cfg(all(apple, feature = "alloc"))]
 mod getpath;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have get[gpu]id.
@@ -89,6 +93,8 @@ pub use fcntl_apple::*;
 #[cfg(apple)]
 pub use fcopyfile::*;
 pub use fd::*;
+#[cfg(all(linux_kernel, feature = "alloc"))]
+pub use file_handle::{name_to_handle_at, open_by_handle_at, FileHandle, MountId, MAX_HANDLE_SZ};
 #[cfg(all(apple, feature = "alloc"))]
 pub use getpath::getpath;
 #[cfg(not(target_os = "wasi"))]
@@ -200,6 +200,10 @@ pub mod fd {
 }
 
 // The public API modules.
+#[cfg(linux_kernel)]
+#[cfg(feature = "bpf")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bpf")))]
+pub mod bpf;
 #[cfg(feature = "event")]
 #[cfg_attr(docsrs, doc(cfg(feature = "event")))]
 pub mod event;
@@ -231,12 +235,16 @@ pub mod net;
 #[cfg_attr(docsrs, doc(cfg(feature = "param")))]
 pub mod param;
 #[cfg(not(windows))]
-#[cfg(any(feature = "fs", feature = "mount", feature = "net"))]
+#[cfg(any(feature = "bpf", feature = "fs", feature = "mount", feature = "net"))]
 #[cfg_attr(
     docsrs,
-    doc(cfg(any(feature = "fs", feature = "mount", feature = "net")))
+    doc(cfg(any(feature = "bpf", feature = "fs", feature = "mount", feature = "net")))
 )]
 pub mod path;
+#[cfg(linux_kernel)]
+#[cfg(feature = "perf_event")]
+#[cfg_attr(docsrs, doc(cfg(feature = "perf_event")))]
+pub mod perf_event;
 #[cfg(feature = "pipe")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pipe")))]
 #[cfg(not(any(windows, target_os = "wasi")))]
@@ -268,6 +276,10 @@ pub mod rand;
 #[cfg(feature = "shm")]
 #[cfg_attr(docsrs, doc(cfg(feature = "shm")))]
 pub mod shm;
+#[cfg(not(any(windows, target_os = "espidf", target_os = "wasi")))]
+#[cfg(feature = "signal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "signal")))]
+pub mod signal;
 #[cfg(not(windows))]
 #[cfg(feature = "stdio")]
 #[cfg_attr(docsrs, doc(cfg(feature = "stdio")))]
@@ -318,7 +330,7 @@ pub(crate) mod fs;
 
 // Similarly, declare `path` as a non-public module if needed.
 #[cfg(not(windows))]
-#[cfg(not(any(feature = "fs", feature = "mount", feature = "net")))]
+#[cfg(not(any(feature = "bpf", feature = "fs", feature = "mount", feature = "net")))]
 #[cfg(all(
     linux_raw,
     not(feature = "use-libc-auxv"),
@@ -334,6 +346,9 @@ pub(crate) mod fs;
 pub(crate) mod path;
 
 // Private modules used by multiple public modules.
+#[cfg(linux_kernel)]
+#[cfg(any(feature = "net", feature = "thread"))]
+mod bpf_filter;
 #[cfg(not(any(windows, target_os = "espidf")))]
 #[cfg(any(feature = "thread", feature = "time", target_arch = "x86"))]
 mod clockid;
@@ -345,14 +360,22 @@ mod clockid;
     feature = "termios",
     feature = "thread",
     all(bsd, feature = "event"),
-    all(linux_kernel, feature = "net")
+    all(linux_kernel, feature = "mm"),
+    all(linux_kernel, feature = "net"),
+    all(linux_kernel, feature = "perf_event"),
+    all(linux_kernel, feature = "signal")
 ))]
 mod pid;
 #[cfg(any(feature = "process", feature = "thread"))]
 #[cfg(linux_kernel)]
 mod prctl;
 #[cfg(not(any(windows, target_os = "espidf", target_os = "wasi")))]
-#[cfg(any(feature = "process", feature = "runtime", all(bsd, feature = "event")))]
+#[cfg(not(feature = "signal"))]
+#[cfg(any(
+    feature = "process",
+    feature = "runtime",
+    all(any(bsd, linux_kernel), feature = "event")
+))]
 mod signal;
 #[cfg(any(
     feature = "fs",
@@ -361,6 +384,8 @@ mod signal;
     feature = "thread",
     feature = "time",
     all(feature = "event", any(bsd, linux_kernel, windows, target_os = "wasi")),
+    all(linux_kernel, feature = "signal"),
+    all(linux_kernel, feature = "net"),
     all(
         linux_raw,
         not(feature = "use-libc-auxv"),
@@ -391,6 +416,7 @@ mod timespec;
             target_arch = "x86",
         )
     ),
-    all(linux_kernel, feature = "net")
+    all(linux_kernel, feature = "net"),
+    all(linux_kernel, feature = "signal")
 ))]
 mod ugid;
@@ -12,4 +12,10 @@ pub use madvise::{madvise, Advice};
 pub use mmap::*;
 pub use msync::{msync, MsyncFlags};
 #[cfg(linux_kernel)]
-pub use userfaultfd::{userfaultfd, UserfaultfdFlags};
+pub use userfaultfd::{
+    ioctl_uffdio_api, ioctl_uffdio_continue, ioctl_uffdio_copy, ioctl_uffdio_register,
+    ioctl_uffdio_unregister, ioctl_uffdio_wake, ioctl_uffdio_writeprotect, ioctl_uffdio_zeropage,
+    ioctl_userfaultfd_ioc_new, read_uffd_msg, userfaultfd, UffdFeatureFlags, UffdIoctlFlags,
+    UffdMsg, UffdPagefaultFlags, UffdioApi, UffdioContinueFlags, UffdioCopyFlags,
+    UffdioRegisterFlags, UffdioWriteprotectFlags, UffdioZeropageFlags, UserfaultfdFlags,
+};
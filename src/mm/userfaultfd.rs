@@ -1,13 +1,34 @@
 //! The Linux `userfaultfd` API.
 //!
+//! A userfaultfd lets a thread handle page faults in registered ranges of
+//! memory, typically by populating the faulting pages with
+//! [`ioctl_uffdio_copy`] or [`ioctl_uffdio_zeropage`]. After creating one
+//! with [`userfaultfd`] or [`ioctl_userfaultfd_ioc_new`], negotiate the API
+//! and features with [`ioctl_uffdio_api`], register ranges with
+//! [`ioctl_uffdio_register`], and then read events with [`read_uffd_msg`].
+//!
 //! # Safety
 //!
 //! Calling `userfaultfd` is safe, but the returned file descriptor lets users
 //! observe and manipulate process memory in magical ways.
+//!
+//! # References
+//!  - [Linux userfaultfd]
+//!
+//! [Linux userfaultfd]: https://docs.kernel.org/admin-guide/mm/userfaultfd.html
 #![allow(unsafe_code)]
 
-use crate::fd::OwnedFd;
-use crate::{backend, io};
+use crate::fd::{AsFd, FromRawFd, OwnedFd, RawFd};
+use crate::pid::Pid;
+use crate::{backend, io, ioctl};
+use bitflags::bitflags;
+use core::ffi::c_void;
+use core::mem::size_of;
+use core::ptr::read_unaligned;
+use linux_raw_sys::general::{
+    uffd_msg, uffdio_api, uffdio_continue, uffdio_copy, uffdio_range, uffdio_register,
+    uffdio_writeprotect, uffdio_zeropage, UFFDIO, UFFD_API,
+};
 
 pub use backend::mm::types::UserfaultfdFlags;
 
@@ -28,3 +49,648 @@ pub use backend::mm::types::UserfaultfdFlags;
 pub unsafe fn userfaultfd(flags: UserfaultfdFlags) -> io::Result<OwnedFd> {
     backend::mm::syscalls::userfaultfd(flags)
 }
+
+// Ioctl numbers within the `UFFDIO` group.
+const UFFDIO_GROUP: u8 = UFFDIO as u8;
+const _UFFDIO_REGISTER: u8 = linux_raw_sys::general::_UFFDIO_REGISTER as u8;
+const _UFFDIO_UNREGISTER: u8 = linux_raw_sys::general::_UFFDIO_UNREGISTER as u8;
+const _UFFDIO_WAKE: u8 = linux_raw_sys::general::_UFFDIO_WAKE as u8;
+const _UFFDIO_COPY: u8 = linux_raw_sys::general::_UFFDIO_COPY as u8;
+const _UFFDIO_ZEROPAGE: u8 = linux_raw_sys::general::_UFFDIO_ZEROPAGE as u8;
+const _UFFDIO_WRITEPROTECT: u8 = linux_raw_sys::general::_UFFDIO_WRITEPROTECT as u8;
+const _UFFDIO_CONTINUE: u8 = linux_raw_sys::general::_UFFDIO_CONTINUE as u8;
+const _UFFDIO_API: u8 = linux_raw_sys::general::_UFFDIO_API as u8;
+
+bitflags! {
+    /// `UFFD_FEATURE_*` flags for use with [`ioctl_uffdio_api`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct UffdFeatureFlags: u64 {
+        /// `UFFD_FEATURE_PAGEFAULT_FLAG_WP`
+        const PAGEFAULT_FLAG_WP = linux_raw_sys::general::UFFD_FEATURE_PAGEFAULT_FLAG_WP as u64;
+        /// `UFFD_FEATURE_EVENT_FORK`
+        const EVENT_FORK = linux_raw_sys::general::UFFD_FEATURE_EVENT_FORK as u64;
+        /// `UFFD_FEATURE_EVENT_REMAP`
+        const EVENT_REMAP = linux_raw_sys::general::UFFD_FEATURE_EVENT_REMAP as u64;
+        /// `UFFD_FEATURE_EVENT_REMOVE`
+        const EVENT_REMOVE = linux_raw_sys::general::UFFD_FEATURE_EVENT_REMOVE as u64;
+        /// `UFFD_FEATURE_MISSING_HUGETLBFS`
+        const MISSING_HUGETLBFS = linux_raw_sys::general::UFFD_FEATURE_MISSING_HUGETLBFS as u64;
+        /// `UFFD_FEATURE_MISSING_SHMEM`
+        const MISSING_SHMEM = linux_raw_sys::general::UFFD_FEATURE_MISSING_SHMEM as u64;
+        /// `UFFD_FEATURE_EVENT_UNMAP`
+        const EVENT_UNMAP = linux_raw_sys::general::UFFD_FEATURE_EVENT_UNMAP as u64;
+        /// `UFFD_FEATURE_SIGBUS`
+        const SIGBUS = linux_raw_sys::general::UFFD_FEATURE_SIGBUS as u64;
+        /// `UFFD_FEATURE_THREAD_ID`
+        const THREAD_ID = linux_raw_sys::general::UFFD_FEATURE_THREAD_ID as u64;
+        /// `UFFD_FEATURE_MINOR_HUGETLBFS`
+        const MINOR_HUGETLBFS = linux_raw_sys::general::UFFD_FEATURE_MINOR_HUGETLBFS as u64;
+        /// `UFFD_FEATURE_MINOR_SHMEM`
+        const MINOR_SHMEM = linux_raw_sys::general::UFFD_FEATURE_MINOR_SHMEM as u64;
+        /// `UFFD_FEATURE_EXACT_ADDRESS`
+        const EXACT_ADDRESS = linux_raw_sys::general::UFFD_FEATURE_EXACT_ADDRESS as u64;
+        /// `UFFD_FEATURE_WP_HUGETLBFS_SHMEM`
+        const WP_HUGETLBFS_SHMEM = linux_raw_sys::general::UFFD_FEATURE_WP_HUGETLBFS_SHMEM as u64;
+        /// `UFFD_FEATURE_WP_UNPOPULATED`
+        const WP_UNPOPULATED = linux_raw_sys::general::UFFD_FEATURE_WP_UNPOPULATED as u64;
+        /// `UFFD_FEATURE_POISON`
+        const POISON = linux_raw_sys::general::UFFD_FEATURE_POISON as u64;
+        /// `UFFD_FEATURE_WP_ASYNC`
+        const WP_ASYNC = linux_raw_sys::general::UFFD_FEATURE_WP_ASYNC as u64;
+        /// `UFFD_FEATURE_MOVE`
+        const MOVE = linux_raw_sys::general::UFFD_FEATURE_MOVE as u64;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `1 << _UFFDIO_*` flags describing the ioctls supported by a
+    /// userfaultfd, as returned by [`ioctl_uffdio_api`] and
+    /// [`ioctl_uffdio_register`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct UffdIoctlFlags: u64 {
+        /// `1 << _UFFDIO_REGISTER`
+        const REGISTER = 1 << _UFFDIO_REGISTER;
+        /// `1 << _UFFDIO_UNREGISTER`
+        const UNREGISTER = 1 << _UFFDIO_UNREGISTER;
+        /// `1 << _UFFDIO_WAKE`
+        const WAKE = 1 << _UFFDIO_WAKE;
+        /// `1 << _UFFDIO_COPY`
+        const COPY = 1 << _UFFDIO_COPY;
+        /// `1 << _UFFDIO_ZEROPAGE`
+        const ZEROPAGE = 1 << _UFFDIO_ZEROPAGE;
+        /// `1 << _UFFDIO_WRITEPROTECT`
+        const WRITEPROTECT = 1 << _UFFDIO_WRITEPROTECT;
+        /// `1 << _UFFDIO_CONTINUE`
+        const CONTINUE = 1 << _UFFDIO_CONTINUE;
+        /// `1 << _UFFDIO_API`
+        const API = 1 << _UFFDIO_API;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `UFFDIO_REGISTER_MODE_*` flags for use with [`ioctl_uffdio_register`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct UffdioRegisterFlags: u64 {
+        /// `UFFDIO_REGISTER_MODE_MISSING`—Report faults on missing pages.
+        const MISSING = linux_raw_sys::general::UFFDIO_REGISTER_MODE_MISSING as u64;
+        /// `UFFDIO_REGISTER_MODE_WP`—Report faults on write-protected pages.
+        const WP = linux_raw_sys::general::UFFDIO_REGISTER_MODE_WP as u64;
+        /// `UFFDIO_REGISTER_MODE_MINOR`—Report faults on pages which are in
+        /// the page cache but not mapped.
+        const MINOR = linux_raw_sys::general::UFFDIO_REGISTER_MODE_MINOR as u64;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `UFFDIO_COPY_MODE_*` flags for use with [`ioctl_uffdio_copy`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct UffdioCopyFlags: u64 {
+        /// `UFFDIO_COPY_MODE_DONTWAKE`—Don't wake the faulting threads.
+        const DONTWAKE = linux_raw_sys::general::UFFDIO_COPY_MODE_DONTWAKE as u64;
+        /// `UFFDIO_COPY_MODE_WP`—Write-protect the copied pages.
+        const WP = linux_raw_sys::general::UFFDIO_COPY_MODE_WP as u64;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `UFFDIO_ZEROPAGE_MODE_*` flags for use with [`ioctl_uffdio_zeropage`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct UffdioZeropageFlags: u64 {
+        /// `UFFDIO_ZEROPAGE_MODE_DONTWAKE`—Don't wake the faulting threads.
+        const DONTWAKE = linux_raw_sys::general::UFFDIO_ZEROPAGE_MODE_DONTWAKE as u64;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `UFFDIO_WRITEPROTECT_MODE_*` flags for use with
+    /// [`ioctl_uffdio_writeprotect`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct UffdioWriteprotectFlags: u64 {
+        /// `UFFDIO_WRITEPROTECT_MODE_WP`—Write-protect the range. Without
+        /// this, the range is un-write-protected.
+        const WP = 1 << 0;
+        /// `UFFDIO_WRITEPROTECT_MODE_DONTWAKE`—Don't wake the faulting
+        /// threads.
+        const DONTWAKE = 1 << 1;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `UFFDIO_CONTINUE_MODE_*` flags for use with [`ioctl_uffdio_continue`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct UffdioContinueFlags: u64 {
+        /// `UFFDIO_CONTINUE_MODE_DONTWAKE`—Don't wake the faulting threads.
+        const DONTWAKE = 1 << 0;
+        /// `UFFDIO_CONTINUE_MODE_WP`—Write-protect the mapped pages.
+        const WP = 1 << 1;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `UFFD_PAGEFAULT_FLAG_*` flags describing a fault in
+    /// [`UffdMsg::Pagefault`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct UffdPagefaultFlags: u64 {
+        /// `UFFD_PAGEFAULT_FLAG_WRITE`—The fault was a write.
+        const WRITE = linux_raw_sys::general::UFFD_PAGEFAULT_FLAG_WRITE as u64;
+        /// `UFFD_PAGEFAULT_FLAG_WP`—The fault was on a write-protected page.
+        const WP = linux_raw_sys::general::UFFD_PAGEFAULT_FLAG_WP as u64;
+        /// `UFFD_PAGEFAULT_FLAG_MINOR`—The fault was a minor fault.
+        const MINOR = linux_raw_sys::general::UFFD_PAGEFAULT_FLAG_MINOR as u64;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// The result of [`ioctl_uffdio_api`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub struct UffdioApi {
+    /// The features which are available.
+    pub features: UffdFeatureFlags,
+
+    /// The ioctls which are available on the userfaultfd.
+    pub ioctls: UffdIoctlFlags,
+}
+
+/// `ioctl(dev, USERFAULTFD_IOC_NEW, flags)`—Creates a userfaultfd from
+/// `/dev/userfaultfd`.
+///
+/// Access to `/dev/userfaultfd` is controlled by its file permissions rather
+/// than by the `vm.unprivileged_userfaultfd` sysctl, which makes it possible
+/// to use userfaultfd without privileges on systems where the
+/// [`userfaultfd`] syscall is restricted.
+///
+/// # Safety
+///
+/// The call itself is safe, but the returned file descriptor lets users
+/// observe and manipulate process memory in magical ways.
+///
+/// # References
+///  - [Linux userfaultfd]
+///
+/// [Linux userfaultfd]: https://docs.kernel.org/admin-guide/mm/userfaultfd.html
+#[inline]
+#[doc(alias = "USERFAULTFD_IOC_NEW")]
+pub unsafe fn ioctl_userfaultfd_ioc_new<Fd: AsFd>(
+    dev: Fd,
+    flags: UserfaultfdFlags,
+) -> io::Result<OwnedFd> {
+    ioctl::ioctl(dev, UserfaultfdIocNew(flags))
+}
+
+struct UserfaultfdIocNew(UserfaultfdFlags);
+
+unsafe impl ioctl::Ioctl for UserfaultfdIocNew {
+    type Output = OwnedFd;
+
+    const IS_MUTATING: bool = false;
+    const OPCODE: ioctl::Opcode =
+        ioctl::Opcode::none::<()>(linux_raw_sys::general::USERFAULTFD_IOC as u8, 0);
+
+    fn as_ptr(&mut self) -> *mut c_void {
+        self.0.bits() as usize as *mut c_void
+    }
+
+    unsafe fn output_from_ptr(out: ioctl::IoctlOutput, _: *mut c_void) -> io::Result<Self::Output> {
+        // SAFETY: The kernel returns a new file descriptor, which we own.
+        Ok(OwnedFd::from_raw_fd(out as RawFd))
+    }
+}
+
+/// `ioctl(uffd, UFFDIO_API, &api)`—Negotiates the API version and enables
+/// `features`.
+///
+/// This must be called exactly once on each new userfaultfd, before any
+/// other ioctl. The result lists all the features the kernel supports, not
+/// just the ones requested, and the ioctls that are available.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_userfaultfd.2.html
+#[inline]
+#[doc(alias = "UFFDIO_API")]
+pub fn ioctl_uffdio_api<Fd: AsFd>(uffd: Fd, features: UffdFeatureFlags) -> io::Result<UffdioApi> {
+    let mut api = uffdio_api {
+        api: UFFD_API.into(),
+        features: features.bits(),
+        ioctls: 0,
+    };
+    // SAFETY: `UFFDIO_API` reads and writes a `uffdio_api`.
+    unsafe {
+        let ctl =
+            ioctl::Updater::<ioctl::ReadWriteOpcode<UFFDIO_GROUP, _UFFDIO_API, uffdio_api>, _>::new(
+                &mut api,
+            );
+        ioctl::ioctl(uffd, ctl)?;
+    }
+    Ok(UffdioApi {
+        features: UffdFeatureFlags::from_bits_retain(api.features),
+        ioctls: UffdIoctlFlags::from_bits_retain(api.ioctls),
+    })
+}
+
+/// `ioctl(uffd, UFFDIO_REGISTER, &register)`—Registers a range of memory
+/// with a userfaultfd, and returns the ioctls available for the range.
+///
+/// # Safety
+///
+/// Faults in the range will block, or raise `SIGBUS` if
+/// [`UffdFeatureFlags::SIGBUS`] is enabled, until they're resolved through
+/// the userfaultfd. The caller must ensure that a handler resolves them.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_userfaultfd.2.html
+#[inline]
+#[doc(alias = "UFFDIO_REGISTER")]
+pub unsafe fn ioctl_uffdio_register<Fd: AsFd>(
+    uffd: Fd,
+    addr: *mut c_void,
+    len: usize,
+    mode: UffdioRegisterFlags,
+) -> io::Result<UffdIoctlFlags> {
+    let mut register = uffdio_register {
+        range: range(addr, len),
+        mode: mode.bits(),
+        ioctls: 0,
+    };
+    let ctl = ioctl::Updater::<
+        ioctl::ReadWriteOpcode<UFFDIO_GROUP, _UFFDIO_REGISTER, uffdio_register>,
+        _,
+    >::new(&mut register);
+    ioctl::ioctl(uffd, ctl)?;
+    Ok(UffdIoctlFlags::from_bits_retain(register.ioctls))
+}
+
+/// `ioctl(uffd, UFFDIO_UNREGISTER, &range)`—Unregisters a range of memory
+/// from a userfaultfd.
+///
+/// # Safety
+///
+/// `addr` and `len` must describe a range of memory which is registered with
+/// `uffd`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_userfaultfd.2.html
+#[inline]
+#[doc(alias = "UFFDIO_UNREGISTER")]
+pub unsafe fn ioctl_uffdio_unregister<Fd: AsFd>(
+    uffd: Fd,
+    addr: *mut c_void,
+    len: usize,
+) -> io::Result<()> {
+    let ctl =
+        ioctl::Setter::<ioctl::ReadOpcode<UFFDIO_GROUP, _UFFDIO_UNREGISTER, uffdio_range>, _>::new(
+            range(addr, len),
+        );
+    ioctl::ioctl(uffd, ctl)
+}
+
+/// `ioctl(uffd, UFFDIO_WAKE, &range)`—Wakes threads waiting on faults in a
+/// range, after they've been resolved with one of the `*_DONTWAKE` modes.
+///
+/// # Safety
+///
+/// `addr` and `len` must describe a range of memory which is registered with
+/// `uffd`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_userfaultfd.2.html
+#[inline]
+#[doc(alias = "UFFDIO_WAKE")]
+pub unsafe fn ioctl_uffdio_wake<Fd: AsFd>(
+    uffd: Fd,
+    addr: *mut c_void,
+    len: usize,
+) -> io::Result<()> {
+    let ctl = ioctl::Setter::<ioctl::ReadOpcode<UFFDIO_GROUP, _UFFDIO_WAKE, uffdio_range>, _>::new(
+        range(addr, len),
+    );
+    ioctl::ioctl(uffd, ctl)
+}
+
+/// `ioctl(uffd, UFFDIO_COPY, &copy)`—Resolves missing-page faults in a range
+/// by atomically copying data into it.
+///
+/// Like `write`, this may copy less than `len` bytes, and returns the number
+/// of bytes copied.
+///
+/// # Safety
+///
+/// `dst` and `len` must describe a page-aligned range of memory which is
+/// registered with `uffd`, and it must be valid to fill the missing pages in
+/// it with the `len` bytes at `src`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_userfaultfd.2.html
+#[inline]
+#[doc(alias = "UFFDIO_COPY")]
+pub unsafe fn ioctl_uffdio_copy<Fd: AsFd>(
+    uffd: Fd,
+    dst: *mut c_void,
+    src: *const c_void,
+    len: usize,
+    mode: UffdioCopyFlags,
+) -> io::Result<usize> {
+    let mut copy = uffdio_copy {
+        dst: dst as usize as u64,
+        src: src as usize as u64,
+        len: len as u64,
+        mode: mode.bits(),
+        copy: 0,
+    };
+    let ctl =
+        ioctl::Updater::<ioctl::ReadWriteOpcode<UFFDIO_GROUP, _UFFDIO_COPY, uffdio_copy>, _>::new(
+            &mut copy,
+        );
+    partial(ioctl::ioctl(uffd, ctl), copy.copy, len)
+}
+
+/// `ioctl(uffd, UFFDIO_ZEROPAGE, &zeropage)`—Resolves missing-page faults in
+/// a range by mapping zeroed pages.
+///
+/// Like `write`, this may fill less than `len` bytes, and returns the number
+/// of bytes filled.
+///
+/// # Safety
+///
+/// `addr` and `len` must describe a page-aligned range of memory which is
+/// registered with `uffd`, and it must be valid to fill the missing pages in
+/// it with zeros.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_userfaultfd.2.html
+#[inline]
+#[doc(alias = "UFFDIO_ZEROPAGE")]
+pub unsafe fn ioctl_uffdio_zeropage<Fd: AsFd>(
+    uffd: Fd,
+    addr: *mut c_void,
+    len: usize,
+    mode: UffdioZeropageFlags,
+) -> io::Result<usize> {
+    let mut zeropage = uffdio_zeropage {
+        range: range(addr, len),
+        mode: mode.bits(),
+        zeropage: 0,
+    };
+    let ctl = ioctl::Updater::<
+        ioctl::ReadWriteOpcode<UFFDIO_GROUP, _UFFDIO_ZEROPAGE, uffdio_zeropage>,
+        _,
+    >::new(&mut zeropage);
+    partial(ioctl::ioctl(uffd, ctl), zeropage.zeropage, len)
+}
+
+/// `ioctl(uffd, UFFDIO_WRITEPROTECT, &writeprotect)`—Write-protects or
+/// un-write-protects a range.
+///
+/// Un-write-protecting a range resolves write-protect faults in it.
+///
+/// # Safety
+///
+/// `addr` and `len` must describe a range of memory which is registered with
+/// `uffd` in [`UffdioRegisterFlags::WP`] mode. Writes to the range while
+/// it's write-protected will block, as described in
+/// [`ioctl_uffdio_register`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_userfaultfd.2.html
+#[inline]
+#[doc(alias = "UFFDIO_WRITEPROTECT")]
+pub unsafe fn ioctl_uffdio_writeprotect<Fd: AsFd>(
+    uffd: Fd,
+    addr: *mut c_void,
+    len: usize,
+    mode: UffdioWriteprotectFlags,
+) -> io::Result<()> {
+    let mut writeprotect = uffdio_writeprotect {
+        range: range(addr, len),
+        mode: mode.bits(),
+    };
+    let ctl = ioctl::Updater::<
+        ioctl::ReadWriteOpcode<UFFDIO_GROUP, _UFFDIO_WRITEPROTECT, uffdio_writeprotect>,
+        _,
+    >::new(&mut writeprotect);
+    ioctl::ioctl(uffd, ctl)
+}
+
+/// `ioctl(uffd, UFFDIO_CONTINUE, &cont)`—Resolves minor faults in a range by
+/// mapping the pages which are already in the page cache.
+///
+/// Like `write`, this may map less than `len` bytes, and returns the number
+/// of bytes mapped.
+///
+/// # Safety
+///
+/// `addr` and `len` must describe a page-aligned range of memory which is
+/// registered with `uffd` in [`UffdioRegisterFlags::MINOR`] mode, and it must
+/// be valid to map the contents of the page cache there.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_userfaultfd.2.html
+#[inline]
+#[doc(alias = "UFFDIO_CONTINUE")]
+pub unsafe fn ioctl_uffdio_continue<Fd: AsFd>(
+    uffd: Fd,
+    addr: *mut c_void,
+    len: usize,
+    mode: UffdioContinueFlags,
+) -> io::Result<usize> {
+    let mut cont = uffdio_continue {
+        range: range(addr, len),
+        mode: mode.bits(),
+        mapped: 0,
+    };
+    let ctl = ioctl::Updater::<
+        ioctl::ReadWriteOpcode<UFFDIO_GROUP, _UFFDIO_CONTINUE, uffdio_continue>,
+        _,
+    >::new(&mut cont);
+    partial(ioctl::ioctl(uffd, ctl), cont.mapped, len)
+}
+
+/// Describe a range of memory for the kernel.
+fn range(addr: *mut c_void, len: usize) -> uffdio_range {
+    uffdio_range {
+        start: addr as usize as u64,
+        len: len as u64,
+    }
+}
+
+/// Convert the result of an ioctl which fills a range, and the number of
+/// bytes it reports having filled, into a `write`-like result.
+///
+/// When the kernel fills only part of the range, it fails with `EAGAIN` and
+/// reports the positive number of bytes it filled.
+fn partial(result: io::Result<()>, filled: i64, len: usize) -> io::Result<usize> {
+    match result {
+        Ok(()) => Ok(len),
+        Err(io::Errno::AGAIN) if filled > 0 => Ok(filled as usize),
+        Err(err) => Err(err),
+    }
+}
+
+/// An event read from a userfaultfd with [`read_uffd_msg`].
+#[doc(alias = "uffd_msg")]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum UffdMsg {
+    /// `UFFD_EVENT_PAGEFAULT`—A thread faulted in a registered range.
+    Pagefault {
+        /// What kind of fault it was.
+        flags: UffdPagefaultFlags,
+        /// The faulting address. This is rounded down to the page size
+        /// unless [`UffdFeatureFlags::EXACT_ADDRESS`] is enabled.
+        address: *mut c_void,
+        /// The faulting thread, if [`UffdFeatureFlags::THREAD_ID`] is
+        /// enabled.
+        ptid: Option<Pid>,
+    },
+
+    /// `UFFD_EVENT_FORK`—The process forked, and this is a new userfaultfd
+    /// for the child's copy of the registered ranges.
+    Fork {
+        /// The child's userfaultfd.
+        uffd: OwnedFd,
+    },
+
+    /// `UFFD_EVENT_REMAP`—A registered range was moved with `mremap`.
+    Remap {
+        /// The old address of the range.
+        from: *mut c_void,
+        /// The new address of the range.
+        to: *mut c_void,
+        /// The length of the range.
+        len: usize,
+    },
+
+    /// `UFFD_EVENT_REMOVE`—Pages in a registered range were freed, such as
+    /// with `madvise(MADV_DONTNEED)`.
+    Remove {
+        /// The start of the range.
+        start: *mut c_void,
+        /// The end of the range.
+        end: *mut c_void,
+    },
+
+    /// `UFFD_EVENT_UNMAP`—A registered range was unmapped.
+    Unmap {
+        /// The start of the range.
+        start: *mut c_void,
+        /// The end of the range.
+        end: *mut c_void,
+    },
+}
+
+/// `read(uffd, &msg, sizeof(msg))`—Reads and decodes one event from a
+/// userfaultfd.
+///
+/// If no event is ready, this blocks, or fails with [`io::Errno::AGAIN`] if
+/// `uffd` was created with [`UserfaultfdFlags::NONBLOCK`].
+///
+/// # Safety
+///
+/// `uffd` must be a userfaultfd. A [`UffdMsg::Fork`] event contains a file
+/// descriptor number which this function takes ownership of.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/userfaultfd.2.html
+pub unsafe fn read_uffd_msg<Fd: AsFd>(uffd: Fd) -> io::Result<UffdMsg> {
+    let mut buf = [0_u8; size_of::<uffd_msg>()];
+    if crate::io::read(uffd, &mut buf)? != buf.len() {
+        return Err(io::Errno::INVAL);
+    }
+
+    // SAFETY: `buf` holds a whole `uffd_msg`, and `uffd_msg` is a plain data
+    // type.
+    let msg = unsafe { read_unaligned(buf.as_ptr().cast::<uffd_msg>()) };
+
+    // SAFETY: The union fields we read are selected by `msg.event`, and are
+    // all plain data types.
+    unsafe {
+        match u32::from(msg.event) {
+            linux_raw_sys::general::UFFD_EVENT_PAGEFAULT => {
+                let pagefault = msg.arg.pagefault;
+                Ok(UffdMsg::Pagefault {
+                    flags: UffdPagefaultFlags::from_bits_retain(pagefault.flags),
+                    address: pagefault.address as usize as *mut c_void,
+                    ptid: Pid::from_raw(pagefault.feat.ptid as _),
+                })
+            }
+            linux_raw_sys::general::UFFD_EVENT_FORK => Ok(UffdMsg::Fork {
+                // SAFETY: Our caller guarantees that `uffd` is a
+                // userfaultfd, so the kernel opened this file descriptor for
+                // the reader of the event, and we own it.
+                uffd: OwnedFd::from_raw_fd(msg.arg.fork.ufd as RawFd),
+            }),
+            linux_raw_sys::general::UFFD_EVENT_REMAP => {
+                let remap = msg.arg.remap;
+                Ok(UffdMsg::Remap {
+                    from: remap.from as usize as *mut c_void,
+                    to: remap.to as usize as *mut c_void,
+                    len: remap.len as usize,
+                })
+            }
+            linux_raw_sys::general::UFFD_EVENT_REMOVE => {
+                let remove = msg.arg.remove;
+                Ok(UffdMsg::Remove {
+                    start: remove.start as usize as *mut c_void,
+                    end: remove.end as usize as *mut c_void,
+                })
+            }
+            linux_raw_sys::general::UFFD_EVENT_UNMAP => {
+                let remove = msg.arg.remove;
+                Ok(UffdMsg::Unmap {
+                    start: remove.start as usize as *mut c_void,
+                    end: remove.end as usize as *mut c_void,
+                })
+            }
+            _ => Err(io::Errno::INVAL),
+        }
+    }
+}
@@ -1,9 +1,10 @@
 //! `fsopen` and related functions in Linux's `mount` API.
 
 use crate::backend::mount::types::{
-    FsMountFlags, FsOpenFlags, FsPickFlags, MountAttrFlags, MoveMountFlags, OpenTreeFlags,
+    FsMountFlags, FsOpenFlags, FsPickFlags, MountAttrFlags, MountPropagationFlags,
+    MountSetattrFlags, MoveMountFlags, OpenTreeFlags,
 };
-use crate::fd::{BorrowedFd, OwnedFd};
+use crate::fd::{AsRawFd, BorrowedFd, OwnedFd};
 use crate::{backend, io, path};
 
 /// `fsopen(fs_name, flags)`
@@ -93,6 +94,80 @@ pub fn fspick<Path: path::Arg>(
     path.into_with_c_str(|path| backend::mount::syscalls::fspick(dfd, path, flags))
 }
 
+/// Mount attributes to change with [`mount_setattr`].
+#[doc(alias = "mount_attr")]
+#[derive(Debug, Clone, Copy)]
+pub struct MountAttr<'a> {
+    /// Attributes to set.
+    pub attr_set: MountAttrFlags,
+
+    /// Attributes to clear.
+    ///
+    /// To change the access-time mode, include
+    /// [`MountAttrFlags::MOUNT_ATTR__ATIME`] here and the new mode in
+    /// `attr_set`.
+    pub attr_clr: MountAttrFlags,
+
+    /// The new propagation type, which may be one of
+    /// [`MountPropagationFlags::SHARED`], [`MountPropagationFlags::PRIVATE`],
+    /// [`MountPropagationFlags::DOWNSTREAM`], or
+    /// [`MountPropagationFlags::UNBINDABLE`], or empty to leave the
+    /// propagation type unchanged.
+    pub propagation: MountPropagationFlags,
+
+    /// The user namespace to use for an ID-mapped mount, with
+    /// [`MountAttrFlags::MOUNT_ATTR_IDMAP`].
+    pub userns_fd: Option<BorrowedFd<'a>>,
+}
+
+impl<'a> MountAttr<'a> {
+    /// Construct a `MountAttr` which doesn't change anything.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            attr_set: MountAttrFlags::empty(),
+            attr_clr: MountAttrFlags::empty(),
+            propagation: MountPropagationFlags::empty(),
+            userns_fd: None,
+        }
+    }
+}
+
+impl<'a> Default for MountAttr<'a> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `mount_setattr(dfd, path, flags, attr, sizeof(*attr))`
+///
+/// Pass [`MountSetattrFlags::AT_RECURSIVE`] to change the attributes of a
+/// whole mount tree, such as to make it recursively read-only.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/mount_setattr.2.html
+#[inline]
+pub fn mount_setattr<Path: path::Arg>(
+    dfd: BorrowedFd<'_>,
+    path: Path,
+    flags: MountSetattrFlags,
+    attr: &MountAttr<'_>,
+) -> io::Result<()> {
+    let attr = linux_raw_sys::general::mount_attr {
+        attr_set: attr.attr_set.bits().into(),
+        attr_clr: attr.attr_clr.bits().into(),
+        propagation: attr.propagation.bits().into(),
+        userns_fd: match attr.userns_fd {
+            Some(userns_fd) => userns_fd.as_raw_fd() as u64,
+            None => 0,
+        },
+    };
+    path.into_with_c_str(|path| backend::mount::syscalls::mount_setattr(dfd, path, flags, &attr))
+}
+
 /// `fsconfig(fs_fd, FSCONFIG_SET_FLAG, key, NULL, 0)`
 ///
 /// # References
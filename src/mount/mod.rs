@@ -2,8 +2,10 @@
 
 mod fsopen;
 mod mount_unmount;
+mod statmount;
 mod types;
 
 pub use fsopen::*;
 pub use mount_unmount::*;
+pub use statmount::*;
 pub use types::*;
@@ -0,0 +1,458 @@
+//! `statmount` and `listmount` functions in Linux's `mount` API.
+//!
+//! These identify mounts by their 64-bit unique mount IDs, which can be
+//! obtained with `statx` and `STATX_MNT_ID_UNIQUE`, from [`listmount`], or
+//! from [`StatMount::mnt_id`].
+#![allow(unsafe_code)]
+
+use crate::backend::c;
+use crate::backend::mount::types::{MountAttrFlags, MountPropagationFlags};
+use crate::ffi::CStr;
+use crate::{backend, io};
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use bitflags::bitflags;
+use core::mem::{size_of, size_of_val};
+use linux_raw_sys::general::mnt_id_req;
+
+/// `LSMT_ROOT`—The mount ID to pass to [`listmount`] to list the mounts at
+/// the root of the mount namespace.
+pub const LSMT_ROOT: u64 = !0;
+
+bitflags! {
+    /// `STATMOUNT_*` constants for use with [`statmount`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct StatMountFlags: u64 {
+        /// `STATMOUNT_SB_BASIC`
+        const SB_BASIC = 0x0001;
+
+        /// `STATMOUNT_MNT_BASIC`
+        const MNT_BASIC = 0x0002;
+
+        /// `STATMOUNT_PROPAGATE_FROM`
+        const PROPAGATE_FROM = 0x0004;
+
+        /// `STATMOUNT_MNT_ROOT`
+        const MNT_ROOT = 0x0008;
+
+        /// `STATMOUNT_MNT_POINT`
+        const MNT_POINT = 0x0010;
+
+        /// `STATMOUNT_FS_TYPE`
+        const FS_TYPE = 0x0020;
+
+        /// `STATMOUNT_MNT_NS_ID` (since Linux 6.11)
+        const MNT_NS_ID = 0x0040;
+
+        /// `STATMOUNT_MNT_OPTS` (since Linux 6.11)
+        const MNT_OPTS = 0x0080;
+
+        /// `STATMOUNT_FS_SUBTYPE` (since Linux 6.13)
+        const FS_SUBTYPE = 0x0100;
+
+        /// `STATMOUNT_SB_SOURCE` (since Linux 6.13)
+        const SB_SOURCE = 0x0200;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `LISTMOUNT_*` constants for use with [`listmount`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct ListMountFlags: c::c_uint {
+        /// `LISTMOUNT_REVERSE` (since Linux 6.11)
+        const REVERSE = 0x0001;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// The fixed-size part of `struct statmount`.
+///
+/// The set of fields in linux-raw-sys depends on the kernel version it was
+/// generated from, so define the current layout here. The kernel doesn't
+/// change the layout, it only assigns fields out of `__spare2`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct StatMountHeader {
+    size: u32,
+    mnt_opts: u32,
+    mask: u64,
+    sb_dev_major: u32,
+    sb_dev_minor: u32,
+    sb_magic: u64,
+    sb_flags: u32,
+    fs_type: u32,
+    mnt_id: u64,
+    mnt_parent_id: u64,
+    mnt_id_old: u32,
+    mnt_parent_id_old: u32,
+    mnt_attr: u64,
+    mnt_propagation: u64,
+    mnt_peer_group: u64,
+    mnt_master: u64,
+    propagate_from: u64,
+    mnt_root: u32,
+    mnt_point: u32,
+    mnt_ns_id: u64,
+    fs_subtype: u32,
+    sb_source: u32,
+    opt_num: u32,
+    opt_array: u32,
+    opt_sec_num: u32,
+    opt_sec_array: u32,
+    __spare2: [u64; 46],
+}
+
+/// The number of `u64`s in a `StatMountHeader`.
+const HEADER_WORDS: usize = size_of::<StatMountHeader>() / size_of::<u64>();
+
+/// Construct a `struct mnt_id_req`.
+fn mnt_id_req(mnt_id: u64, param: u64) -> mnt_id_req {
+    // SAFETY: `mnt_id_req` is a plain data type, and zero is the value the
+    // kernel expects for all the fields we don't set.
+    let mut req: mnt_id_req = unsafe { core::mem::zeroed() };
+    req.size = size_of::<mnt_id_req>() as u32;
+    req.mnt_id = mnt_id;
+    req.param = param;
+    req
+}
+
+/// The result of [`statmount`].
+///
+/// Fields which weren't requested in the mask, or which the kernel doesn't
+/// support, read as zero or `None`; use [`StatMount::mask`] to see which
+/// ones are valid.
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub struct StatMount {
+    // A `u64` buffer, so that it's aligned for `StatMountHeader`.
+    buf: Vec<u64>,
+}
+
+#[cfg(feature = "alloc")]
+impl StatMount {
+    #[inline]
+    fn header(&self) -> &StatMountHeader {
+        debug_assert!(self.buf.len() >= HEADER_WORDS);
+        // SAFETY: `buf` is at least as big as a `StatMountHeader`, it's
+        // aligned for one, and it's a plain data type.
+        unsafe { &*self.buf.as_ptr().cast::<StatMountHeader>() }
+    }
+
+    /// Return the string at `offset` in the string table, if `flag` is in
+    /// the mask.
+    fn string(&self, flag: StatMountFlags, offset: u32) -> Option<&CStr> {
+        if !self.mask().contains(flag) {
+            return None;
+        }
+
+        // SAFETY: `buf` holds initialized `u64`s, so it's valid to view
+        // it as bytes.
+        let bytes = unsafe {
+            core::slice::from_raw_parts(
+                self.buf.as_ptr().cast::<u8>(),
+                size_of_val(self.buf.as_slice()),
+            )
+        };
+        let end = (self.header().size as usize).min(bytes.len());
+        let strings = bytes.get(size_of::<StatMountHeader>()..end)?;
+        let string = strings.get(offset as usize..)?;
+        let nul = string.iter().position(|b| *b == b'\0')?;
+        CStr::from_bytes_with_nul(&string[..=nul]).ok()
+    }
+
+    /// The parts of the result which are valid.
+    #[inline]
+    pub fn mask(&self) -> StatMountFlags {
+        StatMountFlags::from_bits_retain(self.header().mask)
+    }
+
+    /// The major number of the filesystem's device, with
+    /// [`StatMountFlags::SB_BASIC`].
+    #[inline]
+    pub fn sb_dev_major(&self) -> u32 {
+        self.header().sb_dev_major
+    }
+
+    /// The minor number of the filesystem's device, with
+    /// [`StatMountFlags::SB_BASIC`].
+    #[inline]
+    pub fn sb_dev_minor(&self) -> u32 {
+        self.header().sb_dev_minor
+    }
+
+    /// The filesystem's magic number, with [`StatMountFlags::SB_BASIC`].
+    #[inline]
+    pub fn sb_magic(&self) -> u64 {
+        self.header().sb_magic
+    }
+
+    /// The filesystem's `SB_*` flags, with [`StatMountFlags::SB_BASIC`].
+    #[inline]
+    pub fn sb_flags(&self) -> u32 {
+        self.header().sb_flags
+    }
+
+    /// The unique ID of the mount, with [`StatMountFlags::MNT_BASIC`].
+    #[inline]
+    pub fn mnt_id(&self) -> u64 {
+        self.header().mnt_id
+    }
+
+    /// The unique ID of the parent mount, with
+    /// [`StatMountFlags::MNT_BASIC`].
+    #[inline]
+    pub fn mnt_parent_id(&self) -> u64 {
+        self.header().mnt_parent_id
+    }
+
+    /// The ID of the mount used in `/proc/<pid>/mountinfo`, with
+    /// [`StatMountFlags::MNT_BASIC`].
+    #[inline]
+    pub fn mnt_id_old(&self) -> u32 {
+        self.header().mnt_id_old
+    }
+
+    /// The ID of the parent mount used in `/proc/<pid>/mountinfo`, with
+    /// [`StatMountFlags::MNT_BASIC`].
+    #[inline]
+    pub fn mnt_parent_id_old(&self) -> u32 {
+        self.header().mnt_parent_id_old
+    }
+
+    /// The mount's attributes, with [`StatMountFlags::MNT_BASIC`].
+    #[inline]
+    pub fn mnt_attr(&self) -> MountAttrFlags {
+        MountAttrFlags::from_bits_retain(self.header().mnt_attr as _)
+    }
+
+    /// The mount's propagation type, with [`StatMountFlags::MNT_BASIC`].
+    #[inline]
+    pub fn mnt_propagation(&self) -> MountPropagationFlags {
+        MountPropagationFlags::from_bits_retain(self.header().mnt_propagation as _)
+    }
+
+    /// The ID of the mount's shared peer group, with
+    /// [`StatMountFlags::MNT_BASIC`].
+    #[inline]
+    pub fn mnt_peer_group(&self) -> u64 {
+        self.header().mnt_peer_group
+    }
+
+    /// The ID of the mount which this mount receives propagation from, with
+    /// [`StatMountFlags::MNT_BASIC`].
+    #[doc(alias = "mnt_master")]
+    #[inline]
+    pub fn mnt_upstream(&self) -> u64 {
+        self.header().mnt_master
+    }
+
+    /// The ID of the nearest mount in the current namespace which this mount
+    /// receives propagation from, with [`StatMountFlags::PROPAGATE_FROM`].
+    #[inline]
+    pub fn propagate_from(&self) -> u64 {
+        self.header().propagate_from
+    }
+
+    /// The ID of the mount namespace, with [`StatMountFlags::MNT_NS_ID`].
+    #[inline]
+    pub fn mnt_ns_id(&self) -> u64 {
+        self.header().mnt_ns_id
+    }
+
+    /// The root of the mount relative to the root of the filesystem, with
+    /// [`StatMountFlags::MNT_ROOT`].
+    #[inline]
+    pub fn mnt_root(&self) -> Option<&CStr> {
+        self.string(StatMountFlags::MNT_ROOT, self.header().mnt_root)
+    }
+
+    /// The mount point relative to the current root, with
+    /// [`StatMountFlags::MNT_POINT`].
+    #[inline]
+    pub fn mnt_point(&self) -> Option<&CStr> {
+        self.string(StatMountFlags::MNT_POINT, self.header().mnt_point)
+    }
+
+    /// The filesystem type, with [`StatMountFlags::FS_TYPE`].
+    #[inline]
+    pub fn fs_type(&self) -> Option<&CStr> {
+        self.string(StatMountFlags::FS_TYPE, self.header().fs_type)
+    }
+
+    /// The filesystem subtype, with [`StatMountFlags::FS_SUBTYPE`].
+    #[inline]
+    pub fn fs_subtype(&self) -> Option<&CStr> {
+        self.string(StatMountFlags::FS_SUBTYPE, self.header().fs_subtype)
+    }
+
+    /// The source of the mount, with [`StatMountFlags::SB_SOURCE`].
+    #[inline]
+    pub fn sb_source(&self) -> Option<&CStr> {
+        self.string(StatMountFlags::SB_SOURCE, self.header().sb_source)
+    }
+
+    /// The comma-separated, escaped mount options, with
+    /// [`StatMountFlags::MNT_OPTS`].
+    #[inline]
+    pub fn mnt_opts(&self) -> Option<&CStr> {
+        self.string(StatMountFlags::MNT_OPTS, self.header().mnt_opts)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Debug for StatMount {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("StatMount")
+            .field("mask", &self.mask())
+            .field("mnt_id", &self.mnt_id())
+            .field("mnt_parent_id", &self.mnt_parent_id())
+            .field("mnt_root", &self.mnt_root())
+            .field("mnt_point", &self.mnt_point())
+            .field("fs_type", &self.fs_type())
+            .field("mnt_opts", &self.mnt_opts())
+            .finish_non_exhaustive()
+    }
+}
+
+/// `statmount(&req, buf, bufsize, 0)`—Queries information about a mount.
+///
+/// This allocates a buffer big enough for the strings in the result.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/statmount.2.html
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn statmount(mnt_id: u64, mask: StatMountFlags) -> io::Result<StatMount> {
+    let req = mnt_id_req(mnt_id, mask.bits());
+    let mut buf = vec![0_u64; HEADER_WORDS * 2];
+
+    loop {
+        match backend::mount::syscalls::statmount(&req, &mut buf, 0) {
+            Ok(()) => return Ok(StatMount { buf }),
+            Err(io::Errno::OVERFLOW) => {
+                let len = buf.len() * 2;
+                buf.resize(len, 0);
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// `listmount(&req, mnt_ids, mnt_ids.len(), flags)`—Lists the IDs of the
+/// child mounts of a mount, in batches.
+///
+/// This fills `mnt_ids` with the IDs of mounts under `mnt_id` which come
+/// after `last_mnt_id`, or from the start if `last_mnt_id` is zero, and
+/// returns the filled part. To list the mounts at the root of the mount
+/// namespace, pass [`LSMT_ROOT`] as `mnt_id`. See [`ListMounts`] for an
+/// iterator over all the IDs.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/listmount.2.html
+#[inline]
+pub fn listmount(
+    mnt_id: u64,
+    last_mnt_id: u64,
+    mnt_ids: &mut [u64],
+    flags: ListMountFlags,
+) -> io::Result<&mut [u64]> {
+    let req = mnt_id_req(mnt_id, last_mnt_id);
+    let len = backend::mount::syscalls::listmount(&req, mnt_ids, flags.bits())?;
+    Ok(&mut mnt_ids[..len])
+}
+
+/// An iterator over the IDs of the child mounts of a mount, using
+/// [`listmount`] with a caller-provided buffer.
+pub struct ListMounts<'buf> {
+    mnt_id: u64,
+    flags: ListMountFlags,
+    buf: &'buf mut [u64],
+    pos: usize,
+    len: usize,
+    done: bool,
+}
+
+impl<'buf> ListMounts<'buf> {
+    /// Iterate over the IDs of the mounts under `mnt_id`, fetching up to
+    /// `buf.len()` of them at a time.
+    #[inline]
+    pub fn new(mnt_id: u64, buf: &'buf mut [u64], flags: ListMountFlags) -> Self {
+        Self {
+            mnt_id,
+            flags,
+            buf,
+            pos: 0,
+            len: 0,
+            done: false,
+        }
+    }
+}
+
+impl<'buf> Iterator for ListMounts<'buf> {
+    type Item = io::Result<u64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos == self.len {
+            if self.done {
+                return None;
+            }
+            if self.buf.is_empty() {
+                self.done = true;
+                return Some(Err(io::Errno::INVAL));
+            }
+
+            let last = if self.len == 0 {
+                0
+            } else {
+                self.buf[self.len - 1]
+            };
+            match listmount(self.mnt_id, last, self.buf, self.flags) {
+                Ok(ids) => {
+                    let len = ids.len();
+                    self.pos = 0;
+                    self.len = len;
+                    // A short batch means there are no more mounts.
+                    self.done = len < self.buf.len();
+                    if len == 0 {
+                        return None;
+                    }
+                }
+                Err(err) => {
+                    self.done = true;
+                    self.pos = 0;
+                    self.len = 0;
+                    return Some(Err(err));
+                }
+            }
+        }
+
+        let id = self.buf[self.pos];
+        self.pos += 1;
+        Some(Ok(id))
+    }
+}
+
+impl<'buf> core::fmt::Debug for ListMounts<'buf> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ListMounts")
+            .field("mnt_id", &self.mnt_id)
+            .field("flags", &self.flags)
+            .finish_non_exhaustive()
+    }
+}
+
+#[test]
+fn test_sizes() {
+    assert_eq!(size_of::<StatMountHeader>(), 512);
+}
@@ -7,6 +7,8 @@
 //! [`wsa_startup`]: https://docs.rs/rustix/*/x86_64-pc-windows-msvc/rustix/net/fn.wsa_startup.html
 //! [`wsa_cleanup`]: https://docs.rs/rustix/*/x86_64-pc-windows-msvc/rustix/net/fn.wsa_cleanup.html
 
+#[cfg(linux_kernel)]
+mod nlmsg;
 mod send_recv;
 mod socket;
 mod socket_addr_any;
@@ -20,6 +22,8 @@ mod wsa;
 pub mod netdevice;
 pub mod sockopt;
 
+#[cfg(linux_kernel)]
+pub use crate::bpf_filter::SockFilter;
 pub use crate::maybe_polyfill::net::{
     IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6,
 };
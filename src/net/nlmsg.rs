@@ -0,0 +1,427 @@
+//! Netlink message encoding and decoding.
+//!
+//! A netlink datagram holds a sequence of messages, each starting with a
+//! `struct nlmsghdr` and padded to a multiple of `NLMSG_ALIGNTO` bytes.
+//! [`NlMsgIter`] walks the messages in a received buffer without copying
+//! their payloads, and [`NlMsgBuilder`] lays out messages in a caller-provided
+//! buffer so that they can be sent with a single `send` or `sendto`.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use rustix::net::netlink::{self, NlMsgBuilder, NlMsgFlags, NlMsgIter};
+//! use rustix::net::{
+//!     bind_netlink, recv, sendto_netlink, socket, AddressFamily, RecvFlags, SendFlags,
+//!     SocketType,
+//! };
+//!
+//! # fn test() -> rustix::io::Result<()> {
+//! // `RTM_GETLINK` from <linux/rtnetlink.h>, and a `struct rtgenmsg` asking
+//! // for links of any address family.
+//! const RTM_GETLINK: u16 = 18;
+//! let rtgenmsg = [0_u8];
+//!
+//! let fd = socket(AddressFamily::NETLINK, SocketType::RAW, None)?;
+//! bind_netlink(&fd, &netlink::SocketAddrNetlink::new(0, 0))?;
+//!
+//! let mut request = [0_u8; 64];
+//! let mut builder = NlMsgBuilder::new(&mut request);
+//! builder.push(
+//!     RTM_GETLINK,
+//!     NlMsgFlags::REQUEST | NlMsgFlags::DUMP,
+//!     1,
+//!     0,
+//!     &rtgenmsg,
+//! )?;
+//! let request = builder.finish();
+//! sendto_netlink(&fd, request, SendFlags::empty(), &netlink::SocketAddrNetlink::new(0, 0))?;
+//!
+//! let mut buf = vec![0_u8; 32768];
+//! 'dump: loop {
+//!     let n = recv(&fd, &mut buf, RecvFlags::empty())?;
+//!     for msg in NlMsgIter::new(&buf[..n]) {
+//!         let msg = msg?;
+//!         if let Some(result) = msg.done() {
+//!             result?;
+//!             break 'dump;
+//!         }
+//!         if let Some(result) = msg.error() {
+//!             result?;
+//!             continue;
+//!         }
+//!         println!("link message: {} bytes", msg.payload().len());
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://man7.org/linux/man-pages/man7/netlink.7.html
+
+use crate::backend::c;
+use crate::io;
+use bitflags::bitflags;
+use core::mem::size_of;
+
+/// `NLMSG_ALIGNTO`
+const ALIGNTO: usize = 4;
+
+/// `NLMSG_HDRLEN`
+const HDRLEN: usize = align(size_of::<NlMsgHdr>());
+
+/// `NLMSG_ALIGN(len)`
+const fn align(len: usize) -> usize {
+    (len + ALIGNTO - 1) & !(ALIGNTO - 1)
+}
+
+bitflags! {
+    /// `NLM_F_*` flags for use in [`NlMsgHdr`].
+    ///
+    /// Several flags share a value and are distinguished by the kind of
+    /// request they're used with.
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct NlMsgFlags: u16 {
+        /// `NLM_F_REQUEST`
+        const REQUEST = c::NLM_F_REQUEST as u16;
+        /// `NLM_F_MULTI`
+        const MULTI = c::NLM_F_MULTI as u16;
+        /// `NLM_F_ACK`
+        const ACK = c::NLM_F_ACK as u16;
+        /// `NLM_F_ECHO`
+        const ECHO = c::NLM_F_ECHO as u16;
+        /// `NLM_F_DUMP_INTR`
+        const DUMP_INTR = c::NLM_F_DUMP_INTR as u16;
+        /// `NLM_F_DUMP_FILTERED`
+        const DUMP_FILTERED = c::NLM_F_DUMP_FILTERED as u16;
+        /// `NLM_F_ROOT`
+        const ROOT = c::NLM_F_ROOT as u16;
+        /// `NLM_F_MATCH`
+        const MATCH = c::NLM_F_MATCH as u16;
+        /// `NLM_F_ATOMIC`
+        const ATOMIC = c::NLM_F_ATOMIC as u16;
+        /// `NLM_F_DUMP`
+        const DUMP = c::NLM_F_DUMP as u16;
+        /// `NLM_F_REPLACE`
+        const REPLACE = c::NLM_F_REPLACE as u16;
+        /// `NLM_F_EXCL`
+        const EXCL = c::NLM_F_EXCL as u16;
+        /// `NLM_F_CREATE`
+        const CREATE = c::NLM_F_CREATE as u16;
+        /// `NLM_F_APPEND`
+        const APPEND = c::NLM_F_APPEND as u16;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// `struct nlmsghdr`—A netlink message header.
+#[doc(alias = "nlmsghdr")]
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct NlMsgHdr {
+    /// The length of the message, including this header.
+    pub len: u32,
+    /// The message type; either one of the control types such as
+    /// [`NlMsgHdr::DONE`], or a protocol-specific type.
+    pub msg_type: u16,
+    /// Flags.
+    pub flags: NlMsgFlags,
+    /// The sequence number, used to match replies with requests.
+    pub seq: u32,
+    /// The port ID of the sending socket, or 0 for the kernel.
+    pub pid: u32,
+}
+
+impl NlMsgHdr {
+    /// `NLMSG_NOOP`—A message to be ignored.
+    #[doc(alias = "NLMSG_NOOP")]
+    pub const NOOP: u16 = c::NLMSG_NOOP as u16;
+
+    /// `NLMSG_ERROR`—An error or an acknowledgement.
+    #[doc(alias = "NLMSG_ERROR")]
+    pub const ERROR: u16 = c::NLMSG_ERROR as u16;
+
+    /// `NLMSG_DONE`—The end of a multipart message.
+    #[doc(alias = "NLMSG_DONE")]
+    pub const DONE: u16 = c::NLMSG_DONE as u16;
+
+    /// `NLMSG_OVERRUN`—Data was lost.
+    #[doc(alias = "NLMSG_OVERRUN")]
+    pub const OVERRUN: u16 = c::NLMSG_OVERRUN as u16;
+
+    /// Decode a header from the start of `bytes`, which must be at least
+    /// `HDRLEN` bytes long.
+    fn decode(bytes: &[u8]) -> Self {
+        Self {
+            len: u32::from_ne_bytes(split_array(&bytes[0..])),
+            msg_type: u16::from_ne_bytes(split_array(&bytes[4..])),
+            flags: NlMsgFlags::from_bits_retain(u16::from_ne_bytes(split_array(&bytes[6..]))),
+            seq: u32::from_ne_bytes(split_array(&bytes[8..])),
+            pid: u32::from_ne_bytes(split_array(&bytes[12..])),
+        }
+    }
+
+    /// Encode this header into the start of `bytes`, which must be at least
+    /// `HDRLEN` bytes long.
+    fn encode(&self, bytes: &mut [u8]) {
+        bytes[0..4].copy_from_slice(&self.len.to_ne_bytes());
+        bytes[4..6].copy_from_slice(&self.msg_type.to_ne_bytes());
+        bytes[6..8].copy_from_slice(&self.flags.bits().to_ne_bytes());
+        bytes[8..12].copy_from_slice(&self.seq.to_ne_bytes());
+        bytes[12..16].copy_from_slice(&self.pid.to_ne_bytes());
+    }
+}
+
+/// A netlink message borrowed from a buffer, as produced by [`NlMsgIter`].
+#[derive(Copy, Clone, Debug)]
+pub struct NlMsg<'buf> {
+    header: NlMsgHdr,
+    payload: &'buf [u8],
+}
+
+impl<'buf> NlMsg<'buf> {
+    /// Return the message header.
+    #[inline]
+    pub fn header(&self) -> &NlMsgHdr {
+        &self.header
+    }
+
+    /// Return the message type.
+    #[inline]
+    pub fn msg_type(&self) -> u16 {
+        self.header.msg_type
+    }
+
+    /// Return the message flags.
+    #[inline]
+    pub fn flags(&self) -> NlMsgFlags {
+        self.header.flags
+    }
+
+    /// Return the sequence number.
+    #[inline]
+    pub fn seq(&self) -> u32 {
+        self.header.seq
+    }
+
+    /// Return the port ID of the sender.
+    #[inline]
+    pub fn pid(&self) -> u32 {
+        self.header.pid
+    }
+
+    /// Return the payload following the header, excluding any trailing
+    /// alignment padding.
+    #[inline]
+    pub fn payload(&self) -> &'buf [u8] {
+        self.payload
+    }
+
+    /// If this is an `NLMSG_ERROR` message, return `Ok(())` if it's an
+    /// acknowledgement, or the error it reports. Otherwise, return `None`.
+    ///
+    /// A truncated `NLMSG_ERROR` message reports [`io::Errno::INVAL`].
+    #[doc(alias = "NLMSG_ERROR")]
+    #[doc(alias = "nlmsgerr")]
+    pub fn error(&self) -> Option<io::Result<()>> {
+        if self.header.msg_type != NlMsgHdr::ERROR {
+            return None;
+        }
+        if self.payload.len() < size_of::<i32>() {
+            return Some(Err(io::Errno::INVAL));
+        }
+        Some(decode_status(self.payload))
+    }
+
+    /// If this is an `NLMSG_DONE` message, which ends a multipart message,
+    /// return `Ok(())`, or the error it reports if the multipart message was
+    /// cut short. Otherwise, return `None`.
+    #[doc(alias = "NLMSG_DONE")]
+    pub fn done(&self) -> Option<io::Result<()>> {
+        if self.header.msg_type != NlMsgHdr::DONE {
+            return None;
+        }
+        // Some protocols send `NLMSG_DONE` without an error code.
+        if self.payload.len() < size_of::<i32>() {
+            return Some(Ok(()));
+        }
+        Some(decode_status(self.payload))
+    }
+}
+
+/// Decode the `int` status at the start of an `NLMSG_ERROR` or `NLMSG_DONE`
+/// payload, which holds a negated errno value or 0.
+fn decode_status(payload: &[u8]) -> io::Result<()> {
+    match i32::from_ne_bytes(split_array(payload)) {
+        0 => Ok(()),
+        error => Err(io::Errno::from_raw_os_error(error.wrapping_neg())),
+    }
+}
+
+/// An iterator over the netlink messages in a buffer.
+///
+/// This yields [`io::Errno::INVAL`] and stops if it encounters a malformed
+/// header.
+pub struct NlMsgIter<'buf> {
+    buf: &'buf [u8],
+}
+
+impl<'buf> NlMsgIter<'buf> {
+    /// Iterate over the messages in `buf`, which typically holds the bytes
+    /// of a single datagram received from a netlink socket.
+    #[inline]
+    pub const fn new(buf: &'buf [u8]) -> Self {
+        Self { buf }
+    }
+}
+
+impl<'buf> Iterator for NlMsgIter<'buf> {
+    type Item = io::Result<NlMsg<'buf>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buf.is_empty() {
+            return None;
+        }
+
+        if self.buf.len() < HDRLEN {
+            self.buf = &[];
+            return Some(Err(io::Errno::INVAL));
+        }
+        let header = NlMsgHdr::decode(self.buf);
+        let len = header.len as usize;
+        if len < HDRLEN || len > self.buf.len() {
+            self.buf = &[];
+            return Some(Err(io::Errno::INVAL));
+        }
+
+        let payload = &self.buf[HDRLEN..len];
+        self.buf = &self.buf[align(len).min(self.buf.len())..];
+        Some(Ok(NlMsg { header, payload }))
+    }
+}
+
+/// A builder which lays out netlink messages in a caller-provided buffer.
+pub struct NlMsgBuilder<'buf> {
+    buf: &'buf mut [u8],
+    len: usize,
+}
+
+impl<'buf> NlMsgBuilder<'buf> {
+    /// Start building messages at the beginning of `buf`.
+    #[inline]
+    pub fn new(buf: &'buf mut [u8]) -> Self {
+        Self { buf, len: 0 }
+    }
+
+    /// Append a message with the given header fields and payload, padding
+    /// the previous message to the required alignment first. The message
+    /// length is computed from `payload`.
+    ///
+    /// This fails with [`io::Errno::NOBUFS`] if the buffer is too small to
+    /// hold the message.
+    pub fn push(
+        &mut self,
+        msg_type: u16,
+        flags: NlMsgFlags,
+        seq: u32,
+        pid: u32,
+        payload: &[u8],
+    ) -> io::Result<()> {
+        let start = align(self.len);
+        let msg_len = HDRLEN.checked_add(payload.len()).ok_or(io::Errno::NOBUFS)?;
+        let end = start.checked_add(msg_len).ok_or(io::Errno::NOBUFS)?;
+        if end > self.buf.len() {
+            return Err(io::Errno::NOBUFS);
+        }
+        let header = NlMsgHdr {
+            len: msg_len.try_into().map_err(|_| io::Errno::NOBUFS)?,
+            msg_type,
+            flags,
+            seq,
+            pid,
+        };
+
+        self.buf[self.len..start].fill(0);
+        header.encode(&mut self.buf[start..]);
+        self.buf[start + HDRLEN..end].copy_from_slice(payload);
+        self.len = end;
+        Ok(())
+    }
+
+    /// Return the encoded messages, ready to be sent.
+    #[inline]
+    pub fn finish(self) -> &'buf [u8] {
+        &self.buf[..self.len]
+    }
+}
+
+/// Return the first `N` bytes of `bytes` as an array, panicking if it's too
+/// short.
+fn split_array<const N: usize>(bytes: &[u8]) -> [u8; N] {
+    bytes[..N].try_into().unwrap()
+}
+
+#[test]
+fn test_sizes() {
+    assert_eq!(size_of::<NlMsgHdr>(), size_of::<c::nlmsghdr>());
+    assert_eq!(HDRLEN, 16);
+}
+
+#[test]
+fn test_round_trip() {
+    let mut buf = [0xff_u8; 64];
+    let mut builder = NlMsgBuilder::new(&mut buf);
+    builder
+        .push(NlMsgHdr::NOOP, NlMsgFlags::REQUEST, 1, 2, b"abc")
+        .unwrap();
+    builder
+        .push(
+            NlMsgHdr::DONE,
+            NlMsgFlags::MULTI,
+            3,
+            4,
+            &0_i32.to_ne_bytes(),
+        )
+        .unwrap();
+    builder
+        .push(
+            NlMsgHdr::ERROR,
+            NlMsgFlags::empty(),
+            5,
+            6,
+            &(-(io::Errno::PERM.raw_os_error())).to_ne_bytes(),
+        )
+        .unwrap();
+    assert_eq!(
+        builder.push(NlMsgHdr::NOOP, NlMsgFlags::empty(), 0, 0, &[0; 16]),
+        Err(io::Errno::NOBUFS)
+    );
+    let bytes = builder.finish();
+    assert_eq!(bytes.len(), 20 + 20 + 20);
+    assert_eq!(&bytes[19..20], &[0]);
+
+    let mut iter = NlMsgIter::new(bytes);
+    let msg = iter.next().unwrap().unwrap();
+    assert_eq!(msg.msg_type(), NlMsgHdr::NOOP);
+    assert_eq!(msg.flags(), NlMsgFlags::REQUEST);
+    assert_eq!((msg.seq(), msg.pid()), (1, 2));
+    assert_eq!(msg.payload(), b"abc");
+    assert_eq!(msg.error(), None);
+    assert_eq!(msg.done(), None);
+    let msg = iter.next().unwrap().unwrap();
+    assert_eq!(msg.done(), Some(Ok(())));
+    let msg = iter.next().unwrap().unwrap();
+    assert_eq!(msg.error(), Some(Err(io::Errno::PERM)));
+    assert!(iter.next().is_none());
+
+    // A header whose length runs past the end of the buffer is malformed.
+    let mut iter = NlMsgIter::new(&bytes[..30]);
+    assert!(iter.next().unwrap().is_ok());
+    assert_eq!(iter.next().unwrap().unwrap_err(), io::Errno::INVAL);
+    assert!(iter.next().is_none());
+}
@@ -3,6 +3,12 @@
 #![allow(unsafe_code)]
 
 use crate::buffer::split_init;
+#[cfg(linux_kernel)]
+use crate::net::netlink::SocketAddrNetlink;
+#[cfg(linux_kernel)]
+use crate::net::packet::SocketAddrLink;
+#[cfg(linux_kernel)]
+use crate::net::vsock::SocketAddrVsock;
 #[cfg(target_os = "linux")]
 use crate::net::xdp::SocketAddrXdp;
 #[cfg(unix)]
@@ -292,6 +298,14 @@ fn _sendto_any(
         SocketAddrAny::Unix(unix) => backend::net::syscalls::sendto_unix(fd, buf, flags, unix),
         #[cfg(target_os = "linux")]
         SocketAddrAny::Xdp(xdp) => backend::net::syscalls::sendto_xdp(fd, buf, flags, xdp),
+        #[cfg(linux_kernel)]
+        SocketAddrAny::Netlink(netlink) => {
+            backend::net::syscalls::sendto_netlink(fd, buf, flags, netlink)
+        }
+        #[cfg(linux_kernel)]
+        SocketAddrAny::Link(link) => backend::net::syscalls::sendto_link(fd, buf, flags, link),
+        #[cfg(linux_kernel)]
+        SocketAddrAny::Vsock(vsock) => backend::net::syscalls::sendto_vsock(fd, buf, flags, vsock),
     }
 }
 
@@ -428,3 +442,60 @@ pub fn sendto_xdp<Fd: AsFd>(
 ) -> io::Result<usize> {
     backend::net::syscalls::sendto_xdp(fd.as_fd(), buf, flags, addr)
 }
+
+/// `sendto(fd, buf, flags, addr, sizeof(struct sockaddr_nl))`—Writes data
+/// to a socket to a specific netlink address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sendto.2.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "sendto")]
+pub fn sendto_netlink<Fd: AsFd>(
+    fd: Fd,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrNetlink,
+) -> io::Result<usize> {
+    backend::net::syscalls::sendto_netlink(fd.as_fd(), buf, flags, addr)
+}
+
+/// `sendto(fd, buf, flags, addr, sizeof(struct sockaddr_ll))`—Writes data
+/// to a packet socket to a specific link-layer address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sendto.2.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "sendto")]
+pub fn sendto_link<Fd: AsFd>(
+    fd: Fd,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrLink,
+) -> io::Result<usize> {
+    backend::net::syscalls::sendto_link(fd.as_fd(), buf, flags, addr)
+}
+
+/// `sendto(fd, buf, flags, addr, sizeof(struct sockaddr_vm))`—Writes data
+/// to a socket to a specific VM socket address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sendto.2.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "sendto")]
+pub fn sendto_vsock<Fd: AsFd>(
+    fd: Fd,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrVsock,
+) -> io::Result<usize> {
+    backend::net::syscalls::sendto_vsock(fd.as_fd(), buf, flags, addr)
+}
@@ -6,10 +6,14 @@ use crate::backend::{self, c};
 use crate::fd::{AsFd, BorrowedFd, OwnedFd};
 use crate::io::{self, IoSlice, IoSliceMut};
 #[cfg(linux_kernel)]
-use crate::net::UCred;
+use crate::net::tls::TlsRecordType;
+#[cfg(linux_kernel)]
+use crate::net::{Ipv4PktInfo, Ipv6PktInfo, SockExtendedErr, SockExtendedErrOrigin, UCred};
 
 use core::iter::FusedIterator;
 use core::marker::PhantomData;
+#[cfg(linux_kernel)]
+use core::mem::MaybeUninit;
 use core::mem::{align_of, size_of, size_of_val, take};
 #[cfg(linux_kernel)]
 use core::ptr::addr_of;
@@ -17,6 +21,9 @@ use core::{ptr, slice};
 
 use super::{RecvFlags, SendFlags, SocketAddrAny, SocketAddrV4, SocketAddrV6};
 
+#[cfg(linux_kernel)]
+pub use crate::timespec::{Nsecs, Secs, Timespec};
+
 /// Macro for defining the amount of space to allocate in a buffer for use with
 /// [`RecvAncillaryBuffer::new`] and [`SendAncillaryBuffer::new`].
 ///
@@ -45,6 +52,15 @@ use super::{RecvFlags, SendFlags, SocketAddrAny, SocketAddrV4, SocketAddrV6};
 /// let mut space = [0; rustix::cmsg_space!(ScmRights(2), ScmCredentials(1))];
 /// # }
 /// ```
+///
+/// Allocate a buffer for `SO_TIMESTAMPING` timestamps:
+/// ```
+/// # #[cfg(linux_kernel)]
+/// # {
+/// # use rustix::cmsg_space;
+/// let mut space = [0; rustix::cmsg_space!(ScmTimestamping(1))];
+/// # }
+/// ```
 #[macro_export]
 macro_rules! cmsg_space {
     // Base Rules
@@ -58,6 +74,55 @@ macro_rules! cmsg_space {
             $len * ::core::mem::size_of::<$crate::net::UCred>(),
         )
     };
+    (ScmTimestamp($len:expr)) => {
+        $crate::net::__cmsg_space(
+            $len * ::core::mem::size_of::<[i64; 2]>(),
+        )
+    };
+    (ScmTimestampns($len:expr)) => {
+        $crate::net::__cmsg_space(
+            $len * ::core::mem::size_of::<[i64; 2]>(),
+        )
+    };
+    (ScmTimestamping($len:expr)) => {
+        $crate::net::__cmsg_space(
+            $len * ::core::mem::size_of::<[[i64; 2]; 3]>(),
+        )
+    };
+    // `struct in_pktinfo` is three 32-bit fields, and `struct in6_pktinfo`
+    // is a 128-bit address and a 32-bit interface index.
+    (IpPktInfo($len:expr)) => {
+        $crate::net::__cmsg_space(
+            $len * ::core::mem::size_of::<[u32; 3]>(),
+        )
+    };
+    (Ipv6PktInfo($len:expr)) => {
+        $crate::net::__cmsg_space(
+            $len * ::core::mem::size_of::<[u32; 5]>(),
+        )
+    };
+    (UdpGsoSegments($len:expr)) => {
+        $crate::net::__cmsg_space(
+            $len * ::core::mem::size_of::<u16>(),
+        )
+    };
+    (UdpGroSegments($len:expr)) => {
+        $crate::net::__cmsg_space(
+            $len * ::core::mem::size_of::<i32>(),
+        )
+    };
+    (TlsRecordType($len:expr)) => {
+        $crate::net::__cmsg_space(
+            $len * ::core::mem::size_of::<u8>(),
+        )
+    };
+    // `struct sock_extended_err` is four 32-bit fields, and it's followed by
+    // an offender address of up to the size of a `struct sockaddr_in6`.
+    (SockExtendedErr($len:expr)) => {
+        $crate::net::__cmsg_space(
+            $len * ::core::mem::size_of::<[u32; 11]>(),
+        )
+    };
 
     // Combo Rules
     ($firstid:ident($firstex:expr), $($restid:ident($restex:expr)),*) => {{
@@ -86,6 +151,55 @@ macro_rules! cmsg_aligned_space {
             $len * ::core::mem::size_of::<$crate::net::UCred>(),
         )
     };
+    (ScmTimestamp($len:expr)) => {
+        $crate::net::__cmsg_aligned_space(
+            $len * ::core::mem::size_of::<[i64; 2]>(),
+        )
+    };
+    (ScmTimestampns($len:expr)) => {
+        $crate::net::__cmsg_aligned_space(
+            $len * ::core::mem::size_of::<[i64; 2]>(),
+        )
+    };
+    (ScmTimestamping($len:expr)) => {
+        $crate::net::__cmsg_aligned_space(
+            $len * ::core::mem::size_of::<[[i64; 2]; 3]>(),
+        )
+    };
+    // `struct in_pktinfo` is three 32-bit fields, and `struct in6_pktinfo`
+    // is a 128-bit address and a 32-bit interface index.
+    (IpPktInfo($len:expr)) => {
+        $crate::net::__cmsg_aligned_space(
+            $len * ::core::mem::size_of::<[u32; 3]>(),
+        )
+    };
+    (Ipv6PktInfo($len:expr)) => {
+        $crate::net::__cmsg_aligned_space(
+            $len * ::core::mem::size_of::<[u32; 5]>(),
+        )
+    };
+    (UdpGsoSegments($len:expr)) => {
+        $crate::net::__cmsg_aligned_space(
+            $len * ::core::mem::size_of::<u16>(),
+        )
+    };
+    (UdpGroSegments($len:expr)) => {
+        $crate::net::__cmsg_aligned_space(
+            $len * ::core::mem::size_of::<i32>(),
+        )
+    };
+    (TlsRecordType($len:expr)) => {
+        $crate::net::__cmsg_aligned_space(
+            $len * ::core::mem::size_of::<u8>(),
+        )
+    };
+    // `struct sock_extended_err` is four 32-bit fields, and it's followed by
+    // an offender address of up to the size of a `struct sockaddr_in6`.
+    (SockExtendedErr($len:expr)) => {
+        $crate::net::__cmsg_aligned_space(
+            $len * ::core::mem::size_of::<[u32; 11]>(),
+        )
+    };
 
     // Combo Rules
     ($firstid:ident($firstex:expr), $($restid:ident($restex:expr)),*) => {{
@@ -131,6 +245,26 @@ pub enum SendAncillaryMessage<'slice, 'fd> {
     #[cfg(linux_kernel)]
     #[doc(alias = "SCM_CREDENTIAL")]
     ScmCredentials(UCred),
+    /// Choose the source address and interface of an IPv4 packet.
+    #[cfg(linux_kernel)]
+    #[doc(alias = "IP_PKTINFO")]
+    IpPktInfo(Ipv4PktInfo),
+    /// Choose the source address and interface of an IPv6 packet.
+    #[cfg(linux_kernel)]
+    #[doc(alias = "IPV6_PKTINFO")]
+    Ipv6PktInfo(Ipv6PktInfo),
+    /// Split a UDP datagram into segments of the given size, overriding
+    /// [`set_udp_segment`].
+    ///
+    /// [`set_udp_segment`]: crate::net::sockopt::set_udp_segment
+    #[cfg(linux_kernel)]
+    #[doc(alias = "UDP_SEGMENT")]
+    UdpGsoSegments(u16),
+    /// Send data on a kernel TLS socket as a record of the given type,
+    /// instead of as application data.
+    #[cfg(linux_kernel)]
+    #[doc(alias = "TLS_SET_RECORD_TYPE")]
+    TlsRecordType(TlsRecordType),
 }
 
 impl SendAncillaryMessage<'_, '_> {
@@ -142,6 +276,14 @@ impl SendAncillaryMessage<'_, '_> {
             Self::ScmRights(slice) => cmsg_space!(ScmRights(slice.len())),
             #[cfg(linux_kernel)]
             Self::ScmCredentials(_) => cmsg_space!(ScmCredentials(1)),
+            #[cfg(linux_kernel)]
+            Self::IpPktInfo(_) => cmsg_space!(IpPktInfo(1)),
+            #[cfg(linux_kernel)]
+            Self::Ipv6PktInfo(_) => cmsg_space!(Ipv6PktInfo(1)),
+            #[cfg(linux_kernel)]
+            Self::UdpGsoSegments(_) => cmsg_space!(UdpGsoSegments(1)),
+            #[cfg(linux_kernel)]
+            Self::TlsRecordType(_) => cmsg_space!(TlsRecordType(1)),
         }
     }
 }
@@ -156,6 +298,73 @@ pub enum RecvAncillaryMessage<'a> {
     #[cfg(linux_kernel)]
     #[doc(alias = "SCM_CREDENTIALS")]
     ScmCredentials(UCred),
+    /// Received timestamp, enabled with [`set_socket_timestamp`].
+    ///
+    /// The kernel reports this with microsecond resolution.
+    ///
+    /// [`set_socket_timestamp`]: crate::net::sockopt::set_socket_timestamp
+    #[cfg(linux_kernel)]
+    #[doc(alias = "SCM_TIMESTAMP")]
+    #[doc(alias = "SO_TIMESTAMP_NEW")]
+    ScmTimestamp(Timespec),
+    /// Received timestamp, enabled with [`set_socket_timestampns`].
+    ///
+    /// [`set_socket_timestampns`]: crate::net::sockopt::set_socket_timestampns
+    #[cfg(linux_kernel)]
+    #[doc(alias = "SCM_TIMESTAMPNS")]
+    #[doc(alias = "SO_TIMESTAMPNS_NEW")]
+    ScmTimestampns(Timespec),
+    /// Received timestamps, enabled with [`set_socket_timestamping`].
+    ///
+    /// The elements are the software timestamp, a deprecated timestamp which
+    /// is always zero, and the raw hardware timestamp. Elements for
+    /// timestamps that weren't generated are zero.
+    ///
+    /// [`set_socket_timestamping`]: crate::net::sockopt::set_socket_timestamping
+    #[cfg(linux_kernel)]
+    #[doc(alias = "SCM_TIMESTAMPING")]
+    #[doc(alias = "SO_TIMESTAMPING_NEW")]
+    ScmTimestamping([Timespec; 3]),
+    /// Received IPv4 packet information, enabled with [`set_ip_pktinfo`].
+    ///
+    /// [`set_ip_pktinfo`]: crate::net::sockopt::set_ip_pktinfo
+    #[cfg(linux_kernel)]
+    #[doc(alias = "IP_PKTINFO")]
+    IpPktInfo(Ipv4PktInfo),
+    /// Received IPv6 packet information, enabled with
+    /// [`set_ipv6_recvpktinfo`].
+    ///
+    /// [`set_ipv6_recvpktinfo`]: crate::net::sockopt::set_ipv6_recvpktinfo
+    #[cfg(linux_kernel)]
+    #[doc(alias = "IPV6_PKTINFO")]
+    Ipv6PktInfo(Ipv6PktInfo),
+    /// The segment size of coalesced UDP datagrams, enabled with
+    /// [`set_udp_gro`].
+    ///
+    /// [`set_udp_gro`]: crate::net::sockopt::set_udp_gro
+    #[cfg(linux_kernel)]
+    #[doc(alias = "UDP_GRO")]
+    UdpGroSegments(u16),
+    /// The type of a record received on a kernel TLS socket, if it isn't
+    /// application data.
+    ///
+    /// When a non-application-data record is received, `recvmsg` returns
+    /// only that record's data.
+    #[cfg(linux_kernel)]
+    #[doc(alias = "TLS_GET_RECORD_TYPE")]
+    TlsRecordType(TlsRecordType),
+    /// An error or notification from the socket's error queue, received
+    /// with [`RecvFlags::ERRQUEUE`].
+    ///
+    /// This requires [`set_ip_recverr`] or [`set_ipv6_recverr`] for errors,
+    /// and is also used for [`SendFlags::ZEROCOPY`] completions.
+    ///
+    /// [`set_ip_recverr`]: crate::net::sockopt::set_ip_recverr
+    /// [`set_ipv6_recverr`]: crate::net::sockopt::set_ipv6_recverr
+    #[cfg(linux_kernel)]
+    #[doc(alias = "IP_RECVERR")]
+    #[doc(alias = "IPV6_RECVERR")]
+    SockExtendedErr(SockExtendedErr),
 }
 
 /// Buffer for sending ancillary messages with [`sendmsg`], [`sendmsg_v4`],
@@ -280,6 +489,34 @@ impl<'buf, 'slice, 'fd> SendAncillaryBuffer<'buf, 'slice, 'fd> {
                 };
                 self.push_ancillary(ucred_bytes, c::SOL_SOCKET as _, c::SCM_CREDENTIALS as _)
             }
+            #[cfg(linux_kernel)]
+            SendAncillaryMessage::IpPktInfo(info) => {
+                let info = backend::net::write_sockaddr::encode_in_pktinfo(&info);
+                let info_bytes = unsafe {
+                    slice::from_raw_parts(addr_of!(info).cast::<u8>(), size_of_val(&info))
+                };
+                self.push_ancillary(info_bytes, c::IPPROTO_IP as _, c::IP_PKTINFO as _)
+            }
+            #[cfg(linux_kernel)]
+            SendAncillaryMessage::Ipv6PktInfo(info) => {
+                let info = backend::net::write_sockaddr::encode_in6_pktinfo(&info);
+                let info_bytes = unsafe {
+                    slice::from_raw_parts(addr_of!(info).cast::<u8>(), size_of_val(&info))
+                };
+                self.push_ancillary(info_bytes, c::IPPROTO_IPV6 as _, c::IPV6_PKTINFO as _)
+            }
+            #[cfg(linux_kernel)]
+            SendAncillaryMessage::UdpGsoSegments(segment_size) => self.push_ancillary(
+                &segment_size.to_ne_bytes(),
+                c::IPPROTO_UDP as _,
+                c::UDP_SEGMENT as _,
+            ),
+            #[cfg(linux_kernel)]
+            SendAncillaryMessage::TlsRecordType(record_type) => self.push_ancillary(
+                &[record_type.as_raw()],
+                c::SOL_TLS as _,
+                c::TLS_SET_RECORD_TYPE as _,
+            ),
         }
     }
 
@@ -535,12 +772,149 @@ impl<'buf> AncillaryDrain<'buf> {
                         None
                     }
                 }
+                // The `_OLD` variants use the platform's `long`-based
+                // `timeval` and `timespec` layouts, and the `_NEW` variants
+                // use 64-bit fields on all platforms.
+                #[cfg(linux_kernel)]
+                (c::SOL_SOCKET, c::SO_TIMESTAMP_OLD) => read_timestamps::<c::c_long, 1>(payload)
+                    .map(|[ts]| RecvAncillaryMessage::ScmTimestamp(usecs_to_nsecs(ts))),
+                #[cfg(linux_kernel)]
+                (c::SOL_SOCKET, c::SO_TIMESTAMP_NEW) => read_timestamps::<i64, 1>(payload)
+                    .map(|[ts]| RecvAncillaryMessage::ScmTimestamp(usecs_to_nsecs(ts))),
+                #[cfg(linux_kernel)]
+                (c::SOL_SOCKET, c::SO_TIMESTAMPNS_OLD) => read_timestamps::<c::c_long, 1>(payload)
+                    .map(|[ts]| RecvAncillaryMessage::ScmTimestampns(ts)),
+                #[cfg(linux_kernel)]
+                (c::SOL_SOCKET, c::SO_TIMESTAMPNS_NEW) => read_timestamps::<i64, 1>(payload)
+                    .map(|[ts]| RecvAncillaryMessage::ScmTimestampns(ts)),
+                #[cfg(linux_kernel)]
+                (c::SOL_SOCKET, c::SO_TIMESTAMPING_OLD) => read_timestamps::<c::c_long, 3>(payload)
+                    .map(RecvAncillaryMessage::ScmTimestamping),
+                #[cfg(linux_kernel)]
+                (c::SOL_SOCKET, c::SO_TIMESTAMPING_NEW) => {
+                    read_timestamps::<i64, 3>(payload).map(RecvAncillaryMessage::ScmTimestamping)
+                }
+                #[cfg(linux_kernel)]
+                (c::IPPROTO_IP, c::IP_PKTINFO) => {
+                    if payload_len >= size_of::<c::in_pktinfo>() {
+                        let info = payload.as_ptr().cast::<c::in_pktinfo>().read_unaligned();
+                        let info = backend::net::read_sockaddr::decode_in_pktinfo(&info);
+                        Some(RecvAncillaryMessage::IpPktInfo(info))
+                    } else {
+                        None
+                    }
+                }
+                #[cfg(linux_kernel)]
+                (c::IPPROTO_IPV6, c::IPV6_PKTINFO) => {
+                    if payload_len >= size_of::<c::in6_pktinfo>() {
+                        let info = payload.as_ptr().cast::<c::in6_pktinfo>().read_unaligned();
+                        let info = backend::net::read_sockaddr::decode_in6_pktinfo(&info);
+                        Some(RecvAncillaryMessage::Ipv6PktInfo(info))
+                    } else {
+                        None
+                    }
+                }
+                #[cfg(linux_kernel)]
+                (c::IPPROTO_IP, c::IP_RECVERR) | (c::IPPROTO_IPV6, c::IPV6_RECVERR) => {
+                    read_sock_extended_err(payload).map(RecvAncillaryMessage::SockExtendedErr)
+                }
+                #[cfg(linux_kernel)]
+                (c::IPPROTO_UDP, c::UDP_GRO) => {
+                    if payload_len >= size_of::<c::c_int>() {
+                        let segment_size = payload.as_ptr().cast::<c::c_int>().read_unaligned();
+                        Some(RecvAncillaryMessage::UdpGroSegments(segment_size as u16))
+                    } else {
+                        None
+                    }
+                }
+                #[cfg(linux_kernel)]
+                (c::SOL_TLS, c::TLS_GET_RECORD_TYPE) => {
+                    if payload_len >= size_of::<u8>() {
+                        let record_type = TlsRecordType::from_raw(payload[0]);
+                        Some(RecvAncillaryMessage::TlsRecordType(record_type))
+                    } else {
+                        None
+                    }
+                }
                 _ => None,
             }
         }
     }
 }
 
+/// Read a `struct sock_extended_err` and the offender address that follows
+/// it from an `IP_RECVERR` or `IPV6_RECVERR` control message payload.
+#[cfg(linux_kernel)]
+fn read_sock_extended_err(payload: &[u8]) -> Option<SockExtendedErr> {
+    if payload.len() < size_of::<c::sock_extended_err>() {
+        return None;
+    }
+
+    let ee = unsafe {
+        payload
+            .as_ptr()
+            .cast::<c::sock_extended_err>()
+            .read_unaligned()
+    };
+
+    // Copy the offender address out so that it's suitably aligned. Errors
+    // without an offender have an `AF_UNSPEC` address, which `read_sockaddr`
+    // rejects.
+    let offender = &payload[size_of::<c::sock_extended_err>()..];
+    let offender_len = offender.len().min(size_of::<c::sockaddr_storage>());
+    let mut storage = MaybeUninit::<c::sockaddr_storage>::zeroed();
+    let offender = unsafe {
+        ptr::copy_nonoverlapping(
+            offender.as_ptr(),
+            storage.as_mut_ptr().cast::<u8>(),
+            offender_len,
+        );
+        backend::net::read_sockaddr::read_sockaddr(storage.as_ptr().cast(), offender_len).ok()
+    };
+
+    Some(SockExtendedErr {
+        errno: if ee.ee_errno == 0 {
+            None
+        } else {
+            Some(io::Errno::from_raw_os_error(ee.ee_errno as i32))
+        },
+        origin: SockExtendedErrOrigin::from_raw(ee.ee_origin),
+        type_: ee.ee_type,
+        code: ee.ee_code,
+        info: ee.ee_info,
+        data: ee.ee_data,
+        offender,
+    })
+}
+
+/// Read `N` timestamps from a timestamp control message payload, where each
+/// timestamp is a pair of `T`s holding seconds and a sub-second value.
+#[cfg(linux_kernel)]
+fn read_timestamps<T: Copy + Into<i64>, const N: usize>(payload: &[u8]) -> Option<[Timespec; N]> {
+    if payload.len() < size_of::<[[T; 2]; N]>() {
+        return None;
+    }
+
+    let raw = unsafe { payload.as_ptr().cast::<[[T; 2]; N]>().read_unaligned() };
+    Some(raw.map(|[sec, subsec]| {
+        let (sec, subsec): (i64, i64) = (sec.into(), subsec.into());
+        Timespec {
+            tv_sec: sec as _,
+            tv_nsec: subsec as _,
+        }
+    }))
+}
+
+/// Convert a `SCM_TIMESTAMP` timestamp, which holds microseconds in its
+/// sub-second field, to nanoseconds.
+#[cfg(linux_kernel)]
+fn usecs_to_nsecs(ts: Timespec) -> Timespec {
+    Timespec {
+        tv_sec: ts.tv_sec,
+        tv_nsec: ts.tv_nsec * 1000,
+    }
+}
+
 impl<'buf> Iterator for AncillaryDrain<'buf> {
     type Item = RecvAncillaryMessage<'buf>;
 
@@ -595,8 +969,8 @@ impl FusedIterator for AncillaryDrain<'_> {}
 ///
 /// This function is for use on connected sockets, as it doesn't have
 /// a way to specify an address. See the [`sendmsg_v4`], [`sendmsg_v6`]
-/// [`sendmsg_unix`], [`sendmsg_xdp`], and [`sendmsg_any`] to send
-/// messages on unconnected sockets.
+/// [`sendmsg_unix`], [`sendmsg_xdp`], [`sendmsg_netlink`], [`sendmsg_link`],
+/// [`sendmsg_vsock`], and [`sendmsg_any`] to send messages on unconnected sockets.
 ///
 /// # References
 ///  - [POSIX]
@@ -739,6 +1113,63 @@ pub fn sendmsg_xdp(
     backend::net::syscalls::sendmsg_xdp(socket.as_fd(), addr, iov, control, flags)
 }
 
+/// `sendmsg(msghdr)`—Sends a message on a socket to a specific netlink
+/// address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sendmsg.2.html
+#[inline]
+#[cfg(linux_kernel)]
+pub fn sendmsg_netlink(
+    socket: impl AsFd,
+    addr: &super::SocketAddrNetlink,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    flags: SendFlags,
+) -> io::Result<usize> {
+    backend::net::syscalls::sendmsg_netlink(socket.as_fd(), addr, iov, control, flags)
+}
+
+/// `sendmsg(msghdr)`—Sends a message on a packet socket to a specific
+/// link-layer address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sendmsg.2.html
+#[inline]
+#[cfg(linux_kernel)]
+pub fn sendmsg_link(
+    socket: impl AsFd,
+    addr: &super::SocketAddrLink,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    flags: SendFlags,
+) -> io::Result<usize> {
+    backend::net::syscalls::sendmsg_link(socket.as_fd(), addr, iov, control, flags)
+}
+
+/// `sendmsg(msghdr)`—Sends a message on a socket to a specific VM socket
+/// address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sendmsg.2.html
+#[inline]
+#[cfg(linux_kernel)]
+pub fn sendmsg_vsock(
+    socket: impl AsFd,
+    addr: &super::SocketAddrVsock,
+    iov: &[IoSlice<'_>],
+    control: &mut SendAncillaryBuffer<'_, '_, '_>,
+    flags: SendFlags,
+) -> io::Result<usize> {
+    backend::net::syscalls::sendmsg_vsock(socket.as_fd(), addr, iov, control, flags)
+}
+
 /// `sendmsg(msghdr)`—Sends a message on a socket to a specific address.
 ///
 /// # References
@@ -783,6 +1214,18 @@ pub fn sendmsg_any(
         Some(SocketAddrAny::Xdp(addr)) => {
             backend::net::syscalls::sendmsg_xdp(socket.as_fd(), addr, iov, control, flags)
         }
+        #[cfg(linux_kernel)]
+        Some(SocketAddrAny::Netlink(addr)) => {
+            backend::net::syscalls::sendmsg_netlink(socket.as_fd(), addr, iov, control, flags)
+        }
+        #[cfg(linux_kernel)]
+        Some(SocketAddrAny::Link(addr)) => {
+            backend::net::syscalls::sendmsg_link(socket.as_fd(), addr, iov, control, flags)
+        }
+        #[cfg(linux_kernel)]
+        Some(SocketAddrAny::Vsock(addr)) => {
+            backend::net::syscalls::sendmsg_vsock(socket.as_fd(), addr, iov, control, flags)
+        }
     }
 }
 
@@ -828,6 +1271,181 @@ pub struct RecvMsgReturn {
     pub address: Option<SocketAddrAny>,
 }
 
+/// The maximum number of messages transferred by one call to [`sendmmsg`] or
+/// [`recvmmsg`].
+///
+/// Any messages beyond this are left untouched, as if the kernel had
+/// transferred fewer messages than requested.
+#[cfg(linux_kernel)]
+pub const MMSG_MAX: usize = 64;
+
+/// A message to send with [`sendmmsg`].
+///
+/// `struct mmsghdr`
+#[cfg(linux_kernel)]
+#[doc(alias = "mmsghdr")]
+pub struct MMsgHdr<'a> {
+    /// The data to send.
+    pub(crate) iov: &'a [IoSlice<'a>],
+
+    /// The ancillary data to send, which is borrowed for `'a`.
+    pub(crate) control: *mut u8,
+    pub(crate) control_len: usize,
+
+    /// The encoded destination address, if `name_len` is non-zero.
+    pub(crate) name: MaybeUninit<c::sockaddr_storage>,
+    pub(crate) name_len: usize,
+
+    /// The number of bytes sent, filled in by [`sendmmsg`].
+    pub(crate) bytes: usize,
+
+    _phantom: PhantomData<&'a mut [u8]>,
+}
+
+#[cfg(linux_kernel)]
+impl<'a> MMsgHdr<'a> {
+    /// Create a message to send on a connected socket.
+    pub fn new(iov: &'a [IoSlice<'_>], control: &'a mut SendAncillaryBuffer<'_, '_, '_>) -> Self {
+        Self {
+            iov,
+            control: control.as_control_ptr(),
+            control_len: control.control_len(),
+            name: MaybeUninit::uninit(),
+            name_len: 0,
+            bytes: 0,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Create a message to send to a specific address.
+    pub fn new_with_addr(
+        addr: &SocketAddrAny,
+        iov: &'a [IoSlice<'_>],
+        control: &'a mut SendAncillaryBuffer<'_, '_, '_>,
+    ) -> Self {
+        let mut msg = Self::new(iov, control);
+        // SAFETY: `sockaddr_storage` is big enough for any socket address.
+        msg.name_len = unsafe { addr.write(msg.name.as_mut_ptr().cast()) };
+        msg
+    }
+
+    /// Return the number of bytes sent, after this message has been sent by
+    /// [`sendmmsg`].
+    #[inline]
+    pub fn bytes_sent(&self) -> usize {
+        self.bytes
+    }
+}
+
+/// A buffer to receive a message into with [`recvmmsg`].
+///
+/// `struct mmsghdr`
+#[cfg(linux_kernel)]
+#[doc(alias = "mmsghdr")]
+pub struct RecvMMsgHdr<'a> {
+    /// The buffers to receive data into.
+    pub(crate) iov: *mut IoSliceMut<'a>,
+    pub(crate) iov_len: usize,
+
+    /// The buffer to receive ancillary data into, which is borrowed for
+    /// `'a`.
+    pub(crate) control: *mut RecvAncillaryBuffer<'a>,
+
+    /// Storage for the source address.
+    pub(crate) name: MaybeUninit<c::sockaddr_storage>,
+
+    /// The results, filled in by [`recvmmsg`].
+    pub(crate) bytes: usize,
+    pub(crate) flags: RecvFlags,
+    pub(crate) address: Option<SocketAddrAny>,
+
+    _phantom: PhantomData<&'a mut [u8]>,
+}
+
+#[cfg(linux_kernel)]
+impl<'a> RecvMMsgHdr<'a> {
+    /// Create a buffer to receive a message into.
+    ///
+    /// Received ancillary data is stored in `control`, and may be read with
+    /// [`RecvAncillaryBuffer::drain`] once this `RecvMMsgHdr` is dropped.
+    pub fn new(iov: &'a mut [IoSliceMut<'_>], control: &'a mut RecvAncillaryBuffer<'_>) -> Self {
+        Self {
+            iov: iov.as_mut_ptr().cast(),
+            iov_len: iov.len(),
+            control: (control as *mut RecvAncillaryBuffer<'_>).cast(),
+            name: MaybeUninit::uninit(),
+            bytes: 0,
+            flags: RecvFlags::empty(),
+            address: None,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Return the number of bytes received.
+    #[inline]
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Return the flags received.
+    #[inline]
+    pub fn flags(&self) -> RecvFlags {
+        self.flags
+    }
+
+    /// Return the address of the socket we received from, if any.
+    #[inline]
+    pub fn address(&self) -> Option<&SocketAddrAny> {
+        self.address.as_ref()
+    }
+}
+
+/// `sendmmsg(msgvec, vlen)`—Sends multiple messages on a socket.
+///
+/// Returns the number of messages sent, and records the number of bytes sent
+/// for each in [`MMsgHdr::bytes_sent`]. At most [`MMSG_MAX`] messages are sent
+/// per call.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/sendmmsg.2.html
+#[cfg(linux_kernel)]
+#[inline]
+pub fn sendmmsg(
+    socket: impl AsFd,
+    msgs: &mut [MMsgHdr<'_>],
+    flags: SendFlags,
+) -> io::Result<usize> {
+    backend::net::syscalls::sendmmsg(socket.as_fd(), msgs, flags)
+}
+
+/// `recvmmsg(msgvec, vlen, flags, timeout)`—Receives multiple messages from
+/// a socket.
+///
+/// Returns the number of messages received, and records the number of bytes,
+/// flags, and source address of each in its [`RecvMMsgHdr`]. At most
+/// [`MMSG_MAX`] messages are received per call.
+///
+/// As with Linux's `recvmmsg`, the timeout is only checked after each message
+/// is received, so it doesn't bound how long the call may block waiting for
+/// the first message.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/recvmmsg.2.html
+#[cfg(linux_kernel)]
+#[inline]
+pub fn recvmmsg(
+    socket: impl AsFd,
+    msgs: &mut [RecvMMsgHdr<'_>],
+    flags: RecvFlags,
+    timeout: Option<&Timespec>,
+) -> io::Result<usize> {
+    backend::net::syscalls::recvmmsg(socket.as_fd(), msgs, flags, timeout)
+}
+
 /// An iterator over data in an ancillary buffer.
 pub struct AncillaryIter<'data, T> {
     /// The data we're iterating over.
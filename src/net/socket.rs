@@ -3,6 +3,12 @@ use crate::net::{SocketAddr, SocketAddrAny, SocketAddrV4, SocketAddrV6};
 use crate::{backend, io};
 use backend::fd::{AsFd, BorrowedFd};
 
+#[cfg(linux_kernel)]
+use crate::net::netlink::SocketAddrNetlink;
+#[cfg(linux_kernel)]
+use crate::net::packet::SocketAddrLink;
+#[cfg(linux_kernel)]
+use crate::net::vsock::SocketAddrVsock;
 #[cfg(target_os = "linux")]
 use crate::net::xdp::SocketAddrXdp;
 pub use crate::net::{AddressFamily, Protocol, Shutdown, SocketFlags, SocketType};
@@ -172,6 +178,12 @@ fn _bind_any(sockfd: BorrowedFd<'_>, addr: &SocketAddrAny) -> io::Result<()> {
         SocketAddrAny::Unix(unix) => backend::net::syscalls::bind_unix(sockfd, unix),
         #[cfg(target_os = "linux")]
         SocketAddrAny::Xdp(xdp) => backend::net::syscalls::bind_xdp(sockfd, xdp),
+        #[cfg(linux_kernel)]
+        SocketAddrAny::Netlink(netlink) => backend::net::syscalls::bind_netlink(sockfd, netlink),
+        #[cfg(linux_kernel)]
+        SocketAddrAny::Link(link) => backend::net::syscalls::bind_link(sockfd, link),
+        #[cfg(linux_kernel)]
+        SocketAddrAny::Vsock(vsock) => backend::net::syscalls::bind_vsock(sockfd, vsock),
     }
 }
 
@@ -289,6 +301,48 @@ pub fn bind_xdp<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrXdp) -> io::Result<()> {
     backend::net::syscalls::bind_xdp(sockfd.as_fd(), addr)
 }
 
+/// `bind(sockfd, addr, sizeof(struct sockaddr_nl))`—Binds a socket to a
+/// netlink address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/netlink.7.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "bind")]
+pub fn bind_netlink<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrNetlink) -> io::Result<()> {
+    backend::net::syscalls::bind_netlink(sockfd.as_fd(), addr)
+}
+
+/// `bind(sockfd, addr, sizeof(struct sockaddr_ll))`—Binds a packet socket to
+/// a link-layer address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/packet.7.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "bind")]
+pub fn bind_link<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrLink) -> io::Result<()> {
+    backend::net::syscalls::bind_link(sockfd.as_fd(), addr)
+}
+
+/// `bind(sockfd, addr, sizeof(struct sockaddr_vm))`—Binds a socket to a VM
+/// socket address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/vsock.7.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "bind")]
+pub fn bind_vsock<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrVsock) -> io::Result<()> {
+    backend::net::syscalls::bind_vsock(sockfd.as_fd(), addr)
+}
+
 /// `connect(sockfd, addr)`—Initiates a connection to an IP address.
 ///
 /// On Windows, a non-blocking socket returns [`Errno::WOULDBLOCK`] if the
@@ -370,6 +424,12 @@ fn _connect_any(sockfd: BorrowedFd<'_>, addr: &SocketAddrAny) -> io::Result<()>
         SocketAddrAny::Unix(unix) => backend::net::syscalls::connect_unix(sockfd, unix),
         #[cfg(target_os = "linux")]
         SocketAddrAny::Xdp(_) => Err(io::Errno::OPNOTSUPP),
+        #[cfg(linux_kernel)]
+        SocketAddrAny::Netlink(netlink) => backend::net::syscalls::connect_netlink(sockfd, netlink),
+        #[cfg(linux_kernel)]
+        SocketAddrAny::Link(_) => Err(io::Errno::OPNOTSUPP),
+        #[cfg(linux_kernel)]
+        SocketAddrAny::Vsock(vsock) => backend::net::syscalls::connect_vsock(sockfd, vsock),
     }
 }
 
@@ -473,6 +533,34 @@ pub fn connect_unix<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrUnix) -> io::Result<(
     backend::net::syscalls::connect_unix(sockfd.as_fd(), addr)
 }
 
+/// `connect(sockfd, addr, sizeof(struct sockaddr_nl))`—Sets the default
+/// destination of a netlink socket.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/netlink.7.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "connect")]
+pub fn connect_netlink<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrNetlink) -> io::Result<()> {
+    backend::net::syscalls::connect_netlink(sockfd.as_fd(), addr)
+}
+
+/// `connect(sockfd, addr, sizeof(struct sockaddr_vm))`—Initiates a
+/// connection to a VM socket address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/vsock.7.html
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "connect")]
+pub fn connect_vsock<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrVsock) -> io::Result<()> {
+    backend::net::syscalls::connect_vsock(sockfd.as_fd(), addr)
+}
+
 /// `connect(sockfd, {.sa_family = AF_UNSPEC}, sizeof(struct sockaddr))`—
 /// Dissolve the socket's association.
 ///
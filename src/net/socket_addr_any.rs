@@ -9,6 +9,12 @@
 //! OS-specific socket address representations in memory.
 #![allow(unsafe_code)]
 
+#[cfg(linux_kernel)]
+use crate::net::netlink::SocketAddrNetlink;
+#[cfg(linux_kernel)]
+use crate::net::packet::SocketAddrLink;
+#[cfg(linux_kernel)]
+use crate::net::vsock::SocketAddrVsock;
 #[cfg(target_os = "linux")]
 use crate::net::xdp::SocketAddrXdp;
 #[cfg(unix)]
@@ -35,6 +41,15 @@ pub enum SocketAddrAny {
     /// `struct sockaddr_xdp`
     #[cfg(target_os = "linux")]
     Xdp(SocketAddrXdp),
+    /// `struct sockaddr_nl`
+    #[cfg(linux_kernel)]
+    Netlink(SocketAddrNetlink),
+    /// `struct sockaddr_ll`
+    #[cfg(linux_kernel)]
+    Link(SocketAddrLink),
+    /// `struct sockaddr_vm`
+    #[cfg(linux_kernel)]
+    Vsock(SocketAddrVsock),
 }
 
 impl From<SocketAddr> for SocketAddrAny {
@@ -69,6 +84,30 @@ impl From<SocketAddrUnix> for SocketAddrAny {
     }
 }
 
+#[cfg(linux_kernel)]
+impl From<SocketAddrNetlink> for SocketAddrAny {
+    #[inline]
+    fn from(from: SocketAddrNetlink) -> Self {
+        Self::Netlink(from)
+    }
+}
+
+#[cfg(linux_kernel)]
+impl From<SocketAddrLink> for SocketAddrAny {
+    #[inline]
+    fn from(from: SocketAddrLink) -> Self {
+        Self::Link(from)
+    }
+}
+
+#[cfg(linux_kernel)]
+impl From<SocketAddrVsock> for SocketAddrAny {
+    #[inline]
+    fn from(from: SocketAddrVsock) -> Self {
+        Self::Vsock(from)
+    }
+}
+
 impl SocketAddrAny {
     /// Return the address family of this socket address.
     #[inline]
@@ -80,6 +119,12 @@ impl SocketAddrAny {
             Self::Unix(_) => AddressFamily::UNIX,
             #[cfg(target_os = "linux")]
             Self::Xdp(_) => AddressFamily::XDP,
+            #[cfg(linux_kernel)]
+            Self::Netlink(_) => AddressFamily::NETLINK,
+            #[cfg(linux_kernel)]
+            Self::Link(_) => AddressFamily::PACKET,
+            #[cfg(linux_kernel)]
+            Self::Vsock(_) => AddressFamily::VSOCK,
         }
     }
 
@@ -117,6 +162,12 @@ impl fmt::Debug for SocketAddrAny {
             Self::Unix(unix) => unix.fmt(f),
             #[cfg(target_os = "linux")]
             Self::Xdp(xdp) => xdp.fmt(f),
+            #[cfg(linux_kernel)]
+            Self::Netlink(netlink) => netlink.fmt(f),
+            #[cfg(linux_kernel)]
+            Self::Link(link) => link.fmt(f),
+            #[cfg(linux_kernel)]
+            Self::Vsock(vsock) => vsock.fmt(f),
         }
     }
 }
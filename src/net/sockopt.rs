@@ -137,12 +137,34 @@
 //! [DragonFly BSD `tcp`]: https://man.dragonflybsd.org/?command=tcp&section=4
 //! [illumos `tcp`]: https://illumos.org/man/4P/tcp
 //!
+//! # References for `get_udp_*` and `set_udp_*` functions:
+//!
+//!  - [References for all `get_*` functions]
+//!  - [References for all `set_*` functions]
+//!  - [Linux `udp`]
+//!
+//! [Linux `udp`]: https://man7.org/linux/man-pages/man7/udp.7.html
+//!
+//! # References for `get_tls_*` and `set_tls_*` functions:
+//!
+//!  - [References for all `get_*` functions]
+//!  - [References for all `set_*` functions]
+//!  - [Linux Kernel TLS]
+//!
+//! [Linux Kernel TLS]: https://docs.kernel.org/networking/tls.html
+//!
 //! [References for all `get_*` functions]: #references-for-all-get_-functions
 //! [References for all `set_*` functions]: #references-for-all-set_-functions
 
 #![doc(alias = "getsockopt")]
 #![doc(alias = "setsockopt")]
 
+#[cfg(linux_kernel)]
+use crate::bpf_filter::SockFprog;
+#[cfg(target_os = "linux")]
+use crate::net::packet::{PacketFanout, PacketMreq, PacketStats, PacketVersion, TpacketReq3};
+#[cfg(linux_kernel)]
+use crate::net::tls::TlsCryptoInfo;
 #[cfg(target_os = "linux")]
 use crate::net::xdp::{XdpMmapOffsets, XdpOptionsFlags, XdpStatistics, XdpUmemReg};
 #[cfg(not(any(
@@ -172,6 +194,8 @@ use crate::net::SocketAddrV4;
 #[cfg(linux_kernel)]
 use crate::net::SocketAddrV6;
 use crate::net::{Ipv4Addr, Ipv6Addr, SocketType};
+#[cfg(linux_kernel)]
+use crate::net::{PmtuDiscovery, SockFilter, TcpInfo, TimestampingFlags};
 use crate::{backend, io};
 #[cfg(feature = "alloc")]
 #[cfg(any(
@@ -181,6 +205,9 @@ use crate::{backend, io};
     target_os = "illumos"
 ))]
 use alloc::string::String;
+#[cfg(feature = "alloc")]
+#[cfg(linux_kernel)]
+use alloc::vec::Vec;
 use backend::c;
 use backend::fd::AsFd;
 use core::time::Duration;
@@ -603,6 +630,332 @@ pub fn set_socket_incoming_cpu<Fd: AsFd>(fd: Fd, value: u32) -> io::Result<()> {
     backend::net::sockopt::set_socket_incoming_cpu(fd.as_fd(), value)
 }
 
+/// `setsockopt(fd, SOL_SOCKET, SO_TIMESTAMP, value)`—Enable receive
+/// timestamps with microsecond resolution.
+///
+/// Timestamps are delivered as [`RecvAncillaryMessage::ScmTimestamp`]. Where
+/// available, this uses `SO_TIMESTAMP_NEW` so that the timestamps are
+/// y2038-safe on 32-bit platforms.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+/// [`RecvAncillaryMessage::ScmTimestamp`]: crate::net::RecvAncillaryMessage::ScmTimestamp
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_TIMESTAMP")]
+pub fn set_socket_timestamp<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_socket_timestamp(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_TIMESTAMP)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_TIMESTAMP")]
+pub fn socket_timestamp<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::socket_timestamp(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_TIMESTAMPNS, value)`—Enable receive
+/// timestamps with nanosecond resolution.
+///
+/// Timestamps are delivered as [`RecvAncillaryMessage::ScmTimestampns`].
+/// Where available, this uses `SO_TIMESTAMPNS_NEW` so that the timestamps are
+/// y2038-safe on 32-bit platforms.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+/// [`RecvAncillaryMessage::ScmTimestampns`]: crate::net::RecvAncillaryMessage::ScmTimestampns
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_TIMESTAMPNS")]
+pub fn set_socket_timestampns<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_socket_timestampns(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_TIMESTAMPNS)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_TIMESTAMPNS")]
+pub fn socket_timestampns<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::socket_timestampns(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_TIMESTAMPING, value)`—Configure
+/// software and hardware timestamp generation and reporting.
+///
+/// Timestamps are delivered as [`RecvAncillaryMessage::ScmTimestamping`].
+/// Where available, this uses `SO_TIMESTAMPING_NEW` so that the timestamps
+/// are y2038-safe on 32-bit platforms.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+/// [`RecvAncillaryMessage::ScmTimestamping`]: crate::net::RecvAncillaryMessage::ScmTimestamping
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_TIMESTAMPING")]
+pub fn set_socket_timestamping<Fd: AsFd>(fd: Fd, value: TimestampingFlags) -> io::Result<()> {
+    backend::net::sockopt::set_socket_timestamping(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_TIMESTAMPING)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_TIMESTAMPING")]
+pub fn socket_timestamping<Fd: AsFd>(fd: Fd) -> io::Result<TimestampingFlags> {
+    backend::net::sockopt::socket_timestamping(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_ZEROCOPY, value)`—Allow sends with
+/// [`SendFlags::ZEROCOPY`].
+///
+/// Completions are reported on the socket's error queue as
+/// [`RecvAncillaryMessage::SockExtendedErr`] messages.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+/// [`SendFlags::ZEROCOPY`]: crate::net::SendFlags::ZEROCOPY
+/// [`RecvAncillaryMessage::SockExtendedErr`]: crate::net::RecvAncillaryMessage::SockExtendedErr
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_ZEROCOPY")]
+pub fn set_socket_zerocopy<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_socket_zerocopy(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_ZEROCOPY)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_ZEROCOPY")]
+pub fn socket_zerocopy<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::socket_zerocopy(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_BINDTODEVICE, value)`—Only send and
+/// receive packets on the interface with the given name.
+///
+/// Pass an empty string to remove the binding.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_BINDTODEVICE")]
+pub fn set_socket_bindtodevice<Fd: AsFd>(fd: Fd, value: &str) -> io::Result<()> {
+    backend::net::sockopt::set_socket_bindtodevice(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_BINDTODEVICE)`
+///
+/// Returns an empty string if the socket isn't bound to an interface, and
+/// fails with [`io::Errno::INVAL`] if the interface's name isn't valid UTF-8.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(feature = "alloc")]
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_BINDTODEVICE")]
+pub fn socket_bindtodevice<Fd: AsFd>(fd: Fd) -> io::Result<String> {
+    backend::net::sockopt::socket_bindtodevice(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_BINDTOIFINDEX, value)`—Only send and
+/// receive packets on the interface with the given index.
+///
+/// Pass 0 to remove the binding.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_BINDTOIFINDEX")]
+pub fn set_socket_bindtoifindex<Fd: AsFd>(fd: Fd, value: u32) -> io::Result<()> {
+    backend::net::sockopt::set_socket_bindtoifindex(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_BINDTOIFINDEX)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_BINDTOIFINDEX")]
+pub fn socket_bindtoifindex<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    backend::net::sockopt::socket_bindtoifindex(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_MARK, value)`—Set the mark used
+/// for routing and packet filtering of packets sent on the socket.
+///
+/// This requires the `CAP_NET_ADMIN` or `CAP_NET_RAW` capability.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_MARK")]
+pub fn set_socket_mark<Fd: AsFd>(fd: Fd, value: u32) -> io::Result<()> {
+    backend::net::sockopt::set_socket_mark(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_MARK)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_MARK")]
+pub fn socket_mark<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    backend::net::sockopt::socket_mark(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_PRIORITY, value)`—Set the
+/// queueing priority of packets sent on the socket.
+///
+/// Priorities above 6 require the `CAP_NET_ADMIN` capability.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_PRIORITY")]
+pub fn set_socket_priority<Fd: AsFd>(fd: Fd, value: u32) -> io::Result<()> {
+    backend::net::sockopt::set_socket_priority(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_PRIORITY)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_PRIORITY")]
+pub fn socket_priority<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    backend::net::sockopt::socket_priority(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_ATTACH_FILTER, filter)`—Attach a classic
+/// BPF filter to a socket.
+///
+/// The filter is run on each incoming packet, and its return value is the
+/// number of bytes of the packet to keep, with 0 meaning to drop it.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_ATTACH_FILTER")]
+pub fn set_socket_attach_filter<Fd: AsFd>(fd: Fd, filter: &[SockFilter]) -> io::Result<()> {
+    let prog = SockFprog::new(filter)?;
+    backend::net::sockopt::set_socket_attach_filter(fd.as_fd(), &prog)
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_DETACH_FILTER, 0)`—Remove a socket's
+/// classic BPF filter.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_DETACH_FILTER")]
+pub fn set_socket_detach_filter<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    backend::net::sockopt::set_socket_detach_filter(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_LOCK_FILTER, value)`—Prevent the socket's
+/// filter from being changed or removed.
+///
+/// Once set, this can't be cleared.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_LOCK_FILTER")]
+pub fn set_socket_lock_filter<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_socket_lock_filter(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_LOCK_FILTER)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_LOCK_FILTER")]
+pub fn socket_lock_filter<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::socket_lock_filter(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_ATTACH_REUSEPORT_CBPF, filter)`—Attach a
+/// classic BPF program that selects which socket in a [`SO_REUSEPORT`] group
+/// receives each incoming packet or connection.
+///
+/// The program's return value is the index of the socket within the group,
+/// in the order the sockets were bound. If it's out of range, the kernel
+/// falls back to its default hash-based selection.
+///
+/// [`SO_REUSEPORT`]: set_socket_reuseport
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_ATTACH_REUSEPORT_CBPF")]
+pub fn set_socket_attach_reuseport_cbpf<Fd: AsFd>(fd: Fd, filter: &[SockFilter]) -> io::Result<()> {
+    let prog = SockFprog::new(filter)?;
+    backend::net::sockopt::set_socket_attach_reuseport_cbpf(fd.as_fd(), &prog)
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_DETACH_REUSEPORT_BPF, 0)`—Remove the
+/// program attached with [`set_socket_attach_reuseport_cbpf`] from a
+/// [`SO_REUSEPORT`] group.
+///
+/// [`SO_REUSEPORT`]: set_socket_reuseport
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_socket_-and-set_socket_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "SO_DETACH_REUSEPORT_BPF")]
+pub fn set_socket_detach_reuseport_bpf<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    backend::net::sockopt::set_socket_detach_reuseport_bpf(fd.as_fd())
+}
+
 /// `setsockopt(fd, IPPROTO_IP, IP_TTL, value)`
 ///
 /// See the [module-level documentation] for more.
@@ -1032,85 +1385,351 @@ pub fn ipv6_recvtclass<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
     backend::net::sockopt::ipv6_recvtclass(fd.as_fd())
 }
 
-/// `setsockopt(fd, IPPROTO_IP, IP_FREEBIND, value)`
+/// `setsockopt(fd, IPPROTO_IP, IP_PKTINFO, value)`—Enable
+/// [`RecvAncillaryMessage::IpPktInfo`] messages.
 ///
 /// See the [module-level documentation] for more.
 ///
-/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
-#[cfg(any(linux_kernel, target_os = "fuchsia"))]
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
+/// [`RecvAncillaryMessage::IpPktInfo`]: crate::net::RecvAncillaryMessage::IpPktInfo
+#[cfg(linux_kernel)]
 #[inline]
-#[doc(alias = "IP_FREEBIND")]
-pub fn set_ip_freebind<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
-    backend::net::sockopt::set_ip_freebind(fd.as_fd(), value)
+#[doc(alias = "IP_PKTINFO")]
+pub fn set_ip_pktinfo<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_ip_pktinfo(fd.as_fd(), value)
 }
 
-/// `getsockopt(fd, IPPROTO_IP, IP_FREEBIND)`
+/// `getsockopt(fd, IPPROTO_IP, IP_PKTINFO)`
 ///
 /// See the [module-level documentation] for more.
 ///
-/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
-#[cfg(any(linux_kernel, target_os = "fuchsia"))]
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
+#[cfg(linux_kernel)]
 #[inline]
-#[doc(alias = "IP_FREEBIND")]
-pub fn ip_freebind<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
-    backend::net::sockopt::ip_freebind(fd.as_fd())
+#[doc(alias = "IP_PKTINFO")]
+pub fn ip_pktinfo<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::ip_pktinfo(fd.as_fd())
 }
 
-/// `setsockopt(fd, IPPROTO_IPV6, IPV6_FREEBIND, value)`
+/// `setsockopt(fd, IPPROTO_IPV6, IPV6_RECVPKTINFO, value)`—Enable
+/// [`RecvAncillaryMessage::Ipv6PktInfo`] messages.
 ///
 /// See the [module-level documentation] for more.
 ///
 /// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+/// [`RecvAncillaryMessage::Ipv6PktInfo`]: crate::net::RecvAncillaryMessage::Ipv6PktInfo
 #[cfg(linux_kernel)]
 #[inline]
-#[doc(alias = "IPV6_FREEBIND")]
-pub fn set_ipv6_freebind<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
-    backend::net::sockopt::set_ipv6_freebind(fd.as_fd(), value)
+#[doc(alias = "IPV6_RECVPKTINFO")]
+pub fn set_ipv6_recvpktinfo<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_ipv6_recvpktinfo(fd.as_fd(), value)
 }
 
-/// `getsockopt(fd, IPPROTO_IPV6, IPV6_FREEBIND)`
+/// `getsockopt(fd, IPPROTO_IPV6, IPV6_RECVPKTINFO)`
 ///
 /// See the [module-level documentation] for more.
 ///
 /// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
 #[cfg(linux_kernel)]
 #[inline]
-#[doc(alias = "IPV6_FREEBIND")]
-pub fn ipv6_freebind<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
-    backend::net::sockopt::ipv6_freebind(fd.as_fd())
+#[doc(alias = "IPV6_RECVPKTINFO")]
+pub fn ipv6_recvpktinfo<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::ipv6_recvpktinfo(fd.as_fd())
 }
 
-/// `getsockopt(fd, IPPROTO_IP, SO_ORIGINAL_DST)`
+/// `setsockopt(fd, IPPROTO_IP, IP_RECVERR, value)`—Queue extended error
+/// information on the socket's error queue.
 ///
-/// Even though this corresponds to a `SO_*` constant, it is an `IPPROTO_IP`
-/// option.
+/// The errors can be read with [`recvmsg`] and [`RecvFlags::ERRQUEUE`], as
+/// [`RecvAncillaryMessage::SockExtendedErr`] messages.
 ///
 /// See the [module-level documentation] for more.
 ///
-/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
-#[cfg(any(linux_kernel, target_os = "fuchsia"))]
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
+/// [`recvmsg`]: crate::net::recvmsg
+/// [`RecvFlags::ERRQUEUE`]: crate::net::RecvFlags::ERRQUEUE
+/// [`RecvAncillaryMessage::SockExtendedErr`]: crate::net::RecvAncillaryMessage::SockExtendedErr
+#[cfg(linux_kernel)]
 #[inline]
-#[doc(alias = "SO_ORIGINAL_DST")]
-pub fn ip_original_dst<Fd: AsFd>(fd: Fd) -> io::Result<SocketAddrV4> {
-    backend::net::sockopt::ip_original_dst(fd.as_fd())
+#[doc(alias = "IP_RECVERR")]
+pub fn set_ip_recverr<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_ip_recverr(fd.as_fd(), value)
 }
 
-/// `getsockopt(fd, IPPROTO_IPV6, IP6T_SO_ORIGINAL_DST)`
+/// `getsockopt(fd, IPPROTO_IP, IP_RECVERR)`
 ///
-/// Even though this corresponds to a `IP6T_*` constant, it is an
-/// `IPPROTO_IPV6` option.
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IP_RECVERR")]
+pub fn ip_recverr<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::ip_recverr(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IPV6, IPV6_RECVERR, value)`—Queue extended error
+/// information on the socket's error queue.
+///
+/// This is the IPv6 counterpart of [`set_ip_recverr`].
 ///
 /// See the [module-level documentation] for more.
 ///
 /// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
 #[cfg(linux_kernel)]
 #[inline]
-#[doc(alias = "IP6T_SO_ORIGINAL_DST")]
-pub fn ipv6_original_dst<Fd: AsFd>(fd: Fd) -> io::Result<SocketAddrV6> {
-    backend::net::sockopt::ipv6_original_dst(fd.as_fd())
+#[doc(alias = "IPV6_RECVERR")]
+pub fn set_ipv6_recverr<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_ipv6_recverr(fd.as_fd(), value)
 }
 
-/// `setsockopt(fd, IPPROTO_IPV6, IPV6_TCLASS, value)`
+/// `getsockopt(fd, IPPROTO_IPV6, IPV6_RECVERR)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IPV6_RECVERR")]
+pub fn ipv6_recverr<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::ipv6_recverr(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IP, IP_TRANSPARENT, value)`—Allow binding
+/// to and receiving connections for non-local addresses.
+///
+/// This requires the `CAP_NET_ADMIN` or `CAP_NET_RAW` capability.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IP_TRANSPARENT")]
+pub fn set_ip_transparent<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_ip_transparent(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_IP, IP_TRANSPARENT)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IP_TRANSPARENT")]
+pub fn ip_transparent<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::ip_transparent(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IP, IP_BIND_ADDRESS_NO_PORT, value)`—Defer
+/// allocating an ephemeral port when binding to port 0 until the socket is
+/// connected.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IP_BIND_ADDRESS_NO_PORT")]
+pub fn set_ip_bind_address_no_port<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_ip_bind_address_no_port(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_IP, IP_BIND_ADDRESS_NO_PORT)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IP_BIND_ADDRESS_NO_PORT")]
+pub fn ip_bind_address_no_port<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::ip_bind_address_no_port(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IP, IP_MTU_DISCOVER, value)`—Set the path
+/// MTU discovery mode.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IP_MTU_DISCOVER")]
+pub fn set_ip_mtu_discover<Fd: AsFd>(fd: Fd, value: PmtuDiscovery) -> io::Result<()> {
+    backend::net::sockopt::set_ip_mtu_discover(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_IP, IP_MTU_DISCOVER)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IP_MTU_DISCOVER")]
+pub fn ip_mtu_discover<Fd: AsFd>(fd: Fd) -> io::Result<PmtuDiscovery> {
+    backend::net::sockopt::ip_mtu_discover(fd.as_fd())
+}
+
+/// `getsockopt(fd, IPPROTO_IP, IP_MTU)`—Returns the
+/// current known path MTU of a connected socket.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ip_-and-set_ip_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IP_MTU")]
+pub fn ip_mtu<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    backend::net::sockopt::ip_mtu(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IPV6, IPV6_TRANSPARENT, value)`—Allow binding
+/// to and receiving connections for non-local addresses.
+///
+/// This requires the `CAP_NET_ADMIN` or `CAP_NET_RAW` capability.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IPV6_TRANSPARENT")]
+pub fn set_ipv6_transparent<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_ipv6_transparent(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_IPV6, IPV6_TRANSPARENT)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IPV6_TRANSPARENT")]
+pub fn ipv6_transparent<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::ipv6_transparent(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IPV6, IPV6_MTU_DISCOVER, value)`—Set the
+/// path MTU discovery mode.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IPV6_MTU_DISCOVER")]
+pub fn set_ipv6_mtu_discover<Fd: AsFd>(fd: Fd, value: PmtuDiscovery) -> io::Result<()> {
+    backend::net::sockopt::set_ipv6_mtu_discover(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_IPV6, IPV6_MTU_DISCOVER)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IPV6_MTU_DISCOVER")]
+pub fn ipv6_mtu_discover<Fd: AsFd>(fd: Fd) -> io::Result<PmtuDiscovery> {
+    backend::net::sockopt::ipv6_mtu_discover(fd.as_fd())
+}
+
+/// `getsockopt(fd, IPPROTO_IPV6, IPV6_MTU)`—Returns the
+/// current known path MTU of a connected socket.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IPV6_MTU")]
+pub fn ipv6_mtu<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    backend::net::sockopt::ipv6_mtu(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IP, IP_FREEBIND, value)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+#[cfg(any(linux_kernel, target_os = "fuchsia"))]
+#[inline]
+#[doc(alias = "IP_FREEBIND")]
+pub fn set_ip_freebind<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_ip_freebind(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_IP, IP_FREEBIND)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+#[cfg(any(linux_kernel, target_os = "fuchsia"))]
+#[inline]
+#[doc(alias = "IP_FREEBIND")]
+pub fn ip_freebind<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::ip_freebind(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IPV6, IPV6_FREEBIND, value)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IPV6_FREEBIND")]
+pub fn set_ipv6_freebind<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_ipv6_freebind(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_IPV6, IPV6_FREEBIND)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IPV6_FREEBIND")]
+pub fn ipv6_freebind<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::ipv6_freebind(fd.as_fd())
+}
+
+/// `getsockopt(fd, IPPROTO_IP, SO_ORIGINAL_DST)`
+///
+/// Even though this corresponds to a `SO_*` constant, it is an `IPPROTO_IP`
+/// option.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+#[cfg(any(linux_kernel, target_os = "fuchsia"))]
+#[inline]
+#[doc(alias = "SO_ORIGINAL_DST")]
+pub fn ip_original_dst<Fd: AsFd>(fd: Fd) -> io::Result<SocketAddrV4> {
+    backend::net::sockopt::ip_original_dst(fd.as_fd())
+}
+
+/// `getsockopt(fd, IPPROTO_IPV6, IP6T_SO_ORIGINAL_DST)`
+///
+/// Even though this corresponds to a `IP6T_*` constant, it is an
+/// `IPPROTO_IPV6` option.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_ipv6_-and-set_ipv6_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "IP6T_SO_ORIGINAL_DST")]
+pub fn ipv6_original_dst<Fd: AsFd>(fd: Fd) -> io::Result<SocketAddrV6> {
+    backend::net::sockopt::ipv6_original_dst(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IPV6, IPV6_TCLASS, value)`
 ///
 /// See the [module-level documentation] for more.
 ///
@@ -1375,6 +1994,346 @@ pub fn tcp_cork<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
     backend::net::sockopt::tcp_cork(fd.as_fd())
 }
 
+/// `getsockopt(fd, IPPROTO_TCP, TCP_INFO)`—Returns information about a
+/// TCP connection, such as its round trip time and retransmission counts.
+///
+/// This also returns the number of bytes of the [`TcpInfo`] that the kernel
+/// filled in. Older kernels fill in a shorter prefix, and the fields past it
+/// are zero; see [`TcpInfo`] for which fields are valid for a given length.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_tcp_-and-set_tcp_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TCP_INFO")]
+pub fn tcp_info<Fd: AsFd>(fd: Fd) -> io::Result<(TcpInfo, usize)> {
+    backend::net::sockopt::tcp_info(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_TCP, TCP_MAXSEG, value)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_tcp_-and-set_tcp_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TCP_MAXSEG")]
+pub fn set_tcp_maxseg<Fd: AsFd>(fd: Fd, value: u32) -> io::Result<()> {
+    backend::net::sockopt::set_tcp_maxseg(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_MAXSEG)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_tcp_-and-set_tcp_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TCP_MAXSEG")]
+pub fn tcp_maxseg<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    backend::net::sockopt::tcp_maxseg(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_TCP, TCP_DEFER_ACCEPT, value)`
+///
+/// Only wake up a listening socket's `accept` once data has arrived, or
+/// the given time has passed. The kernel rounds the time to a number of
+/// SYN-ACK retransmissions.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_tcp_-and-set_tcp_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TCP_DEFER_ACCEPT")]
+pub fn set_tcp_defer_accept<Fd: AsFd>(fd: Fd, value: Duration) -> io::Result<()> {
+    backend::net::sockopt::set_tcp_defer_accept(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_DEFER_ACCEPT)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_tcp_-and-set_tcp_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TCP_DEFER_ACCEPT")]
+pub fn tcp_defer_accept<Fd: AsFd>(fd: Fd) -> io::Result<Duration> {
+    backend::net::sockopt::tcp_defer_accept(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_TCP, TCP_WINDOW_CLAMP, value)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_tcp_-and-set_tcp_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TCP_WINDOW_CLAMP")]
+pub fn set_tcp_window_clamp<Fd: AsFd>(fd: Fd, value: u32) -> io::Result<()> {
+    backend::net::sockopt::set_tcp_window_clamp(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_WINDOW_CLAMP)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_tcp_-and-set_tcp_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TCP_WINDOW_CLAMP")]
+pub fn tcp_window_clamp<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    backend::net::sockopt::tcp_window_clamp(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_TCP, TCP_FASTOPEN, value)`
+///
+/// Enable TCP Fast Open on a listening socket, with the given maximum
+/// queue length of pending Fast Open requests.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_tcp_-and-set_tcp_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TCP_FASTOPEN")]
+pub fn set_tcp_fastopen<Fd: AsFd>(fd: Fd, value: u32) -> io::Result<()> {
+    backend::net::sockopt::set_tcp_fastopen(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_FASTOPEN)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_tcp_-and-set_tcp_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TCP_FASTOPEN")]
+pub fn tcp_fastopen<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    backend::net::sockopt::tcp_fastopen(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_TCP, TCP_FASTOPEN_CONNECT, value)`
+///
+/// Use TCP Fast Open for the data of the first `send` on a connecting
+/// socket.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_tcp_-and-set_tcp_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TCP_FASTOPEN_CONNECT")]
+pub fn set_tcp_fastopen_connect<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_tcp_fastopen_connect(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_FASTOPEN_CONNECT)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_tcp_-and-set_tcp_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TCP_FASTOPEN_CONNECT")]
+pub fn tcp_fastopen_connect<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::tcp_fastopen_connect(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_TCP, TCP_NOTSENT_LOWAT, value)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_tcp_-and-set_tcp_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TCP_NOTSENT_LOWAT")]
+pub fn set_tcp_notsent_lowat<Fd: AsFd>(fd: Fd, value: u32) -> io::Result<()> {
+    backend::net::sockopt::set_tcp_notsent_lowat(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_NOTSENT_LOWAT)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_tcp_-and-set_tcp_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TCP_NOTSENT_LOWAT")]
+pub fn tcp_notsent_lowat<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    backend::net::sockopt::tcp_notsent_lowat(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_TCP, TCP_SAVE_SYN, value)`
+///
+/// Save the headers of the SYN packet of connections accepted by a
+/// listening socket, for use with [`tcp_saved_syn`].
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_tcp_-and-set_tcp_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TCP_SAVE_SYN")]
+pub fn set_tcp_save_syn<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_tcp_save_syn(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_SAVE_SYN)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_tcp_-and-set_tcp_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TCP_SAVE_SYN")]
+pub fn tcp_save_syn<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::tcp_save_syn(fd.as_fd())
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_SAVED_SYN)`—Returns the IP and TCP
+/// headers of the SYN packet that opened an accepted connection.
+///
+/// The listening socket must have had [`set_tcp_save_syn`] enabled. The
+/// kernel only returns the headers once; later calls return an empty
+/// `Vec`.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_tcp_-and-set_tcp_-functions
+#[cfg(feature = "alloc")]
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TCP_SAVED_SYN")]
+pub fn tcp_saved_syn<Fd: AsFd>(fd: Fd) -> io::Result<Vec<u8>> {
+    backend::net::sockopt::tcp_saved_syn(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_TCP, TCP_ULP, value)`—Attach an upper layer
+/// protocol, such as `"tls"`, to a TCP socket.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_tcp_-and-set_tcp_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TCP_ULP")]
+pub fn set_tcp_ulp<Fd: AsFd>(fd: Fd, value: &str) -> io::Result<()> {
+    backend::net::sockopt::set_tcp_ulp(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_ULP)`
+///
+/// Returns an empty string if no upper layer protocol is attached, and fails
+/// with [`io::Errno::INVAL`] if the protocol's name isn't valid UTF-8.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_tcp_-and-set_tcp_-functions
+#[cfg(feature = "alloc")]
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TCP_ULP")]
+pub fn tcp_ulp<Fd: AsFd>(fd: Fd) -> io::Result<String> {
+    backend::net::sockopt::tcp_ulp(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_UDP, UDP_SEGMENT, value)`—Set the segment size
+/// for UDP generic segmentation offload (GSO).
+///
+/// Datagrams sent on the socket that are larger than `value` are split into
+/// segments of `value` bytes. A value of 0 disables segmentation. This can
+/// be overridden for individual sends with
+/// [`SendAncillaryMessage::UdpGsoSegments`].
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_udp_-and-set_udp_-functions
+/// [`SendAncillaryMessage::UdpGsoSegments`]: crate::net::SendAncillaryMessage::UdpGsoSegments
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "UDP_SEGMENT")]
+pub fn set_udp_segment<Fd: AsFd>(fd: Fd, value: u16) -> io::Result<()> {
+    backend::net::sockopt::set_udp_segment(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_UDP, UDP_SEGMENT)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_udp_-and-set_udp_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "UDP_SEGMENT")]
+pub fn udp_segment<Fd: AsFd>(fd: Fd) -> io::Result<u16> {
+    backend::net::sockopt::udp_segment(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_UDP, UDP_GRO, value)`—Enable UDP generic receive
+/// offload (GRO).
+///
+/// When enabled, the kernel may coalesce consecutive datagrams into one
+/// buffer, and reports the segment size with
+/// [`RecvAncillaryMessage::UdpGroSegments`].
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_udp_-and-set_udp_-functions
+/// [`RecvAncillaryMessage::UdpGroSegments`]: crate::net::RecvAncillaryMessage::UdpGroSegments
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "UDP_GRO")]
+pub fn set_udp_gro<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::sockopt::set_udp_gro(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_UDP, UDP_GRO)`
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_udp_-and-set_udp_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "UDP_GRO")]
+pub fn udp_gro<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::sockopt::udp_gro(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_TLS, TLS_TX, value)`—Set the transmit keys of a
+/// kernel TLS socket.
+///
+/// The socket must be a connected TCP socket with the `"tls"` upper layer
+/// protocol attached with [`set_tcp_ulp`]. Once set, data written to the
+/// socket is sent as encrypted TLS application data records.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_tls_-and-set_tls_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TLS_TX")]
+pub fn set_tls_tx<Fd: AsFd>(fd: Fd, value: &TlsCryptoInfo) -> io::Result<()> {
+    backend::net::sockopt::set_tls_tx(fd.as_fd(), value)
+}
+
+/// `setsockopt(fd, SOL_TLS, TLS_RX, value)`—Set the receive keys of a
+/// kernel TLS socket.
+///
+/// The socket must be a connected TCP socket with the `"tls"` upper layer
+/// protocol attached with [`set_tcp_ulp`]. Once set, data read from the
+/// socket is decrypted.
+///
+/// See the [module-level documentation] for more.
+///
+/// [module-level documentation]: self#references-for-get_tls_-and-set_tls_-functions
+#[cfg(linux_kernel)]
+#[inline]
+#[doc(alias = "TLS_RX")]
+pub fn set_tls_rx<Fd: AsFd>(fd: Fd, value: &TlsCryptoInfo) -> io::Result<()> {
+    backend::net::sockopt::set_tls_rx(fd.as_fd(), value)
+}
+
 /// `getsockopt(fd, SOL_SOCKET, SO_PEERCRED)`—Get credentials of Unix domain
 /// socket peer process.
 ///
@@ -1486,6 +2445,132 @@ pub fn xdp_options<Fd: AsFd>(fd: Fd) -> io::Result<XdpOptionsFlags> {
     backend::net::sockopt::xdp_options(fd.as_fd())
 }
 
+/// `setsockopt(fd, SOL_PACKET, PACKET_ADD_MEMBERSHIP, value)`
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/packet.7.html
+#[cfg(target_os = "linux")]
+#[inline]
+#[doc(alias = "PACKET_ADD_MEMBERSHIP")]
+pub fn set_packet_add_membership<Fd: AsFd>(fd: Fd, value: PacketMreq) -> io::Result<()> {
+    backend::net::sockopt::set_packet_add_membership(fd.as_fd(), value)
+}
+
+/// `setsockopt(fd, SOL_PACKET, PACKET_DROP_MEMBERSHIP, value)`
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/packet.7.html
+#[cfg(target_os = "linux")]
+#[inline]
+#[doc(alias = "PACKET_DROP_MEMBERSHIP")]
+pub fn set_packet_drop_membership<Fd: AsFd>(fd: Fd, value: PacketMreq) -> io::Result<()> {
+    backend::net::sockopt::set_packet_drop_membership(fd.as_fd(), value)
+}
+
+/// `setsockopt(fd, SOL_PACKET, PACKET_VERSION, value)`
+///
+/// The version must be set before setting up a ring with
+/// [`set_packet_rx_ring`] or [`set_packet_tx_ring`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/networking/packet_mmap.html
+#[cfg(target_os = "linux")]
+#[inline]
+#[doc(alias = "PACKET_VERSION")]
+pub fn set_packet_version<Fd: AsFd>(fd: Fd, value: PacketVersion) -> io::Result<()> {
+    backend::net::sockopt::set_packet_version(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, SOL_PACKET, PACKET_VERSION)`
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/networking/packet_mmap.html
+#[cfg(target_os = "linux")]
+#[inline]
+#[doc(alias = "PACKET_VERSION")]
+pub fn packet_version<Fd: AsFd>(fd: Fd) -> io::Result<PacketVersion> {
+    backend::net::sockopt::packet_version(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_PACKET, PACKET_FANOUT, value)`—Adds the socket to a
+/// fanout group, creating the group if it doesn't exist.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/packet.7.html
+#[cfg(target_os = "linux")]
+#[inline]
+#[doc(alias = "PACKET_FANOUT")]
+pub fn set_packet_fanout<Fd: AsFd>(fd: Fd, value: PacketFanout) -> io::Result<()> {
+    backend::net::sockopt::set_packet_fanout(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, SOL_PACKET, PACKET_FANOUT)`—Returns the fanout group the
+/// socket is in, if any.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/packet.7.html
+#[cfg(target_os = "linux")]
+#[inline]
+#[doc(alias = "PACKET_FANOUT")]
+pub fn packet_fanout<Fd: AsFd>(fd: Fd) -> io::Result<Option<PacketFanout>> {
+    backend::net::sockopt::packet_fanout(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_PACKET, PACKET_RX_RING, value)`—Sets up a
+/// memory-mapped receive ring.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/networking/packet_mmap.html
+#[cfg(target_os = "linux")]
+#[inline]
+#[doc(alias = "PACKET_RX_RING")]
+pub fn set_packet_rx_ring<Fd: AsFd>(fd: Fd, value: TpacketReq3) -> io::Result<()> {
+    backend::net::sockopt::set_packet_rx_ring(fd.as_fd(), value)
+}
+
+/// `setsockopt(fd, SOL_PACKET, PACKET_TX_RING, value)`—Sets up a
+/// memory-mapped transmit ring.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/networking/packet_mmap.html
+#[cfg(target_os = "linux")]
+#[inline]
+#[doc(alias = "PACKET_TX_RING")]
+pub fn set_packet_tx_ring<Fd: AsFd>(fd: Fd, value: TpacketReq3) -> io::Result<()> {
+    backend::net::sockopt::set_packet_tx_ring(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, SOL_PACKET, PACKET_STATISTICS)`
+///
+/// Reading the statistics resets the kernel's counters.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/packet.7.html
+#[cfg(target_os = "linux")]
+#[inline]
+#[doc(alias = "PACKET_STATISTICS")]
+pub fn packet_statistics<Fd: AsFd>(fd: Fd) -> io::Result<PacketStats> {
+    backend::net::sockopt::packet_statistics(fd.as_fd())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -613,7 +613,7 @@ impl AddressFamily {
     #[cfg(apple)]
     pub const UTUN: Self = Self(c::AF_UTUN as _);
     /// `AF_VSOCK`
-    #[cfg(any(apple, target_os = "emscripten", target_os = "fuchsia"))]
+    #[cfg(any(apple, linux_kernel, target_os = "emscripten", target_os = "fuchsia"))]
     pub const VSOCK: Self = Self(c::AF_VSOCK as _);
     /// `AF_XDP`
     #[cfg(target_os = "linux")]
@@ -1053,6 +1053,64 @@ pub mod netlink {
     /// `NETLINK_GET_STRICT_CHK`
     #[cfg(linux_kernel)]
     pub const GET_STRICT_CHK: Protocol = Protocol(new_raw_protocol(c::NETLINK_GET_STRICT_CHK as _));
+
+    #[cfg(linux_kernel)]
+    pub use crate::net::nlmsg::{NlMsg, NlMsgBuilder, NlMsgFlags, NlMsgHdr, NlMsgIter};
+
+    /// A netlink socket address.
+    ///
+    /// Used to bind to a netlink socket, and to address messages to the
+    /// kernel (port ID 0) or to other netlink sockets.
+    ///
+    /// Not ABI compatible with `struct sockaddr_nl`
+    #[cfg(linux_kernel)]
+    #[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Debug)]
+    #[doc(alias = "sockaddr_nl")]
+    pub struct SocketAddrNetlink {
+        /// Port ID.
+        nl_pid: u32,
+        /// Multicast groups mask.
+        nl_groups: u32,
+    }
+
+    #[cfg(linux_kernel)]
+    impl SocketAddrNetlink {
+        /// Construct a new netlink address.
+        ///
+        /// When binding, a `pid` of 0 asks the kernel to assign a unique port
+        /// ID. When sending, a `pid` of 0 addresses the kernel.
+        #[inline]
+        pub const fn new(pid: u32, groups: u32) -> Self {
+            Self {
+                nl_pid: pid,
+                nl_groups: groups,
+            }
+        }
+
+        /// Return port ID.
+        #[inline]
+        pub const fn pid(&self) -> u32 {
+            self.nl_pid
+        }
+
+        /// Set port ID.
+        #[inline]
+        pub fn set_pid(&mut self, pid: u32) {
+            self.nl_pid = pid;
+        }
+
+        /// Return multicast groups mask.
+        #[inline]
+        pub const fn groups(&self) -> u32 {
+            self.nl_groups
+        }
+
+        /// Set multicast groups mask.
+        #[inline]
+        pub fn set_groups(&mut self, groups: u32) {
+            self.nl_groups = groups;
+        }
+    }
 }
 
 /// `ETH_P_*` constants.
@@ -1477,6 +1535,106 @@ bitflags! {
     }
 }
 
+#[cfg(linux_kernel)]
+bitflags! {
+    /// `SOF_TIMESTAMPING_*` constants for use with [`set_socket_timestamping`]
+    /// and [`socket_timestamping`].
+    ///
+    /// [`set_socket_timestamping`]: crate::net::sockopt::set_socket_timestamping
+    /// [`socket_timestamping`]: crate::net::sockopt::socket_timestamping
+    // https://github.com/torvalds/linux/blob/v6.12/include/uapi/linux/net_tstamp.h#L18-L48
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct TimestampingFlags: u32 {
+        /// `SOF_TIMESTAMPING_TX_HARDWARE`
+        const TX_HARDWARE = 1 << 0;
+        /// `SOF_TIMESTAMPING_TX_SOFTWARE`
+        const TX_SOFTWARE = 1 << 1;
+        /// `SOF_TIMESTAMPING_RX_HARDWARE`
+        const RX_HARDWARE = 1 << 2;
+        /// `SOF_TIMESTAMPING_RX_SOFTWARE`
+        const RX_SOFTWARE = 1 << 3;
+        /// `SOF_TIMESTAMPING_SOFTWARE`
+        const SOFTWARE = 1 << 4;
+        /// `SOF_TIMESTAMPING_SYS_HARDWARE`
+        const SYS_HARDWARE = 1 << 5;
+        /// `SOF_TIMESTAMPING_RAW_HARDWARE`
+        const RAW_HARDWARE = 1 << 6;
+        /// `SOF_TIMESTAMPING_OPT_ID`
+        const OPT_ID = 1 << 7;
+        /// `SOF_TIMESTAMPING_TX_SCHED`
+        const TX_SCHED = 1 << 8;
+        /// `SOF_TIMESTAMPING_TX_ACK`
+        const TX_ACK = 1 << 9;
+        /// `SOF_TIMESTAMPING_OPT_CMSG`
+        const OPT_CMSG = 1 << 10;
+        /// `SOF_TIMESTAMPING_OPT_TSONLY`
+        const OPT_TSONLY = 1 << 11;
+        /// `SOF_TIMESTAMPING_OPT_STATS`
+        const OPT_STATS = 1 << 12;
+        /// `SOF_TIMESTAMPING_OPT_PKTINFO`
+        const OPT_PKTINFO = 1 << 13;
+        /// `SOF_TIMESTAMPING_OPT_TX_SWHW`
+        const OPT_TX_SWHW = 1 << 14;
+        /// `SOF_TIMESTAMPING_BIND_PHC`
+        const BIND_PHC = 1 << 15;
+        /// `SOF_TIMESTAMPING_OPT_ID_TCP`
+        const OPT_ID_TCP = 1 << 16;
+        /// `SOF_TIMESTAMPING_OPT_RX_FILTER`
+        const OPT_RX_FILTER = 1 << 17;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// `IP_PMTUDISC_*` and `IPV6_PMTUDISC_*` constants for use with
+/// [`set_ip_mtu_discover`] and [`set_ipv6_mtu_discover`].
+///
+/// [`set_ip_mtu_discover`]: crate::net::sockopt::set_ip_mtu_discover
+/// [`set_ipv6_mtu_discover`]: crate::net::sockopt::set_ipv6_mtu_discover
+#[cfg(linux_kernel)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[repr(transparent)]
+pub struct PmtuDiscovery(u32);
+
+#[cfg(linux_kernel)]
+impl PmtuDiscovery {
+    /// `IP_PMTUDISC_DONT`—Never set the Don't Fragment flag.
+    #[doc(alias = "IP_PMTUDISC_DONT")]
+    pub const DONT: Self = Self(0);
+    /// `IP_PMTUDISC_WANT`—Use per-route settings.
+    #[doc(alias = "IP_PMTUDISC_WANT")]
+    pub const WANT: Self = Self(1);
+    /// `IP_PMTUDISC_DO`—Always set the Don't Fragment flag, and perform path
+    /// MTU discovery.
+    #[doc(alias = "IP_PMTUDISC_DO")]
+    pub const DO: Self = Self(2);
+    /// `IP_PMTUDISC_PROBE`—Set the Don't Fragment flag, but ignore the path
+    /// MTU.
+    #[doc(alias = "IP_PMTUDISC_PROBE")]
+    pub const PROBE: Self = Self(3);
+    /// `IP_PMTUDISC_INTERFACE`—Use the interface MTU, and ignore the path
+    /// MTU.
+    #[doc(alias = "IP_PMTUDISC_INTERFACE")]
+    pub const INTERFACE: Self = Self(4);
+    /// `IP_PMTUDISC_OMIT`—Like `INTERFACE`, but allow fragmentation.
+    #[doc(alias = "IP_PMTUDISC_OMIT")]
+    pub const OMIT: Self = Self(5);
+
+    /// Constructs a `PmtuDiscovery` from a raw integer.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw integer for this `PmtuDiscovery`.
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        self.0
+    }
+}
+
 /// `AF_XDP` related types and constants.
 #[cfg(target_os = "linux")]
 pub mod xdp {
@@ -1764,6 +1922,643 @@ pub mod xdp {
     pub const XSK_UNALIGNED_BUF_ADDR_MASK: u64 = c::XSK_UNALIGNED_BUF_ADDR_MASK;
 }
 
+/// `AF_PACKET` related types and constants.
+#[cfg(linux_kernel)]
+pub mod packet {
+    use super::{bitflags, Protocol, RawProtocol};
+    use crate::io;
+
+    /// A link-layer socket address.
+    ///
+    /// Used to bind packet sockets to an interface and to address packets
+    /// sent on them, and reported as the source of received packets.
+    ///
+    /// Not ABI compatible with `struct sockaddr_ll`
+    // https://github.com/torvalds/linux/blob/v6.6/include/uapi/linux/if_packet.h#L14-L22
+    #[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Debug)]
+    #[doc(alias = "sockaddr_ll")]
+    pub struct SocketAddrLink {
+        /// Protocol, in network byte order.
+        sll_protocol: u16,
+        /// Interface index.
+        sll_ifindex: u32,
+        /// ARP hardware type.
+        sll_hatype: u16,
+        /// Packet type.
+        sll_pkttype: PacketType,
+        /// Length of the hardware address.
+        sll_halen: u8,
+        /// Hardware address.
+        sll_addr: [u8; 8],
+    }
+
+    impl SocketAddrLink {
+        /// Construct a new link-layer address for the given protocol, such
+        /// as [`eth::ALL`], and interface index.
+        ///
+        /// An interface index of 0 matches any interface when binding.
+        ///
+        /// [`eth::ALL`]: crate::net::eth::ALL
+        #[inline]
+        pub const fn new(protocol: Option<Protocol>, interface_index: u32) -> Self {
+            Self {
+                sll_protocol: match protocol {
+                    Some(protocol) => protocol.as_raw().get() as u16,
+                    None => 0,
+                },
+                sll_ifindex: interface_index,
+                sll_hatype: 0,
+                sll_pkttype: PacketType::HOST,
+                sll_halen: 0,
+                sll_addr: [0; 8],
+            }
+        }
+
+        /// Construct a link-layer address from its raw fields, as decoded
+        /// from a `struct sockaddr_ll`.
+        #[inline]
+        pub(crate) fn from_raw_parts(
+            protocol: u16,
+            interface_index: u32,
+            hardware_type: u16,
+            packet_type: u8,
+            address: &[u8],
+        ) -> Self {
+            let mut sll_addr = [0; 8];
+            let len = address.len().min(sll_addr.len());
+            sll_addr[..len].copy_from_slice(&address[..len]);
+            Self {
+                sll_protocol: protocol,
+                sll_ifindex: interface_index,
+                sll_hatype: hardware_type,
+                sll_pkttype: PacketType(packet_type),
+                sll_halen: len as u8,
+                sll_addr,
+            }
+        }
+
+        /// Return protocol.
+        #[inline]
+        pub const fn protocol(&self) -> Option<Protocol> {
+            match RawProtocol::new(self.sll_protocol as u32) {
+                Some(raw) => Some(Protocol::from_raw(raw)),
+                None => None,
+            }
+        }
+
+        /// Set protocol.
+        #[inline]
+        pub fn set_protocol(&mut self, protocol: Option<Protocol>) {
+            self.sll_protocol = protocol.map_or(0, |protocol| protocol.as_raw().get() as u16);
+        }
+
+        /// Return interface index.
+        #[inline]
+        pub const fn interface_index(&self) -> u32 {
+            self.sll_ifindex
+        }
+
+        /// Set interface index.
+        #[inline]
+        pub fn set_interface_index(&mut self, interface_index: u32) {
+            self.sll_ifindex = interface_index;
+        }
+
+        /// Return ARP hardware type, one of the `ARPHRD_*` values.
+        #[inline]
+        pub const fn hardware_type(&self) -> u16 {
+            self.sll_hatype
+        }
+
+        /// Return packet type.
+        #[inline]
+        pub const fn packet_type(&self) -> PacketType {
+            self.sll_pkttype
+        }
+
+        /// Return hardware address.
+        #[inline]
+        pub fn address(&self) -> &[u8] {
+            &self.sll_addr[..self.sll_halen as usize]
+        }
+
+        /// Set hardware address, used as the destination when sending.
+        ///
+        /// This fails with [`io::Errno::INVAL`] if `address` is longer than
+        /// 8 bytes.
+        #[inline]
+        pub fn set_address(&mut self, address: &[u8]) -> io::Result<()> {
+            if address.len() > self.sll_addr.len() {
+                return Err(io::Errno::INVAL);
+            }
+            self.sll_addr = [0; 8];
+            self.sll_addr[..address.len()].copy_from_slice(address);
+            self.sll_halen = address.len() as u8;
+            Ok(())
+        }
+    }
+
+    /// `PACKET_*` constants for the type of a received packet, as reported
+    /// by [`SocketAddrLink::packet_type`].
+    #[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Debug)]
+    #[repr(transparent)]
+    pub struct PacketType(u8);
+
+    impl PacketType {
+        /// `PACKET_HOST`
+        pub const HOST: Self = Self(0);
+        /// `PACKET_BROADCAST`
+        pub const BROADCAST: Self = Self(1);
+        /// `PACKET_MULTICAST`
+        pub const MULTICAST: Self = Self(2);
+        /// `PACKET_OTHERHOST`
+        pub const OTHERHOST: Self = Self(3);
+        /// `PACKET_OUTGOING`
+        pub const OUTGOING: Self = Self(4);
+        /// `PACKET_LOOPBACK`
+        pub const LOOPBACK: Self = Self(5);
+        /// `PACKET_USER`
+        pub const USER: Self = Self(6);
+        /// `PACKET_KERNEL`
+        pub const KERNEL: Self = Self(7);
+
+        /// Constructs a `PacketType` from a raw integer.
+        #[inline]
+        pub const fn from_raw(raw: u8) -> Self {
+            Self(raw)
+        }
+
+        /// Returns the raw integer for this `PacketType`.
+        #[inline]
+        pub const fn as_raw(self) -> u8 {
+            self.0
+        }
+    }
+
+    /// `PACKET_MR_*` constants for use in [`PacketMreq`].
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+    #[repr(u16)]
+    pub enum PacketMreqType {
+        /// `PACKET_MR_MULTICAST`—Join the multicast group given by the
+        /// request's address.
+        Multicast = 0,
+        /// `PACKET_MR_PROMISC`—Put the interface in promiscuous mode.
+        Promisc = 1,
+        /// `PACKET_MR_ALLMULTI`—Receive all multicast packets.
+        Allmulti = 2,
+        /// `PACKET_MR_UNICAST`—Add the request's address to the interface's
+        /// unicast filter.
+        Unicast = 3,
+    }
+
+    /// A packet socket membership request, for use with
+    /// [`set_packet_add_membership`] and [`set_packet_drop_membership`].
+    ///
+    /// `struct packet_mreq`
+    ///
+    /// [`set_packet_add_membership`]: crate::net::sockopt::set_packet_add_membership
+    /// [`set_packet_drop_membership`]: crate::net::sockopt::set_packet_drop_membership
+    // https://github.com/torvalds/linux/blob/v6.6/include/uapi/linux/if_packet.h#L293-L298
+    #[repr(C)]
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+    #[doc(alias = "packet_mreq")]
+    pub struct PacketMreq {
+        /// Interface index.
+        mr_ifindex: u32,
+        /// Request type.
+        mr_type: PacketMreqType,
+        /// Length of the address.
+        mr_alen: u16,
+        /// Address.
+        mr_address: [u8; 8],
+    }
+
+    impl PacketMreq {
+        /// Construct a new membership request with an empty address.
+        #[inline]
+        pub const fn new(interface_index: u32, mr_type: PacketMreqType) -> Self {
+            Self {
+                mr_ifindex: interface_index,
+                mr_type,
+                mr_alen: 0,
+                mr_address: [0; 8],
+            }
+        }
+
+        /// Return interface index.
+        #[inline]
+        pub const fn interface_index(&self) -> u32 {
+            self.mr_ifindex
+        }
+
+        /// Return request type.
+        #[inline]
+        pub const fn mr_type(&self) -> PacketMreqType {
+            self.mr_type
+        }
+
+        /// Return address.
+        #[inline]
+        pub fn address(&self) -> &[u8] {
+            &self.mr_address[..self.mr_alen as usize]
+        }
+
+        /// Set address, for [`PacketMreqType::Multicast`] and
+        /// [`PacketMreqType::Unicast`] requests.
+        ///
+        /// This fails with [`io::Errno::INVAL`] if `address` is longer than
+        /// 8 bytes.
+        #[inline]
+        pub fn set_address(&mut self, address: &[u8]) -> io::Result<()> {
+            if address.len() > self.mr_address.len() {
+                return Err(io::Errno::INVAL);
+            }
+            self.mr_address = [0; 8];
+            self.mr_address[..address.len()].copy_from_slice(address);
+            self.mr_alen = address.len() as u16;
+            Ok(())
+        }
+    }
+
+    /// `TPACKET_V*` constants for use with [`set_packet_version`].
+    ///
+    /// [`set_packet_version`]: crate::net::sockopt::set_packet_version
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+    #[repr(u32)]
+    pub enum PacketVersion {
+        /// `TPACKET_V1`
+        #[doc(alias = "TPACKET_V1")]
+        V1 = 0,
+        /// `TPACKET_V2`
+        #[doc(alias = "TPACKET_V2")]
+        V2 = 1,
+        /// `TPACKET_V3`
+        #[doc(alias = "TPACKET_V3")]
+        V3 = 2,
+    }
+
+    /// `PACKET_FANOUT_*` constants for the mode of a [`PacketFanout`] group.
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+    #[repr(transparent)]
+    pub struct PacketFanoutMode(u16);
+
+    impl PacketFanoutMode {
+        /// `PACKET_FANOUT_HASH`
+        pub const HASH: Self = Self(0);
+        /// `PACKET_FANOUT_LB`
+        pub const LB: Self = Self(1);
+        /// `PACKET_FANOUT_CPU`
+        pub const CPU: Self = Self(2);
+        /// `PACKET_FANOUT_ROLLOVER`
+        pub const ROLLOVER: Self = Self(3);
+        /// `PACKET_FANOUT_RND`
+        pub const RND: Self = Self(4);
+        /// `PACKET_FANOUT_QM`
+        pub const QM: Self = Self(5);
+        /// `PACKET_FANOUT_CBPF`
+        pub const CBPF: Self = Self(6);
+        /// `PACKET_FANOUT_EBPF`
+        pub const EBPF: Self = Self(7);
+
+        /// Constructs a `PacketFanoutMode` from a raw integer.
+        #[inline]
+        pub const fn from_raw(raw: u16) -> Self {
+            Self(raw)
+        }
+
+        /// Returns the raw integer for this `PacketFanoutMode`.
+        #[inline]
+        pub const fn as_raw(self) -> u16 {
+            self.0
+        }
+    }
+
+    bitflags! {
+        /// `PACKET_FANOUT_FLAG_*` constants for use in [`PacketFanout`].
+        #[repr(transparent)]
+        #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+        pub struct PacketFanoutFlags: u16 {
+            /// `PACKET_FANOUT_FLAG_ROLLOVER`
+            const ROLLOVER = 0x1000;
+            /// `PACKET_FANOUT_FLAG_UNIQUEID`
+            const UNIQUEID = 0x2000;
+            /// `PACKET_FANOUT_FLAG_IGNORE_OUTGOING`
+            const IGNORE_OUTGOING = 0x4000;
+            /// `PACKET_FANOUT_FLAG_DEFRAG`
+            const DEFRAG = 0x8000;
+
+            /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+            const _ = !0;
+        }
+    }
+
+    /// A packet fanout group, for use with [`set_packet_fanout`] and
+    /// [`packet_fanout`].
+    ///
+    /// [`set_packet_fanout`]: crate::net::sockopt::set_packet_fanout
+    /// [`packet_fanout`]: crate::net::sockopt::packet_fanout
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+    pub struct PacketFanout {
+        /// Group ID, shared by all the sockets in the group.
+        pub group_id: u16,
+        /// How packets are distributed among the sockets in the group.
+        pub mode: PacketFanoutMode,
+        /// Flags.
+        pub flags: PacketFanoutFlags,
+    }
+
+    /// A packet ring request, for use with [`set_packet_rx_ring`] and
+    /// [`set_packet_tx_ring`].
+    ///
+    /// The last three fields are only used with [`PacketVersion::V3`]; the
+    /// kernel ignores them for older versions.
+    ///
+    /// `struct tpacket_req3`
+    ///
+    /// [`set_packet_rx_ring`]: crate::net::sockopt::set_packet_rx_ring
+    /// [`set_packet_tx_ring`]: crate::net::sockopt::set_packet_tx_ring
+    // https://github.com/torvalds/linux/blob/v6.6/include/uapi/linux/if_packet.h#L273-L281
+    #[repr(C)]
+    #[derive(Copy, Clone, Default, Eq, PartialEq, Hash, Debug)]
+    #[doc(alias = "tpacket_req3")]
+    pub struct TpacketReq3 {
+        /// Minimal size of a contiguous block.
+        pub block_size: u32,
+        /// Number of blocks.
+        pub block_nr: u32,
+        /// Size of a frame.
+        pub frame_size: u32,
+        /// Total number of frames.
+        pub frame_nr: u32,
+        /// Timeout in milliseconds after which a partially filled block is
+        /// retired to user space.
+        pub retire_blk_tov: u32,
+        /// Size of the private area reserved in each block.
+        pub sizeof_priv: u32,
+        /// Requested features, such as `TP_FT_REQ_FILL_RXHASH`.
+        pub feature_req_word: u32,
+    }
+
+    /// Packet socket statistics, as returned by [`packet_statistics`].
+    ///
+    /// Not ABI compatible with `struct tpacket_stats_v3`
+    ///
+    /// [`packet_statistics`]: crate::net::sockopt::packet_statistics
+    // https://github.com/torvalds/linux/blob/v6.6/include/uapi/linux/if_packet.h#L82-L86
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct PacketStats {
+        /// Packets received.
+        pub packets: u32,
+        /// Packets dropped.
+        pub drops: u32,
+        /// Number of times the ring was frozen because it was full.
+        ///
+        /// Is `None` if the socket isn't using [`PacketVersion::V3`].
+        pub freeze_queue_count: Option<u32>,
+    }
+}
+
+/// `AF_VSOCK` related types and constants.
+#[cfg(linux_kernel)]
+pub mod vsock {
+    /// `VMADDR_CID_ANY`—Bind to any CID.
+    pub const VMADDR_CID_ANY: u32 = 0xffff_ffff;
+
+    /// `VMADDR_CID_HYPERVISOR`—The hypervisor.
+    pub const VMADDR_CID_HYPERVISOR: u32 = 0;
+
+    /// `VMADDR_CID_LOCAL`—Local communication, provided by the
+    /// `vsock_loopback` transport.
+    pub const VMADDR_CID_LOCAL: u32 = 1;
+
+    /// `VMADDR_CID_HOST`—The host, as seen from a guest.
+    pub const VMADDR_CID_HOST: u32 = 2;
+
+    /// `VMADDR_PORT_ANY`—Bind to any port.
+    pub const VMADDR_PORT_ANY: u32 = 0xffff_ffff;
+
+    /// A VM socket address.
+    ///
+    /// Used to bind to and connect `AF_VSOCK` sockets, which communicate
+    /// between virtual machines and their host.
+    ///
+    /// Not ABI compatible with `struct sockaddr_vm`
+    #[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Debug)]
+    #[doc(alias = "sockaddr_vm")]
+    pub struct SocketAddrVsock {
+        /// Context ID.
+        svm_cid: u32,
+        /// Port.
+        svm_port: u32,
+    }
+
+    impl SocketAddrVsock {
+        /// Construct a new VM socket address.
+        #[inline]
+        pub const fn new(cid: u32, port: u32) -> Self {
+            Self {
+                svm_cid: cid,
+                svm_port: port,
+            }
+        }
+
+        /// Return context ID.
+        #[inline]
+        pub const fn cid(&self) -> u32 {
+            self.svm_cid
+        }
+
+        /// Set context ID.
+        #[inline]
+        pub fn set_cid(&mut self, cid: u32) {
+            self.svm_cid = cid;
+        }
+
+        /// Return port.
+        #[inline]
+        pub const fn port(&self) -> u32 {
+            self.svm_port
+        }
+
+        /// Set port.
+        #[inline]
+        pub fn set_port(&mut self, port: u32) {
+            self.svm_port = port;
+        }
+    }
+}
+
+/// Kernel TLS (`TCP_ULP` `"tls"`) related types and constants.
+#[cfg(linux_kernel)]
+pub mod tls {
+    /// `TLS_*_VERSION` constants for use with [`TlsCryptoInfo`].
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+    #[repr(transparent)]
+    pub struct TlsVersion(u16);
+
+    impl TlsVersion {
+        /// `TLS_1_2_VERSION`
+        #[doc(alias = "TLS_1_2_VERSION")]
+        pub const TLS_1_2: Self = Self(0x0303);
+        /// `TLS_1_3_VERSION`
+        #[doc(alias = "TLS_1_3_VERSION")]
+        pub const TLS_1_3: Self = Self(0x0304);
+
+        /// Constructs a `TlsVersion` from a raw integer.
+        #[inline]
+        pub const fn from_raw(raw: u16) -> Self {
+            Self(raw)
+        }
+
+        /// Returns the raw integer for this `TlsVersion`.
+        #[inline]
+        pub const fn as_raw(self) -> u16 {
+            self.0
+        }
+    }
+
+    /// `struct tls_crypto_info`
+    #[repr(C)]
+    #[derive(Copy, Clone)]
+    struct TlsCryptoInfoHeader {
+        version: u16,
+        cipher_type: u16,
+    }
+
+    macro_rules! crypto_info {
+        (
+            $(#[$attr:meta])*
+            $name:ident, $cipher_type:expr, $cipher:literal,
+            iv: $iv:literal, key: $key:literal, salt: $salt:literal
+        ) => {
+            $(#[$attr])*
+            #[repr(C)]
+            #[derive(Copy, Clone)]
+            pub struct $name {
+                info: TlsCryptoInfoHeader,
+                /// The initialization vector.
+                pub iv: [u8; $iv],
+                /// The key.
+                pub key: [u8; $key],
+                /// The salt.
+                pub salt: [u8; $salt],
+                /// The TLS record sequence number, in network byte order.
+                pub rec_seq: [u8; 8],
+            }
+
+            impl $name {
+                #[doc = concat!("Constructs a new `", stringify!($name), "` for ", $cipher, ".")]
+                #[inline]
+                pub const fn new(
+                    version: TlsVersion,
+                    key: [u8; $key],
+                    iv: [u8; $iv],
+                    salt: [u8; $salt],
+                    rec_seq: [u8; 8],
+                ) -> Self {
+                    Self {
+                        info: TlsCryptoInfoHeader {
+                            version: version.0,
+                            cipher_type: $cipher_type,
+                        },
+                        iv,
+                        key,
+                        salt,
+                        rec_seq,
+                    }
+                }
+
+                /// Returns the TLS version.
+                #[inline]
+                pub const fn version(&self) -> TlsVersion {
+                    TlsVersion(self.info.version)
+                }
+            }
+
+            // Don't print key material.
+            impl core::fmt::Debug for $name {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.debug_struct(stringify!($name))
+                        .field("version", &self.version())
+                        .finish_non_exhaustive()
+                }
+            }
+        };
+    }
+
+    crypto_info!(
+        /// `struct tls12_crypto_info_aes_gcm_128`
+        #[doc(alias = "tls12_crypto_info_aes_gcm_128")]
+        TlsCryptoInfoAesGcm128, 51, "AES-GCM with a 128-bit key",
+        iv: 8, key: 16, salt: 4
+    );
+
+    crypto_info!(
+        /// `struct tls12_crypto_info_aes_gcm_256`
+        #[doc(alias = "tls12_crypto_info_aes_gcm_256")]
+        TlsCryptoInfoAesGcm256, 52, "AES-GCM with a 256-bit key",
+        iv: 8, key: 32, salt: 4
+    );
+
+    crypto_info!(
+        /// `struct tls12_crypto_info_chacha20_poly1305`
+        #[doc(alias = "tls12_crypto_info_chacha20_poly1305")]
+        TlsCryptoInfoChacha20Poly1305, 54, "ChaCha20-Poly1305",
+        iv: 12, key: 32, salt: 0
+    );
+
+    /// Cipher parameters for [`set_tls_tx`] and [`set_tls_rx`].
+    ///
+    /// [`set_tls_tx`]: crate::net::sockopt::set_tls_tx
+    /// [`set_tls_rx`]: crate::net::sockopt::set_tls_rx
+    #[derive(Copy, Clone, Debug)]
+    #[non_exhaustive]
+    pub enum TlsCryptoInfo {
+        /// `TLS_CIPHER_AES_GCM_128`
+        #[doc(alias = "TLS_CIPHER_AES_GCM_128")]
+        AesGcm128(TlsCryptoInfoAesGcm128),
+        /// `TLS_CIPHER_AES_GCM_256`
+        #[doc(alias = "TLS_CIPHER_AES_GCM_256")]
+        AesGcm256(TlsCryptoInfoAesGcm256),
+        /// `TLS_CIPHER_CHACHA20_POLY1305`
+        #[doc(alias = "TLS_CIPHER_CHACHA20_POLY1305")]
+        Chacha20Poly1305(TlsCryptoInfoChacha20Poly1305),
+    }
+
+    /// TLS record content types, for use with
+    /// [`SendAncillaryMessage::TlsRecordType`] and
+    /// [`RecvAncillaryMessage::TlsRecordType`].
+    ///
+    /// [`SendAncillaryMessage::TlsRecordType`]: crate::net::SendAncillaryMessage::TlsRecordType
+    /// [`RecvAncillaryMessage::TlsRecordType`]: crate::net::RecvAncillaryMessage::TlsRecordType
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+    #[repr(transparent)]
+    pub struct TlsRecordType(u8);
+
+    impl TlsRecordType {
+        /// `change_cipher_spec`
+        pub const CHANGE_CIPHER_SPEC: Self = Self(20);
+        /// `alert`
+        pub const ALERT: Self = Self(21);
+        /// `handshake`
+        pub const HANDSHAKE: Self = Self(22);
+        /// `application_data`
+        pub const APPLICATION_DATA: Self = Self(23);
+
+        /// Constructs a `TlsRecordType` from a raw integer.
+        #[inline]
+        pub const fn from_raw(raw: u8) -> Self {
+            Self(raw)
+        }
+
+        /// Returns the raw integer for this `TlsRecordType`.
+        #[inline]
+        pub const fn as_raw(self) -> u8 {
+            self.0
+        }
+    }
+}
+
 /// UNIX credentials of socket peer, for use with [`get_socket_peercred`]
 /// [`SendAncillaryMessage::ScmCredentials`] and
 /// [`RecvAncillaryMessage::ScmCredentials`].
@@ -1783,6 +2578,365 @@ pub struct UCred {
     pub gid: crate::ugid::Gid,
 }
 
+/// IPv4 packet information, for use with [`RecvAncillaryMessage::IpPktInfo`]
+/// and [`SendAncillaryMessage::IpPktInfo`].
+///
+/// `struct in_pktinfo`
+///
+/// [`RecvAncillaryMessage::IpPktInfo`]: crate::net::RecvAncillaryMessage::IpPktInfo
+/// [`SendAncillaryMessage::IpPktInfo`]: crate::net::SendAncillaryMessage::IpPktInfo
+#[cfg(linux_kernel)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[doc(alias = "in_pktinfo")]
+pub struct Ipv4PktInfo {
+    /// Index of the interface the packet was received on or is to be sent
+    /// on, or 0 to let the kernel choose when sending.
+    pub ifindex: u32,
+    /// The local address; when sending, the source address to use if it is
+    /// not [`Ipv4Addr::UNSPECIFIED`].
+    ///
+    /// [`Ipv4Addr::UNSPECIFIED`]: crate::net::Ipv4Addr::UNSPECIFIED
+    pub spec_dst: crate::net::Ipv4Addr,
+    /// The destination address in the packet header. Ignored when sending.
+    pub addr: crate::net::Ipv4Addr,
+}
+
+/// IPv6 packet information, for use with
+/// [`RecvAncillaryMessage::Ipv6PktInfo`] and
+/// [`SendAncillaryMessage::Ipv6PktInfo`].
+///
+/// `struct in6_pktinfo`
+///
+/// [`RecvAncillaryMessage::Ipv6PktInfo`]: crate::net::RecvAncillaryMessage::Ipv6PktInfo
+/// [`SendAncillaryMessage::Ipv6PktInfo`]: crate::net::SendAncillaryMessage::Ipv6PktInfo
+#[cfg(linux_kernel)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[doc(alias = "in6_pktinfo")]
+pub struct Ipv6PktInfo {
+    /// The destination address of a received packet, or the source address
+    /// to use when sending if it is not [`Ipv6Addr::UNSPECIFIED`].
+    ///
+    /// [`Ipv6Addr::UNSPECIFIED`]: crate::net::Ipv6Addr::UNSPECIFIED
+    pub addr: crate::net::Ipv6Addr,
+    /// Index of the interface the packet was received on or is to be sent
+    /// on, or 0 to let the kernel choose when sending.
+    pub ifindex: u32,
+}
+
+/// `SO_EE_ORIGIN_*` constants for the origin of a [`SockExtendedErr`].
+#[cfg(linux_kernel)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[repr(transparent)]
+pub struct SockExtendedErrOrigin(u8);
+
+#[cfg(linux_kernel)]
+impl SockExtendedErrOrigin {
+    /// `SO_EE_ORIGIN_NONE`
+    pub const NONE: Self = Self(0);
+    /// `SO_EE_ORIGIN_LOCAL`—The error was generated locally.
+    pub const LOCAL: Self = Self(1);
+    /// `SO_EE_ORIGIN_ICMP`—The error was reported by an ICMP message.
+    pub const ICMP: Self = Self(2);
+    /// `SO_EE_ORIGIN_ICMP6`—The error was reported by an ICMPv6 message.
+    pub const ICMP6: Self = Self(3);
+    /// `SO_EE_ORIGIN_TIMESTAMPING`—A transmit timestamp is attached.
+    #[doc(alias = "SO_EE_ORIGIN_TXSTATUS")]
+    pub const TIMESTAMPING: Self = Self(4);
+    /// `SO_EE_ORIGIN_ZEROCOPY`—A range of zerocopy sends has completed.
+    pub const ZEROCOPY: Self = Self(5);
+    /// `SO_EE_ORIGIN_TXTIME`—A packet with a transmit time was dropped.
+    pub const TXTIME: Self = Self(6);
+
+    /// Constructs a `SockExtendedErrOrigin` from a raw integer.
+    #[inline]
+    pub const fn from_raw(raw: u8) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw integer for this `SockExtendedErrOrigin`.
+    #[inline]
+    pub const fn as_raw(self) -> u8 {
+        self.0
+    }
+}
+
+/// An error or notification read from a socket's error queue, for use with
+/// [`RecvAncillaryMessage::SockExtendedErr`].
+///
+/// `struct sock_extended_err`
+///
+/// [`RecvAncillaryMessage::SockExtendedErr`]: crate::net::RecvAncillaryMessage::SockExtendedErr
+#[cfg(linux_kernel)]
+#[derive(Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "std", derive(Debug))]
+#[doc(alias = "sock_extended_err")]
+pub struct SockExtendedErr {
+    /// The error, or `None` for notifications that don't carry one, such as
+    /// zerocopy completions.
+    pub errno: Option<crate::io::Errno>,
+    /// Where the error came from.
+    pub origin: SockExtendedErrOrigin,
+    /// The ICMP type, for ICMP errors.
+    pub type_: u8,
+    /// The ICMP code, for ICMP errors, or flags for zerocopy completions.
+    pub code: u8,
+    /// Extra information; for example, the MTU for `EMSGSIZE` errors.
+    pub info: u32,
+    /// Extra data.
+    pub data: u32,
+    /// `SO_EE_OFFENDER`—The address of the node that reported the error, if
+    /// known.
+    pub offender: Option<crate::net::SocketAddrAny>,
+}
+
+#[cfg(linux_kernel)]
+impl SockExtendedErr {
+    /// For a [`SockExtendedErrOrigin::ZEROCOPY`] notification, returns the
+    /// inclusive range of send calls that have completed.
+    ///
+    /// Send calls on a socket with [`set_socket_zerocopy`] enabled are
+    /// numbered consecutively from zero.
+    ///
+    /// [`set_socket_zerocopy`]: crate::net::sockopt::set_socket_zerocopy
+    #[inline]
+    pub fn zerocopy_range(&self) -> Option<core::ops::RangeInclusive<u32>> {
+        if self.origin == SockExtendedErrOrigin::ZEROCOPY {
+            Some(self.info..=self.data)
+        } else {
+            None
+        }
+    }
+
+    /// For a [`SockExtendedErrOrigin::ZEROCOPY`] notification, returns
+    /// whether the kernel fell back to copying the data.
+    #[inline]
+    #[doc(alias = "SO_EE_CODE_ZEROCOPY_COPIED")]
+    pub fn zerocopy_copied(&self) -> bool {
+        self.origin == SockExtendedErrOrigin::ZEROCOPY && (self.code & 1) != 0
+    }
+}
+
+/// TCP connection information, for use with [`tcp_info`].
+///
+/// The layout matches the Linux kernel's `struct tcp_info`. Older kernels
+/// fill in only a prefix of it, and [`tcp_info`] returns the length of that
+/// prefix. Fields which don't end within the returned length are zero, rather
+/// than reported by the kernel. The lengths at which fields become valid are:
+///
+/// | Length | Fields                                                        |
+/// | ------ | ------------------------------------------------------------- |
+/// | 104    | `state` through `total_retrans`                               |
+/// | 120    | `pacing_rate`, `max_pacing_rate`                              |
+/// | 136    | `bytes_acked`, `bytes_received`                               |
+/// | 144    | `segs_out`, `segs_in`                                         |
+/// | 160    | `notsent_bytes`, `min_rtt`, `data_segs_in`, `data_segs_out`   |
+/// | 168    | `delivery_rate`, `delivery_rate_app_limited`                  |
+/// | 192    | `busy_time`, `rwnd_limited`, `sndbuf_limited`                 |
+/// | 200    | `delivered`, `delivered_ce`                                   |
+/// | 224    | `bytes_sent`, `bytes_retrans`, `dsack_dups`, `reord_seen`     |
+/// | 228    | `rcv_ooopack`                                                 |
+/// | 232    | `snd_wnd`                                                     |
+/// | 240    | `rcv_wnd`, `rehash`                                           |
+/// | 248    | `total_rto`, `total_rto_recoveries`, `total_rto_time`         |
+///
+/// [`tcp_info`]: crate::net::sockopt::tcp_info
+#[cfg(linux_kernel)]
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[doc(alias = "tcp_info")]
+#[non_exhaustive]
+pub struct TcpInfo {
+    /// `tcpi_state`
+    pub state: u8,
+    /// `tcpi_ca_state`
+    pub ca_state: u8,
+    /// `tcpi_retransmits`
+    pub retransmits: u8,
+    /// `tcpi_probes`
+    pub probes: u8,
+    /// `tcpi_backoff`
+    pub backoff: u8,
+    /// `tcpi_options`
+    pub options: u8,
+    wscale: u8,
+    bitfield: u8,
+
+    /// `tcpi_rto`—Retransmission timeout, in microseconds.
+    pub rto: u32,
+    /// `tcpi_ato`—Delayed ACK timeout, in microseconds.
+    pub ato: u32,
+    /// `tcpi_snd_mss`
+    pub snd_mss: u32,
+    /// `tcpi_rcv_mss`
+    pub rcv_mss: u32,
+
+    /// `tcpi_unacked`
+    pub unacked: u32,
+    /// `tcpi_sacked`
+    pub sacked: u32,
+    /// `tcpi_lost`
+    pub lost: u32,
+    /// `tcpi_retrans`
+    pub retrans: u32,
+    /// `tcpi_fackets`
+    pub fackets: u32,
+
+    /// `tcpi_last_data_sent`—Milliseconds since the last data was sent.
+    pub last_data_sent: u32,
+    /// `tcpi_last_ack_sent`—Not currently used by Linux.
+    pub last_ack_sent: u32,
+    /// `tcpi_last_data_recv`—Milliseconds since the last data was received.
+    pub last_data_recv: u32,
+    /// `tcpi_last_ack_recv`—Milliseconds since the last ACK was received.
+    pub last_ack_recv: u32,
+
+    /// `tcpi_pmtu`
+    pub pmtu: u32,
+    /// `tcpi_rcv_ssthresh`
+    pub rcv_ssthresh: u32,
+    /// `tcpi_rtt`—Smoothed round trip time, in microseconds.
+    pub rtt: u32,
+    /// `tcpi_rttvar`—Round trip time variance, in microseconds.
+    pub rttvar: u32,
+    /// `tcpi_snd_ssthresh`
+    pub snd_ssthresh: u32,
+    /// `tcpi_snd_cwnd`
+    pub snd_cwnd: u32,
+    /// `tcpi_advmss`
+    pub advmss: u32,
+    /// `tcpi_reordering`
+    pub reordering: u32,
+
+    /// `tcpi_rcv_rtt`
+    pub rcv_rtt: u32,
+    /// `tcpi_rcv_space`
+    pub rcv_space: u32,
+
+    /// `tcpi_total_retrans`
+    pub total_retrans: u32,
+
+    /// `tcpi_pacing_rate`—Bytes per second.
+    pub pacing_rate: u64,
+    /// `tcpi_max_pacing_rate`—Bytes per second.
+    pub max_pacing_rate: u64,
+    /// `tcpi_bytes_acked`
+    pub bytes_acked: u64,
+    /// `tcpi_bytes_received`
+    pub bytes_received: u64,
+    /// `tcpi_segs_out`
+    pub segs_out: u32,
+    /// `tcpi_segs_in`
+    pub segs_in: u32,
+
+    /// `tcpi_notsent_bytes`
+    pub notsent_bytes: u32,
+    /// `tcpi_min_rtt`—Minimum round trip time observed, in microseconds.
+    pub min_rtt: u32,
+    /// `tcpi_data_segs_in`
+    pub data_segs_in: u32,
+    /// `tcpi_data_segs_out`
+    pub data_segs_out: u32,
+
+    /// `tcpi_delivery_rate`—Bytes per second.
+    pub delivery_rate: u64,
+
+    /// `tcpi_busy_time`—Time busy sending data, in microseconds.
+    pub busy_time: u64,
+    /// `tcpi_rwnd_limited`—Time limited by the receive window, in microseconds.
+    pub rwnd_limited: u64,
+    /// `tcpi_sndbuf_limited`—Time limited by the send buffer, in microseconds.
+    pub sndbuf_limited: u64,
+
+    /// `tcpi_delivered`
+    pub delivered: u32,
+    /// `tcpi_delivered_ce`
+    pub delivered_ce: u32,
+
+    /// `tcpi_bytes_sent`
+    pub bytes_sent: u64,
+    /// `tcpi_bytes_retrans`
+    pub bytes_retrans: u64,
+    /// `tcpi_dsack_dups`
+    pub dsack_dups: u32,
+    /// `tcpi_reord_seen`
+    pub reord_seen: u32,
+
+    /// `tcpi_rcv_ooopack`
+    pub rcv_ooopack: u32,
+
+    /// `tcpi_snd_wnd`
+    pub snd_wnd: u32,
+    /// `tcpi_rcv_wnd`
+    pub rcv_wnd: u32,
+
+    /// `tcpi_rehash`
+    pub rehash: u32,
+
+    /// `tcpi_total_rto`
+    pub total_rto: u16,
+    /// `tcpi_total_rto_recoveries`
+    pub total_rto_recoveries: u16,
+    /// `tcpi_total_rto_time`—Time spent in RTO recovery, in milliseconds.
+    pub total_rto_time: u32,
+}
+
+#[cfg(linux_kernel)]
+impl TcpInfo {
+    /// The send window scale, `tcpi_snd_wscale`.
+    #[inline]
+    pub const fn snd_wscale(&self) -> u8 {
+        #[cfg(target_endian = "little")]
+        {
+            self.wscale & 0xf
+        }
+        #[cfg(target_endian = "big")]
+        {
+            self.wscale >> 4
+        }
+    }
+
+    /// The receive window scale, `tcpi_rcv_wscale`.
+    #[inline]
+    pub const fn rcv_wscale(&self) -> u8 {
+        #[cfg(target_endian = "little")]
+        {
+            self.wscale >> 4
+        }
+        #[cfg(target_endian = "big")]
+        {
+            self.wscale & 0xf
+        }
+    }
+
+    /// Whether `delivery_rate` was measured while application-limited,
+    /// `tcpi_delivery_rate_app_limited`.
+    #[inline]
+    pub const fn delivery_rate_app_limited(&self) -> bool {
+        #[cfg(target_endian = "little")]
+        {
+            (self.bitfield & 0x1) != 0
+        }
+        #[cfg(target_endian = "big")]
+        {
+            (self.bitfield & 0x80) != 0
+        }
+    }
+
+    /// The reason a TCP Fast Open attempt by a client failed,
+    /// `tcpi_fastopen_client_fail`.
+    #[inline]
+    pub const fn fastopen_client_fail(&self) -> u8 {
+        #[cfg(target_endian = "little")]
+        {
+            (self.bitfield >> 1) & 0x3
+        }
+        #[cfg(target_endian = "big")]
+        {
+            (self.bitfield >> 5) & 0x3
+        }
+    }
+}
+
 #[test]
 fn test_sizes() {
     use crate::backend::c;
@@ -1813,10 +2967,25 @@ fn test_sizes() {
     #[cfg(linux_kernel)]
     assert_eq_size!(UCred, libc::ucred);
 
+    // `struct tcp_info` as of Linux 6.7.
+    #[cfg(linux_kernel)]
+    assert_eq_size!(TcpInfo, [u64; 31]);
+
+    #[cfg(linux_kernel)]
+    {
+        assert_eq_size!(tls::TlsCryptoInfoAesGcm128, [u8; 40]);
+        assert_eq_size!(tls::TlsCryptoInfoAesGcm256, [u8; 56]);
+        assert_eq_size!(tls::TlsCryptoInfoChacha20Poly1305, [u8; 56]);
+    }
+
     #[cfg(target_os = "linux")]
     assert_eq_size!(super::xdp::XdpUmemReg, c::xdp_umem_reg);
     #[cfg(target_os = "linux")]
     assert_eq_size!(super::xdp::XdpOptions, c::xdp_options);
     #[cfg(target_os = "linux")]
     assert_eq_size!(super::xdp::XdpDesc, c::xdp_desc);
+    #[cfg(target_os = "linux")]
+    assert_eq_size!(super::packet::PacketMreq, c::packet_mreq);
+    #[cfg(target_os = "linux")]
+    assert_eq_size!(super::packet::TpacketReq3, c::tpacket_req3);
 }
@@ -0,0 +1,758 @@
+//! Linux `perf_event_open`, for performance counters.
+//!
+//! Counters are described by a [`PerfEventAttr`], opened with
+//! [`perf_event_open`], and controlled with [`ioctl_enable`],
+//! [`ioctl_disable`], and [`ioctl_reset`]. Reading from a counter's file
+//! descriptor produces values in the layout selected by the attribute's
+//! [`ReadFormat`], which [`ReadValues`] decodes.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use rustix::perf_event::{self, PerfEventAttr, PerfEventFlags, PerfEventPid, SoftwareEvent};
+//!
+//! # fn test() -> std::io::Result<()> {
+//! // Count the CPU time used by the calling thread.
+//! let attr = PerfEventAttr::software(SoftwareEvent::TASK_CLOCK)
+//!     .disabled(true)
+//!     .exclude_kernel(true);
+//! let counter = perf_event::perf_event_open(
+//!     &attr,
+//!     PerfEventPid::CallingThread,
+//!     None,
+//!     None,
+//!     PerfEventFlags::FD_CLOEXEC,
+//! )?;
+//!
+//! perf_event::ioctl_enable(&counter, perf_event::IocFlags::empty())?;
+//! // … do some work …
+//! perf_event::ioctl_disable(&counter, perf_event::IocFlags::empty())?;
+//!
+//! let mut buf = [0_u8; 64];
+//! let values = perf_event::read_values(&counter, attr.read_format_flags(), &mut buf)?;
+//! let nanos = values.iter().next().unwrap().value;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # References
+//!  - [Linux `perf_event_open`]
+//!
+//! [Linux `perf_event_open`]: https://man7.org/linux/man-pages/man2/perf_event_open.2.html
+#![allow(unsafe_code)]
+
+use crate::fd::{AsFd, AsRawFd, BorrowedFd, OwnedFd};
+use crate::{backend, io, ioctl};
+use bitflags::bitflags;
+
+// Export types used in perf_event APIs.
+pub use crate::pid::Pid;
+
+// linux-raw-sys doesn't provide <linux/perf_event.h>, so define what we need
+// here.
+
+/// `PERF_ATTR_SIZE_VER7`
+const PERF_ATTR_SIZE_VER7: u32 = 128;
+
+/// `PERF_FLAG_PID_CGROUP`
+const PERF_FLAG_PID_CGROUP: u32 = 1 << 2;
+
+/// `PERF_TYPE_*` constants for use with [`PerfEventAttr::new`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct EventType(u32);
+
+#[rustfmt::skip]
+impl EventType {
+    /// `PERF_TYPE_HARDWARE`
+    pub const HARDWARE: Self = Self(0);
+    /// `PERF_TYPE_SOFTWARE`
+    pub const SOFTWARE: Self = Self(1);
+    /// `PERF_TYPE_TRACEPOINT`
+    pub const TRACEPOINT: Self = Self(2);
+    /// `PERF_TYPE_HW_CACHE`
+    pub const HW_CACHE: Self = Self(3);
+    /// `PERF_TYPE_RAW`
+    pub const RAW: Self = Self(4);
+    /// `PERF_TYPE_BREAKPOINT`
+    pub const BREAKPOINT: Self = Self(5);
+
+    /// Constructs an `EventType` from a raw integer, such as a dynamic PMU
+    /// type read from `/sys/bus/event_source/devices/*/type`.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw integer for this `EventType`.
+    #[inline]
+    pub const fn as_raw(self) -> u32 {
+        self.0
+    }
+}
+
+/// `PERF_COUNT_HW_*` constants for use with [`PerfEventAttr::hardware`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct HardwareEvent(u64);
+
+#[rustfmt::skip]
+impl HardwareEvent {
+    /// `PERF_COUNT_HW_CPU_CYCLES`
+    pub const CPU_CYCLES: Self = Self(0);
+    /// `PERF_COUNT_HW_INSTRUCTIONS`
+    pub const INSTRUCTIONS: Self = Self(1);
+    /// `PERF_COUNT_HW_CACHE_REFERENCES`
+    pub const CACHE_REFERENCES: Self = Self(2);
+    /// `PERF_COUNT_HW_CACHE_MISSES`
+    pub const CACHE_MISSES: Self = Self(3);
+    /// `PERF_COUNT_HW_BRANCH_INSTRUCTIONS`
+    pub const BRANCH_INSTRUCTIONS: Self = Self(4);
+    /// `PERF_COUNT_HW_BRANCH_MISSES`
+    pub const BRANCH_MISSES: Self = Self(5);
+    /// `PERF_COUNT_HW_BUS_CYCLES`
+    pub const BUS_CYCLES: Self = Self(6);
+    /// `PERF_COUNT_HW_STALLED_CYCLES_FRONTEND`
+    pub const STALLED_CYCLES_FRONTEND: Self = Self(7);
+    /// `PERF_COUNT_HW_STALLED_CYCLES_BACKEND`
+    pub const STALLED_CYCLES_BACKEND: Self = Self(8);
+    /// `PERF_COUNT_HW_REF_CPU_CYCLES`
+    pub const REF_CPU_CYCLES: Self = Self(9);
+
+    /// Constructs a `HardwareEvent` from a raw integer.
+    #[inline]
+    pub const fn from_raw(raw: u64) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw integer for this `HardwareEvent`.
+    #[inline]
+    pub const fn as_raw(self) -> u64 {
+        self.0
+    }
+}
+
+/// `PERF_COUNT_SW_*` constants for use with [`PerfEventAttr::software`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct SoftwareEvent(u64);
+
+#[rustfmt::skip]
+impl SoftwareEvent {
+    /// `PERF_COUNT_SW_CPU_CLOCK`
+    pub const CPU_CLOCK: Self = Self(0);
+    /// `PERF_COUNT_SW_TASK_CLOCK`
+    pub const TASK_CLOCK: Self = Self(1);
+    /// `PERF_COUNT_SW_PAGE_FAULTS`
+    pub const PAGE_FAULTS: Self = Self(2);
+    /// `PERF_COUNT_SW_CONTEXT_SWITCHES`
+    pub const CONTEXT_SWITCHES: Self = Self(3);
+    /// `PERF_COUNT_SW_CPU_MIGRATIONS`
+    pub const CPU_MIGRATIONS: Self = Self(4);
+    /// `PERF_COUNT_SW_PAGE_FAULTS_MIN`
+    pub const PAGE_FAULTS_MIN: Self = Self(5);
+    /// `PERF_COUNT_SW_PAGE_FAULTS_MAJ`
+    pub const PAGE_FAULTS_MAJ: Self = Self(6);
+    /// `PERF_COUNT_SW_ALIGNMENT_FAULTS`
+    pub const ALIGNMENT_FAULTS: Self = Self(7);
+    /// `PERF_COUNT_SW_EMULATION_FAULTS`
+    pub const EMULATION_FAULTS: Self = Self(8);
+    /// `PERF_COUNT_SW_DUMMY`
+    pub const DUMMY: Self = Self(9);
+    /// `PERF_COUNT_SW_BPF_OUTPUT`
+    pub const BPF_OUTPUT: Self = Self(10);
+    /// `PERF_COUNT_SW_CGROUP_SWITCHES`
+    pub const CGROUP_SWITCHES: Self = Self(11);
+
+    /// Constructs a `SoftwareEvent` from a raw integer.
+    #[inline]
+    pub const fn from_raw(raw: u64) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw integer for this `SoftwareEvent`.
+    #[inline]
+    pub const fn as_raw(self) -> u64 {
+        self.0
+    }
+}
+
+bitflags! {
+    /// `PERF_FORMAT_*` flags for use with [`PerfEventAttr::read_format`] and
+    /// [`ReadValues`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct ReadFormat: u64 {
+        /// `PERF_FORMAT_TOTAL_TIME_ENABLED`
+        const TOTAL_TIME_ENABLED = 1 << 0;
+        /// `PERF_FORMAT_TOTAL_TIME_RUNNING`
+        const TOTAL_TIME_RUNNING = 1 << 1;
+        /// `PERF_FORMAT_ID`
+        const ID = 1 << 2;
+        /// `PERF_FORMAT_GROUP`
+        const GROUP = 1 << 3;
+        /// `PERF_FORMAT_LOST` (since Linux 6.0)
+        const LOST = 1 << 4;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `PERF_FLAG_*` flags for use with [`perf_event_open`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct PerfEventFlags: u32 {
+        /// `PERF_FLAG_FD_NO_GROUP`
+        const FD_NO_GROUP = 1 << 0;
+        /// `PERF_FLAG_FD_OUTPUT`
+        const FD_OUTPUT = 1 << 1;
+        /// `PERF_FLAG_FD_CLOEXEC`
+        const FD_CLOEXEC = 1 << 3;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `PERF_IOC_FLAG_*` flags for use with [`ioctl_enable`],
+    /// [`ioctl_disable`], and [`ioctl_reset`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct IocFlags: u32 {
+        /// `PERF_IOC_FLAG_GROUP`—Apply the operation to all the counters in
+        /// the group led by the file descriptor.
+        const GROUP = 1 << 0;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+// Bit positions within the `perf_event_attr` flags bitfield.
+const ATTR_DISABLED: u32 = 0;
+const ATTR_INHERIT: u32 = 1;
+const ATTR_PINNED: u32 = 2;
+const ATTR_EXCLUSIVE: u32 = 3;
+const ATTR_EXCLUDE_USER: u32 = 4;
+const ATTR_EXCLUDE_KERNEL: u32 = 5;
+const ATTR_EXCLUDE_HV: u32 = 6;
+const ATTR_EXCLUDE_IDLE: u32 = 7;
+const ATTR_FREQ: u32 = 10;
+const ATTR_ENABLE_ON_EXEC: u32 = 12;
+
+/// `struct perf_event_attr`—A description of a performance counter, for use
+/// with [`perf_event_open`].
+///
+/// This is constructed with one of [`PerfEventAttr::hardware`],
+/// [`PerfEventAttr::software`], [`PerfEventAttr::tracepoint`], or
+/// [`PerfEventAttr::new`], and then adjusted with the builder methods.
+#[doc(alias = "perf_event_attr")]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct PerfEventAttr {
+    type_: u32,
+    size: u32,
+    config: u64,
+    sample_period_or_freq: u64,
+    sample_type: u64,
+    read_format: u64,
+    flags: u64,
+    wakeup_events_or_watermark: u32,
+    bp_type: u32,
+    config1: u64,
+    config2: u64,
+    branch_sample_type: u64,
+    sample_regs_user: u64,
+    sample_stack_user: u32,
+    clockid: i32,
+    sample_regs_intr: u64,
+    aux_watermark: u32,
+    sample_max_stack: u16,
+    reserved_2: u16,
+    aux_sample_size: u32,
+    reserved_3: u32,
+    sig_data: u64,
+}
+
+impl PerfEventAttr {
+    /// Constructs an attribute for the event with type `type_` and
+    /// type-specific configuration `config`.
+    #[inline]
+    pub const fn new(type_: EventType, config: u64) -> Self {
+        Self {
+            type_: type_.0,
+            size: PERF_ATTR_SIZE_VER7,
+            config,
+            sample_period_or_freq: 0,
+            sample_type: 0,
+            read_format: 0,
+            flags: 0,
+            wakeup_events_or_watermark: 0,
+            bp_type: 0,
+            config1: 0,
+            config2: 0,
+            branch_sample_type: 0,
+            sample_regs_user: 0,
+            sample_stack_user: 0,
+            clockid: 0,
+            sample_regs_intr: 0,
+            aux_watermark: 0,
+            sample_max_stack: 0,
+            reserved_2: 0,
+            aux_sample_size: 0,
+            reserved_3: 0,
+            sig_data: 0,
+        }
+    }
+
+    /// Constructs an attribute for a generalized hardware event.
+    #[inline]
+    pub const fn hardware(event: HardwareEvent) -> Self {
+        Self::new(EventType::HARDWARE, event.0)
+    }
+
+    /// Constructs an attribute for a software event.
+    #[inline]
+    pub const fn software(event: SoftwareEvent) -> Self {
+        Self::new(EventType::SOFTWARE, event.0)
+    }
+
+    /// Constructs an attribute for the tracepoint with ID `id`, as read from
+    /// `/sys/kernel/tracing/events/*/*/id`.
+    #[inline]
+    pub const fn tracepoint(id: u64) -> Self {
+        Self::new(EventType::TRACEPOINT, id)
+    }
+
+    /// Sets the `disabled` bit, so that the counter starts out disabled, to be
+    /// enabled later with [`ioctl_enable`].
+    #[inline]
+    pub const fn disabled(self, disabled: bool) -> Self {
+        self.with_flag(ATTR_DISABLED, disabled)
+    }
+
+    /// Sets the `inherit` bit, so that the counter also counts events of
+    /// child tasks created after it's opened.
+    #[inline]
+    pub const fn inherit(self, inherit: bool) -> Self {
+        self.with_flag(ATTR_INHERIT, inherit)
+    }
+
+    /// Sets the `pinned` bit, so that the counter should always be on the
+    /// CPU.
+    #[inline]
+    pub const fn pinned(self, pinned: bool) -> Self {
+        self.with_flag(ATTR_PINNED, pinned)
+    }
+
+    /// Sets the `exclusive` bit, so that the counter's group should be the
+    /// only group on the CPU when it's on.
+    #[inline]
+    pub const fn exclusive(self, exclusive: bool) -> Self {
+        self.with_flag(ATTR_EXCLUSIVE, exclusive)
+    }
+
+    /// Sets the `exclude_user` bit, to not count events in user space.
+    #[inline]
+    pub const fn exclude_user(self, exclude: bool) -> Self {
+        self.with_flag(ATTR_EXCLUDE_USER, exclude)
+    }
+
+    /// Sets the `exclude_kernel` bit, to not count events in the kernel.
+    ///
+    /// Unprivileged users typically need to set this, depending on
+    /// `/proc/sys/kernel/perf_event_paranoid`.
+    #[inline]
+    pub const fn exclude_kernel(self, exclude: bool) -> Self {
+        self.with_flag(ATTR_EXCLUDE_KERNEL, exclude)
+    }
+
+    /// Sets the `exclude_hv` bit, to not count events in the hypervisor.
+    #[inline]
+    pub const fn exclude_hv(self, exclude: bool) -> Self {
+        self.with_flag(ATTR_EXCLUDE_HV, exclude)
+    }
+
+    /// Sets the `exclude_idle` bit, to not count when the CPU is idle.
+    #[inline]
+    pub const fn exclude_idle(self, exclude: bool) -> Self {
+        self.with_flag(ATTR_EXCLUDE_IDLE, exclude)
+    }
+
+    /// Sets the `enable_on_exec` bit, so that a disabled counter is enabled
+    /// when its task calls `exec`.
+    #[inline]
+    pub const fn enable_on_exec(self, enable: bool) -> Self {
+        self.with_flag(ATTR_ENABLE_ON_EXEC, enable)
+    }
+
+    /// Sets the `read_format` field, which selects what reading from the
+    /// counter produces.
+    #[inline]
+    pub const fn read_format(mut self, format: ReadFormat) -> Self {
+        self.read_format = format.bits();
+        self
+    }
+
+    /// Sets the `sample_period` field, and clears the `freq` bit.
+    #[inline]
+    pub const fn sample_period(mut self, period: u64) -> Self {
+        self.sample_period_or_freq = period;
+        self.with_flag(ATTR_FREQ, false)
+    }
+
+    /// Sets the `sample_freq` field, and sets the `freq` bit.
+    #[inline]
+    pub const fn sample_freq(mut self, freq: u64) -> Self {
+        self.sample_period_or_freq = freq;
+        self.with_flag(ATTR_FREQ, true)
+    }
+
+    /// Returns the `read_format` field.
+    #[inline]
+    pub const fn read_format_flags(&self) -> ReadFormat {
+        ReadFormat::from_bits_retain(self.read_format)
+    }
+
+    /// Sets or clears the one-bit field at `bit` in the flags bitfield.
+    ///
+    /// C bitfields are allocated starting at the least significant bit on
+    /// little-endian targets, and at the most significant bit on big-endian
+    /// targets.
+    #[inline]
+    const fn with_flag(mut self, bit: u32, value: bool) -> Self {
+        #[cfg(target_endian = "little")]
+        let mask = 1_u64 << bit;
+        #[cfg(target_endian = "big")]
+        let mask = 1_u64 << (63 - bit);
+
+        if value {
+            self.flags |= mask;
+        } else {
+            self.flags &= !mask;
+        }
+        self
+    }
+}
+
+/// The process or cgroup to monitor in a call to [`perf_event_open`].
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum PerfEventPid<'a> {
+    /// Monitor the calling thread.
+    CallingThread,
+
+    /// Monitor a specific thread.
+    Pid(Pid),
+
+    /// Monitor all threads. This requires a specific CPU.
+    All,
+
+    /// Monitor the cgroup with the given directory file descriptor. This
+    /// requires a specific CPU.
+    #[doc(alias = "PERF_FLAG_PID_CGROUP")]
+    Cgroup(BorrowedFd<'a>),
+}
+
+/// `perf_event_open(attr, pid, cpu, group_fd, flags)`—Opens a performance
+/// counter.
+///
+/// `cpu` is the CPU to count on, or `None` to count on any CPU. `group_fd` is
+/// the group leader to add this counter to, or `None` to create a new group
+/// with this counter as its leader.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/perf_event_open.2.html
+#[inline]
+pub fn perf_event_open(
+    attr: &PerfEventAttr,
+    pid: PerfEventPid<'_>,
+    cpu: Option<u32>,
+    group_fd: Option<BorrowedFd<'_>>,
+    flags: PerfEventFlags,
+) -> io::Result<OwnedFd> {
+    let (pid, flags) = match pid {
+        PerfEventPid::CallingThread => (0, flags.bits()),
+        PerfEventPid::Pid(pid) => (pid.as_raw_nonzero().get(), flags.bits()),
+        PerfEventPid::All => (-1, flags.bits()),
+        PerfEventPid::Cgroup(fd) => (fd.as_raw_fd(), flags.bits() | PERF_FLAG_PID_CGROUP),
+    };
+    let cpu = match cpu {
+        Some(cpu) => cpu.try_into().map_err(|_| io::Errno::INVAL)?,
+        None => -1,
+    };
+    let group_fd = group_fd.map_or(-1, |fd| fd.as_raw_fd());
+    backend::perf_event::syscalls::perf_event_open(attr, pid, cpu, group_fd, flags)
+}
+
+/// `ioctl(fd, PERF_EVENT_IOC_ENABLE, flags)`—Enables a counter.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/perf_event_open.2.html
+#[inline]
+#[doc(alias = "PERF_EVENT_IOC_ENABLE")]
+pub fn ioctl_enable<Fd: AsFd>(fd: Fd, flags: IocFlags) -> io::Result<()> {
+    // SAFETY: `PERF_EVENT_IOC_ENABLE` takes an integer flags argument.
+    unsafe {
+        let ctl = ioctl::IntegerSetter::<ioctl::NoneOpcode<b'$', 0, ()>>::new_usize(
+            flags.bits() as usize
+        );
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, PERF_EVENT_IOC_DISABLE, flags)`—Disables a counter.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/perf_event_open.2.html
+#[inline]
+#[doc(alias = "PERF_EVENT_IOC_DISABLE")]
+pub fn ioctl_disable<Fd: AsFd>(fd: Fd, flags: IocFlags) -> io::Result<()> {
+    // SAFETY: `PERF_EVENT_IOC_DISABLE` takes an integer flags argument.
+    unsafe {
+        let ctl = ioctl::IntegerSetter::<ioctl::NoneOpcode<b'$', 1, ()>>::new_usize(
+            flags.bits() as usize
+        );
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// `ioctl(fd, PERF_EVENT_IOC_RESET, flags)`—Resets a counter's value to zero.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/perf_event_open.2.html
+#[inline]
+#[doc(alias = "PERF_EVENT_IOC_RESET")]
+pub fn ioctl_reset<Fd: AsFd>(fd: Fd, flags: IocFlags) -> io::Result<()> {
+    // SAFETY: `PERF_EVENT_IOC_RESET` takes an integer flags argument.
+    unsafe {
+        let ctl = ioctl::IntegerSetter::<ioctl::NoneOpcode<b'$', 3, ()>>::new_usize(
+            flags.bits() as usize
+        );
+        ioctl::ioctl(fd, ctl)
+    }
+}
+
+/// Reads a counter, or a group of counters, into `buf` and decodes the
+/// result.
+///
+/// `format` must be the `read_format` that the counter was opened with. If
+/// `buf` is too small for the values, this fails with [`io::Errno::NOSPC`].
+#[inline]
+pub fn read_values<Fd: AsFd>(
+    fd: Fd,
+    format: ReadFormat,
+    buf: &mut [u8],
+) -> io::Result<ReadValues<'_>> {
+    let len = crate::io::read(fd, &mut *buf)?;
+    ReadValues::parse(format, &buf[..len]).ok_or(io::Errno::INVAL)
+}
+
+/// The values read from a counter, or a group of counters, in the layout
+/// selected by a [`ReadFormat`].
+#[derive(Debug, Clone)]
+pub struct ReadValues<'a> {
+    format: ReadFormat,
+    time_enabled: Option<u64>,
+    time_running: Option<u64>,
+    /// The value, if this isn't a group.
+    single: Option<ReadValue>,
+    /// The encoded values, if this is a group.
+    group: &'a [u8],
+}
+
+/// One counter's value in [`ReadValues`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub struct ReadValue {
+    /// The value of the counter.
+    pub value: u64,
+
+    /// The counter's unique ID, if [`ReadFormat::ID`] is set.
+    pub id: Option<u64>,
+
+    /// The number of lost samples, if [`ReadFormat::LOST`] is set.
+    pub lost: Option<u64>,
+}
+
+impl<'a> ReadValues<'a> {
+    /// Decodes the bytes read from a counter opened with `format`.
+    ///
+    /// Returns `None` if `bytes` is too short.
+    pub fn parse(format: ReadFormat, bytes: &'a [u8]) -> Option<Self> {
+        let mut words = bytes.chunks_exact(8).map(read_u64);
+        let optional = |words: &mut dyn Iterator<Item = u64>, flag| {
+            if format.contains(flag) {
+                words.next().map(Some)
+            } else {
+                Some(None)
+            }
+        };
+
+        if format.contains(ReadFormat::GROUP) {
+            let nr = usize::try_from(words.next()?).ok()?;
+            let time_enabled = optional(&mut words, ReadFormat::TOTAL_TIME_ENABLED)?;
+            let time_running = optional(&mut words, ReadFormat::TOTAL_TIME_RUNNING)?;
+            let header = 1 + time_enabled.is_some() as usize + time_running.is_some() as usize;
+            let size = nr.checked_mul(entry_size(format))?;
+            let group = bytes.get(header * 8..)?.get(..size)?;
+            Some(Self {
+                format,
+                time_enabled,
+                time_running,
+                single: None,
+                group,
+            })
+        } else {
+            let value = words.next()?;
+            let time_enabled = optional(&mut words, ReadFormat::TOTAL_TIME_ENABLED)?;
+            let time_running = optional(&mut words, ReadFormat::TOTAL_TIME_RUNNING)?;
+            let id = optional(&mut words, ReadFormat::ID)?;
+            let lost = optional(&mut words, ReadFormat::LOST)?;
+            Some(Self {
+                format,
+                time_enabled,
+                time_running,
+                single: Some(ReadValue { value, id, lost }),
+                group: &[],
+            })
+        }
+    }
+
+    /// The number of values.
+    #[inline]
+    pub fn len(&self) -> usize {
+        match self.single {
+            Some(_) => 1,
+            None => self.group.len() / entry_size(self.format),
+        }
+    }
+
+    /// Returns `true` if there are no values.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The time the counter was enabled, in nanoseconds, if
+    /// [`ReadFormat::TOTAL_TIME_ENABLED`] is set.
+    #[inline]
+    pub fn time_enabled(&self) -> Option<u64> {
+        self.time_enabled
+    }
+
+    /// The time the counter was running, in nanoseconds, if
+    /// [`ReadFormat::TOTAL_TIME_RUNNING`] is set.
+    ///
+    /// If this is less than [`time_enabled`], the counter was multiplexed
+    /// with other counters, and its value can be scaled by
+    /// `time_enabled / time_running` to estimate the full count.
+    ///
+    /// [`time_enabled`]: Self::time_enabled
+    #[inline]
+    pub fn time_running(&self) -> Option<u64> {
+        self.time_running
+    }
+
+    /// Returns an iterator over the values, in the order the counters were
+    /// added to the group.
+    pub fn iter(&self) -> impl Iterator<Item = ReadValue> + '_ {
+        let format = self.format;
+        let group = self
+            .group
+            .chunks_exact(entry_size(format))
+            .map(move |entry| {
+                let mut words = entry.chunks_exact(8).map(read_u64);
+                let value = words.next().unwrap();
+                let id = if format.contains(ReadFormat::ID) {
+                    words.next()
+                } else {
+                    None
+                };
+                let lost = if format.contains(ReadFormat::LOST) {
+                    words.next()
+                } else {
+                    None
+                };
+                ReadValue { value, id, lost }
+            });
+        self.single.into_iter().chain(group)
+    }
+}
+
+/// The size in bytes of each value in a group: the value itself, and its ID
+/// and lost count if requested.
+fn entry_size(format: ReadFormat) -> usize {
+    (1 + format.contains(ReadFormat::ID) as usize + format.contains(ReadFormat::LOST) as usize) * 8
+}
+
+fn read_u64(bytes: &[u8]) -> u64 {
+    let mut word = [0_u8; 8];
+    word.copy_from_slice(bytes);
+    u64::from_ne_bytes(word)
+}
+
+#[test]
+fn test_sizes() {
+    assert_eq!(
+        core::mem::size_of::<PerfEventAttr>(),
+        PERF_ATTR_SIZE_VER7 as usize
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_read_values() {
+    fn bytes(words: &[u64]) -> alloc::vec::Vec<u8> {
+        words.iter().flat_map(|word| word.to_ne_bytes()).collect()
+    }
+
+    // A single value with times and an ID.
+    let format = ReadFormat::TOTAL_TIME_ENABLED | ReadFormat::TOTAL_TIME_RUNNING | ReadFormat::ID;
+    let buf = bytes(&[42, 100, 50, 7]);
+    let values = ReadValues::parse(format, &buf).unwrap();
+    assert_eq!(values.len(), 1);
+    assert_eq!(values.time_enabled(), Some(100));
+    assert_eq!(values.time_running(), Some(50));
+    let all: alloc::vec::Vec<_> = values.iter().collect();
+    assert_eq!(all.len(), 1);
+    assert_eq!(all[0].value, 42);
+    assert_eq!(all[0].id, Some(7));
+    assert_eq!(all[0].lost, None);
+    assert!(ReadValues::parse(format, &buf[..24]).is_none());
+
+    // A group of two values with IDs and lost counts.
+    let format = ReadFormat::GROUP | ReadFormat::ID | ReadFormat::LOST;
+    let buf = bytes(&[2, 10, 1, 0, 20, 2, 3]);
+    let values = ReadValues::parse(format, &buf).unwrap();
+    assert_eq!(values.len(), 2);
+    assert_eq!(values.time_enabled(), None);
+    let all: alloc::vec::Vec<_> = values.iter().collect();
+    assert_eq!(
+        all,
+        [
+            ReadValue {
+                value: 10,
+                id: Some(1),
+                lost: Some(0)
+            },
+            ReadValue {
+                value: 20,
+                id: Some(2),
+                lost: Some(3)
+            },
+        ]
+    );
+    assert!(ReadValues::parse(format, &buf[..48]).is_none());
+}
@@ -0,0 +1,285 @@
+//! Linux Landlock, an unprivileged access-control sandbox.
+//!
+//! A Landlock sandbox is built by creating a ruleset which lists the kinds of
+//! access it handles, adding rules which allow some of those accesses for
+//! specific file hierarchies or network ports, and then enforcing the ruleset
+//! on the calling thread with [`restrict_self`]. Handled accesses which aren't
+//! allowed by any rule are denied from then on, in the calling thread and in
+//! any threads or processes it subsequently creates.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use rustix::process::landlock;
+//! use rustix::thread::set_no_new_privs;
+//! use std::fs::File;
+//!
+//! # fn test() -> std::io::Result<()> {
+//! // Handle everything this kernel knows how to restrict.
+//! let abi = landlock::abi_version()?;
+//! let ruleset = landlock::create_ruleset(
+//!     landlock::AccessFs::from_abi(abi),
+//!     landlock::AccessNet::from_abi(abi),
+//! )?;
+//!
+//! // Allow reading files under `/usr`.
+//! let usr = File::open("/usr")?;
+//! landlock::add_path_beneath_rule(
+//!     &ruleset,
+//!     landlock::AccessFs::READ_FILE | landlock::AccessFs::READ_DIR,
+//!     &usr,
+//! )?;
+//!
+//! set_no_new_privs(true)?;
+//! landlock::restrict_self(&ruleset)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # References
+//!  - [Linux `landlock` feature]
+//!  - [Linux kernel documentation]
+//!
+//! [Linux `landlock` feature]: https://man7.org/linux/man-pages/man7/landlock.7.html
+//! [Linux kernel documentation]: https://docs.kernel.org/userspace-api/landlock.html
+
+use crate::fd::{AsFd, AsRawFd, OwnedFd};
+use crate::{backend, io};
+use bitflags::bitflags;
+
+// linux-raw-sys doesn't provide <linux/landlock.h> yet, so define what we
+// need here.
+
+/// `struct landlock_ruleset_attr`
+#[repr(C)]
+pub(crate) struct RulesetAttr {
+    pub(crate) handled_access_fs: u64,
+    pub(crate) handled_access_net: u64,
+}
+
+/// `struct landlock_path_beneath_attr`, which the kernel declares as packed.
+#[repr(C, packed)]
+pub(crate) struct PathBeneathAttr {
+    pub(crate) allowed_access: u64,
+    pub(crate) parent_fd: i32,
+}
+
+/// `struct landlock_net_port_attr`
+#[repr(C)]
+pub(crate) struct NetPortAttr {
+    pub(crate) allowed_access: u64,
+    pub(crate) port: u64,
+}
+
+/// `LANDLOCK_CREATE_RULESET_VERSION`
+pub(crate) const CREATE_RULESET_VERSION: u32 = 1 << 0;
+
+/// `LANDLOCK_RULE_PATH_BENEATH`
+pub(crate) const RULE_PATH_BENEATH: u32 = 1;
+
+/// `LANDLOCK_RULE_NET_PORT`
+pub(crate) const RULE_NET_PORT: u32 = 2;
+
+bitflags! {
+    /// `LANDLOCK_ACCESS_FS_*` flags for use with [`create_ruleset`] and
+    /// [`add_path_beneath_rule`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct AccessFs: u64 {
+        /// `LANDLOCK_ACCESS_FS_EXECUTE`
+        const EXECUTE = 1 << 0;
+        /// `LANDLOCK_ACCESS_FS_WRITE_FILE`
+        const WRITE_FILE = 1 << 1;
+        /// `LANDLOCK_ACCESS_FS_READ_FILE`
+        const READ_FILE = 1 << 2;
+        /// `LANDLOCK_ACCESS_FS_READ_DIR`
+        const READ_DIR = 1 << 3;
+        /// `LANDLOCK_ACCESS_FS_REMOVE_DIR`
+        const REMOVE_DIR = 1 << 4;
+        /// `LANDLOCK_ACCESS_FS_REMOVE_FILE`
+        const REMOVE_FILE = 1 << 5;
+        /// `LANDLOCK_ACCESS_FS_MAKE_CHAR`
+        const MAKE_CHAR = 1 << 6;
+        /// `LANDLOCK_ACCESS_FS_MAKE_DIR`
+        const MAKE_DIR = 1 << 7;
+        /// `LANDLOCK_ACCESS_FS_MAKE_REG`
+        const MAKE_REG = 1 << 8;
+        /// `LANDLOCK_ACCESS_FS_MAKE_SOCK`
+        const MAKE_SOCK = 1 << 9;
+        /// `LANDLOCK_ACCESS_FS_MAKE_FIFO`
+        const MAKE_FIFO = 1 << 10;
+        /// `LANDLOCK_ACCESS_FS_MAKE_BLOCK`
+        const MAKE_BLOCK = 1 << 11;
+        /// `LANDLOCK_ACCESS_FS_MAKE_SYM`
+        const MAKE_SYM = 1 << 12;
+        /// `LANDLOCK_ACCESS_FS_REFER` (since ABI version 2)
+        const REFER = 1 << 13;
+        /// `LANDLOCK_ACCESS_FS_TRUNCATE` (since ABI version 3)
+        const TRUNCATE = 1 << 14;
+        /// `LANDLOCK_ACCESS_FS_IOCTL_DEV` (since ABI version 5)
+        const IOCTL_DEV = 1 << 15;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+impl AccessFs {
+    /// Returns all the filesystem accesses which can be handled by the given
+    /// Landlock ABI version, as returned by [`abi_version`].
+    pub const fn from_abi(abi: u32) -> Self {
+        let v1 = Self::EXECUTE.bits()
+            | Self::WRITE_FILE.bits()
+            | Self::READ_FILE.bits()
+            | Self::READ_DIR.bits()
+            | Self::REMOVE_DIR.bits()
+            | Self::REMOVE_FILE.bits()
+            | Self::MAKE_CHAR.bits()
+            | Self::MAKE_DIR.bits()
+            | Self::MAKE_REG.bits()
+            | Self::MAKE_SOCK.bits()
+            | Self::MAKE_FIFO.bits()
+            | Self::MAKE_BLOCK.bits()
+            | Self::MAKE_SYM.bits();
+        let bits = match abi {
+            0 => 0,
+            1 => v1,
+            2 => v1 | Self::REFER.bits(),
+            3 | 4 => v1 | Self::REFER.bits() | Self::TRUNCATE.bits(),
+            _ => v1 | Self::REFER.bits() | Self::TRUNCATE.bits() | Self::IOCTL_DEV.bits(),
+        };
+        Self::from_bits_retain(bits)
+    }
+}
+
+bitflags! {
+    /// `LANDLOCK_ACCESS_NET_*` flags for use with [`create_ruleset`] and
+    /// [`add_net_port_rule`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct AccessNet: u64 {
+        /// `LANDLOCK_ACCESS_NET_BIND_TCP` (since ABI version 4)
+        const BIND_TCP = 1 << 0;
+        /// `LANDLOCK_ACCESS_NET_CONNECT_TCP` (since ABI version 4)
+        const CONNECT_TCP = 1 << 1;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+impl AccessNet {
+    /// Returns all the network accesses which can be handled by the given
+    /// Landlock ABI version, as returned by [`abi_version`].
+    pub const fn from_abi(abi: u32) -> Self {
+        if abi >= 4 {
+            Self::from_bits_retain(Self::BIND_TCP.bits() | Self::CONNECT_TCP.bits())
+        } else {
+            Self::empty()
+        }
+    }
+}
+
+/// `landlock_create_ruleset(NULL, 0, LANDLOCK_CREATE_RULESET_VERSION)`—Returns
+/// the highest Landlock ABI version supported by the kernel.
+///
+/// This fails with [`io::Errno::NOSYS`] if the kernel doesn't support
+/// Landlock, and [`io::Errno::OPNOTSUPP`] if Landlock is supported but has
+/// been disabled at boot time.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/landlock_create_ruleset.2.html
+#[doc(alias = "landlock_create_ruleset")]
+#[doc(alias = "LANDLOCK_CREATE_RULESET_VERSION")]
+#[inline]
+pub fn abi_version() -> io::Result<u32> {
+    backend::process::syscalls::landlock_abi_version()
+}
+
+/// `landlock_create_ruleset(&attr, sizeof(attr), 0)`—Creates a new Landlock
+/// ruleset which handles the given accesses.
+///
+/// Accesses in `handled_fs` and `handled_net` are denied once the ruleset is
+/// enforced, except where allowed by a rule. Passing accesses not known to
+/// the running kernel fails with [`io::Errno::INVAL`]; use
+/// [`AccessFs::from_abi`] and [`AccessNet::from_abi`] to mask them.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/landlock_create_ruleset.2.html
+#[doc(alias = "landlock_create_ruleset")]
+#[inline]
+pub fn create_ruleset(handled_fs: AccessFs, handled_net: AccessNet) -> io::Result<OwnedFd> {
+    let attr = RulesetAttr {
+        handled_access_fs: handled_fs.bits(),
+        handled_access_net: handled_net.bits(),
+    };
+    backend::process::syscalls::landlock_create_ruleset(&attr)
+}
+
+/// `landlock_add_rule(ruleset, LANDLOCK_RULE_PATH_BENEATH, &attr, 0)`—Allows
+/// the given accesses to the file hierarchy beneath `parent`.
+///
+/// `parent` may be a directory or a file, and may be opened with `O_PATH`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/landlock_add_rule.2.html
+#[doc(alias = "landlock_add_rule")]
+#[doc(alias = "LANDLOCK_RULE_PATH_BENEATH")]
+#[inline]
+pub fn add_path_beneath_rule<Ruleset: AsFd, Parent: AsFd>(
+    ruleset: Ruleset,
+    allowed: AccessFs,
+    parent: Parent,
+) -> io::Result<()> {
+    let attr = PathBeneathAttr {
+        allowed_access: allowed.bits(),
+        parent_fd: parent.as_fd().as_raw_fd(),
+    };
+    backend::process::syscalls::landlock_add_path_beneath_rule(ruleset.as_fd(), &attr)
+}
+
+/// `landlock_add_rule(ruleset, LANDLOCK_RULE_NET_PORT, &attr, 0)`—Allows the
+/// given accesses for the given TCP port.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/landlock_add_rule.2.html
+#[doc(alias = "landlock_add_rule")]
+#[doc(alias = "LANDLOCK_RULE_NET_PORT")]
+#[inline]
+pub fn add_net_port_rule<Ruleset: AsFd>(
+    ruleset: Ruleset,
+    allowed: AccessNet,
+    port: u16,
+) -> io::Result<()> {
+    let attr = NetPortAttr {
+        allowed_access: allowed.bits(),
+        port: port.into(),
+    };
+    backend::process::syscalls::landlock_add_net_port_rule(ruleset.as_fd(), &attr)
+}
+
+/// `landlock_restrict_self(ruleset, 0)`—Enforces a Landlock ruleset on the
+/// calling thread.
+///
+/// The calling thread must have called [`set_no_new_privs`] or have
+/// `CAP_SYS_ADMIN` in its user namespace. The restriction is inherited by
+/// threads and processes created afterward, and cannot be undone.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/landlock_restrict_self.2.html
+/// [`set_no_new_privs`]: crate::thread::set_no_new_privs
+#[doc(alias = "landlock_restrict_self")]
+#[inline]
+pub fn restrict_self<Ruleset: AsFd>(ruleset: Ruleset) -> io::Result<()> {
+    backend::process::syscalls::landlock_restrict_self(ruleset.as_fd())
+}
@@ -11,6 +11,8 @@ mod id;
 mod ioctl;
 #[cfg(not(any(target_os = "espidf", target_os = "wasi")))]
 mod kill;
+#[cfg(linux_kernel)]
+pub mod landlock;
 #[cfg(target_os = "linux")]
 mod pidfd;
 #[cfg(target_os = "linux")]
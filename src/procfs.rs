@@ -21,11 +21,13 @@
 use crate::fd::{AsFd, BorrowedFd, OwnedFd};
 use crate::ffi::CStr;
 use crate::fs::{
-    fstat, fstatfs, major, openat, renameat, seek, FileType, FsWord, Mode, OFlags, RawDir,
-    SeekFrom, Stat, CWD, PROC_SUPER_MAGIC,
+    fstat, fstatfs, major, makedev, openat, renameat, seek, Dev, FileType, FsWord, Mode, OFlags,
+    RawDir, SeekFrom, Stat, CWD, PROC_SUPER_MAGIC,
 };
 use crate::io;
 use crate::path::DecInt;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 #[cfg(feature = "rustc-dep-of-std")]
 use core::lazy::OnceCell;
 use core::mem::MaybeUninit;
@@ -441,6 +443,22 @@ pub fn proc_self_status() -> io::Result<OwnedFd> {
     proc_self_file(cstr!("status"))
 }
 
+/// Returns a handle to a Linux `/proc/self/mountinfo` file.
+///
+/// This ensures that `/proc/self/mountinfo` is `procfs`, that nothing is
+/// mounted on top of it, and that it looks normal. See [`mountinfo`] for a
+/// parser.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man5/proc_pid_mountinfo.5.html
+#[inline]
+#[cfg_attr(docsrs, doc(cfg(feature = "procfs")))]
+pub fn proc_self_mountinfo() -> io::Result<OwnedFd> {
+    proc_self_file(cstr!("mountinfo"))
+}
+
 /// Open a file under `/proc/self`.
 fn proc_self_file(name: &CStr) -> io::Result<OwnedFd> {
     let (proc_self, proc_self_stat) = proc_self()?;
@@ -529,3 +547,431 @@ fn open_and_check_file(
         Err(io::Errno::NOTSUP)
     }
 }
+
+/// Reads and parses Linux's `/proc/self/mountinfo` file, which describes the
+/// mounts in the current process' mount namespace.
+///
+/// This reads the file through [`proc_self_mountinfo`], so it has the same
+/// checks that `/proc` is really `procfs`. The whole file is read into one
+/// buffer, and the returned entries borrow their fields from it. On Linux
+/// 6.8 and later, `listmount` and `statmount` in `rustix::mount` provide
+/// this information without parsing.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man5/proc_pid_mountinfo.5.html
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "procfs", feature = "alloc"))))]
+pub fn mountinfo() -> io::Result<MountInfo> {
+    let file = proc_self_mountinfo()?;
+
+    // procfs files don't report their size, so read until we get to the end.
+    let mut buf = Vec::new();
+    let mut len = 0;
+    loop {
+        if len == buf.len() {
+            buf.resize(len + 4096, 0);
+        }
+        let nread = io::retry_on_intr(|| io::read(&file, &mut buf[len..]))?;
+        if nread == 0 {
+            break;
+        }
+        len += nread;
+    }
+    buf.truncate(len);
+
+    MountInfo::parse(buf)
+}
+
+/// The contents of Linux's `/proc/self/mountinfo` file, returned by
+/// [`mountinfo`].
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub struct MountInfo {
+    /// The contents of the file, with each field unescaped and
+    /// NUL-terminated in place.
+    buf: Vec<u8>,
+
+    /// The offset of the end of each line in `buf`.
+    ends: Vec<usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl MountInfo {
+    /// Unescape and NUL-terminate the fields of each line in `buf`, in
+    /// place, and check that each line is well-formed.
+    fn parse(mut buf: Vec<u8>) -> io::Result<Self> {
+        let mut ends = Vec::new();
+
+        // Each input byte produces at most one output byte, so writing at
+        // `write` never clobbers input we haven't read yet.
+        let mut read = 0;
+        let mut write = 0;
+        while read < buf.len() {
+            let line_end = buf[read..]
+                .iter()
+                .position(|b| *b == b'\n')
+                .map_or(buf.len(), |i| read + i);
+            if line_end == read {
+                read += 1;
+                continue;
+            }
+
+            let start = write;
+            while read < line_end {
+                let byte = match buf[read] {
+                    b' ' => b'\0',
+                    b'\\' => match unescape_octal(&buf[read..line_end]) {
+                        Some(byte) => {
+                            read += 3;
+                            byte
+                        }
+                        None => b'\\',
+                    },
+                    byte => byte,
+                };
+                buf[write] = byte;
+                read += 1;
+                write += 1;
+            }
+
+            // Terminate the last field in the newline's place, or append a
+            // terminator if the file doesn't end with a newline.
+            if write < buf.len() {
+                buf[write] = b'\0';
+            } else {
+                buf.push(b'\0');
+            }
+            write += 1;
+            read = line_end + 1;
+
+            if parse_mountinfo_line(&buf[start..write]).is_none() {
+                return Err(io::Errno::INVAL);
+            }
+            ends.push(write);
+        }
+        buf.truncate(write);
+
+        Ok(Self { buf, ends })
+    }
+
+    /// Returns an iterator over the entries.
+    #[inline]
+    pub fn iter(&self) -> MountInfoIter<'_> {
+        MountInfoIter {
+            buf: &self.buf,
+            ends: self.ends.iter(),
+            start: 0,
+        }
+    }
+
+    /// Returns the number of entries.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Returns `true` if there are no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> IntoIterator for &'a MountInfo {
+    type Item = MountInfoEntry<'a>;
+    type IntoIter = MountInfoIter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Debug for MountInfo {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// An iterator over the entries of a [`MountInfo`].
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct MountInfoIter<'a> {
+    buf: &'a [u8],
+    ends: core::slice::Iter<'a, usize>,
+    start: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Iterator for MountInfoIter<'a> {
+    type Item = MountInfoEntry<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let end = *self.ends.next()?;
+        let line = &self.buf[self.start..end];
+        self.start = end;
+        parse_mountinfo_line(line)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ends.size_hint()
+    }
+}
+
+/// An entry in Linux's `/proc/self/mountinfo` file, describing one mount.
+///
+/// Paths are unescaped; the kernel escapes spaces, tabs, newlines, and
+/// backslashes in them.
+#[derive(Clone, Copy)]
+pub struct MountInfoEntry<'a> {
+    mount_id: u32,
+    parent_id: u32,
+    major: u32,
+    minor: u32,
+    root: &'a CStr,
+    mount_point: &'a CStr,
+    mount_options: &'a CStr,
+    optional_fields: &'a [u8],
+    fs_type: &'a CStr,
+    source: &'a CStr,
+    super_options: &'a CStr,
+}
+
+impl<'a> MountInfoEntry<'a> {
+    /// The ID of the mount. This may be reused after the mount is
+    /// unmounted.
+    #[inline]
+    pub fn mount_id(&self) -> u32 {
+        self.mount_id
+    }
+
+    /// The ID of the parent mount, or of this mount if it's at the root of
+    /// the mount tree.
+    #[inline]
+    pub fn parent_id(&self) -> u32 {
+        self.parent_id
+    }
+
+    /// The major number of the device holding the filesystem.
+    #[inline]
+    pub fn major(&self) -> u32 {
+        self.major
+    }
+
+    /// The minor number of the device holding the filesystem.
+    #[inline]
+    pub fn minor(&self) -> u32 {
+        self.minor
+    }
+
+    /// The device holding the filesystem, as it would appear in `st_dev`.
+    #[inline]
+    pub fn dev(&self) -> Dev {
+        makedev(self.major, self.minor)
+    }
+
+    /// The directory within the filesystem which forms the root of the
+    /// mount.
+    #[inline]
+    pub fn root(&self) -> &'a CStr {
+        self.root
+    }
+
+    /// The mount point, relative to the process' root directory.
+    #[inline]
+    pub fn mount_point(&self) -> &'a CStr {
+        self.mount_point
+    }
+
+    /// The comma-separated per-mount options.
+    #[inline]
+    pub fn mount_options(&self) -> &'a CStr {
+        self.mount_options
+    }
+
+    /// The optional fields, such as `shared:N` and `master:N`.
+    #[inline]
+    pub fn optional_fields(&self) -> MountInfoOptionalFields<'a> {
+        MountInfoOptionalFields {
+            rest: self.optional_fields,
+        }
+    }
+
+    /// The filesystem type, in the form `type[.subtype]`.
+    #[inline]
+    pub fn fs_type(&self) -> &'a CStr {
+        self.fs_type
+    }
+
+    /// The filesystem-specific source, such as a device path, or `none`.
+    #[inline]
+    pub fn source(&self) -> &'a CStr {
+        self.source
+    }
+
+    /// The comma-separated per-superblock options.
+    #[inline]
+    pub fn super_options(&self) -> &'a CStr {
+        self.super_options
+    }
+}
+
+impl<'a> core::fmt::Debug for MountInfoEntry<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MountInfoEntry")
+            .field("mount_id", &self.mount_id)
+            .field("parent_id", &self.parent_id)
+            .field("major", &self.major)
+            .field("minor", &self.minor)
+            .field("root", &self.root)
+            .field("mount_point", &self.mount_point)
+            .field("mount_options", &self.mount_options)
+            .field("optional_fields", &self.optional_fields())
+            .field("fs_type", &self.fs_type)
+            .field("source", &self.source)
+            .field("super_options", &self.super_options)
+            .finish()
+    }
+}
+
+/// An iterator over the optional fields of a [`MountInfoEntry`].
+#[derive(Clone)]
+pub struct MountInfoOptionalFields<'a> {
+    rest: &'a [u8],
+}
+
+impl<'a> Iterator for MountInfoOptionalFields<'a> {
+    type Item = &'a CStr;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        next_field(&mut self.rest)
+    }
+}
+
+impl<'a> core::fmt::Debug for MountInfoOptionalFields<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// Decode a `\ooo` octal escape at the start of `bytes`.
+fn unescape_octal(bytes: &[u8]) -> Option<u8> {
+    let digits = bytes.get(1..4)?;
+    let mut value: u32 = 0;
+    for digit in digits {
+        match digit {
+            b'0'..=b'7' => value = value * 8 + u32::from(digit - b'0'),
+            _ => return None,
+        }
+    }
+    // Don't decode NULs, which can't appear in the strings we return.
+    match u8::try_from(value) {
+        Ok(0) | Err(_) => None,
+        Ok(byte) => Some(byte),
+    }
+}
+
+/// Split the next NUL-terminated field off the front of `rest`.
+fn next_field<'a>(rest: &mut &'a [u8]) -> Option<&'a CStr> {
+    let nul = rest.iter().position(|b| *b == b'\0')?;
+    let (field, tail) = rest.split_at(nul + 1);
+    *rest = tail;
+    CStr::from_bytes_with_nul(field).ok()
+}
+
+/// Parse a decimal number field.
+fn parse_u32(field: &[u8]) -> Option<u32> {
+    core::str::from_utf8(field).ok()?.parse().ok()
+}
+
+/// Parse a line of `/proc/self/mountinfo` which has been unescaped and
+/// NUL-terminated by `MountInfo::parse`.
+fn parse_mountinfo_line(line: &[u8]) -> Option<MountInfoEntry<'_>> {
+    let mut rest = line;
+    let mount_id = parse_u32(next_field(&mut rest)?.to_bytes())?;
+    let parent_id = parse_u32(next_field(&mut rest)?.to_bytes())?;
+    let dev = next_field(&mut rest)?.to_bytes();
+    let colon = dev.iter().position(|b| *b == b':')?;
+    let major = parse_u32(&dev[..colon])?;
+    let minor = parse_u32(&dev[colon + 1..])?;
+    let root = next_field(&mut rest)?;
+    let mount_point = next_field(&mut rest)?;
+    let mount_options = next_field(&mut rest)?;
+
+    // The optional fields are terminated by a field containing just "-".
+    let optional_start = rest;
+    let mut optional_len = 0;
+    loop {
+        let field = next_field(&mut rest)?;
+        if field.to_bytes() == b"-" {
+            break;
+        }
+        optional_len += field.to_bytes_with_nul().len();
+    }
+    let optional_fields = &optional_start[..optional_len];
+
+    let fs_type = next_field(&mut rest)?;
+    let source = next_field(&mut rest)?;
+    let super_options = next_field(&mut rest)?;
+
+    Some(MountInfoEntry {
+        mount_id,
+        parent_id,
+        major,
+        minor,
+        root,
+        mount_point,
+        mount_options,
+        optional_fields,
+        fs_type,
+        source,
+        super_options,
+    })
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_parse_mountinfo() {
+    let text = b"36 35 98:0 /mnt1 /mnt2 rw,noatime master:1 - ext3 /dev/root rw,errors=continue\n\
+        22 1 0:21 / /with\\040space\\134 rw shared:5 master:2 - tmpfs none rw\n\
+        23 22 0:22 / /no/optional rw - proc proc rw";
+    let info = MountInfo::parse(text.to_vec()).unwrap();
+    assert_eq!(info.len(), 3);
+
+    let entries = info.iter().collect::<Vec<_>>();
+    assert_eq!(entries[0].mount_id(), 36);
+    assert_eq!(entries[0].parent_id(), 35);
+    assert_eq!((entries[0].major(), entries[0].minor()), (98, 0));
+    assert_eq!(entries[0].root(), cstr!("/mnt1"));
+    assert_eq!(entries[0].mount_point(), cstr!("/mnt2"));
+    assert_eq!(entries[0].mount_options(), cstr!("rw,noatime"));
+    assert_eq!(
+        entries[0].optional_fields().collect::<Vec<_>>(),
+        [cstr!("master:1")]
+    );
+    assert_eq!(entries[0].fs_type(), cstr!("ext3"));
+    assert_eq!(entries[0].source(), cstr!("/dev/root"));
+    assert_eq!(entries[0].super_options(), cstr!("rw,errors=continue"));
+
+    assert_eq!(entries[1].mount_point(), cstr!("/with space\\"));
+    assert_eq!(
+        entries[1].optional_fields().collect::<Vec<_>>(),
+        [cstr!("shared:5"), cstr!("master:2")]
+    );
+    assert_eq!(entries[1].fs_type(), cstr!("tmpfs"));
+
+    assert_eq!(entries[2].mount_point(), cstr!("/no/optional"));
+    assert_eq!(entries[2].optional_fields().count(), 0);
+    assert_eq!(entries[2].super_options(), cstr!("rw"));
+
+    assert_eq!(
+        MountInfo::parse(b"36 35 98:0 /mnt1 /mnt2 rw master:1\n".to_vec()).unwrap_err(),
+        io::Errno::INVAL
+    );
+}
@@ -0,0 +1,85 @@
+#![allow(unsafe_code)]
+
+use crate::backend::c;
+use crate::signal::{SigSet, Siginfo, Signal};
+use crate::{backend, io};
+use core::ffi::c_void;
+
+pub use backend::signal::types::SigactionFlags;
+
+/// A signal disposition, for use with [`SigAction`].
+#[derive(Copy, Clone, Debug)]
+pub enum SigHandler {
+    /// `SIG_DFL`—Perform the default action for the signal.
+    #[doc(alias = "SIG_DFL")]
+    Default,
+
+    /// `SIG_IGN`—Ignore the signal.
+    #[doc(alias = "SIG_IGN")]
+    Ignore,
+
+    /// Call a function, passing it the signal number.
+    Handler(extern "C" fn(c::c_int)),
+
+    /// `SA_SIGINFO`—Call a function, passing it the signal number, a
+    /// [`Siginfo`] describing the signal, and a pointer to the interrupted
+    /// context.
+    #[doc(alias = "SA_SIGINFO")]
+    SigInfo(extern "C" fn(c::c_int, *mut Siginfo, *mut c_void)),
+}
+
+/// `struct sigaction`—A signal action, for use with [`sigaction`].
+#[doc(alias = "sigaction")]
+#[derive(Copy, Clone, Debug)]
+pub struct SigAction {
+    /// What to do when the signal is delivered.
+    pub handler: SigHandler,
+
+    /// Flags modifying the behavior of the handler.
+    pub flags: SigactionFlags,
+
+    /// Signals to block while the handler runs, in addition to the signal
+    /// itself unless [`SigactionFlags::NODEFER`] is set.
+    pub mask: SigSet,
+}
+
+impl SigAction {
+    /// Returns a new `SigAction` with the given handler, no flags, and an
+    /// empty mask.
+    #[inline]
+    pub fn new(handler: SigHandler) -> Self {
+        Self {
+            handler,
+            flags: SigactionFlags::empty(),
+            mask: SigSet::empty(),
+        }
+    }
+}
+
+/// `sigaction(signal, &new, &old)`—Modify or query a signal's action.
+///
+/// If `new` is `None`, the action is left unchanged. In either case, the
+/// previous action is returned.
+///
+/// # Safety
+///
+/// Signal handlers run asynchronously, interrupting whatever the thread was
+/// doing, so a [`SigHandler::Handler`] or [`SigHandler::SigInfo`] function
+/// must only do things which are async-signal-safe.
+///
+/// Other parts of the program, including libc and the Rust standard library,
+/// may depend on the actions of some signals; for example, `std` installs
+/// handlers for `SIGSEGV` and `SIGBUS` to detect stack overflows, and
+/// expects `SIGPIPE` to be ignored.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/sigaction.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/sigaction.2.html
+#[doc(alias = "rt_sigaction")]
+#[inline]
+pub unsafe fn sigaction(signal: Signal, new: Option<&SigAction>) -> io::Result<SigAction> {
+    backend::signal::syscalls::sigaction(signal, new)
+}
@@ -0,0 +1,54 @@
+use crate::backend::c;
+use crate::signal::SigSet;
+use crate::{backend, io};
+
+/// `SIG_*` constants for use with [`thread_sigmask`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum SigmaskHow {
+    /// `SIG_BLOCK`—Add the signals in the set to the mask.
+    Block = c::SIG_BLOCK,
+    /// `SIG_UNBLOCK`—Remove the signals in the set from the mask.
+    Unblock = c::SIG_UNBLOCK,
+    /// `SIG_SETMASK`—Replace the mask with the set.
+    SetMask = c::SIG_SETMASK,
+}
+
+/// `pthread_sigmask(how, set, &old)`—Adjust the calling thread's signal
+/// mask.
+///
+/// If `set` is `None`, the mask is left unchanged and `how` is ignored. In
+/// either case, the previous mask is returned.
+///
+/// The mask is per-thread, so this is the function to use for blocking
+/// signals before waiting for them with [`sigwait`] or a signalfd.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [`sigwait`]: crate::signal::sigwait
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/pthread_sigmask.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/pthread_sigmask.3.html
+#[doc(alias = "pthread_sigmask")]
+#[doc(alias = "sigprocmask")]
+#[doc(alias = "rt_sigprocmask")]
+#[inline]
+pub fn thread_sigmask(how: SigmaskHow, set: Option<&SigSet>) -> io::Result<SigSet> {
+    backend::signal::syscalls::thread_sigmask(how, set)
+}
+
+/// `sigpending()`—Query the set of signals that are pending for the calling
+/// thread or process, because they are blocked.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/sigpending.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/sigpending.2.html
+#[doc(alias = "rt_sigpending")]
+#[inline]
+pub fn sigpending() -> SigSet {
+    backend::signal::syscalls::sigpending()
+}
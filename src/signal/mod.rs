@@ -1,4 +1,31 @@
+//! Signal numbers, signal sets, and signal handling.
+//!
+//! Most of this module is only available on Linux. The [`Signal`] type is
+//! also used by [`rustix::process`] for sending signals.
+//!
+//! [`rustix::process`]: crate::process
+
 use crate::backend::c;
+#[cfg(all(linux_kernel, any(feature = "event", feature = "signal")))]
+use crate::backend::signal::types::{sigaddset, sigdelset, sigemptyset, sigismember, RawSigSet};
+#[cfg(all(linux_kernel, any(feature = "event", feature = "signal")))]
+use core::fmt;
+
+#[cfg(all(linux_kernel, feature = "signal"))]
+mod action;
+#[cfg(all(linux_kernel, feature = "signal"))]
+mod mask;
+#[cfg(all(linux_kernel, feature = "signal"))]
+mod wait;
+
+#[cfg(all(linux_kernel, feature = "signal"))]
+pub use crate::timespec::{Nsecs, Secs, Timespec};
+#[cfg(all(linux_kernel, feature = "signal"))]
+pub use action::{sigaction, SigAction, SigHandler, SigactionFlags};
+#[cfg(all(linux_kernel, feature = "signal"))]
+pub use mask::{sigpending, thread_sigmask, SigmaskHow};
+#[cfg(all(linux_kernel, feature = "signal"))]
+pub use wait::{sigtimedwait, sigwait, sigwaitinfo, Siginfo};
 
 /// A signal number for use with [`kill_process`], [`kill_process_group`], and
 /// [`kill_current_process_group`].
@@ -246,6 +273,162 @@ impl Signal {
     }
 }
 
+/// `sigset_t`—A set of [`Signal`]s.
+///
+/// This is used with [`signalfd`], [`thread_sigmask`], [`sigwait`], and
+/// related functions.
+///
+/// The methods for building a `SigSet` only add signals that [`Signal`] can
+/// represent. Sets obtained from the OS, such as from [`thread_sigmask`], may
+/// also contain real-time signals, which [`SigSet::iter`] skips, but which
+/// [`SigSet::is_empty`] and comparisons take into account.
+///
+/// [`signalfd`]: crate::event::signalfd
+/// [`thread_sigmask`]: crate::signal::thread_sigmask
+/// [`sigwait`]: crate::signal::sigwait
+#[cfg(all(linux_kernel, any(feature = "event", feature = "signal")))]
+#[doc(alias = "sigset_t")]
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct SigSet(RawSigSet);
+
+/// The range of standard, non-real-time, signal numbers on Linux.
+#[cfg(all(linux_kernel, any(feature = "event", feature = "signal")))]
+const STANDARD_SIGNALS: core::ops::Range<c::c_int> = 1..32;
+
+/// The range of all signal numbers on Linux, including real-time signals.
+#[cfg(all(linux_kernel, any(feature = "event", feature = "signal")))]
+const ALL_SIGNALS: core::ops::RangeInclusive<c::c_int> =
+    1..=linux_raw_sys::general::_NSIG as c::c_int;
+
+#[cfg(all(linux_kernel, any(feature = "event", feature = "signal")))]
+impl SigSet {
+    /// Returns a new set containing no signals.
+    #[doc(alias = "sigemptyset")]
+    #[inline]
+    pub fn empty() -> Self {
+        Self(sigemptyset())
+    }
+
+    /// Returns a new set containing every signal that [`Signal`] can
+    /// represent.
+    #[doc(alias = "sigfillset")]
+    #[inline]
+    pub fn full() -> Self {
+        let mut set = sigemptyset();
+        for sig in STANDARD_SIGNALS {
+            sigaddset(&mut set, sig);
+        }
+        Self(set)
+    }
+
+    /// Adds `sig` to this set.
+    #[doc(alias = "sigaddset")]
+    #[inline]
+    pub fn add(&mut self, sig: Signal) {
+        sigaddset(&mut self.0, sig as c::c_int)
+    }
+
+    /// Removes `sig` from this set.
+    #[doc(alias = "sigdelset")]
+    #[inline]
+    pub fn remove(&mut self, sig: Signal) {
+        sigdelset(&mut self.0, sig as c::c_int)
+    }
+
+    /// Tests whether `sig` is in this set.
+    #[doc(alias = "sigismember")]
+    #[inline]
+    pub fn contains(&self, sig: Signal) -> bool {
+        sigismember(&self.0, sig as c::c_int)
+    }
+
+    /// Tests whether this set contains no signals, including any signals
+    /// that [`Signal`] can't represent.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        !ALL_SIGNALS.into_iter().any(|sig| sigismember(&self.0, sig))
+    }
+
+    /// Returns an iterator over the signals in this set, in order of their
+    /// signal numbers.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = Signal> + '_ {
+        STANDARD_SIGNALS
+            .filter(move |sig| sigismember(&self.0, *sig))
+            .filter_map(Signal::from_raw)
+    }
+
+    /// Tests whether this set contains any signals that [`Signal`] can't
+    /// represent, such as real-time signals in a set obtained from the OS.
+    #[cfg(feature = "signal")]
+    pub(crate) fn has_unrepresentable(&self) -> bool {
+        ALL_SIGNALS
+            .into_iter()
+            .any(|sig| sigismember(&self.0, sig) && Signal::from_raw(sig).is_none())
+    }
+
+    /// Returns a reference to the underlying raw signal set.
+    #[inline]
+    pub(crate) fn as_raw(&self) -> &RawSigSet {
+        &self.0
+    }
+
+    /// Wraps a raw signal set obtained from the OS.
+    #[cfg(feature = "signal")]
+    #[inline]
+    pub(crate) fn from_raw(raw: RawSigSet) -> Self {
+        Self(raw)
+    }
+}
+
+#[cfg(all(linux_kernel, any(feature = "event", feature = "signal")))]
+impl Default for SigSet {
+    #[inline]
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+#[cfg(all(linux_kernel, any(feature = "event", feature = "signal")))]
+impl PartialEq for SigSet {
+    fn eq(&self, other: &Self) -> bool {
+        // Compare every signal number rather than the raw bytes, as libc's
+        // `sigset_t` may have padding beyond the kernel's signal set.
+        ALL_SIGNALS
+            .into_iter()
+            .all(|sig| sigismember(&self.0, sig) == sigismember(&other.0, sig))
+    }
+}
+
+#[cfg(all(linux_kernel, any(feature = "event", feature = "signal")))]
+impl Eq for SigSet {}
+
+#[cfg(all(linux_kernel, any(feature = "event", feature = "signal")))]
+impl FromIterator<Signal> for SigSet {
+    fn from_iter<I: IntoIterator<Item = Signal>>(iter: I) -> Self {
+        let mut set = Self::empty();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(all(linux_kernel, any(feature = "event", feature = "signal")))]
+impl Extend<Signal> for SigSet {
+    fn extend<I: IntoIterator<Item = Signal>>(&mut self, iter: I) {
+        for sig in iter {
+            self.add(sig);
+        }
+    }
+}
+
+#[cfg(all(linux_kernel, any(feature = "event", feature = "signal")))]
+impl fmt::Debug for SigSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
 #[test]
 fn test_sizes() {
     assert_eq_size!(Signal, c::c_int);
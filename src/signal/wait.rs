@@ -0,0 +1,156 @@
+use crate::backend::c;
+use crate::backend::signal::types::{
+    siginfo_addr, siginfo_code, siginfo_errno, siginfo_pid, siginfo_signo, siginfo_status,
+    siginfo_uid, RawSiginfo,
+};
+use crate::pid::Pid;
+use crate::signal::{SigSet, Signal};
+use crate::timespec::Timespec;
+use crate::ugid::Uid;
+use crate::{backend, io};
+use core::ffi::c_void;
+use core::fmt;
+
+/// `sigwait(set)`—Wait for one of the signals in `set` to become pending,
+/// and accept it.
+///
+/// The signals in `set` should be blocked, with [`thread_sigmask`], in all
+/// threads, so that they stay pending until this function accepts them.
+///
+/// If `set` contains signals that [`Signal`] can't represent, such as
+/// real-time signals in a set returned by [`thread_sigmask`] or
+/// [`sigpending`], this fails with [`io::Errno::INVAL`] without waiting, so
+/// that it never accepts a signal it can't return. Use [`sigwaitinfo`] to
+/// wait for such signals.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [`thread_sigmask`]: crate::signal::thread_sigmask
+/// [`sigpending`]: crate::signal::sigpending
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/sigwait.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/sigwait.3.html
+#[inline]
+pub fn sigwait(set: &SigSet) -> io::Result<Signal> {
+    if set.has_unrepresentable() {
+        return Err(io::Errno::INVAL);
+    }
+    backend::signal::syscalls::sigwait(set)
+}
+
+/// `sigwaitinfo(set)`—Wait for one of the signals in `set` to become
+/// pending, and accept it, returning a [`Siginfo`].
+///
+/// As with [`sigwait`], the signals in `set` should be blocked.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/sigwaitinfo.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/sigwaitinfo.2.html
+#[doc(alias = "rt_sigtimedwait")]
+#[inline]
+pub fn sigwaitinfo(set: &SigSet) -> io::Result<Siginfo> {
+    backend::signal::syscalls::sigwaitinfo(set).map(Siginfo)
+}
+
+/// `sigtimedwait(set, timeout)`—Like [`sigwaitinfo`], but with a timeout.
+///
+/// If no signal in `set` becomes pending within `timeout`, this fails with
+/// [`io::Errno::AGAIN`]. A `timeout` of `None` waits indefinitely.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9799919799/functions/sigtimedwait.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/sigtimedwait.2.html
+#[doc(alias = "rt_sigtimedwait")]
+#[inline]
+pub fn sigtimedwait(set: &SigSet, timeout: Option<&Timespec>) -> io::Result<Siginfo> {
+    backend::signal::syscalls::sigtimedwait(set, timeout).map(Siginfo)
+}
+
+/// `siginfo_t`—Information about a signal.
+///
+/// Which fields are meaningful depends on the signal and on [`code`]. See
+/// the [Linux] documentation for details.
+///
+/// [`code`]: Self::code
+/// [Linux]: https://man7.org/linux/man-pages/man2/sigaction.2.html
+#[doc(alias = "siginfo_t")]
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct Siginfo(RawSiginfo);
+
+impl Siginfo {
+    /// Returns the signal, if it is one that [`Signal`] can represent.
+    #[inline]
+    pub fn signal(&self) -> Option<Signal> {
+        Signal::from_raw(self.signo())
+    }
+
+    /// `si_signo`—Returns the raw signal number.
+    #[inline]
+    pub fn signo(&self) -> c::c_int {
+        siginfo_signo(&self.0)
+    }
+
+    /// `si_errno`—Returns the error number, which is usually unused.
+    #[inline]
+    pub fn errno(&self) -> c::c_int {
+        siginfo_errno(&self.0)
+    }
+
+    /// `si_code`—Returns the signal code, such as `SI_USER` or `SI_QUEUE`,
+    /// which describes why the signal was sent.
+    #[inline]
+    pub fn code(&self) -> c::c_int {
+        siginfo_code(&self.0)
+    }
+
+    /// `si_pid`—Returns the PID of the sender.
+    ///
+    /// This is meaningful for signals sent by `kill`, `sigqueue`, and
+    /// `tgkill`, and for `SIGCHLD`.
+    #[inline]
+    pub fn pid(&self) -> Option<Pid> {
+        Pid::from_raw(siginfo_pid(&self.0))
+    }
+
+    /// `si_uid`—Returns the real UID of the sender.
+    ///
+    /// This is meaningful in the same cases as [`pid`].
+    ///
+    /// [`pid`]: Self::pid
+    #[inline]
+    pub fn uid(&self) -> Uid {
+        siginfo_uid(&self.0)
+    }
+
+    /// `si_status`—Returns the exit status or signal of the child, for
+    /// `SIGCHLD`.
+    #[inline]
+    pub fn status(&self) -> c::c_int {
+        siginfo_status(&self.0)
+    }
+
+    /// `si_addr`—Returns the address of the fault, for `SIGSEGV`, `SIGBUS`,
+    /// `SIGILL`, and `SIGFPE`.
+    #[inline]
+    pub fn addr(&self) -> *mut c_void {
+        siginfo_addr(&self.0)
+    }
+}
+
+impl fmt::Debug for Siginfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Siginfo")
+            .field("signo", &self.signo())
+            .field("errno", &self.errno())
+            .field("code", &self.code())
+            .finish_non_exhaustive()
+    }
+}
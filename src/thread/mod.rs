@@ -16,6 +16,8 @@ mod prctl;
 mod sched;
 mod sched_yield;
 #[cfg(linux_kernel)]
+pub mod seccomp;
+#[cfg(linux_kernel)]
 mod setns;
 
 #[cfg(linux_kernel)]
@@ -0,0 +1,646 @@
+//! Linux seccomp filters.
+//!
+//! A seccomp filter is a classic BPF program which runs on each system call
+//! the calling thread makes, inspecting a [`seccomp_data`] record and
+//! returning an [`Action`] which decides what happens to the call.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use rustix::thread::{seccomp, set_no_new_privs};
+//! use rustix::thread::seccomp::{Action, SockFilter};
+//!
+//! # fn test() -> rustix::io::Result<()> {
+//! // Fail `getpid` with `EPERM`, and allow everything else.
+//! let filter = [
+//!     seccomp::load_arch(),
+//!     SockFilter::jump_eq(seccomp::AUDIT_ARCH, 1, 0),
+//!     SockFilter::ret(Action::KillProcess.to_raw()),
+//!     seccomp::load_syscall_nr(),
+//!     SockFilter::jump_eq(libc::SYS_getpid as u32, 0, 1),
+//!     SockFilter::ret(Action::Errno(1).to_raw()),
+//!     SockFilter::ret(Action::Allow.to_raw()),
+//! ];
+//!
+//! set_no_new_privs(true)?;
+//! seccomp::set_mode_filter(seccomp::FilterFlags::empty(), &filter)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! A filter installed with [`set_mode_filter_with_listener`] can forward
+//! system calls to a supervisor with [`Action::UserNotif`]. The supervisor
+//! receives them from the listener with [`notif_recv`], and decides their
+//! outcome with [`notif_send`].
+//!
+//! # References
+//!  - [Linux `seccomp` system call]
+//!  - [Linux `seccomp_unotify`]
+//!  - [Linux kernel documentation]
+//!
+//! [`seccomp_data`]: https://man7.org/linux/man-pages/man2/seccomp.2.html
+//! [Linux `seccomp` system call]: https://man7.org/linux/man-pages/man2/seccomp.2.html
+//! [Linux `seccomp_unotify`]: https://man7.org/linux/man-pages/man2/seccomp_unotify.2.html
+//! [Linux kernel documentation]: https://docs.kernel.org/userspace-api/seccomp_filter.html
+#![allow(unsafe_code)]
+
+use crate::backend::c;
+use crate::bpf_filter::SockFprog;
+use crate::fd::{AsFd, AsRawFd, RawFd};
+use crate::{backend, io, ioctl};
+use bitflags::bitflags;
+
+pub use crate::bpf_filter::SockFilter;
+
+// linux-raw-sys doesn't provide <linux/seccomp.h>, so define what we need
+// here.
+
+/// `SECCOMP_SET_MODE_FILTER`
+pub(crate) const SET_MODE_FILTER: u32 = 1;
+
+/// `SECCOMP_GET_ACTION_AVAIL`
+pub(crate) const GET_ACTION_AVAIL: u32 = 2;
+
+const RET_KILL_PROCESS: u32 = 0x8000_0000;
+const RET_KILL_THREAD: u32 = 0x0000_0000;
+const RET_TRAP: u32 = 0x0003_0000;
+const RET_ERRNO: u32 = 0x0005_0000;
+const RET_USER_NOTIF: u32 = 0x7fc0_0000;
+const RET_TRACE: u32 = 0x7ff0_0000;
+const RET_LOG: u32 = 0x7ffc_0000;
+const RET_ALLOW: u32 = 0x7fff_0000;
+const RET_ACTION_FULL: u32 = 0xffff_0000;
+const RET_DATA: u32 = 0x0000_ffff;
+
+bitflags! {
+    /// `SECCOMP_FILTER_FLAG_*` flags for use with [`set_mode_filter`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct FilterFlags: u32 {
+        /// `SECCOMP_FILTER_FLAG_TSYNC`—Install the filter on all threads of
+        /// the process.
+        const TSYNC = 1 << 0;
+        /// `SECCOMP_FILTER_FLAG_LOG`—Log all actions except `Allow`.
+        const LOG = 1 << 1;
+        /// `SECCOMP_FILTER_FLAG_SPEC_ALLOW`—Don't enable speculative store
+        /// bypass mitigation.
+        const SPEC_ALLOW = 1 << 2;
+        /// `SECCOMP_FILTER_FLAG_NEW_LISTENER`—Return a notification file
+        /// descriptor for [`Action::UserNotif`].
+        const NEW_LISTENER = 1 << 3;
+        /// `SECCOMP_FILTER_FLAG_TSYNC_ESRCH`—With `TSYNC`, fail with
+        /// `ESRCH` instead of returning a thread ID.
+        const TSYNC_ESRCH = 1 << 4;
+        /// `SECCOMP_FILTER_FLAG_WAIT_KILLABLE_RECV`—With `NEW_LISTENER`,
+        /// only allow fatal signals to interrupt a notified system call once
+        /// the supervisor has received it.
+        const WAIT_KILLABLE_RECV = 1 << 5;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// `SECCOMP_RET_*`—The action for a filter to return.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Action {
+    /// `SECCOMP_RET_KILL_PROCESS`—Kill the process.
+    KillProcess,
+    /// `SECCOMP_RET_KILL_THREAD`—Kill the calling thread.
+    KillThread,
+    /// `SECCOMP_RET_TRAP`—Send the calling thread a `SIGSYS`.
+    Trap,
+    /// `SECCOMP_RET_ERRNO`—Fail the system call with the given error
+    /// number.
+    Errno(u16),
+    /// `SECCOMP_RET_USER_NOTIF`—Forward the system call to a supervisor
+    /// listening on a notification file descriptor.
+    UserNotif,
+    /// `SECCOMP_RET_TRACE`—Notify a `ptrace` tracer, passing it the given
+    /// value.
+    Trace(u16),
+    /// `SECCOMP_RET_LOG`—Log the system call, then allow it.
+    Log,
+    /// `SECCOMP_RET_ALLOW`—Allow the system call.
+    Allow,
+}
+
+impl Action {
+    /// Encode this action as a filter return value.
+    #[inline]
+    pub const fn to_raw(self) -> u32 {
+        match self {
+            Self::KillProcess => RET_KILL_PROCESS,
+            Self::KillThread => RET_KILL_THREAD,
+            Self::Trap => RET_TRAP,
+            Self::Errno(errno) => RET_ERRNO | errno as u32,
+            Self::UserNotif => RET_USER_NOTIF,
+            Self::Trace(data) => RET_TRACE | data as u32,
+            Self::Log => RET_LOG,
+            Self::Allow => RET_ALLOW,
+        }
+    }
+
+    /// Decode a filter return value, returning `None` if it doesn't contain
+    /// a known action.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Option<Self> {
+        let data = (raw & RET_DATA) as u16;
+        Some(match raw & RET_ACTION_FULL {
+            RET_KILL_PROCESS => Self::KillProcess,
+            RET_KILL_THREAD => Self::KillThread,
+            RET_TRAP => Self::Trap,
+            RET_ERRNO => Self::Errno(data),
+            RET_USER_NOTIF => Self::UserNotif,
+            RET_TRACE => Self::Trace(data),
+            RET_LOG => Self::Log,
+            RET_ALLOW => Self::Allow,
+            _ => return None,
+        })
+    }
+}
+
+/// The `AUDIT_ARCH_*` value for the current target, as found in the `arch`
+/// field of `seccomp_data`.
+///
+/// Filters should check this before checking system call numbers, because
+/// the same number means different things on different architectures.
+#[cfg(any(
+    target_arch = "x86_64",
+    target_arch = "x86",
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64",
+    target_arch = "powerpc64",
+    target_arch = "s390x",
+    target_arch = "mips",
+    target_arch = "mips32r6",
+    target_arch = "mips64",
+    target_arch = "mips64r6",
+))]
+pub const AUDIT_ARCH: u32 = {
+    // `EM_*` machine numbers from <linux/elf-em.h>, and flags from
+    // <linux/audit.h>.
+    const AUDIT_ARCH_64BIT: u32 = 0x8000_0000;
+    const AUDIT_ARCH_LE: u32 = 0x4000_0000;
+
+    #[cfg(target_arch = "x86_64")]
+    const EM: u32 = 62;
+    #[cfg(target_arch = "x86")]
+    const EM: u32 = 3;
+    #[cfg(target_arch = "aarch64")]
+    const EM: u32 = 183;
+    #[cfg(target_arch = "arm")]
+    const EM: u32 = 40;
+    #[cfg(target_arch = "riscv64")]
+    const EM: u32 = 243;
+    #[cfg(target_arch = "powerpc64")]
+    const EM: u32 = 21;
+    #[cfg(target_arch = "s390x")]
+    const EM: u32 = 22;
+    #[cfg(any(
+        target_arch = "mips",
+        target_arch = "mips32r6",
+        target_arch = "mips64",
+        target_arch = "mips64r6"
+    ))]
+    const EM: u32 = 8;
+
+    let mut arch = EM;
+    if cfg!(any(
+        target_arch = "x86_64",
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "powerpc64",
+        target_arch = "s390x",
+        target_arch = "mips64",
+        target_arch = "mips64r6"
+    )) {
+        arch |= AUDIT_ARCH_64BIT;
+    }
+    if cfg!(target_endian = "little") {
+        arch |= AUDIT_ARCH_LE;
+    }
+    arch
+};
+
+/// Offset of the `nr` field in `struct seccomp_data`.
+const DATA_NR: u32 = 0;
+/// Offset of the `arch` field in `struct seccomp_data`.
+const DATA_ARCH: u32 = 4;
+/// Offset of the `instruction_pointer` field in `struct seccomp_data`.
+const DATA_INSTRUCTION_POINTER: u32 = 8;
+/// Offset of the `args` field in `struct seccomp_data`.
+const DATA_ARGS: u32 = 16;
+
+/// Load the system call number from `seccomp_data` into the accumulator.
+#[inline]
+pub const fn load_syscall_nr() -> SockFilter {
+    SockFilter::load_word(DATA_NR)
+}
+
+/// Load the `AUDIT_ARCH_*` value from `seccomp_data` into the accumulator.
+///
+/// Compare it against [`AUDIT_ARCH`] for the current target.
+#[inline]
+pub const fn load_arch() -> SockFilter {
+    SockFilter::load_word(DATA_ARCH)
+}
+
+/// Load the low 32 bits of the instruction pointer from `seccomp_data` into
+/// the accumulator.
+#[inline]
+pub const fn load_instruction_pointer_low() -> SockFilter {
+    SockFilter::load_word(DATA_INSTRUCTION_POINTER + low_word_offset())
+}
+
+/// Load the low 32 bits of system call argument `index`, which must be less
+/// than 6, from `seccomp_data` into the accumulator.
+#[inline]
+pub const fn load_arg_low(index: u32) -> SockFilter {
+    assert!(index < 6);
+    SockFilter::load_word(DATA_ARGS + index * 8 + low_word_offset())
+}
+
+/// Load the high 32 bits of system call argument `index`, which must be less
+/// than 6, from `seccomp_data` into the accumulator.
+#[inline]
+pub const fn load_arg_high(index: u32) -> SockFilter {
+    assert!(index < 6);
+    SockFilter::load_word(DATA_ARGS + index * 8 + (4 - low_word_offset()))
+}
+
+/// The offset of the low half of a 64-bit field in `seccomp_data`, which
+/// stores fields in native byte order.
+#[inline]
+const fn low_word_offset() -> u32 {
+    if cfg!(target_endian = "little") {
+        0
+    } else {
+        4
+    }
+}
+
+/// `seccomp(SECCOMP_SET_MODE_FILTER, flags, &prog)`—Install a seccomp filter
+/// on the calling thread.
+///
+/// Unless the calling thread has `CAP_SYS_ADMIN` in its user namespace, it
+/// must first call [`set_no_new_privs`].
+///
+/// If `flags` contains [`FilterFlags::TSYNC`] and another thread can't be
+/// synchronized, this fails with [`io::Errno::SRCH`], as if
+/// [`FilterFlags::TSYNC_ESRCH`] were also set.
+///
+/// `flags` must not contain [`FilterFlags::NEW_LISTENER`]; use
+/// [`set_mode_filter_with_listener`] for that.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp.2.html
+/// [`set_no_new_privs`]: crate::thread::set_no_new_privs
+#[doc(alias = "seccomp")]
+#[doc(alias = "SECCOMP_SET_MODE_FILTER")]
+#[inline]
+pub fn set_mode_filter(flags: FilterFlags, filter: &[SockFilter]) -> io::Result<()> {
+    if flags.contains(FilterFlags::NEW_LISTENER) {
+        return Err(io::Errno::INVAL);
+    }
+    let prog = SockFprog::new(filter)?;
+    match backend::thread::syscalls::seccomp_set_mode_filter(flags.bits(), &prog)? {
+        0 => Ok(()),
+        // With `TSYNC`, a positive return value is the ID of a thread which
+        // couldn't be synchronized.
+        _ => Err(io::Errno::SRCH),
+    }
+}
+
+/// `seccomp(SECCOMP_SET_MODE_FILTER, flags | SECCOMP_FILTER_FLAG_NEW_LISTENER,
+/// &prog)`—Install a seccomp filter on the calling thread, and return a
+/// notification file descriptor for system calls the filter forwards with
+/// [`Action::UserNotif`].
+///
+/// See [`set_mode_filter`] for details.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp.2.html
+#[doc(alias = "seccomp")]
+#[doc(alias = "SECCOMP_FILTER_FLAG_NEW_LISTENER")]
+#[inline]
+pub fn set_mode_filter_with_listener(
+    flags: FilterFlags,
+    filter: &[SockFilter],
+) -> io::Result<crate::fd::OwnedFd> {
+    let prog = SockFprog::new(filter)?;
+    backend::thread::syscalls::seccomp_set_mode_filter_listener(
+        (flags | FilterFlags::NEW_LISTENER).bits(),
+        &prog,
+    )
+}
+
+/// `seccomp(SECCOMP_GET_ACTION_AVAIL, 0, &action)`—Test whether the kernel
+/// supports a filter action.
+///
+/// Only the kind of action is checked; any data in `action` is ignored.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp.2.html
+#[doc(alias = "seccomp")]
+#[doc(alias = "SECCOMP_GET_ACTION_AVAIL")]
+#[inline]
+pub fn action_avail(action: Action) -> io::Result<bool> {
+    let raw = action.to_raw() & RET_ACTION_FULL;
+    match backend::thread::syscalls::seccomp_get_action_avail(&raw) {
+        Ok(()) => Ok(true),
+        Err(io::Errno::OPNOTSUPP) => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+/// `struct seccomp_data`—The system call a filter inspects, as reported to a
+/// supervisor in [`SeccompNotif`].
+#[doc(alias = "seccomp_data")]
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct SeccompData {
+    /// The system call number.
+    pub nr: i32,
+    /// The `AUDIT_ARCH_*` value for the calling convention; see
+    /// [`AUDIT_ARCH`].
+    pub arch: u32,
+    /// The instruction pointer at the time of the system call.
+    pub instruction_pointer: u64,
+    /// The system call arguments.
+    pub args: [u64; 6],
+}
+
+/// `struct seccomp_notif`—A system call forwarded to a supervisor.
+#[doc(alias = "seccomp_notif")]
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct SeccompNotif {
+    /// The cookie identifying this notification.
+    pub id: u64,
+    /// The thread ID of the caller, in the supervisor's PID namespace, or 0
+    /// if it isn't visible there.
+    pub pid: u32,
+    /// Currently unused, and always 0.
+    pub flags: u32,
+    /// The system call.
+    pub data: SeccompData,
+}
+
+/// `struct seccomp_notif_resp`—A supervisor's response to a
+/// [`SeccompNotif`].
+#[doc(alias = "seccomp_notif_resp")]
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SeccompNotifResp {
+    /// The cookie from the [`SeccompNotif`] being responded to.
+    pub id: u64,
+    /// The system call's return value, if `error` is 0.
+    pub val: i64,
+    /// The negated error number for the system call to fail with, or 0.
+    pub error: i32,
+    /// Response flags.
+    pub flags: NotifRespFlags,
+}
+
+impl SeccompNotifResp {
+    /// A response which makes the system call return `val`.
+    #[inline]
+    pub const fn new_return(id: u64, val: i64) -> Self {
+        Self {
+            id,
+            val,
+            error: 0,
+            flags: NotifRespFlags::empty(),
+        }
+    }
+
+    /// A response which makes the system call fail with `errno`.
+    #[inline]
+    pub const fn new_error(id: u64, errno: io::Errno) -> Self {
+        Self {
+            id,
+            val: 0,
+            error: -errno.raw_os_error(),
+            flags: NotifRespFlags::empty(),
+        }
+    }
+
+    /// `SECCOMP_USER_NOTIF_FLAG_CONTINUE`—A response which lets the kernel
+    /// execute the system call as usual.
+    ///
+    /// The system call's arguments may have changed since the supervisor
+    /// inspected them, so this must not be used to make security decisions.
+    #[doc(alias = "SECCOMP_USER_NOTIF_FLAG_CONTINUE")]
+    #[inline]
+    pub const fn new_continue(id: u64) -> Self {
+        Self {
+            id,
+            val: 0,
+            error: 0,
+            flags: NotifRespFlags::CONTINUE,
+        }
+    }
+}
+
+bitflags! {
+    /// `SECCOMP_USER_NOTIF_FLAG_*` flags for use with [`SeccompNotifResp`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct NotifRespFlags: u32 {
+        /// `SECCOMP_USER_NOTIF_FLAG_CONTINUE`—Execute the system call as
+        /// usual, ignoring `val` and `error`.
+        const CONTINUE = 1 << 0;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+bitflags! {
+    /// `SECCOMP_ADDFD_FLAG_*` flags for use with [`notif_addfd`].
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    pub struct AddfdFlags: u32 {
+        /// `SECCOMP_ADDFD_FLAG_SEND`—Also respond to the notification,
+        /// making the system call return the new file descriptor number.
+        const SEND = 1 << 1;
+
+        /// <https://docs.rs/bitflags/*/bitflags/#externally-defined-flags>
+        const _ = !0;
+    }
+}
+
+/// `SECCOMP_IOC_MAGIC`
+const IOC_MAGIC: u8 = b'!';
+
+/// `SECCOMP_ADDFD_FLAG_SETFD`
+const ADDFD_FLAG_SETFD: u32 = 1 << 0;
+
+/// `struct seccomp_notif_addfd`
+#[repr(C)]
+struct NotifAddfdAttr {
+    id: u64,
+    flags: u32,
+    srcfd: u32,
+    newfd: u32,
+    newfd_flags: u32,
+}
+
+/// `ioctl(fd, SECCOMP_IOCTL_NOTIF_RECV, &notif)`—Wait for a system call to
+/// be forwarded to the supervisor.
+///
+/// This fails with [`io::Errno::NOENT`] if the system call was interrupted,
+/// or its caller was killed, before it could be received.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp_unotify.2.html
+#[doc(alias = "SECCOMP_IOCTL_NOTIF_RECV")]
+#[inline]
+pub fn notif_recv<Fd: AsFd>(listener: Fd) -> io::Result<SeccompNotif> {
+    // The kernel requires the buffer to be zeroed.
+    let mut notif = SeccompNotif::default();
+
+    // SAFETY: `SECCOMP_IOCTL_NOTIF_RECV` is a read/write opcode that updates
+    // a `struct seccomp_notif`.
+    unsafe {
+        let ctl = ioctl::Updater::<ioctl::ReadWriteOpcode<IOC_MAGIC, 0, SeccompNotif>, _>::new(
+            &mut notif,
+        );
+        ioctl::ioctl(listener, ctl)?;
+    }
+    Ok(notif)
+}
+
+/// `ioctl(fd, SECCOMP_IOCTL_NOTIF_SEND, &resp)`—Respond to a system call
+/// received with [`notif_recv`].
+///
+/// This fails with [`io::Errno::NOENT`] if the system call was interrupted,
+/// or its caller was killed, after it was received.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp_unotify.2.html
+#[doc(alias = "SECCOMP_IOCTL_NOTIF_SEND")]
+#[inline]
+pub fn notif_send<Fd: AsFd>(listener: Fd, resp: &SeccompNotifResp) -> io::Result<()> {
+    let mut resp = *resp;
+
+    // SAFETY: `SECCOMP_IOCTL_NOTIF_SEND` is a read/write opcode that takes a
+    // `struct seccomp_notif_resp`.
+    unsafe {
+        let ctl = ioctl::Updater::<ioctl::ReadWriteOpcode<IOC_MAGIC, 1, SeccompNotifResp>, _>::new(
+            &mut resp,
+        );
+        ioctl::ioctl(listener, ctl)
+    }
+}
+
+/// `ioctl(fd, SECCOMP_IOCTL_NOTIF_ID_VALID, &id)`—Test whether a received
+/// notification is still pending.
+///
+/// A supervisor which inspects the caller's memory, for example through
+/// `/proc/<pid>/mem`, should check this afterward, to ensure that the caller
+/// hasn't been replaced by a different process with the same ID.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp_unotify.2.html
+#[doc(alias = "SECCOMP_IOCTL_NOTIF_ID_VALID")]
+#[inline]
+pub fn notif_id_valid<Fd: AsFd>(listener: Fd, id: u64) -> io::Result<bool> {
+    // SAFETY: `SECCOMP_IOCTL_NOTIF_ID_VALID` is a write opcode that takes a
+    // `u64`.
+    let result = unsafe {
+        let ctl = ioctl::Setter::<ioctl::WriteOpcode<IOC_MAGIC, 2, u64>, u64>::new(id);
+        ioctl::ioctl(listener, ctl)
+    };
+    match result {
+        Ok(()) => Ok(true),
+        Err(io::Errno::NOENT) => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+/// `ioctl(fd, SECCOMP_IOCTL_NOTIF_ADDFD, &addfd)`—Install a copy of `src` in
+/// the file descriptor table of a notification's caller, returning its file
+/// descriptor number there.
+///
+/// If `target` is `Some`, the file descriptor is installed at that number,
+/// replacing any file descriptor already open there. `fd_flags` may contain
+/// [`io::FdFlags::CLOEXEC`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp_unotify.2.html
+#[doc(alias = "SECCOMP_IOCTL_NOTIF_ADDFD")]
+#[inline]
+pub fn notif_addfd<Fd: AsFd, SrcFd: AsFd>(
+    listener: Fd,
+    id: u64,
+    src: SrcFd,
+    target: Option<RawFd>,
+    fd_flags: io::FdFlags,
+    flags: AddfdFlags,
+) -> io::Result<RawFd> {
+    let mut attr = NotifAddfdAttr {
+        id,
+        flags: flags.bits(),
+        srcfd: src.as_fd().as_raw_fd() as u32,
+        newfd: 0,
+        newfd_flags: 0,
+    };
+    if let Some(target) = target {
+        attr.flags |= ADDFD_FLAG_SETFD;
+        attr.newfd = target as u32;
+    }
+    if fd_flags.contains(io::FdFlags::CLOEXEC) {
+        attr.newfd_flags |= c::O_CLOEXEC as u32;
+    }
+
+    // SAFETY: `NotifAddfd` implements `SECCOMP_IOCTL_NOTIF_ADDFD`.
+    unsafe { ioctl::ioctl(listener, NotifAddfd(attr)) }
+}
+
+struct NotifAddfd(NotifAddfdAttr);
+
+unsafe impl ioctl::Ioctl for NotifAddfd {
+    type Output = RawFd;
+
+    const IS_MUTATING: bool = false;
+    const OPCODE: ioctl::Opcode = ioctl::Opcode::write::<NotifAddfdAttr>(IOC_MAGIC, 3);
+
+    fn as_ptr(&mut self) -> *mut c::c_void {
+        (&mut self.0 as *mut NotifAddfdAttr).cast()
+    }
+
+    unsafe fn output_from_ptr(
+        out: ioctl::IoctlOutput,
+        _: *mut c::c_void,
+    ) -> io::Result<Self::Output> {
+        Ok(out as RawFd)
+    }
+}
+
+#[test]
+fn test_sizes() {
+    use core::mem::size_of;
+
+    assert_eq!(size_of::<SeccompData>(), 64);
+    assert_eq!(size_of::<SeccompNotif>(), 80);
+    assert_eq!(size_of::<SeccompNotifResp>(), 24);
+    assert_eq!(size_of::<NotifAddfdAttr>(), 24);
+}
@@ -0,0 +1,16 @@
+//! Tests for [`rustix::bpf`].
+
+#![cfg(feature = "bpf")]
+#![cfg(linux_kernel)]
+
+mod map;
+mod prog;
+
+/// Unprivileged users may not be allowed to use `bpf` at all, depending on
+/// `kernel.unprivileged_bpf_disabled`, so skip tests when it's denied.
+fn bpf_denied(err: rustix::io::Errno) -> bool {
+    matches!(
+        err,
+        rustix::io::Errno::PERM | rustix::io::Errno::ACCESS | rustix::io::Errno::NOSYS
+    )
+}
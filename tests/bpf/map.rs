@@ -0,0 +1,100 @@
+use rustix::bpf::{self, MapFlags, MapType, MapUpdateFlags};
+use rustix::io;
+
+#[test]
+fn test_array_map() {
+    let map = match bpf::map_create(MapType::ARRAY, 4, 8, 4, MapFlags::empty()) {
+        Ok(map) => map,
+        Err(err) if crate::bpf_denied(err) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    unsafe {
+        // Array elements always exist, and start out zeroed.
+        let mut value = [0xff_u8; 8];
+        bpf::map_lookup_elem(&map, &2_u32.to_ne_bytes(), &mut value).unwrap();
+        assert_eq!(value, [0; 8]);
+
+        bpf::map_update_elem(
+            &map,
+            &2_u32.to_ne_bytes(),
+            &0x1234_u64.to_ne_bytes(),
+            MapUpdateFlags::empty(),
+        )
+        .unwrap();
+        bpf::map_lookup_elem(&map, &2_u32.to_ne_bytes(), &mut value).unwrap();
+        assert_eq!(u64::from_ne_bytes(value), 0x1234);
+
+        // Out-of-range keys don't exist.
+        assert_eq!(
+            bpf::map_lookup_elem(&map, &4_u32.to_ne_bytes(), &mut value),
+            Err(io::Errno::NOENT)
+        );
+
+        // Array elements can't be deleted.
+        assert_eq!(
+            bpf::map_delete_elem(&map, &2_u32.to_ne_bytes()),
+            Err(io::Errno::INVAL)
+        );
+
+        // Iterate over all the keys.
+        let mut keys = Vec::new();
+        let mut next_key = [0_u8; 4];
+        loop {
+            let prev = keys.last().map(|key: &u32| key.to_ne_bytes());
+            match bpf::map_get_next_key(&map, prev.as_ref().map(|key| &key[..]), &mut next_key) {
+                Ok(()) => keys.push(u32::from_ne_bytes(next_key)),
+                Err(io::Errno::NOENT) => break,
+                Err(err) => panic!("{:?}", err),
+            }
+        }
+        assert_eq!(keys, [0, 1, 2, 3]);
+    }
+}
+
+#[test]
+fn test_hash_map() {
+    let map = match bpf::map_create(MapType::HASH, 4, 4, 16, MapFlags::NO_PREALLOC) {
+        Ok(map) => map,
+        Err(err) if crate::bpf_denied(err) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    let key = 7_u32.to_ne_bytes();
+    let mut value = [0_u8; 4];
+
+    unsafe {
+        assert_eq!(
+            bpf::map_lookup_elem(&map, &key, &mut value),
+            Err(io::Errno::NOENT)
+        );
+        assert_eq!(
+            bpf::map_update_elem(&map, &key, &1_u32.to_ne_bytes(), MapUpdateFlags::EXIST),
+            Err(io::Errno::NOENT)
+        );
+
+        bpf::map_update_elem(&map, &key, &1_u32.to_ne_bytes(), MapUpdateFlags::NOEXIST).unwrap();
+        assert_eq!(
+            bpf::map_update_elem(&map, &key, &2_u32.to_ne_bytes(), MapUpdateFlags::NOEXIST),
+            Err(io::Errno::EXIST)
+        );
+        bpf::map_update_elem(&map, &key, &3_u32.to_ne_bytes(), MapUpdateFlags::EXIST).unwrap();
+
+        bpf::map_lookup_elem(&map, &key, &mut value).unwrap();
+        assert_eq!(u32::from_ne_bytes(value), 3);
+
+        let mut next_key = [0_u8; 4];
+        bpf::map_get_next_key(&map, None, &mut next_key).unwrap();
+        assert_eq!(next_key, key);
+        assert_eq!(
+            bpf::map_get_next_key(&map, Some(&key), &mut next_key),
+            Err(io::Errno::NOENT)
+        );
+
+        bpf::map_delete_elem(&map, &key).unwrap();
+        assert_eq!(
+            bpf::map_lookup_elem(&map, &key, &mut value),
+            Err(io::Errno::NOENT)
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use rustix::bpf::{self, AttachFlags, AttachType, Insn, MapFlags, MapType, ProgType};
+use rustix::fd::AsFd;
+use rustix::{cstr, io};
+
+#[test]
+fn test_prog_load() {
+    let insns = [Insn::mov64_imm(0, 0), Insn::exit()];
+    let mut log = vec![0_u8; 4096];
+    let _prog = match bpf::prog_load(
+        ProgType::SOCKET_FILTER,
+        &insns,
+        cstr!("GPL"),
+        None,
+        Some(&mut log),
+    ) {
+        Ok(prog) => prog,
+        Err(err) if crate::bpf_denied(err) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+}
+
+#[test]
+fn test_prog_load_rejected() {
+    // Exit without setting `r0`, which the verifier rejects.
+    let insns = [Insn::exit()];
+    let mut log = vec![0_u8; 4096];
+    match bpf::prog_load(
+        ProgType::SOCKET_FILTER,
+        &insns,
+        cstr!("GPL"),
+        None,
+        Some(&mut log),
+    ) {
+        Ok(_) => panic!("the verifier accepted an invalid program"),
+        Err(io::Errno::PERM) | Err(io::Errno::NOSYS) => return,
+        Err(err) => assert_eq!(err, io::Errno::ACCESS),
+    }
+
+    let len = log.iter().position(|b| *b == b'\0').unwrap();
+    let log = std::str::from_utf8(&log[..len]).unwrap();
+    assert!(log.contains("R0"), "{}", log);
+}
+
+#[test]
+fn test_insn() {
+    let insn = Insn::new(0xbf, 3, 10, -8, 42);
+    assert_eq!(insn.code, 0xbf);
+    assert_eq!(insn.dst_reg(), 3);
+    assert_eq!(insn.src_reg(), 10);
+    assert_eq!(insn.off, -8);
+    assert_eq!(insn.imm, 42);
+}
+
+#[test]
+fn test_prog_load_with_map() {
+    let map = match bpf::map_create(MapType::ARRAY, 4, 8, 1, MapFlags::empty()) {
+        Ok(map) => map,
+        Err(err) if crate::bpf_denied(err) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    // r1 = map; r0 = 0; exit
+    let [ld0, ld1] = Insn::ld_map_fd(1, map.as_fd());
+    let insns = [ld0, ld1, Insn::mov64_imm(0, 0), Insn::exit()];
+    match bpf::prog_load(ProgType::SOCKET_FILTER, &insns, cstr!("GPL"), None, None) {
+        Ok(_) => {}
+        Err(err) if crate::bpf_denied(err) => {}
+        Err(err) => panic!("{:?}", err),
+    }
+}
+
+#[test]
+fn test_obj_pin() {
+    let map = match bpf::map_create(MapType::ARRAY, 4, 4, 1, MapFlags::empty()) {
+        Ok(map) => map,
+        Err(err) if crate::bpf_denied(err) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    // Pinning requires a BPF filesystem, which is usually at `/sys/fs/bpf`.
+    let path = format!("/sys/fs/bpf/rustix-test-{}", std::process::id());
+    match bpf::obj_pin(&map, &path) {
+        Ok(()) => {}
+        Err(io::Errno::NOENT)
+        | Err(io::Errno::PERM)
+        | Err(io::Errno::ACCESS)
+        | Err(io::Errno::OPNOTSUPP)
+        | Err(io::Errno::ROFS) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+
+    let value = 0x5678_u32.to_ne_bytes();
+    unsafe {
+        bpf::map_update_elem(
+            &map,
+            &0_u32.to_ne_bytes(),
+            &value,
+            bpf::MapUpdateFlags::empty(),
+        )
+        .unwrap();
+    }
+    drop(map);
+
+    let map = bpf::obj_get(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let mut got = [0_u8; 4];
+    unsafe {
+        bpf::map_lookup_elem(&map, &0_u32.to_ne_bytes(), &mut got).unwrap();
+    }
+    assert_eq!(got, value);
+}
+
+#[test]
+fn test_prog_attach_cgroup() {
+    // Use a fresh cgroup, so that we don't affect anything else.
+    let cgroup = format!("/sys/fs/cgroup/rustix-test-{}", std::process::id());
+    if std::fs::create_dir(&cgroup).is_err() {
+        return;
+    }
+    let dir = std::fs::File::open(&cgroup).unwrap();
+
+    // Allow all packets.
+    let insns = [Insn::mov64_imm(0, 1), Insn::exit()];
+    let prog = match bpf::prog_load(
+        ProgType::CGROUP_SKB,
+        &insns,
+        cstr!("GPL"),
+        Some(AttachType::CGROUP_INET_EGRESS),
+        None,
+    ) {
+        Ok(prog) => prog,
+        Err(err) if crate::bpf_denied(err) => {
+            std::fs::remove_dir(&cgroup).unwrap();
+            return;
+        }
+        Err(err) => panic!("{:?}", err),
+    };
+
+    let result = bpf::prog_attach(
+        &dir,
+        &prog,
+        AttachType::CGROUP_INET_EGRESS,
+        AttachFlags::ALLOW_MULTI,
+    );
+    if result.is_ok() {
+        bpf::prog_detach(&dir, &prog, AttachType::CGROUP_INET_EGRESS).unwrap();
+    }
+    drop(dir);
+    std::fs::remove_dir(&cgroup).unwrap();
+
+    match result {
+        Ok(()) => {}
+        // cgroup v1 directories don't support BPF programs.
+        Err(io::Errno::BADF) | Err(io::Errno::INVAL) => {}
+        Err(err) if crate::bpf_denied(err) => {}
+        Err(err) => panic!("{:?}", err),
+    }
+}
@@ -12,6 +12,8 @@ mod eventfd;
 mod poll;
 #[cfg(any(bsd, linux_kernel, windows, target_os = "wasi"))]
 mod select;
+#[cfg(linux_kernel)]
+mod signalfd;
 
 #[cfg(windows)]
 mod windows {
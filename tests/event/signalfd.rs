@@ -0,0 +1,97 @@
+use rustix::event::{
+    signalfd, signalfd_read, signalfd_set_mask, SigSet, Signal, SignalfdFlags, SignalfdSiginfo,
+};
+use rustix::io;
+use std::mem::MaybeUninit;
+
+/// Block `sig` in the current thread, returning the previous mask.
+fn block(sig: i32) -> libc::sigset_t {
+    unsafe {
+        let mut set = MaybeUninit::<libc::sigset_t>::uninit();
+        let mut old = MaybeUninit::<libc::sigset_t>::uninit();
+        libc::sigemptyset(set.as_mut_ptr());
+        libc::sigaddset(set.as_mut_ptr(), sig);
+        assert_eq!(
+            libc::pthread_sigmask(libc::SIG_BLOCK, set.as_ptr(), old.as_mut_ptr()),
+            0
+        );
+        old.assume_init()
+    }
+}
+
+fn restore(old: &libc::sigset_t) {
+    unsafe {
+        assert_eq!(
+            libc::pthread_sigmask(libc::SIG_SETMASK, old, std::ptr::null_mut()),
+            0
+        );
+    }
+}
+
+#[test]
+fn test_sigset() {
+    let mut set = SigSet::empty();
+    assert!(!set.contains(Signal::Usr1));
+    set.add(Signal::Usr1);
+    set.add(Signal::Term);
+    assert!(set.contains(Signal::Usr1));
+    assert!(set.contains(Signal::Term));
+    assert!(!set.contains(Signal::Usr2));
+    set.remove(Signal::Usr1);
+    assert!(!set.contains(Signal::Usr1));
+    assert!(set.contains(Signal::Term));
+}
+
+#[test]
+fn test_signalfd() {
+    let old = block(libc::SIGUSR1);
+
+    let mut mask = SigSet::empty();
+    mask.add(Signal::Usr1);
+    let fd = signalfd(&mask, SignalfdFlags::CLOEXEC | SignalfdFlags::NONBLOCK).unwrap();
+
+    assert_eq!(signalfd_read(&fd), Err(io::Errno::AGAIN));
+
+    unsafe {
+        assert_eq!(libc::pthread_kill(libc::pthread_self(), libc::SIGUSR1), 0);
+    }
+
+    let info = signalfd_read(&fd).unwrap();
+    assert_eq!(info.signal(), Some(Signal::Usr1));
+    assert_eq!(info.signo(), libc::SIGUSR1 as u32);
+    assert_eq!(info.code(), -6); // `SI_TKILL`
+    assert_eq!(info.pid(), std::process::id());
+
+    assert_eq!(signalfd_read(&fd), Err(io::Errno::AGAIN));
+
+    // Switch the mask to `SIGUSR2`; `SIGUSR1` is no longer reported.
+    let old2 = block(libc::SIGUSR2);
+    let mut mask = SigSet::empty();
+    mask.add(Signal::Usr2);
+    signalfd_set_mask(&fd, &mask).unwrap();
+
+    unsafe {
+        assert_eq!(libc::pthread_kill(libc::pthread_self(), libc::SIGUSR2), 0);
+    }
+
+    let info = signalfd_read(&fd).unwrap();
+    assert_eq!(info.signal(), Some(Signal::Usr2));
+
+    restore(&old2);
+    restore(&old);
+}
+
+#[test]
+fn test_signalfd_siginfo_from_bytes() {
+    let mut bytes = [0_u8; SignalfdSiginfo::SIZE];
+    bytes[0..4].copy_from_slice(&(libc::SIGTERM as u32).to_ne_bytes());
+    bytes[12..16].copy_from_slice(&1234_u32.to_ne_bytes());
+    bytes[44..48].copy_from_slice(&(-7_i32).to_ne_bytes());
+    bytes[96..100].copy_from_slice(&0xc000_003e_u32.to_ne_bytes());
+
+    let info = SignalfdSiginfo::from_bytes(&bytes);
+    assert_eq!(info.signal(), Some(Signal::Term));
+    assert_eq!(info.pid(), 1234);
+    assert_eq!(info.int(), -7);
+    assert_eq!(info.arch(), 0xc000_003e);
+}
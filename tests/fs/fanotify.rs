@@ -0,0 +1,150 @@
+use rustix::fs::fanotify::{self, CreateFlags, EventFlags, Info, MarkFlags, Response};
+use rustix::fs::{OFlags, CWD};
+use rustix::io::Errno;
+use std::fs::File;
+use std::mem::MaybeUninit;
+
+#[test]
+fn test_fanotify_dfid_name() {
+    let fanotify = match fanotify::init(
+        CreateFlags::CLOEXEC | CreateFlags::NONBLOCK | CreateFlags::REPORT_DFID_NAME,
+        OFlags::RDONLY,
+    ) {
+        // fanotify is unavailable, or this kernel doesn't support
+        // unprivileged use with file handles.
+        Err(Errno::NOSYS) | Err(Errno::PERM) | Err(Errno::INVAL) => return,
+        r => r.unwrap(),
+    };
+
+    let tmp = tempfile::tempdir().unwrap();
+    fanotify::mark(
+        &fanotify,
+        MarkFlags::ADD,
+        EventFlags::CREATE | EventFlags::DELETE,
+        CWD,
+        tmp.path(),
+    )
+    .unwrap();
+
+    File::create(tmp.path().join("foo")).unwrap();
+    std::fs::remove_file(tmp.path().join("foo")).unwrap();
+
+    let mut mask = EventFlags::empty();
+    let mut buf = [MaybeUninit::uninit(); 512];
+    let mut iter = unsafe { fanotify::Reader::new(&fanotify, &mut buf) };
+    loop {
+        let event = match iter.next() {
+            Err(Errno::WOULDBLOCK) => break,
+            r => r.unwrap(),
+        };
+
+        // With file handles, no file descriptors are reported.
+        assert!(event.fd().is_none());
+        assert_eq!(event.pid(), std::process::id() as i32);
+
+        let mut infos = event.info();
+        match infos.next() {
+            Some(Info::DfidName(id, name)) => {
+                assert!(!id.handle().is_empty());
+                assert_eq!(name.to_bytes(), b"foo");
+            }
+            info => panic!("unexpected info record {info:?}"),
+        }
+        assert!(infos.next().is_none());
+
+        // The kernel may merge the events, since they're for the same name.
+        mask |= event.mask();
+    }
+
+    assert_eq!(mask, EventFlags::CREATE | EventFlags::DELETE);
+}
+
+#[test]
+fn test_fanotify_permission() {
+    let fanotify = match fanotify::init(
+        CreateFlags::CLOEXEC | CreateFlags::CLASS_CONTENT | CreateFlags::REPORT_PIDFD,
+        OFlags::RDONLY | OFlags::CLOEXEC,
+    ) {
+        // Permission events require `CAP_SYS_ADMIN`, and `REPORT_PIDFD`
+        // requires Linux 5.15.
+        Err(Errno::NOSYS) | Err(Errno::PERM) | Err(Errno::INVAL) => return,
+        r => r.unwrap(),
+    };
+
+    let tmp = tempfile::tempdir().unwrap();
+    let path = tmp.path().join("file");
+    std::fs::write(&path, b"hello").unwrap();
+    fanotify::mark(&fanotify, MarkFlags::ADD, EventFlags::OPEN_PERM, CWD, &path).unwrap();
+
+    let opener = {
+        let path = path.clone();
+        std::thread::spawn(move || {
+            let denied = File::open(&path).unwrap_err();
+            let allowed = File::open(&path);
+            (denied.raw_os_error(), allowed.is_ok())
+        })
+    };
+
+    let mut buf = [MaybeUninit::uninit(); 512];
+    let mut iter = unsafe { fanotify::Reader::new(&fanotify, &mut buf) };
+    for response in [Response::DENY, Response::ALLOW] {
+        let event = iter.next().unwrap();
+        assert_eq!(event.mask(), EventFlags::OPEN_PERM);
+        assert_eq!(event.pid(), std::process::id() as i32);
+        assert!(event.pidfd().is_some());
+        assert_eq!(event.info().count(), 0);
+        fanotify::respond(&fanotify, event.fd().unwrap(), response).unwrap();
+    }
+
+    assert_eq!(
+        opener.join().unwrap(),
+        (Some(Errno::PERM.raw_os_error()), true)
+    );
+
+    fanotify::mark(&fanotify, MarkFlags::FLUSH, EventFlags::empty(), CWD, "/").unwrap();
+}
+
+#[test]
+fn test_fanotify_reader_closes_unreturned_fds() {
+    let fanotify = match fanotify::init(
+        CreateFlags::CLOEXEC | CreateFlags::NONBLOCK,
+        OFlags::RDONLY | OFlags::CLOEXEC,
+    ) {
+        // Reporting file descriptors requires `CAP_SYS_ADMIN`.
+        Err(Errno::NOSYS) | Err(Errno::PERM) | Err(Errno::INVAL) => return,
+        r => r.unwrap(),
+    };
+
+    let tmp = tempfile::tempdir().unwrap();
+    let tmp_path = tmp.path().canonicalize().unwrap();
+    for name in ["a", "b", "c"] {
+        let path = tmp_path.join(name);
+        std::fs::write(&path, b"hello").unwrap();
+        fanotify::mark(&fanotify, MarkFlags::ADD, EventFlags::OPEN, CWD, &path).unwrap();
+    }
+    for name in ["a", "b", "c"] {
+        File::open(tmp_path.join(name)).unwrap();
+    }
+
+    // Counts our open file descriptors for files in `tmp_path`.
+    let count_open = || {
+        std::fs::read_dir("/proc/self/fd")
+            .unwrap()
+            .filter_map(|entry| std::fs::read_link(entry.unwrap().path()).ok())
+            .filter(|target| target.starts_with(&tmp_path))
+            .count()
+    };
+
+    let mut buf = [MaybeUninit::uninit(); 4096];
+    let mut reader = unsafe { fanotify::Reader::new(&fanotify, &mut buf) };
+    let event = reader.next().unwrap();
+    assert!(event.fd().is_some());
+    drop(event);
+    assert!(!reader.is_buffer_empty());
+    assert_eq!(count_open(), 2);
+
+    // Dropping the reader closes the file descriptors of the events it read
+    // but didn't return.
+    drop(reader);
+    assert_eq!(count_open(), 0);
+}
@@ -0,0 +1,77 @@
+use rustix::fs::{
+    fstat, name_to_handle_at, open_by_handle_at, openat, statx, AtFlags, FileHandle, Mode, OFlags,
+    StatxFlags, CWD,
+};
+use rustix::io::{self, write};
+
+#[test]
+fn test_file_handle() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::WRONLY | OFlags::CREATE,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    write(&file, b"hello").unwrap();
+
+    let (handle, _mount_id) = match name_to_handle_at(&dir, "file", AtFlags::empty()) {
+        Ok(result) => result,
+        // Not all filesystems support file handles.
+        Err(io::Errno::OPNOTSUPP) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    assert!(!handle.handle().is_empty());
+
+    // The same file has the same handle.
+    let (again, _) = name_to_handle_at(&file, "", AtFlags::EMPTY_PATH).unwrap();
+    assert_eq!(again, handle);
+
+    // Handles survive serialization.
+    let handle = FileHandle::from_bytes(&handle.to_bytes()).unwrap();
+
+    let opened = match open_by_handle_at(&dir, &handle, OFlags::RDONLY | OFlags::CLOEXEC) {
+        Ok(opened) => opened,
+        // Opening by handle requires `CAP_DAC_READ_SEARCH`.
+        Err(io::Errno::PERM) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    assert_eq!(fstat(&opened).unwrap().st_ino, fstat(&file).unwrap().st_ino);
+
+    let mut buf = [0_u8; 5];
+    io::read(&opened, &mut buf).unwrap();
+    assert_eq!(&buf, b"hello");
+}
+
+#[test]
+fn test_file_handle_fid_and_unique_mount_id() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(CWD, tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    match name_to_handle_at(&dir, "", AtFlags::EMPTY_PATH | AtFlags::HANDLE_FID) {
+        Ok((handle, _)) => assert!(!handle.handle().is_empty()),
+        // `AT_HANDLE_FID` is new in Linux 6.5.
+        Err(io::Errno::INVAL | io::Errno::OPNOTSUPP) => {}
+        Err(err) => panic!("{:?}", err),
+    }
+
+    let mount_id = match name_to_handle_at(
+        &dir,
+        "",
+        AtFlags::EMPTY_PATH | AtFlags::HANDLE_MNT_ID_UNIQUE,
+    ) {
+        Ok((_, mount_id)) => mount_id,
+        // `AT_HANDLE_MNT_ID_UNIQUE` is new in Linux 6.12.
+        Err(io::Errno::INVAL | io::Errno::OPNOTSUPP) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    // It's the same ID that `statx` reports with `STATX_MNT_ID_UNIQUE`.
+    let mnt_id_unique = StatxFlags::from_bits_retain(0x4000);
+    let stat = statx(&dir, "", AtFlags::EMPTY_PATH, mnt_id_unique).unwrap();
+    if StatxFlags::from_bits_retain(stat.stx_mask).contains(mnt_id_unique) {
+        assert_eq!(stat.stx_mnt_id, mount_id);
+    }
+}
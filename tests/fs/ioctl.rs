@@ -23,3 +23,67 @@ fn test_ioctl_ficlone() {
         Err(err) => panic!("{:?}", err),
     }
 }
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_ioctl_ficlonerange() {
+    use rustix::io;
+    use std::io::{Read, Seek, Write};
+
+    let mut src = tempfile::tempfile().unwrap();
+    src.write_all(&[0xa5; 8192]).unwrap();
+    let mut dest = tempfile::tempfile().unwrap();
+    let readonly = std::fs::File::open("Cargo.toml").unwrap();
+
+    // `readonly` isn't opened for writing, so passing it as the output fails.
+    assert_eq!(
+        rustix::fs::ioctl_ficlonerange(&readonly, &src, 0, 4096, 0),
+        Err(io::Errno::BADF)
+    );
+
+    // Now try something that might succeed, though be prepared for filesystems
+    // that don't support this.
+    match rustix::fs::ioctl_ficlonerange(&dest, &src, 4096, 4096, 0) {
+        Ok(()) => {
+            let mut buf = Vec::new();
+            dest.rewind().unwrap();
+            dest.read_to_end(&mut buf).unwrap();
+            assert_eq!(buf, [0xa5; 4096]);
+        }
+        Err(io::Errno::OPNOTSUPP | io::Errno::XDEV | io::Errno::INVAL) => (),
+        Err(err) => panic!("{:?}", err),
+    }
+}
+
+#[cfg(all(linux_kernel, feature = "alloc"))]
+#[test]
+fn test_ioctl_fideduperange() {
+    use rustix::fd::AsFd;
+    use rustix::fs::FileDedupeRangeStatus;
+    use rustix::io;
+    use std::io::Write;
+
+    let mut src = tempfile::tempfile().unwrap();
+    src.write_all(&[0xa5; 8192]).unwrap();
+    let mut same = tempfile::tempfile().unwrap();
+    same.write_all(&[0xa5; 8192]).unwrap();
+    let mut differs = tempfile::tempfile().unwrap();
+    differs.write_all(&[0x5a; 8192]).unwrap();
+
+    let dests = [(same.as_fd(), 0), (differs.as_fd(), 4096)];
+    let infos = match rustix::fs::ioctl_fideduperange(&src, 0, 4096, &dests) {
+        Ok(infos) => infos,
+        // Not all filesystems support deduplication.
+        Err(io::Errno::OPNOTSUPP | io::Errno::INVAL | io::Errno::NOTTY) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    assert_eq!(infos.len(), 2);
+
+    match infos[0].status {
+        Ok(FileDedupeRangeStatus::Same) => assert_eq!(infos[0].bytes_deduped, 4096),
+        Err(io::Errno::OPNOTSUPP | io::Errno::INVAL) => return,
+        other => panic!("{:?}", other),
+    }
+    assert_eq!(infos[1].status, Ok(FileDedupeRangeStatus::Differs));
+    assert_eq!(infos[1].bytes_deduped, 0);
+}
@@ -7,6 +7,8 @@
 mod chmodat;
 #[cfg(not(target_os = "redox"))]
 mod dir;
+#[cfg(linux_kernel)]
+mod fanotify;
 mod fcntl;
 #[cfg(not(any(
     target_os = "emscripten",
@@ -17,6 +19,8 @@ mod fcntl;
 )))]
 mod fcntl_lock;
 mod file;
+#[cfg(linux_kernel)]
+mod file_handle;
 #[cfg(not(target_os = "wasi"))]
 mod flock;
 mod futimens;
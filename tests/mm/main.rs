@@ -9,3 +9,6 @@ mod mlock;
 mod mmap;
 #[cfg(not(any(windows, target_os = "wasi")))]
 mod prot;
+#[cfg(linux_kernel)]
+#[cfg(feature = "param")]
+mod userfaultfd;
@@ -0,0 +1,186 @@
+//! Tests for the typed userfaultfd ioctls.
+
+use rustix::fd::{AsRawFd, OwnedFd};
+use rustix::io::Errno;
+use rustix::mm::{
+    ioctl_uffdio_api, ioctl_uffdio_copy, ioctl_uffdio_register, ioctl_uffdio_unregister,
+    ioctl_uffdio_wake, ioctl_uffdio_zeropage, ioctl_userfaultfd_ioc_new, mmap_anonymous, munmap,
+    read_uffd_msg, userfaultfd, MapFlags, ProtFlags, UffdFeatureFlags, UffdIoctlFlags, UffdMsg,
+    UffdPagefaultFlags, UffdioCopyFlags, UffdioRegisterFlags, UffdioZeropageFlags,
+    UserfaultfdFlags,
+};
+use rustix::param::page_size;
+use std::ffi::c_void;
+use std::ptr::null_mut;
+
+/// Create a userfaultfd, with the syscall or with `/dev/userfaultfd`, or
+/// return `None` if this environment doesn't allow us to.
+fn new_uffd(flags: UserfaultfdFlags) -> Option<OwnedFd> {
+    let flags = flags | UserfaultfdFlags::CLOEXEC | UserfaultfdFlags::USER_MODE_ONLY;
+    match unsafe { userfaultfd(flags) } {
+        Ok(uffd) => return Some(uffd),
+        Err(Errno::PERM | Errno::NOSYS | Errno::INVAL) => {}
+        Err(err) => panic!("{:?}", err),
+    }
+    let dev = match std::fs::File::open("/dev/userfaultfd") {
+        Ok(dev) => dev,
+        Err(_) => return None,
+    };
+    match unsafe { ioctl_userfaultfd_ioc_new(&dev, flags) } {
+        Ok(uffd) => Some(uffd),
+        Err(Errno::PERM | Errno::ACCESS | Errno::NOTTY | Errno::INVAL) => None,
+        Err(err) => panic!("{:?}", err),
+    }
+}
+
+#[test]
+fn test_uffdio_api() {
+    let uffd = match new_uffd(UserfaultfdFlags::empty()) {
+        Some(uffd) => uffd,
+        None => return,
+    };
+
+    let api = ioctl_uffdio_api(&uffd, UffdFeatureFlags::empty()).unwrap();
+    assert!(api
+        .ioctls
+        .contains(UffdIoctlFlags::API | UffdIoctlFlags::REGISTER | UffdIoctlFlags::UNREGISTER));
+
+    // The handshake may only be done once.
+    assert!(ioctl_uffdio_api(&uffd, UffdFeatureFlags::empty()).is_err());
+}
+
+#[test]
+fn test_uffdio_copy_on_fault() {
+    let uffd = match new_uffd(UserfaultfdFlags::empty()) {
+        Some(uffd) => uffd,
+        None => return,
+    };
+    ioctl_uffdio_api(&uffd, UffdFeatureFlags::empty()).unwrap();
+
+    let page_size = page_size();
+    let len = page_size * 2;
+    unsafe {
+        let addr = mmap_anonymous(
+            null_mut(),
+            len,
+            ProtFlags::READ | ProtFlags::WRITE,
+            MapFlags::PRIVATE,
+        )
+        .unwrap();
+
+        let ioctls = ioctl_uffdio_register(&uffd, addr, len, UffdioRegisterFlags::MISSING).unwrap();
+        assert!(ioctls.contains(UffdIoctlFlags::COPY | UffdIoctlFlags::WAKE));
+
+        // Fault on the second page from another thread, which blocks until
+        // we resolve the fault.
+        let target = addr as usize + page_size + 7;
+        let reader = std::thread::spawn(move || std::ptr::read_volatile(target as *const u8));
+
+        match read_uffd_msg(&uffd).unwrap() {
+            UffdMsg::Pagefault { flags, address, .. } => {
+                assert!(!flags.contains(UffdPagefaultFlags::WRITE));
+                assert_eq!(address as usize, addr as usize + page_size);
+            }
+            other => panic!("unexpected message: {:?}", other),
+        }
+
+        let src = vec![0x5a_u8; page_size];
+        let copied = ioctl_uffdio_copy(
+            &uffd,
+            (addr as usize + page_size) as *mut c_void,
+            src.as_ptr().cast(),
+            page_size,
+            UffdioCopyFlags::empty(),
+        )
+        .unwrap();
+        assert_eq!(copied, page_size);
+        assert_eq!(reader.join().unwrap(), 0x5a);
+
+        // Populate the first page with zeros without waking anything, and
+        // then wake explicitly.
+        let zeroed =
+            ioctl_uffdio_zeropage(&uffd, addr, page_size, UffdioZeropageFlags::DONTWAKE).unwrap();
+        assert_eq!(zeroed, page_size);
+        ioctl_uffdio_wake(&uffd, addr, page_size).unwrap();
+        assert_eq!(std::ptr::read_volatile(addr.cast::<u8>()), 0);
+
+        // The pages are populated now, so copying over them fails.
+        assert_eq!(
+            ioctl_uffdio_copy(
+                &uffd,
+                addr,
+                src.as_ptr().cast(),
+                page_size,
+                UffdioCopyFlags::empty(),
+            ),
+            Err(Errno::EXIST)
+        );
+
+        ioctl_uffdio_unregister(&uffd, addr, len).unwrap();
+        munmap(addr, len).unwrap();
+    }
+}
+
+#[test]
+fn test_read_uffd_msg_nonblock() {
+    let uffd = match new_uffd(UserfaultfdFlags::NONBLOCK) {
+        Some(uffd) => uffd,
+        None => return,
+    };
+    ioctl_uffdio_api(&uffd, UffdFeatureFlags::empty()).unwrap();
+
+    assert_eq!(unsafe { read_uffd_msg(&uffd) }.unwrap_err(), Errno::AGAIN);
+}
+
+#[test]
+fn test_read_uffd_msg_fork() {
+    let uffd = match new_uffd(UserfaultfdFlags::empty()) {
+        Some(uffd) => uffd,
+        None => return,
+    };
+    // `UFFD_FEATURE_EVENT_FORK` needs `CAP_SYS_PTRACE` for user-mode-only
+    // userfaultfds.
+    match ioctl_uffdio_api(&uffd, UffdFeatureFlags::EVENT_FORK) {
+        Ok(_) => {}
+        Err(Errno::PERM | Errno::INVAL) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+
+    let page_size = page_size();
+    unsafe {
+        let addr = mmap_anonymous(
+            null_mut(),
+            page_size,
+            ProtFlags::READ | ProtFlags::WRITE,
+            MapFlags::PRIVATE,
+        )
+        .unwrap();
+        ioctl_uffdio_register(&uffd, addr, page_size, UffdioRegisterFlags::MISSING).unwrap();
+
+        // `fork` doesn't return until the event is read, so fork from
+        // another thread.
+        let forker = std::thread::spawn(|| {
+            let child = libc::fork();
+            if child == 0 {
+                libc::_exit(0);
+            }
+            assert!(child > 0);
+            let mut status = 0;
+            assert_eq!(libc::waitpid(child, &mut status, 0), child);
+        });
+
+        let child_uffd = match read_uffd_msg(&uffd).unwrap() {
+            UffdMsg::Fork { uffd } => uffd,
+            other => panic!("unexpected message: {:?}", other),
+        };
+        forker.join().unwrap();
+
+        // The message carries a new userfaultfd for the child's memory.
+        let link = std::fs::read_link(format!("/proc/self/fd/{}", child_uffd.as_raw_fd())).unwrap();
+        assert_eq!(link.to_str().unwrap(), "anon_inode:[userfaultfd]");
+        assert_ne!(child_uffd.as_raw_fd(), uffd.as_raw_fd());
+
+        ioctl_uffdio_unregister(&uffd, addr, page_size).unwrap();
+        munmap(addr, page_size).unwrap();
+    }
+}
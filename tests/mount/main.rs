@@ -3,5 +3,8 @@
 #![cfg(feature = "mount")]
 #![cfg(linux_kernel)]
 
-// At this time, we have no tests for the `mount` functions, because they
-// all require elevated privileges.
+// At this time, we have no tests for most of the `mount` functions, because
+// they require elevated privileges.
+
+mod mount_setattr;
+mod statmount;
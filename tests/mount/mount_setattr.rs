@@ -0,0 +1,44 @@
+use rustix::fd::AsFd;
+use rustix::io::Errno;
+use rustix::mount::{
+    mount_setattr, open_tree, MountAttr, MountAttrFlags, MountPropagationFlags, MountSetattrFlags,
+    OpenTreeFlags,
+};
+
+#[test]
+fn test_mount_setattr_detached() {
+    let dir = std::fs::File::open("/").unwrap();
+
+    // Make a detached copy of a mount, so that changing its attributes
+    // doesn't affect anything else.
+    let tree = match open_tree(
+        dir.as_fd(),
+        "",
+        OpenTreeFlags::OPEN_TREE_CLONE
+            | OpenTreeFlags::OPEN_TREE_CLOEXEC
+            | OpenTreeFlags::AT_EMPTY_PATH,
+    ) {
+        Ok(tree) => tree,
+        Err(Errno::PERM | Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    let attr = MountAttr {
+        attr_set: MountAttrFlags::MOUNT_ATTR_RDONLY | MountAttrFlags::MOUNT_ATTR_NOSUID,
+        ..MountAttr::new()
+    };
+    match mount_setattr(tree.as_fd(), "", MountSetattrFlags::AT_EMPTY_PATH, &attr) {
+        Ok(()) | Err(Errno::PERM | Errno::NOSYS) => {}
+        Err(err) => panic!("{:?}", err),
+    }
+
+    // `MS_REC` isn't a propagation type; use `AT_RECURSIVE` instead.
+    let attr = MountAttr {
+        propagation: MountPropagationFlags::PRIVATE | MountPropagationFlags::REC,
+        ..MountAttr::new()
+    };
+    match mount_setattr(tree.as_fd(), "", MountSetattrFlags::AT_EMPTY_PATH, &attr) {
+        Err(Errno::INVAL | Errno::NOSYS) => {}
+        other => panic!("{:?}", other),
+    }
+}
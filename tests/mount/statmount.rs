@@ -0,0 +1,68 @@
+use rustix::io::Errno;
+use rustix::mount::{listmount, ListMountFlags, ListMounts, LSMT_ROOT};
+
+/// Return all the mount IDs under the root, or `None` if `listmount` isn't
+/// supported.
+fn all_mounts() -> Option<Vec<u64>> {
+    let mut buf = vec![0_u64; 4096];
+    match listmount(LSMT_ROOT, 0, &mut buf, ListMountFlags::empty()) {
+        Ok(ids) => Some(ids.to_vec()),
+        Err(Errno::NOSYS) => None,
+        Err(err) => panic!("{:?}", err),
+    }
+}
+
+#[test]
+fn test_list_mounts() {
+    let expected = match all_mounts() {
+        Some(expected) => expected,
+        None => return,
+    };
+    assert!(!expected.is_empty());
+
+    // Use a tiny buffer so that we exercise fetching in batches.
+    let mut buf = [0_u64; 2];
+    let ids = ListMounts::new(LSMT_ROOT, &mut buf, ListMountFlags::empty())
+        .collect::<rustix::io::Result<Vec<u64>>>()
+        .unwrap();
+    assert_eq!(ids, expected);
+
+    let mut buf = [];
+    let mut iter = ListMounts::new(LSMT_ROOT, &mut buf, ListMountFlags::empty());
+    assert_eq!(iter.next(), Some(Err(Errno::INVAL)));
+    assert_eq!(iter.next(), None);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_statmount() {
+    use rustix::mount::{statmount, StatMountFlags};
+
+    let ids = match all_mounts() {
+        Some(ids) => ids,
+        None => return,
+    };
+
+    for id in ids {
+        let info = match statmount(
+            id,
+            StatMountFlags::MNT_BASIC
+                | StatMountFlags::SB_BASIC
+                | StatMountFlags::MNT_ROOT
+                | StatMountFlags::MNT_POINT
+                | StatMountFlags::FS_TYPE,
+        ) {
+            Ok(info) => info,
+            // The mount may have gone away since we listed it.
+            Err(Errno::NOENT) => continue,
+            Err(err) => panic!("{:?}", err),
+        };
+        assert!(info.mask().contains(
+            StatMountFlags::MNT_BASIC | StatMountFlags::MNT_POINT | StatMountFlags::FS_TYPE
+        ));
+        assert_eq!(info.mnt_id(), id);
+        assert!(info.mnt_point().unwrap().to_bytes().starts_with(b"/"));
+        assert!(!info.fs_type().unwrap().to_bytes().is_empty());
+        assert!(info.mnt_root().unwrap().to_bytes().starts_with(b"/"));
+    }
+}
@@ -0,0 +1,117 @@
+use rustix::fd::OwnedFd;
+use rustix::io::{self, IoSliceMut};
+use rustix::net::{
+    bind, getsockname, recv, recvmsg, send, sendto, socket, sockopt, AddressFamily,
+    RecvAncillaryBuffer, RecvAncillaryMessage, RecvFlags, SendFlags, SockExtendedErr,
+    SockExtendedErrOrigin, SocketAddrAny, SocketType,
+};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+fn udp_socket() -> (OwnedFd, SocketAddr) {
+    let fd = socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    bind(&fd, &SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0)).unwrap();
+    let addr = match getsockname(&fd).unwrap() {
+        SocketAddrAny::V4(addr) => SocketAddr::V4(addr),
+        other => panic!("unexpected address {:?}", other),
+    };
+    (fd, addr)
+}
+
+/// Read one message from `fd`'s error queue, retrying for a while since
+/// notifications may be queued asynchronously.
+fn recv_errqueue(fd: &OwnedFd) -> SockExtendedErr {
+    for _ in 0..100 {
+        let mut buf = [0_u8; 64];
+        let mut space = [0; rustix::cmsg_space!(SockExtendedErr(1))];
+        let mut control = RecvAncillaryBuffer::new(&mut space);
+        match recvmsg(
+            fd,
+            &mut [IoSliceMut::new(&mut buf)],
+            &mut control,
+            RecvFlags::ERRQUEUE | RecvFlags::DONTWAIT,
+        ) {
+            Ok(_) => {
+                return control
+                    .drain()
+                    .find_map(|msg| match msg {
+                        RecvAncillaryMessage::SockExtendedErr(err) => Some(err),
+                        _ => None,
+                    })
+                    .unwrap()
+            }
+            Err(io::Errno::AGAIN) => std::thread::sleep(std::time::Duration::from_millis(10)),
+            Err(err) => panic!("{:?}", err),
+        }
+    }
+    panic!("no message on the error queue");
+}
+
+#[test]
+fn test_errqueue_sockopts() {
+    let (fd, _addr) = udp_socket();
+
+    assert!(!sockopt::ip_recverr(&fd).unwrap());
+    sockopt::set_ip_recverr(&fd, true).unwrap();
+    assert!(sockopt::ip_recverr(&fd).unwrap());
+
+    assert!(!sockopt::socket_zerocopy(&fd).unwrap());
+    sockopt::set_socket_zerocopy(&fd, true).unwrap();
+    assert!(sockopt::socket_zerocopy(&fd).unwrap());
+
+    let fd = socket(AddressFamily::INET6, SocketType::DGRAM, None).unwrap();
+    assert!(!sockopt::ipv6_recverr(&fd).unwrap());
+    sockopt::set_ipv6_recverr(&fd, true).unwrap();
+    assert!(sockopt::ipv6_recverr(&fd).unwrap());
+}
+
+#[test]
+fn test_ip_recverr() {
+    let (sender, _sender_addr) = udp_socket();
+    sockopt::set_ip_recverr(&sender, true).unwrap();
+
+    // Find a port that nothing is listening on.
+    let (closed, closed_addr) = udp_socket();
+    drop(closed);
+
+    sendto(&sender, b"hello", SendFlags::empty(), &closed_addr).unwrap();
+
+    let err = recv_errqueue(&sender);
+    assert_eq!(err.errno, Some(io::Errno::CONNREFUSED));
+    assert_eq!(err.origin, SockExtendedErrOrigin::ICMP);
+    // ICMP destination unreachable, port unreachable.
+    assert_eq!((err.type_, err.code), (3, 3));
+    match err.offender {
+        Some(SocketAddrAny::V4(offender)) => assert_eq!(*offender.ip(), Ipv4Addr::LOCALHOST),
+        other => panic!("unexpected offender {:?}", other),
+    }
+    assert_eq!(err.zerocopy_range(), None);
+}
+
+#[test]
+fn test_msg_zerocopy() {
+    let (receiver, receiver_addr) = udp_socket();
+    let (sender, _sender_addr) = udp_socket();
+    rustix::net::connect(&sender, &receiver_addr).unwrap();
+    sockopt::set_socket_zerocopy(&sender, true).unwrap();
+
+    for _ in 0..2 {
+        send(&sender, &[0xa5; 1024], SendFlags::ZEROCOPY).unwrap();
+    }
+    let mut buf = [0_u8; 2048];
+    for _ in 0..2 {
+        assert_eq!(recv(&receiver, &mut buf, RecvFlags::empty()).unwrap(), 1024);
+    }
+
+    // The two sends are numbered 0 and 1, and their completions may be
+    // coalesced into one notification.
+    let mut completed = 0;
+    while completed < 2 {
+        let err = recv_errqueue(&sender);
+        assert_eq!(err.errno, None);
+        assert_eq!(err.origin, SockExtendedErrOrigin::ZEROCOPY);
+        assert_eq!(err.offender, None);
+        let range = err.zerocopy_range().unwrap();
+        assert_eq!(*range.start(), completed);
+        completed = *range.end() + 1;
+    }
+}
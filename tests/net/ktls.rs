@@ -0,0 +1,113 @@
+use rustix::fd::OwnedFd;
+use rustix::io::{self, IoSlice, IoSliceMut};
+use rustix::net::tls::{TlsCryptoInfo, TlsCryptoInfoAesGcm128, TlsRecordType, TlsVersion};
+use rustix::net::{
+    accept, bind, getsockname, listen, recv, recvmsg, send, sendmsg, socket, sockopt,
+    AddressFamily, RecvAncillaryBuffer, RecvAncillaryMessage, RecvFlags, SendAncillaryBuffer,
+    SendAncillaryMessage, SendFlags, SocketAddrAny, SocketType,
+};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+/// Returns a connected pair of TCP sockets.
+fn tcp_pair() -> (OwnedFd, OwnedFd) {
+    let listener = socket(AddressFamily::INET, SocketType::STREAM, None).unwrap();
+    bind(
+        &listener,
+        &SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+    )
+    .unwrap();
+    listen(&listener, 1).unwrap();
+    let addr = match getsockname(&listener).unwrap() {
+        SocketAddrAny::V4(addr) => addr,
+        other => panic!("unexpected address {:?}", other),
+    };
+
+    let client = socket(AddressFamily::INET, SocketType::STREAM, None).unwrap();
+    rustix::net::connect_v4(&client, &addr).unwrap();
+    let server = accept(&listener).unwrap();
+    (client, server)
+}
+
+/// Attaches the `"tls"` upper layer protocol to both sockets, or returns
+/// `false` if the kernel doesn't support kernel TLS.
+fn attach_tls(client: &OwnedFd, server: &OwnedFd) -> bool {
+    match sockopt::set_tcp_ulp(client, "tls") {
+        Ok(()) => {}
+        // The `tls` module isn't available.
+        Err(io::Errno::NOENT) => return false,
+        Err(err) => panic!("{:?}", err),
+    }
+    sockopt::set_tcp_ulp(server, "tls").unwrap();
+    assert_eq!(sockopt::tcp_ulp(client).unwrap(), "tls");
+    true
+}
+
+fn crypto_info() -> TlsCryptoInfo {
+    TlsCryptoInfo::AesGcm128(TlsCryptoInfoAesGcm128::new(
+        TlsVersion::TLS_1_2,
+        [0x42; 16],
+        [0x01; 8],
+        [0x02; 4],
+        [0; 8],
+    ))
+}
+
+#[test]
+fn test_tcp_ulp_none() {
+    let (client, _server) = tcp_pair();
+    assert_eq!(sockopt::tcp_ulp(&client).unwrap(), "");
+}
+
+#[test]
+fn test_ktls_send_recv() {
+    let (client, server) = tcp_pair();
+    if !attach_tls(&client, &server) {
+        return;
+    }
+    sockopt::set_tls_tx(&client, &crypto_info()).unwrap();
+    sockopt::set_tls_rx(&server, &crypto_info()).unwrap();
+
+    send(&client, b"hello", SendFlags::empty()).unwrap();
+    let mut buf = [0_u8; 16];
+    let n = recv(&server, &mut buf, RecvFlags::empty()).unwrap();
+    assert_eq!(&buf[..n], b"hello");
+}
+
+#[test]
+fn test_ktls_record_type() {
+    let (client, server) = tcp_pair();
+    if !attach_tls(&client, &server) {
+        return;
+    }
+    sockopt::set_tls_tx(&client, &crypto_info()).unwrap();
+    sockopt::set_tls_rx(&server, &crypto_info()).unwrap();
+
+    // Send a TLS alert: a fatal `close_notify`.
+    let mut space = [0; rustix::cmsg_space!(TlsRecordType(1))];
+    let mut control = SendAncillaryBuffer::new(&mut space);
+    assert!(control.push(SendAncillaryMessage::TlsRecordType(TlsRecordType::ALERT)));
+    sendmsg(
+        &client,
+        &[IoSlice::new(&[2, 0])],
+        &mut control,
+        SendFlags::empty(),
+    )
+    .unwrap();
+
+    let mut buf = [0_u8; 16];
+    let mut space = [0; rustix::cmsg_space!(TlsRecordType(1))];
+    let mut control = RecvAncillaryBuffer::new(&mut space);
+    let result = recvmsg(
+        &server,
+        &mut [IoSliceMut::new(&mut buf)],
+        &mut control,
+        RecvFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(&buf[..result.bytes], &[2, 0]);
+    let record_type = control.drain().find_map(|msg| match msg {
+        RecvAncillaryMessage::TlsRecordType(record_type) => Some(record_type),
+        _ => None,
+    });
+    assert_eq!(record_type, Some(TlsRecordType::ALERT));
+}
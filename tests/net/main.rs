@@ -10,17 +10,39 @@ mod addr;
 mod cmsg;
 mod connect_bind_send;
 mod dgram;
+#[cfg(linux_kernel)]
+mod errqueue;
+#[cfg(linux_kernel)]
+mod ktls;
+#[cfg(linux_kernel)]
+mod mmsg;
+#[cfg(linux_kernel)]
+mod netlink;
+#[cfg(linux_kernel)]
+mod packet;
+#[cfg(linux_kernel)]
+mod pktinfo;
 #[cfg(feature = "event")]
 mod poll;
 #[cfg(unix)]
 mod recv_trunc;
+#[cfg(linux_kernel)]
+mod sockfilter;
 mod sockopt;
+#[cfg(linux_kernel)]
+mod tcp_info;
+#[cfg(linux_kernel)]
+mod timestamp;
+#[cfg(linux_kernel)]
+mod udp_offload;
 #[cfg(unix)]
 mod unix;
 #[cfg(unix)]
 mod unix_alloc;
 mod v4;
 mod v6;
+#[cfg(linux_kernel)]
+mod vsock;
 
 #[cfg(windows)]
 mod windows {
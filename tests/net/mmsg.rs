@@ -0,0 +1,116 @@
+use rustix::io::{IoSlice, IoSliceMut};
+use rustix::net::{
+    bind, getsockname, recvmmsg, sendmmsg, socket, AddressFamily, MMsgHdr, RecvAncillaryBuffer,
+    RecvFlags, RecvMMsgHdr, SendAncillaryBuffer, SendFlags, SocketAddrAny, SocketType, Timespec,
+    MMSG_MAX,
+};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+fn udp_socket() -> (rustix::fd::OwnedFd, SocketAddrAny) {
+    let fd = socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    bind(&fd, &SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0)).unwrap();
+    let addr = getsockname(&fd).unwrap();
+    (fd, addr)
+}
+
+#[test]
+fn test_sendmmsg_recvmmsg() {
+    let (receiver, receiver_addr) = udp_socket();
+    let (sender, sender_addr) = udp_socket();
+
+    let payloads: [&[u8]; 3] = [b"first", b"second message", b"3"];
+    let iovs = payloads.map(|payload| [IoSlice::new(payload)]);
+    let mut controls = [
+        SendAncillaryBuffer::default(),
+        SendAncillaryBuffer::default(),
+        SendAncillaryBuffer::default(),
+    ];
+    let mut msgs = iovs
+        .iter()
+        .zip(controls.iter_mut())
+        .map(|(iov, control)| MMsgHdr::new_with_addr(&receiver_addr, iov, control))
+        .collect::<Vec<_>>();
+    assert_eq!(sendmmsg(&sender, &mut msgs, SendFlags::empty()).unwrap(), 3);
+    for (msg, payload) in msgs.iter().zip(&payloads) {
+        assert_eq!(msg.bytes_sent(), payload.len());
+    }
+
+    // Receive into more buffers than there are messages, so that the call
+    // returns early.
+    let mut bufs = [[0_u8; 32]; 4];
+    let mut iovs = bufs
+        .iter_mut()
+        .map(|buf| [IoSliceMut::new(buf)])
+        .collect::<Vec<_>>();
+    let mut controls = [
+        RecvAncillaryBuffer::default(),
+        RecvAncillaryBuffer::default(),
+        RecvAncillaryBuffer::default(),
+        RecvAncillaryBuffer::default(),
+    ];
+    let mut msgs = iovs
+        .iter_mut()
+        .zip(controls.iter_mut())
+        .map(|(iov, control)| RecvMMsgHdr::new(iov, control))
+        .collect::<Vec<_>>();
+    let n = recvmmsg(&receiver, &mut msgs, RecvFlags::WAITFORONE, None).unwrap();
+    // Datagrams over loopback are delivered synchronously.
+    assert_eq!(n, 3);
+    let lens = msgs[..n]
+        .iter()
+        .map(|msg| {
+            assert_eq!(msg.address(), Some(&sender_addr));
+            assert!(!msg.flags().contains(RecvFlags::TRUNC));
+            msg.bytes()
+        })
+        .collect::<Vec<_>>();
+    drop(msgs);
+    drop(iovs);
+
+    for ((buf, len), payload) in bufs.iter().zip(lens).zip(&payloads) {
+        assert_eq!(&buf[..len], *payload);
+    }
+}
+
+#[test]
+fn test_recvmmsg_timeout() {
+    let (receiver, _receiver_addr) = udp_socket();
+
+    let mut buf = [0_u8; 16];
+    let mut iov = [IoSliceMut::new(&mut buf)];
+    let mut control = RecvAncillaryBuffer::default();
+    let mut msgs = [RecvMMsgHdr::new(&mut iov, &mut control)];
+
+    // Nothing has been sent, so a non-blocking receive fails.
+    let timeout = Timespec {
+        tv_sec: 0,
+        tv_nsec: 1_000_000,
+    };
+    assert_eq!(
+        recvmmsg(&receiver, &mut msgs, RecvFlags::DONTWAIT, Some(&timeout)),
+        Err(rustix::io::Errno::AGAIN)
+    );
+}
+
+#[test]
+fn test_sendmmsg_max() {
+    let (receiver, receiver_addr) = udp_socket();
+    let (sender, _sender_addr) = udp_socket();
+
+    // Messages beyond `MMSG_MAX` are left for a later call.
+    let iov = [IoSlice::new(b"x")];
+    let mut controls = (0..MMSG_MAX + 1)
+        .map(|_| SendAncillaryBuffer::default())
+        .collect::<Vec<_>>();
+    let mut msgs = controls
+        .iter_mut()
+        .map(|control| MMsgHdr::new_with_addr(&receiver_addr, &iov, control))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        sendmmsg(&sender, &mut msgs, SendFlags::empty()).unwrap(),
+        MMSG_MAX
+    );
+    assert_eq!(msgs[MMSG_MAX - 1].bytes_sent(), 1);
+    assert_eq!(msgs[MMSG_MAX].bytes_sent(), 0);
+    drop(receiver);
+}
@@ -0,0 +1,116 @@
+use rustix::io;
+use rustix::io::IoSlice;
+use rustix::net::netlink::{NlMsgBuilder, NlMsgFlags, NlMsgIter, SocketAddrNetlink};
+use rustix::net::{
+    bind_any, getsockname, recvfrom, sendmsg_any, sendto_netlink, socket, AddressFamily, RecvFlags,
+    SendAncillaryBuffer, SendFlags, SocketAddrAny, SocketType,
+};
+
+// From <linux/rtnetlink.h>.
+const RTM_NEWLINK: u16 = 16;
+const RTM_GETLINK: u16 = 18;
+
+#[test]
+fn test_netlink_addr() {
+    let fd = socket(AddressFamily::NETLINK, SocketType::RAW, None).unwrap();
+    bind_any(&fd, &SocketAddrNetlink::new(0, 0).into()).unwrap();
+
+    let addr = getsockname(&fd).unwrap();
+    assert_eq!(addr.address_family(), AddressFamily::NETLINK);
+    match addr {
+        SocketAddrAny::Netlink(addr) => {
+            // The kernel assigned us a port ID.
+            assert_ne!(addr.pid(), 0);
+            assert_eq!(addr.groups(), 0);
+        }
+        other => panic!("unexpected address {:?}", other),
+    }
+}
+
+#[test]
+fn test_netlink_route_dump() {
+    let fd = socket(AddressFamily::NETLINK, SocketType::RAW, None).unwrap();
+    bind_any(&fd, &SocketAddrNetlink::new(0, 0).into()).unwrap();
+    let local = match getsockname(&fd).unwrap() {
+        SocketAddrAny::Netlink(addr) => addr,
+        other => panic!("unexpected address {:?}", other),
+    };
+    let kernel = SocketAddrNetlink::new(0, 0);
+
+    // A `struct rtgenmsg` asking for links of any address family.
+    let rtgenmsg = [0_u8];
+    let mut request = [0_u8; 64];
+    let mut builder = NlMsgBuilder::new(&mut request);
+    builder
+        .push(
+            RTM_GETLINK,
+            NlMsgFlags::REQUEST | NlMsgFlags::DUMP,
+            1,
+            local.pid(),
+            &rtgenmsg,
+        )
+        .unwrap();
+    let request = builder.finish();
+    let n = sendmsg_any(
+        &fd,
+        Some(&kernel.into()),
+        &[IoSlice::new(request)],
+        &mut SendAncillaryBuffer::default(),
+        SendFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(n, request.len());
+
+    let mut links = 0;
+    let mut buf = vec![0_u8; 32768];
+    'dump: loop {
+        let (n, from) = recvfrom(&fd, &mut buf, RecvFlags::empty()).unwrap();
+        assert_eq!(from, Some(SocketAddrAny::Netlink(kernel)));
+
+        for msg in NlMsgIter::new(&buf[..n]) {
+            let msg = msg.unwrap();
+            assert_eq!(msg.seq(), 1);
+            assert_eq!(msg.pid(), local.pid());
+            if let Some(result) = msg.done() {
+                result.unwrap();
+                break 'dump;
+            }
+            assert_eq!(msg.error(), None);
+            assert_eq!(msg.msg_type(), RTM_NEWLINK);
+            assert!(msg.flags().contains(NlMsgFlags::MULTI));
+            links += 1;
+        }
+    }
+
+    // There's always at least a loopback device.
+    assert!(links >= 1);
+}
+
+#[test]
+fn test_netlink_error() {
+    let fd = socket(AddressFamily::NETLINK, SocketType::RAW, None).unwrap();
+    bind_any(&fd, &SocketAddrNetlink::new(0, 0).into()).unwrap();
+
+    // A message type beyond `RTM_MAX` is rejected.
+    let mut request = [0_u8; 64];
+    let mut builder = NlMsgBuilder::new(&mut request);
+    builder
+        .push(0x7fff, NlMsgFlags::REQUEST | NlMsgFlags::ACK, 7, 0, &[])
+        .unwrap();
+    let request = builder.finish();
+    sendto_netlink(
+        &fd,
+        request,
+        SendFlags::empty(),
+        &SocketAddrNetlink::new(0, 0),
+    )
+    .unwrap();
+
+    let mut buf = vec![0_u8; 4096];
+    let (n, _from) = recvfrom(&fd, &mut buf, RecvFlags::empty()).unwrap();
+    let mut iter = NlMsgIter::new(&buf[..n]);
+    let msg = iter.next().unwrap().unwrap();
+    assert_eq!(msg.seq(), 7);
+    assert_eq!(msg.error(), Some(Err(io::Errno::OPNOTSUPP)));
+    assert!(iter.next().is_none());
+}
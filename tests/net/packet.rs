@@ -0,0 +1,136 @@
+use rustix::fd::OwnedFd;
+use rustix::io;
+use rustix::net::netdevice::name_to_index;
+use rustix::net::packet::{
+    PacketFanout, PacketFanoutFlags, PacketFanoutMode, PacketMreq, PacketMreqType, PacketVersion,
+    SocketAddrLink, TpacketReq3,
+};
+use rustix::net::{
+    bind_link, eth, getsockname, ipproto, recvfrom, sendto, socket, sockopt, AddressFamily,
+    RecvFlags, SendFlags, SocketAddrAny, SocketType,
+};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+// From <linux/if_arp.h>.
+const ARPHRD_LOOPBACK: u16 = 772;
+
+/// Open a packet socket bound to the loopback interface, or return `None` if
+/// we don't have the privileges to do so.
+fn loopback_packet_socket() -> Option<(OwnedFd, u32)> {
+    let fd = match socket(AddressFamily::PACKET, SocketType::RAW, Some(eth::ALL)) {
+        Ok(fd) => fd,
+        Err(io::Errno::PERM | io::Errno::ACCESS) => return None,
+        Err(err) => panic!("{:?}", err),
+    };
+    let index = name_to_index(&fd, "lo").unwrap();
+    bind_link(&fd, &SocketAddrLink::new(Some(eth::ALL), index)).unwrap();
+    Some((fd, index))
+}
+
+#[test]
+fn test_packet_addr() {
+    let mut addr = SocketAddrLink::new(Some(eth::IP), 3);
+    assert_eq!(addr.protocol(), Some(eth::IP));
+    assert_eq!(addr.interface_index(), 3);
+    assert_eq!(addr.address(), &[] as &[u8]);
+    addr.set_address(&[1, 2, 3, 4, 5, 6]).unwrap();
+    assert_eq!(addr.address(), &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(addr.set_address(&[0; 9]), Err(io::Errno::INVAL));
+    assert_eq!(
+        SocketAddrAny::from(addr).address_family(),
+        AddressFamily::PACKET
+    );
+}
+
+#[test]
+fn test_packet_loopback() {
+    let (fd, index) = match loopback_packet_socket() {
+        Some(fd) => fd,
+        None => return,
+    };
+
+    match getsockname(&fd).unwrap() {
+        SocketAddrAny::Link(addr) => {
+            assert_eq!(addr.interface_index(), index);
+            assert_eq!(addr.protocol(), Some(eth::ALL));
+            assert_eq!(addr.hardware_type(), ARPHRD_LOOPBACK);
+        }
+        other => panic!("unexpected address {:?}", other),
+    }
+
+    // Send a UDP packet over loopback and observe it on the packet socket.
+    let udp = socket(AddressFamily::INET, SocketType::DGRAM, Some(ipproto::UDP)).unwrap();
+    let localhost = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0);
+    rustix::net::bind(&udp, &localhost).unwrap();
+    let dest = getsockname(&udp).unwrap();
+    let payload = b"hello, packet socket";
+    match dest {
+        SocketAddrAny::V4(dest) => {
+            sendto(&udp, payload, SendFlags::empty(), &SocketAddr::V4(dest)).unwrap();
+        }
+        other => panic!("unexpected address {:?}", other),
+    }
+
+    let mut buf = [0_u8; 2048];
+    loop {
+        let (n, from) = recvfrom(&fd, &mut buf, RecvFlags::empty()).unwrap();
+        let from = match from {
+            Some(SocketAddrAny::Link(from)) => from,
+            other => panic!("unexpected address {:?}", other),
+        };
+        assert_eq!(from.interface_index(), index);
+        if buf[..n].windows(payload.len()).any(|w| w == payload) {
+            assert_eq!(from.protocol(), Some(eth::IP));
+            break;
+        }
+    }
+}
+
+#[test]
+fn test_packet_sockopts() {
+    let (fd, index) = match loopback_packet_socket() {
+        Some(fd) => fd,
+        None => return,
+    };
+
+    assert_eq!(sockopt::packet_version(&fd).unwrap(), PacketVersion::V1);
+    sockopt::set_packet_version(&fd, PacketVersion::V3).unwrap();
+    assert_eq!(sockopt::packet_version(&fd).unwrap(), PacketVersion::V3);
+
+    let mreq = PacketMreq::new(index, PacketMreqType::Promisc);
+    sockopt::set_packet_add_membership(&fd, mreq).unwrap();
+    sockopt::set_packet_drop_membership(&fd, mreq).unwrap();
+
+    assert_eq!(sockopt::packet_fanout(&fd).unwrap(), None);
+    let fanout = PacketFanout {
+        group_id: 0x1234,
+        mode: PacketFanoutMode::HASH,
+        flags: PacketFanoutFlags::ROLLOVER,
+    };
+    sockopt::set_packet_fanout(&fd, fanout).unwrap();
+    assert_eq!(sockopt::packet_fanout(&fd).unwrap(), Some(fanout));
+
+    let req = TpacketReq3 {
+        block_size: 4096,
+        block_nr: 4,
+        frame_size: 2048,
+        frame_nr: 8,
+        retire_blk_tov: 60,
+        ..TpacketReq3::default()
+    };
+    sockopt::set_packet_rx_ring(&fd, req).unwrap();
+
+    let stats = sockopt::packet_statistics(&fd).unwrap();
+    assert!(stats.freeze_queue_count.is_some());
+}
+
+#[test]
+fn test_packet_statistics_v1() {
+    let (fd, _index) = match loopback_packet_socket() {
+        Some(fd) => fd,
+        None => return,
+    };
+
+    let stats = sockopt::packet_statistics(&fd).unwrap();
+    assert_eq!(stats.freeze_queue_count, None);
+}
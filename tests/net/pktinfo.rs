@@ -0,0 +1,168 @@
+use rustix::fd::OwnedFd;
+use rustix::io::{IoSlice, IoSliceMut};
+use rustix::net::netdevice::name_to_index;
+use rustix::net::{
+    bind, getsockname, recvfrom, recvmsg, sendmsg_v4, sendmsg_v6, sendto, socket, sockopt,
+    AddressFamily, Ipv4PktInfo, Ipv6PktInfo, RecvAncillaryBuffer, RecvAncillaryMessage, RecvFlags,
+    SendAncillaryBuffer, SendAncillaryMessage, SendFlags, SocketAddrAny, SocketType,
+};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+fn udp_socket(ip: IpAddr) -> (OwnedFd, SocketAddr) {
+    let family = match ip {
+        IpAddr::V4(_) => AddressFamily::INET,
+        IpAddr::V6(_) => AddressFamily::INET6,
+    };
+    let fd = socket(family, SocketType::DGRAM, None).unwrap();
+    bind(&fd, &SocketAddr::new(ip, 0)).unwrap();
+    let addr = match getsockname(&fd).unwrap() {
+        SocketAddrAny::V4(addr) => SocketAddr::V4(addr),
+        SocketAddrAny::V6(addr) => SocketAddr::V6(addr),
+        other => panic!("unexpected address {:?}", other),
+    };
+    (fd, addr)
+}
+
+#[test]
+fn test_pktinfo_sockopts() {
+    let (fd, _addr) = udp_socket(IpAddr::V4(Ipv4Addr::LOCALHOST));
+    assert!(!sockopt::ip_pktinfo(&fd).unwrap());
+    sockopt::set_ip_pktinfo(&fd, true).unwrap();
+    assert!(sockopt::ip_pktinfo(&fd).unwrap());
+
+    let (fd, _addr) = udp_socket(IpAddr::V6(Ipv6Addr::LOCALHOST));
+    assert!(!sockopt::ipv6_recvpktinfo(&fd).unwrap());
+    sockopt::set_ipv6_recvpktinfo(&fd, true).unwrap();
+    assert!(sockopt::ipv6_recvpktinfo(&fd).unwrap());
+}
+
+#[test]
+fn test_ip_pktinfo() {
+    // Listen on all addresses, and reply from the address the request was
+    // sent to.
+    let (server, server_addr) = udp_socket(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+    sockopt::set_ip_pktinfo(&server, true).unwrap();
+    let (client, client_addr) = udp_socket(IpAddr::V4(Ipv4Addr::LOCALHOST));
+    let lo = name_to_index(&client, "lo").unwrap();
+
+    let dest = Ipv4Addr::LOCALHOST;
+    sendto(
+        &client,
+        b"request",
+        SendFlags::empty(),
+        &SocketAddr::new(IpAddr::V4(dest), server_addr.port()),
+    )
+    .unwrap();
+
+    let mut buf = [0_u8; 16];
+    let mut space = [0; rustix::cmsg_space!(IpPktInfo(1))];
+    let mut control = RecvAncillaryBuffer::new(&mut space);
+    let msg = recvmsg(
+        &server,
+        &mut [IoSliceMut::new(&mut buf)],
+        &mut control,
+        RecvFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(msg.bytes, 7);
+    let info = control
+        .drain()
+        .find_map(|msg| match msg {
+            RecvAncillaryMessage::IpPktInfo(info) => Some(info),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(info.ifindex, lo);
+    assert_eq!(info.addr, dest);
+    assert_eq!(info.spec_dst, dest);
+
+    let mut space = [0; rustix::cmsg_space!(IpPktInfo(1))];
+    let mut control = SendAncillaryBuffer::new(&mut space);
+    assert!(control.push(SendAncillaryMessage::IpPktInfo(Ipv4PktInfo {
+        ifindex: lo,
+        spec_dst: info.addr,
+        addr: Ipv4Addr::UNSPECIFIED,
+    })));
+    let client_addr = match client_addr {
+        SocketAddr::V4(addr) => addr,
+        SocketAddr::V6(_) => unreachable!(),
+    };
+    sendmsg_v4(
+        &server,
+        &client_addr,
+        &[IoSlice::new(b"reply")],
+        &mut control,
+        SendFlags::empty(),
+    )
+    .unwrap();
+
+    let (n, from) = recvfrom(&client, &mut buf, RecvFlags::empty()).unwrap();
+    assert_eq!(&buf[..n], b"reply");
+    match from {
+        Some(SocketAddrAny::V4(from)) => assert_eq!(*from.ip(), dest),
+        other => panic!("unexpected address {:?}", other),
+    }
+}
+
+#[test]
+fn test_ipv6_pktinfo() {
+    let (server, server_addr) = udp_socket(IpAddr::V6(Ipv6Addr::UNSPECIFIED));
+    sockopt::set_ipv6_recvpktinfo(&server, true).unwrap();
+    let (client, client_addr) = udp_socket(IpAddr::V6(Ipv6Addr::LOCALHOST));
+    let lo = name_to_index(&client, "lo").unwrap();
+
+    sendto(
+        &client,
+        b"request",
+        SendFlags::empty(),
+        &SocketAddr::new(IpAddr::V6(Ipv6Addr::LOCALHOST), server_addr.port()),
+    )
+    .unwrap();
+
+    let mut buf = [0_u8; 16];
+    let mut space = [0; rustix::cmsg_space!(Ipv6PktInfo(1))];
+    let mut control = RecvAncillaryBuffer::new(&mut space);
+    let msg = recvmsg(
+        &server,
+        &mut [IoSliceMut::new(&mut buf)],
+        &mut control,
+        RecvFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(msg.bytes, 7);
+    let info = control
+        .drain()
+        .find_map(|msg| match msg {
+            RecvAncillaryMessage::Ipv6PktInfo(info) => Some(info),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(info.ifindex, lo);
+    assert_eq!(info.addr, Ipv6Addr::LOCALHOST);
+
+    let mut space = [0; rustix::cmsg_space!(Ipv6PktInfo(1))];
+    let mut control = SendAncillaryBuffer::new(&mut space);
+    assert!(control.push(SendAncillaryMessage::Ipv6PktInfo(Ipv6PktInfo {
+        addr: Ipv6Addr::LOCALHOST,
+        ifindex: lo,
+    })));
+    let client_addr = match client_addr {
+        SocketAddr::V6(addr) => addr,
+        SocketAddr::V4(_) => unreachable!(),
+    };
+    sendmsg_v6(
+        &server,
+        &client_addr,
+        &[IoSlice::new(b"reply")],
+        &mut control,
+        SendFlags::empty(),
+    )
+    .unwrap();
+
+    let (n, from) = recvfrom(&client, &mut buf, RecvFlags::empty()).unwrap();
+    assert_eq!(&buf[..n], b"reply");
+    match from {
+        Some(SocketAddrAny::V6(from)) => assert_eq!(*from.ip(), Ipv6Addr::LOCALHOST),
+        other => panic!("unexpected address {:?}", other),
+    }
+}
@@ -0,0 +1,106 @@
+use rustix::fd::OwnedFd;
+use rustix::io;
+use rustix::net::{
+    bind, getsockname, recv, sendto, socket, sockopt, AddressFamily, RecvFlags, SendFlags,
+    SockFilter, SocketAddrAny, SocketType,
+};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, SocketAddrV4};
+
+fn udp_socket(port: u16, reuseport: bool) -> (OwnedFd, SocketAddrV4) {
+    let fd = socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    if reuseport {
+        sockopt::set_socket_reuseport(&fd, true).unwrap();
+    }
+    bind(&fd, &SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), port)).unwrap();
+    let addr = match getsockname(&fd).unwrap() {
+        SocketAddrAny::V4(addr) => addr,
+        other => panic!("unexpected address {:?}", other),
+    };
+    (fd, addr)
+}
+
+/// Send a datagram to `addr` and report whether `receiver` got it.
+fn delivered(sender: &OwnedFd, addr: &SocketAddrV4, receiver: &OwnedFd) -> bool {
+    sendto(sender, b"hello", SendFlags::empty(), &SocketAddr::V4(*addr)).unwrap();
+    let mut buf = [0_u8; 16];
+    match recv(receiver, &mut buf, RecvFlags::DONTWAIT) {
+        Ok(n) => {
+            assert_eq!(&buf[..n], b"hello");
+            true
+        }
+        Err(io::Errno::AGAIN) => false,
+        Err(err) => panic!("{:?}", err),
+    }
+}
+
+#[test]
+fn test_socket_filter() {
+    let (receiver, addr) = udp_socket(0, false);
+    let (sender, _) = udp_socket(0, false);
+
+    // A filter that drops everything.
+    sockopt::set_socket_attach_filter(&receiver, &[SockFilter::ret(0)]).unwrap();
+    assert!(!delivered(&sender, &addr, &receiver));
+
+    sockopt::set_socket_detach_filter(&receiver).unwrap();
+    assert!(delivered(&sender, &addr, &receiver));
+
+    // Detaching again fails since there's no filter.
+    assert_eq!(
+        sockopt::set_socket_detach_filter(&receiver),
+        Err(io::Errno::NOENT)
+    );
+}
+
+#[test]
+fn test_socket_lock_filter() {
+    let (fd, _addr) = udp_socket(0, false);
+
+    sockopt::set_socket_attach_filter(&fd, &[SockFilter::ret(u32::MAX)]).unwrap();
+    assert!(!sockopt::socket_lock_filter(&fd).unwrap());
+    sockopt::set_socket_lock_filter(&fd, true).unwrap();
+    assert!(sockopt::socket_lock_filter(&fd).unwrap());
+
+    assert_eq!(sockopt::set_socket_detach_filter(&fd), Err(io::Errno::PERM));
+    assert_eq!(
+        sockopt::set_socket_attach_filter(&fd, &[SockFilter::ret(0)]),
+        Err(io::Errno::PERM)
+    );
+}
+
+#[test]
+fn test_socket_attach_filter_invalid() {
+    let (fd, _addr) = udp_socket(0, false);
+
+    // An empty program is rejected.
+    assert_eq!(
+        sockopt::set_socket_attach_filter(&fd, &[]),
+        Err(io::Errno::INVAL)
+    );
+}
+
+#[test]
+fn test_socket_reuseport_cbpf() {
+    let (first, addr) = udp_socket(0, true);
+    let (second, _) = udp_socket(addr.port(), true);
+    let (sender, _) = udp_socket(0, false);
+
+    // Steer everything to the second socket in the group.
+    sockopt::set_socket_attach_reuseport_cbpf(&first, &[SockFilter::ret(1)]).unwrap();
+    for _ in 0..4 {
+        assert!(delivered(&sender, &addr, &second));
+    }
+    let mut buf = [0_u8; 16];
+    assert_eq!(
+        recv(&first, &mut buf, RecvFlags::DONTWAIT),
+        Err(io::Errno::AGAIN)
+    );
+
+    // And then everything to the first.
+    sockopt::set_socket_attach_reuseport_cbpf(&second, &[SockFilter::ret(0)]).unwrap();
+    for _ in 0..4 {
+        assert!(delivered(&sender, &addr, &first));
+    }
+
+    sockopt::set_socket_detach_reuseport_bpf(&first).unwrap();
+}
@@ -176,6 +176,47 @@ fn test_sockopts_socket(s: &OwnedFd) {
         assert_eq!(sockopt::socket_incoming_cpu(s).unwrap(), 3);
     }
 
+    // Check the initial values of `SO_BINDTODEVICE` and `SO_BINDTOIFINDEX`,
+    // set them, and check them.
+    #[cfg(linux_kernel)]
+    {
+        #[cfg(feature = "alloc")]
+        assert_eq!(sockopt::socket_bindtodevice(s).unwrap(), "");
+        assert_eq!(sockopt::socket_bindtoifindex(s).unwrap(), 0);
+
+        let lo = rustix::net::netdevice::name_to_index(s, "lo").unwrap();
+        sockopt::set_socket_bindtoifindex(s, lo).unwrap();
+        assert_eq!(sockopt::socket_bindtoifindex(s).unwrap(), lo);
+        #[cfg(feature = "alloc")]
+        assert_eq!(sockopt::socket_bindtodevice(s).unwrap(), "lo");
+
+        // Changing an existing binding requires `CAP_NET_RAW`.
+        match sockopt::set_socket_bindtodevice(s, "") {
+            Ok(()) => assert_eq!(sockopt::socket_bindtoifindex(s).unwrap(), 0),
+            Err(io::Errno::PERM) => (),
+            Err(err) => panic!("{:?}", err),
+        }
+    }
+
+    // Check the initial value of `SO_MARK`, set it, and check it.
+    #[cfg(linux_kernel)]
+    {
+        assert_eq!(sockopt::socket_mark(s).unwrap(), 0);
+        match sockopt::set_socket_mark(s, 0x1234) {
+            Ok(()) => assert_eq!(sockopt::socket_mark(s).unwrap(), 0x1234),
+            Err(io::Errno::PERM) => (),
+            Err(err) => panic!("{:?}", err),
+        }
+    }
+
+    // Check the initial value of `SO_PRIORITY`, set it, and check it.
+    #[cfg(linux_kernel)]
+    {
+        assert_eq!(sockopt::socket_priority(s).unwrap(), 0);
+        sockopt::set_socket_priority(s, 4).unwrap();
+        assert_eq!(sockopt::socket_priority(s).unwrap(), 4);
+    }
+
     // Check the initial value of `SO_NOSIGPIPE`, set it, and check it.
     #[cfg(any(apple, freebsdlike, target_os = "netbsd"))]
     {
@@ -280,6 +321,46 @@ fn test_sockopts_tcp(s: &OwnedFd) {
         sockopt::set_tcp_cork(s, true).unwrap();
         assert!(sockopt::tcp_cork(s).unwrap());
     }
+
+    // Check `TCP_MAXSEG`, `TCP_DEFER_ACCEPT`, `TCP_WINDOW_CLAMP`,
+    // `TCP_FASTOPEN`, `TCP_FASTOPEN_CONNECT`, `TCP_NOTSENT_LOWAT`, and
+    // `TCP_SAVE_SYN`.
+    #[cfg(linux_kernel)]
+    {
+        sockopt::set_tcp_maxseg(s, 1000).unwrap();
+        assert_eq!(sockopt::tcp_maxseg(s).unwrap(), 1000);
+
+        // The kernel stores this as a number of SYN-ACK retransmissions, so
+        // use a value that converts back exactly.
+        assert_eq!(sockopt::tcp_defer_accept(s).unwrap(), Duration::ZERO);
+        sockopt::set_tcp_defer_accept(s, Duration::from_secs(15)).unwrap();
+        assert_eq!(
+            sockopt::tcp_defer_accept(s).unwrap(),
+            Duration::from_secs(15)
+        );
+
+        sockopt::set_tcp_window_clamp(s, 20000).unwrap();
+        assert_eq!(sockopt::tcp_window_clamp(s).unwrap(), 20000);
+
+        assert_eq!(sockopt::tcp_fastopen(s).unwrap(), 0);
+        sockopt::set_tcp_fastopen(s, 5).unwrap();
+        assert_eq!(sockopt::tcp_fastopen(s).unwrap(), 5);
+
+        assert!(!sockopt::tcp_fastopen_connect(s).unwrap());
+        match sockopt::set_tcp_fastopen_connect(s, true) {
+            Ok(()) => assert!(sockopt::tcp_fastopen_connect(s).unwrap()),
+            // Client-side Fast Open is disabled by the `tcp_fastopen` sysctl.
+            Err(io::Errno::OPNOTSUPP) => (),
+            Err(err) => panic!("{:?}", err),
+        }
+
+        sockopt::set_tcp_notsent_lowat(s, 16384).unwrap();
+        assert_eq!(sockopt::tcp_notsent_lowat(s).unwrap(), 16384);
+
+        assert!(!sockopt::tcp_save_syn(s).unwrap());
+        sockopt::set_tcp_save_syn(s, true).unwrap();
+        assert!(sockopt::tcp_save_syn(s).unwrap());
+    }
 }
 
 #[test]
@@ -368,6 +449,37 @@ fn test_sockopts_ipv4() {
         ));
     }
 
+    // Check the initial value of `IP_TRANSPARENT`, set it, and check it.
+    #[cfg(linux_kernel)]
+    {
+        assert!(!sockopt::ip_transparent(&s).unwrap());
+        match sockopt::set_ip_transparent(&s, true) {
+            Ok(()) => assert!(sockopt::ip_transparent(&s).unwrap()),
+            Err(io::Errno::PERM) => (),
+            Err(err) => panic!("{:?}", err),
+        }
+    }
+
+    // Check the initial value of `IP_BIND_ADDRESS_NO_PORT`, set it, and check
+    // it.
+    #[cfg(linux_kernel)]
+    {
+        assert!(!sockopt::ip_bind_address_no_port(&s).unwrap());
+        sockopt::set_ip_bind_address_no_port(&s, true).unwrap();
+        assert!(sockopt::ip_bind_address_no_port(&s).unwrap());
+    }
+
+    // Set `IP_MTU_DISCOVER` and check it, and check that `IP_MTU` requires a
+    // connected socket.
+    #[cfg(linux_kernel)]
+    {
+        use rustix::net::PmtuDiscovery;
+
+        sockopt::set_ip_mtu_discover(&s, PmtuDiscovery::PROBE).unwrap();
+        assert_eq!(sockopt::ip_mtu_discover(&s).unwrap(), PmtuDiscovery::PROBE);
+        assert_eq!(sockopt::ip_mtu(&s), Err(io::Errno::NOTCONN));
+    }
+
     test_sockopts_tcp(&s);
 }
 
@@ -475,6 +587,28 @@ fn test_sockopts_ipv6() {
         assert_eq!(sockopt::ipv6_tclass(&s).unwrap(), 12);
     }
 
+    // Check the initial value of `IPV6_TRANSPARENT`, set it, and check it.
+    #[cfg(linux_kernel)]
+    {
+        assert!(!sockopt::ipv6_transparent(&s).unwrap());
+        match sockopt::set_ipv6_transparent(&s, true) {
+            Ok(()) => assert!(sockopt::ipv6_transparent(&s).unwrap()),
+            Err(io::Errno::PERM) => (),
+            Err(err) => panic!("{:?}", err),
+        }
+    }
+
+    // Set `IPV6_MTU_DISCOVER` and check it, and check that `IPV6_MTU`
+    // requires a connected socket.
+    #[cfg(linux_kernel)]
+    {
+        use rustix::net::PmtuDiscovery;
+
+        sockopt::set_ipv6_mtu_discover(&s, PmtuDiscovery::DO).unwrap();
+        assert_eq!(sockopt::ipv6_mtu_discover(&s).unwrap(), PmtuDiscovery::DO);
+        assert_eq!(sockopt::ipv6_mtu(&s), Err(io::Errno::NOTCONN));
+    }
+
     // Check that we can query `IP6T_SO_ORIGINAL_DST`.
     #[cfg(linux_kernel)]
     {
@@ -486,3 +620,24 @@ fn test_sockopts_ipv6() {
 
     test_sockopts_tcp(&s);
 }
+
+#[cfg(linux_kernel)]
+#[test]
+fn test_sockopts_mtu() {
+    use rustix::net::{bind, connect_any, getsockname};
+    use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+    crate::init();
+
+    let receiver = rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    bind(
+        &receiver,
+        &SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+    )
+    .unwrap();
+    let addr = getsockname(&receiver).unwrap();
+
+    let s = rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    connect_any(&s, &addr).unwrap();
+    assert_ne!(sockopt::ip_mtu(&s).unwrap(), 0);
+}
@@ -0,0 +1,82 @@
+use rustix::fd::OwnedFd;
+use rustix::net::{
+    accept, bind, connect, getsockname, listen, recv, send, socket, sockopt, AddressFamily,
+    RecvFlags, SendFlags, SocketAddrAny, SocketType,
+};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+/// Returns a listening socket bound to an ephemeral localhost port, and the
+/// port's address.
+fn listener() -> (OwnedFd, SocketAddr) {
+    let fd = socket(AddressFamily::INET, SocketType::STREAM, None).unwrap();
+    bind(&fd, &SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0)).unwrap();
+    let addr = match getsockname(&fd).unwrap() {
+        SocketAddrAny::V4(addr) => SocketAddr::V4(addr),
+        other => panic!("unexpected address {:?}", other),
+    };
+    (fd, addr)
+}
+
+#[test]
+fn test_tcp_info() {
+    let (listener, addr) = listener();
+    listen(&listener, 1).unwrap();
+
+    let client = socket(AddressFamily::INET, SocketType::STREAM, None).unwrap();
+    connect(&client, &addr).unwrap();
+    let server = accept(&listener).unwrap();
+
+    send(&client, b"hello", SendFlags::empty()).unwrap();
+    let mut buf = [0_u8; 16];
+    assert_eq!(recv(&server, &mut buf, RecvFlags::empty()).unwrap(), 5);
+
+    let (info, len) = sockopt::tcp_info(&client).unwrap();
+    assert!(len >= 104, "{}", len);
+    assert!(len <= std::mem::size_of_val(&info), "{}", len);
+    // `TCP_ESTABLISHED`
+    assert_eq!(info.state, 1);
+    assert_ne!(info.snd_mss, 0);
+    assert_ne!(info.rto, 0);
+    assert_eq!(info.total_retrans, 0);
+
+    let (info, len) = sockopt::tcp_info(&server).unwrap();
+    assert_eq!(info.state, 1);
+    // Check fields only if the kernel is new enough to report them.
+    if len >= 136 {
+        assert_eq!(info.bytes_received, 5);
+    }
+    if len >= 160 {
+        assert_eq!(info.data_segs_in, 1);
+    }
+}
+
+#[test]
+fn test_tcp_saved_syn() {
+    let (listener, addr) = listener();
+    sockopt::set_tcp_save_syn(&listener, true).unwrap();
+    listen(&listener, 1).unwrap();
+
+    let client = socket(AddressFamily::INET, SocketType::STREAM, None).unwrap();
+    connect(&client, &addr).unwrap();
+    let server = accept(&listener).unwrap();
+
+    let syn = sockopt::tcp_saved_syn(&server).unwrap();
+    // An IPv4 header followed by a TCP header.
+    assert!(syn.len() >= 40, "{:?}", syn);
+    assert_eq!(syn[0] >> 4, 4);
+    let ihl = usize::from(syn[0] & 0xf) * 4;
+    let dst_port = u16::from_be_bytes([syn[ihl + 2], syn[ihl + 3]]);
+    assert_eq!(dst_port, addr.port());
+
+    // The kernel only returns the saved SYN once.
+    assert!(sockopt::tcp_saved_syn(&server).unwrap().is_empty());
+
+    // Connections accepted from a listener without `TCP_SAVE_SYN` have
+    // nothing saved.
+    let (plain_listener, plain_addr) = self::listener();
+    listen(&plain_listener, 1).unwrap();
+    let plain_client = socket(AddressFamily::INET, SocketType::STREAM, None).unwrap();
+    connect(&plain_client, &plain_addr).unwrap();
+    let plain_server = accept(&plain_listener).unwrap();
+    assert!(sockopt::tcp_saved_syn(&plain_server).unwrap().is_empty());
+}
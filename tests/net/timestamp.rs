@@ -0,0 +1,117 @@
+use rustix::fd::OwnedFd;
+use rustix::io::IoSliceMut;
+use rustix::net::{
+    bind, getsockname, recvmsg, sendto_any, socket, sockopt, AddressFamily, RecvAncillaryBuffer,
+    RecvAncillaryMessage, RecvFlags, SendFlags, SocketAddrAny, SocketType, TimestampingFlags,
+};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+fn udp_socket() -> (OwnedFd, SocketAddrAny) {
+    let fd = socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    bind(&fd, &SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0)).unwrap();
+    let addr = getsockname(&fd).unwrap();
+    (fd, addr)
+}
+
+/// Send a datagram to `receiver` and return the ancillary messages it is
+/// received with.
+fn send_and_receive<T>(
+    receiver: &OwnedFd,
+    receiver_addr: &SocketAddrAny,
+    space: &mut [u8],
+    f: impl FnMut(RecvAncillaryMessage<'_>) -> Option<T>,
+) -> Option<T> {
+    let (sender, _sender_addr) = udp_socket();
+    sendto_any(&sender, b"ping", SendFlags::empty(), receiver_addr).unwrap();
+
+    let mut buf = [0_u8; 16];
+    let mut control = RecvAncillaryBuffer::new(space);
+    let msg = recvmsg(
+        receiver,
+        &mut [IoSliceMut::new(&mut buf)],
+        &mut control,
+        RecvFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(msg.bytes, 4);
+    control.drain().find_map(f)
+}
+
+#[test]
+fn test_timestamp_sockopts() {
+    let (fd, _addr) = udp_socket();
+
+    assert!(!sockopt::socket_timestamp(&fd).unwrap());
+    sockopt::set_socket_timestamp(&fd, true).unwrap();
+    assert!(sockopt::socket_timestamp(&fd).unwrap());
+    sockopt::set_socket_timestamp(&fd, false).unwrap();
+    assert!(!sockopt::socket_timestamp(&fd).unwrap());
+
+    assert!(!sockopt::socket_timestampns(&fd).unwrap());
+    sockopt::set_socket_timestampns(&fd, true).unwrap();
+    assert!(sockopt::socket_timestampns(&fd).unwrap());
+    sockopt::set_socket_timestampns(&fd, false).unwrap();
+    assert!(!sockopt::socket_timestampns(&fd).unwrap());
+
+    assert_eq!(
+        sockopt::socket_timestamping(&fd).unwrap(),
+        TimestampingFlags::empty()
+    );
+    let flags = TimestampingFlags::RX_SOFTWARE | TimestampingFlags::SOFTWARE;
+    sockopt::set_socket_timestamping(&fd, flags).unwrap();
+    assert_eq!(sockopt::socket_timestamping(&fd).unwrap(), flags);
+}
+
+#[test]
+fn test_scm_timestamp() {
+    let (receiver, receiver_addr) = udp_socket();
+    sockopt::set_socket_timestamp(&receiver, true).unwrap();
+
+    let mut space = [0; rustix::cmsg_space!(ScmTimestamp(1))];
+    let ts = send_and_receive(&receiver, &receiver_addr, &mut space, |msg| match msg {
+        RecvAncillaryMessage::ScmTimestamp(ts) => Some(ts),
+        _ => None,
+    })
+    .unwrap();
+    assert!(ts.tv_sec > 0);
+    // The kernel reports microseconds, which we convert to nanoseconds.
+    assert_eq!(ts.tv_nsec % 1000, 0);
+    assert!(ts.tv_nsec < 1_000_000_000);
+}
+
+#[test]
+fn test_scm_timestampns() {
+    let (receiver, receiver_addr) = udp_socket();
+    sockopt::set_socket_timestampns(&receiver, true).unwrap();
+
+    let mut space = [0; rustix::cmsg_space!(ScmTimestampns(1))];
+    let ts = send_and_receive(&receiver, &receiver_addr, &mut space, |msg| match msg {
+        RecvAncillaryMessage::ScmTimestampns(ts) => Some(ts),
+        _ => None,
+    })
+    .unwrap();
+    assert!(ts.tv_sec > 0);
+    assert!(ts.tv_nsec < 1_000_000_000);
+}
+
+#[test]
+fn test_scm_timestamping() {
+    let (receiver, receiver_addr) = udp_socket();
+    sockopt::set_socket_timestamping(
+        &receiver,
+        TimestampingFlags::RX_SOFTWARE | TimestampingFlags::SOFTWARE,
+    )
+    .unwrap();
+
+    let mut space = [0; rustix::cmsg_space!(ScmTimestamping(1))];
+    let [software, _deprecated, hardware] =
+        send_and_receive(&receiver, &receiver_addr, &mut space, |msg| match msg {
+            RecvAncillaryMessage::ScmTimestamping(ts) => Some(ts),
+            _ => None,
+        })
+        .unwrap();
+    assert!(software.tv_sec > 0);
+    assert!(software.tv_nsec < 1_000_000_000);
+    // Loopback doesn't generate hardware timestamps.
+    assert_eq!((hardware.tv_sec, hardware.tv_nsec), (0, 0));
+}
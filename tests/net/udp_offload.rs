@@ -0,0 +1,95 @@
+use rustix::fd::OwnedFd;
+use rustix::io::{IoSlice, IoSliceMut};
+use rustix::net::{
+    bind, getsockname, recv, recvmsg, sendmsg_v4, socket, sockopt, AddressFamily,
+    RecvAncillaryBuffer, RecvAncillaryMessage, RecvFlags, SendAncillaryBuffer,
+    SendAncillaryMessage, SendFlags, SocketAddrAny, SocketAddrV4, SocketType,
+};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+const SEGMENT_SIZE: u16 = 1000;
+
+fn udp_socket() -> (OwnedFd, SocketAddrV4) {
+    let fd = socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    bind(&fd, &SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0)).unwrap();
+    let addr = match getsockname(&fd).unwrap() {
+        SocketAddrAny::V4(addr) => addr,
+        other => panic!("unexpected address {:?}", other),
+    };
+    (fd, addr)
+}
+
+/// Send three segments' worth of data in one `sendmsg` call, using a
+/// `UDP_SEGMENT` control message.
+fn send_super_datagram(dest: &SocketAddrV4) -> Vec<u8> {
+    let (sender, _sender_addr) = udp_socket();
+    let payload = (0..3 * SEGMENT_SIZE as usize)
+        .map(|i| i as u8)
+        .collect::<Vec<_>>();
+
+    let mut space = [0; rustix::cmsg_space!(UdpGsoSegments(1))];
+    let mut control = SendAncillaryBuffer::new(&mut space);
+    assert!(control.push(SendAncillaryMessage::UdpGsoSegments(SEGMENT_SIZE)));
+    let n = sendmsg_v4(
+        &sender,
+        dest,
+        &[IoSlice::new(&payload)],
+        &mut control,
+        SendFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(n, payload.len());
+    payload
+}
+
+#[test]
+fn test_udp_offload_sockopts() {
+    let (fd, _addr) = udp_socket();
+
+    assert_eq!(sockopt::udp_segment(&fd).unwrap(), 0);
+    sockopt::set_udp_segment(&fd, 1200).unwrap();
+    assert_eq!(sockopt::udp_segment(&fd).unwrap(), 1200);
+
+    assert!(!sockopt::udp_gro(&fd).unwrap());
+    sockopt::set_udp_gro(&fd, true).unwrap();
+    assert!(sockopt::udp_gro(&fd).unwrap());
+}
+
+#[test]
+fn test_udp_gso() {
+    let (receiver, receiver_addr) = udp_socket();
+    let payload = send_super_datagram(&receiver_addr);
+
+    // Without GRO, the receiver sees the individual segments.
+    let mut buf = [0_u8; 4096];
+    for segment in payload.chunks(SEGMENT_SIZE.into()) {
+        let n = recv(&receiver, &mut buf, RecvFlags::DONTWAIT).unwrap();
+        assert_eq!(&buf[..n], segment);
+    }
+}
+
+#[test]
+fn test_udp_gro() {
+    let (receiver, receiver_addr) = udp_socket();
+    sockopt::set_udp_gro(&receiver, true).unwrap();
+    let payload = send_super_datagram(&receiver_addr);
+
+    // With GRO, the receiver sees the segments coalesced, along with the
+    // segment size.
+    let mut buf = [0_u8; 4096];
+    let mut space = [0; rustix::cmsg_space!(UdpGroSegments(1))];
+    let mut control = RecvAncillaryBuffer::new(&mut space);
+    let msg = recvmsg(
+        &receiver,
+        &mut [IoSliceMut::new(&mut buf)],
+        &mut control,
+        RecvFlags::DONTWAIT,
+    )
+    .unwrap();
+    let segment_size = control.drain().find_map(|msg| match msg {
+        RecvAncillaryMessage::UdpGroSegments(segment_size) => Some(segment_size),
+        _ => None,
+    });
+    assert_eq!(segment_size, Some(SEGMENT_SIZE));
+    assert_eq!(&buf[..msg.bytes], &payload[..]);
+}
@@ -194,3 +194,36 @@ fn test_v4_msg() {
     client.join().unwrap();
     server.join().unwrap();
 }
+
+/// `sendmsg_v4` passes the address to the kernel with the length of the
+/// C `sockaddr` struct, rather than the length of the Rust address type.
+#[cfg(not(any(windows, target_os = "espidf", target_os = "redox", target_os = "wasi")))]
+#[test]
+fn test_v4_sendmsg_v4() {
+    crate::init();
+
+    use rustix::io::IoSlice;
+    use rustix::net::sendmsg_v4;
+
+    let receiver = socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    bind_v4(&receiver, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let addr = match getsockname(&receiver).unwrap() {
+        SocketAddrAny::V4(addr) => addr,
+        _ => panic!(),
+    };
+
+    let sender = socket(AddressFamily::INET, SocketType::DGRAM, None).unwrap();
+    let n = sendmsg_v4(
+        &sender,
+        &addr,
+        &[IoSlice::new(b"hello, world")],
+        &mut Default::default(),
+        SendFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(n, 12);
+
+    let mut buffer = vec![0; BUFFER_SIZE];
+    let n = recv(&receiver, &mut buffer, RecvFlags::empty()).unwrap();
+    assert_eq!(&buffer[..n], b"hello, world");
+}
@@ -193,3 +193,36 @@ fn test_v6_msg() {
     client.join().unwrap();
     server.join().unwrap();
 }
+
+/// `sendmsg_v6` passes the address to the kernel with the length of the
+/// C `sockaddr` struct, rather than the length of the Rust address type.
+#[cfg(not(any(windows, target_os = "espidf", target_os = "redox", target_os = "wasi")))]
+#[test]
+fn test_v6_sendmsg_v6() {
+    crate::init();
+
+    use rustix::io::IoSlice;
+    use rustix::net::sendmsg_v6;
+
+    let receiver = socket(AddressFamily::INET6, SocketType::DGRAM, None).unwrap();
+    bind_v6(&receiver, &SocketAddrV6::new(Ipv6Addr::LOCALHOST, 0, 0, 0)).unwrap();
+    let addr = match getsockname(&receiver).unwrap() {
+        SocketAddrAny::V6(addr) => addr,
+        _ => panic!(),
+    };
+
+    let sender = socket(AddressFamily::INET6, SocketType::DGRAM, None).unwrap();
+    let n = sendmsg_v6(
+        &sender,
+        &addr,
+        &[IoSlice::new(b"hello, world")],
+        &mut Default::default(),
+        SendFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(n, 12);
+
+    let mut buffer = vec![0; BUFFER_SIZE];
+    let n = recv(&receiver, &mut buffer, RecvFlags::empty()).unwrap();
+    assert_eq!(&buffer[..n], b"hello, world");
+}
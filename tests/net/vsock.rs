@@ -0,0 +1,77 @@
+use rustix::io;
+use rustix::net::vsock::{SocketAddrVsock, VMADDR_CID_ANY, VMADDR_CID_LOCAL, VMADDR_PORT_ANY};
+use rustix::net::{
+    accept_with, acceptfrom, bind_vsock, connect_vsock, getpeername, getsockname, listen, recv,
+    send, socket, AddressFamily, RecvFlags, SendFlags, SocketAddrAny, SocketFlags, SocketType,
+};
+
+#[test]
+fn test_vsock_addr() {
+    let mut addr = SocketAddrVsock::new(VMADDR_CID_LOCAL, 1234);
+    assert_eq!(addr.cid(), VMADDR_CID_LOCAL);
+    assert_eq!(addr.port(), 1234);
+    addr.set_cid(VMADDR_CID_ANY);
+    addr.set_port(VMADDR_PORT_ANY);
+    assert_eq!(addr.cid(), VMADDR_CID_ANY);
+    assert_eq!(addr.port(), VMADDR_PORT_ANY);
+    assert_eq!(
+        SocketAddrAny::from(addr).address_family(),
+        AddressFamily::VSOCK
+    );
+}
+
+#[test]
+fn test_vsock_loopback() {
+    let listener = match socket(AddressFamily::VSOCK, SocketType::STREAM, None) {
+        Ok(fd) => fd,
+        // vsock isn't available in this kernel.
+        Err(io::Errno::AFNOSUPPORT) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    match bind_vsock(
+        &listener,
+        &SocketAddrVsock::new(VMADDR_CID_LOCAL, VMADDR_PORT_ANY),
+    ) {
+        Ok(()) => {}
+        // The `vsock_loopback` transport isn't loaded.
+        Err(io::Errno::ADDRNOTAVAIL) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    listen(&listener, 1).unwrap();
+
+    let local = match getsockname(&listener).unwrap() {
+        SocketAddrAny::Vsock(addr) => addr,
+        other => panic!("unexpected address {:?}", other),
+    };
+    assert_eq!(local.cid(), VMADDR_CID_LOCAL);
+    assert_ne!(local.port(), VMADDR_PORT_ANY);
+
+    let client = socket(AddressFamily::VSOCK, SocketType::STREAM, None).unwrap();
+    match connect_vsock(&client, &local) {
+        Ok(()) => {}
+        // Loopback may be unavailable even though binding succeeded.
+        Err(io::Errno::NODEV | io::Errno::CONNRESET) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    assert_eq!(
+        getpeername(&client).unwrap(),
+        Some(SocketAddrAny::Vsock(local))
+    );
+
+    let (server, from) = acceptfrom(&listener).unwrap();
+    let client_addr = match getsockname(&client).unwrap() {
+        SocketAddrAny::Vsock(addr) => addr,
+        other => panic!("unexpected address {:?}", other),
+    };
+    assert_eq!(from, Some(SocketAddrAny::Vsock(client_addr)));
+
+    assert_eq!(send(&client, b"hello", SendFlags::empty()).unwrap(), 5);
+    let mut buf = [0_u8; 16];
+    let n = recv(&server, &mut buf, RecvFlags::empty()).unwrap();
+    assert_eq!(&buf[..n], b"hello");
+
+    // A second connection to make sure `accept_with` works too.
+    let client = socket(AddressFamily::VSOCK, SocketType::STREAM, None).unwrap();
+    connect_vsock(&client, &local).unwrap();
+    let _server = accept_with(&listener, SocketFlags::CLOEXEC).unwrap();
+}
@@ -0,0 +1,114 @@
+//! Tests for [`rustix::perf_event`].
+
+#![cfg(feature = "perf_event")]
+#![cfg(linux_kernel)]
+
+use rustix::fd::{AsFd, OwnedFd};
+use rustix::io;
+use rustix::perf_event::{
+    self, IocFlags, PerfEventAttr, PerfEventFlags, PerfEventPid, ReadFormat, SoftwareEvent,
+};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Open a counter on the calling thread, or return `None` if perf events
+/// aren't available, such as when `perf_event_paranoid` forbids them.
+fn open(attr: &PerfEventAttr, group: Option<&OwnedFd>) -> Option<OwnedFd> {
+    match perf_event::perf_event_open(
+        attr,
+        PerfEventPid::CallingThread,
+        None,
+        group.map(|fd| fd.as_fd()),
+        PerfEventFlags::FD_CLOEXEC,
+    ) {
+        Ok(fd) => Some(fd),
+        Err(io::Errno::ACCESS)
+        | Err(io::Errno::PERM)
+        | Err(io::Errno::NOENT)
+        | Err(io::Errno::NOSYS) => None,
+        Err(err) => panic!("{:?}", err),
+    }
+}
+
+fn spin() {
+    let x = AtomicU64::new(0);
+    for i in 0..1_000_000 {
+        x.fetch_add(i, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn test_task_clock() {
+    let format = ReadFormat::TOTAL_TIME_ENABLED | ReadFormat::TOTAL_TIME_RUNNING;
+    let attr = PerfEventAttr::software(SoftwareEvent::TASK_CLOCK)
+        .disabled(true)
+        .exclude_kernel(true)
+        .exclude_hv(true)
+        .read_format(format);
+    let counter = match open(&attr, None) {
+        Some(fd) => fd,
+        None => return,
+    };
+
+    let mut buf = [0_u8; 64];
+    let values = perf_event::read_values(&counter, format, &mut buf).unwrap();
+    assert_eq!(values.len(), 1);
+    assert_eq!(values.iter().next().unwrap().value, 0);
+    assert_eq!(values.time_enabled(), Some(0));
+
+    perf_event::ioctl_enable(&counter, IocFlags::empty()).unwrap();
+    spin();
+    perf_event::ioctl_disable(&counter, IocFlags::empty()).unwrap();
+
+    let values = perf_event::read_values(&counter, format, &mut buf).unwrap();
+    let value = values.iter().next().unwrap();
+    assert!(value.value > 0);
+    assert_eq!(value.id, None);
+    assert!(values.time_enabled().unwrap() > 0);
+    assert!(values.time_running().unwrap() > 0);
+
+    // The counter is disabled, so after a reset it stays at zero.
+    perf_event::ioctl_reset(&counter, IocFlags::empty()).unwrap();
+    spin();
+    let values = perf_event::read_values(&counter, format, &mut buf).unwrap();
+    assert_eq!(values.iter().next().unwrap().value, 0);
+}
+
+#[test]
+fn test_group() {
+    let format = ReadFormat::GROUP | ReadFormat::ID;
+    let leader_attr = PerfEventAttr::software(SoftwareEvent::TASK_CLOCK)
+        .disabled(true)
+        .exclude_kernel(true)
+        .read_format(format);
+    let leader = match open(&leader_attr, None) {
+        Some(fd) => fd,
+        None => return,
+    };
+    let member_attr = PerfEventAttr::software(SoftwareEvent::PAGE_FAULTS)
+        .exclude_kernel(true)
+        .read_format(format);
+    let member = open(&member_attr, Some(&leader)).unwrap();
+
+    perf_event::ioctl_enable(&leader, IocFlags::GROUP).unwrap();
+    spin();
+    // Touch some new memory to cause page faults.
+    let mut pages = vec![0_u8; 1 << 20];
+    for page in pages.chunks_mut(4096) {
+        unsafe { std::ptr::write_volatile(&mut page[0], 1) };
+    }
+    perf_event::ioctl_disable(&leader, IocFlags::GROUP).unwrap();
+
+    let mut buf = [0_u8; 128];
+    let values = perf_event::read_values(&leader, format, &mut buf).unwrap();
+    assert_eq!(values.len(), 2);
+    let values: Vec<_> = values.iter().collect();
+    assert!(values[0].value > 0);
+    assert!(values[1].value > 0);
+    assert_ne!(values[0].id, values[1].id);
+
+    // A buffer that's too small for the whole group fails.
+    assert_eq!(
+        perf_event::read_values(&member, format, &mut [0_u8; 16]).unwrap_err(),
+        io::Errno::NOSPC
+    );
+}
@@ -0,0 +1,100 @@
+use rustix::io;
+use rustix::process::landlock;
+use rustix::thread::set_no_new_privs;
+use std::fs::File;
+
+/// Returns the Landlock ABI version, or `None` if Landlock is unavailable.
+fn abi_version() -> Option<u32> {
+    match landlock::abi_version() {
+        Ok(abi) => {
+            assert!(abi >= 1);
+            Some(abi)
+        }
+        Err(io::Errno::NOSYS) | Err(io::Errno::OPNOTSUPP) => None,
+        Err(err) => panic!("unexpected error: {:?}", err),
+    }
+}
+
+#[test]
+fn test_landlock_from_abi() {
+    assert!(landlock::AccessFs::from_abi(0).is_empty());
+    assert!(!landlock::AccessFs::from_abi(1).contains(landlock::AccessFs::REFER));
+    assert!(landlock::AccessFs::from_abi(2).contains(landlock::AccessFs::REFER));
+    assert!(landlock::AccessFs::from_abi(5).contains(landlock::AccessFs::IOCTL_DEV));
+    assert!(landlock::AccessNet::from_abi(3).is_empty());
+    assert_eq!(
+        landlock::AccessNet::from_abi(4),
+        landlock::AccessNet::BIND_TCP | landlock::AccessNet::CONNECT_TCP
+    );
+}
+
+#[test]
+fn test_landlock_rules() {
+    let abi = match abi_version() {
+        Some(abi) => abi,
+        None => return,
+    };
+
+    let ruleset = landlock::create_ruleset(
+        landlock::AccessFs::from_abi(abi),
+        landlock::AccessNet::from_abi(abi),
+    )
+    .unwrap();
+
+    let dir = File::open("/").unwrap();
+    landlock::add_path_beneath_rule(&ruleset, landlock::AccessFs::READ_DIR, &dir).unwrap();
+
+    // Rules can't allow accesses that the ruleset doesn't handle.
+    let ruleset =
+        landlock::create_ruleset(landlock::AccessFs::READ_FILE, landlock::AccessNet::empty())
+            .unwrap();
+    assert_eq!(
+        landlock::add_path_beneath_rule(&ruleset, landlock::AccessFs::WRITE_FILE, &dir),
+        Err(io::Errno::INVAL)
+    );
+
+    if abi >= 4 {
+        let ruleset =
+            landlock::create_ruleset(landlock::AccessFs::empty(), landlock::AccessNet::BIND_TCP)
+                .unwrap();
+        landlock::add_net_port_rule(&ruleset, landlock::AccessNet::BIND_TCP, 8080).unwrap();
+    }
+}
+
+#[test]
+fn test_landlock_restrict_self() {
+    if abi_version().is_none() {
+        return;
+    }
+
+    let allowed = tempfile::tempdir().unwrap();
+    let denied = tempfile::tempdir().unwrap();
+    std::fs::write(allowed.path().join("file"), b"allowed").unwrap();
+    std::fs::write(denied.path().join("file"), b"denied").unwrap();
+
+    // Landlock restricts only the calling thread, so do it in a new thread
+    // to avoid affecting other tests.
+    std::thread::spawn(move || {
+        let ruleset =
+            landlock::create_ruleset(landlock::AccessFs::READ_FILE, landlock::AccessNet::empty())
+                .unwrap();
+        let dir = File::open(allowed.path()).unwrap();
+        landlock::add_path_beneath_rule(&ruleset, landlock::AccessFs::READ_FILE, &dir).unwrap();
+
+        set_no_new_privs(true).unwrap();
+        landlock::restrict_self(&ruleset).unwrap();
+
+        assert_eq!(
+            std::fs::read(allowed.path().join("file")).unwrap(),
+            b"allowed"
+        );
+        assert_eq!(
+            std::fs::read(denied.path().join("file"))
+                .unwrap_err()
+                .kind(),
+            std::io::ErrorKind::PermissionDenied
+        );
+    })
+    .join()
+    .unwrap();
+}
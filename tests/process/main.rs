@@ -6,6 +6,9 @@
 
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have get[gpu]id.
 mod id;
+#[cfg(linux_kernel)]
+#[cfg(feature = "thread")]
+mod landlock;
 #[cfg(target_os = "linux")]
 mod pidfd;
 #[cfg(linux_kernel)]
@@ -16,6 +19,9 @@ mod priority;
 mod procctl;
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
 mod rlimit;
+#[cfg(linux_kernel)]
+#[cfg(all(feature = "net", feature = "thread"))]
+mod seccomp_notify;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have umask.
 mod umask;
 #[cfg(not(any(target_os = "espidf", target_os = "wasi")))] // WASI doesn't have waitpid.
@@ -0,0 +1,162 @@
+use rustix::fd::{AsFd, OwnedFd};
+use rustix::io::{Errno, FdFlags};
+use rustix::net::{
+    recvmsg, sendmsg, socketpair, AddressFamily, RecvAncillaryBuffer, RecvAncillaryMessage,
+    RecvFlags, SendAncillaryBuffer, SendAncillaryMessage, SendFlags, SocketFlags, SocketType,
+};
+use rustix::process::{waitpid, Pid, WaitOptions};
+use rustix::thread::seccomp::{
+    self, Action, AddfdFlags, FilterFlags, SeccompNotifResp, SockFilter,
+};
+use rustix::thread::set_no_new_privs;
+use serial_test::serial;
+use std::io::{IoSlice, IoSliceMut};
+use std::os::raw::c_int;
+
+/// The value the supervisor makes the child's first `getppid` return.
+const EMULATED: i64 = 4242;
+
+// Run serially with the `wait` tests, which wait for any child process.
+#[test]
+#[serial]
+fn test_seccomp_notify() {
+    // Forward `getppid` to the supervisor, and allow everything else.
+    let filter = [
+        seccomp::load_arch(),
+        SockFilter::jump_eq(seccomp::AUDIT_ARCH, 1, 0),
+        SockFilter::ret(Action::Allow.to_raw()),
+        seccomp::load_syscall_nr(),
+        SockFilter::jump_eq(libc::SYS_getppid as u32, 0, 1),
+        SockFilter::ret(Action::UserNotif.to_raw()),
+        SockFilter::ret(Action::Allow.to_raw()),
+    ];
+
+    let (parent_sock, child_sock) = socketpair(
+        AddressFamily::UNIX,
+        SocketType::STREAM,
+        SocketFlags::CLOEXEC,
+        None,
+    )
+    .unwrap();
+    let parent_pid = i64::from(std::process::id());
+
+    // The filter can't be removed once installed, so install it in a child
+    // process, which sends the listener back to us.
+    let child = unsafe { libc::fork() };
+    assert!(child >= 0);
+    if child == 0 {
+        let code = run_child(&filter, child_sock, parent_pid);
+        unsafe { libc::_exit(code) };
+    }
+    drop(child_sock);
+    let child = Pid::from_raw(child).unwrap();
+
+    let mut byte = [0_u8];
+    let mut space = [0; rustix::cmsg_space!(ScmRights(1))];
+    let mut control = RecvAncillaryBuffer::new(&mut space);
+    recvmsg(
+        &parent_sock,
+        &mut [IoSliceMut::new(&mut byte)],
+        &mut control,
+        RecvFlags::empty(),
+    )
+    .unwrap();
+    let listener: Option<OwnedFd> = control.drain().find_map(|msg| match msg {
+        RecvAncillaryMessage::ScmRights(mut fds) => fds.next(),
+        _ => None,
+    });
+    let listener = match listener {
+        Some(listener) => listener,
+        None => panic!("child failed with status {:?}", wait_child(child)),
+    };
+
+    // Emulate the first call.
+    let notif = seccomp::notif_recv(&listener).unwrap();
+    assert_eq!(notif.pid, child.as_raw_nonzero().get() as u32);
+    assert_eq!(notif.data.nr, libc::SYS_getppid as i32);
+    assert_eq!(notif.data.arch, seccomp::AUDIT_ARCH);
+    assert!(seccomp::notif_id_valid(&listener, notif.id).unwrap());
+    seccomp::notif_send(&listener, &SeccompNotifResp::new_return(notif.id, EMULATED)).unwrap();
+
+    // Fail the second call.
+    let notif = seccomp::notif_recv(&listener).unwrap();
+    seccomp::notif_send(
+        &listener,
+        &SeccompNotifResp::new_error(notif.id, Errno::PERM),
+    )
+    .unwrap();
+
+    // Make the third call return a new file descriptor.
+    let notif = seccomp::notif_recv(&listener).unwrap();
+    let fd = seccomp::notif_addfd(
+        &listener,
+        notif.id,
+        &parent_sock,
+        None,
+        FdFlags::CLOEXEC,
+        AddfdFlags::SEND,
+    )
+    .unwrap();
+    assert!(fd >= 0);
+
+    // Let the fourth call run as usual.
+    let notif = seccomp::notif_recv(&listener).unwrap();
+    seccomp::notif_send(&listener, &SeccompNotifResp::new_continue(notif.id)).unwrap();
+
+    assert_eq!(wait_child(child), Some(0));
+
+    // The notification is gone once the child has exited.
+    assert!(!seccomp::notif_id_valid(&listener, notif.id).unwrap());
+    assert_eq!(seccomp::notif_recv(&listener), Err(Errno::NOENT));
+}
+
+fn wait_child(child: Pid) -> Option<u32> {
+    let (_, status) = waitpid(Some(child), WaitOptions::empty()).unwrap().unwrap();
+    status.exit_status()
+}
+
+/// Runs in the forked child, so it must not panic or allocate.
+fn run_child(filter: &[SockFilter], sock: OwnedFd, parent_pid: i64) -> i32 {
+    if set_no_new_privs(true).is_err() {
+        return 1;
+    }
+    let listener = match seccomp::set_mode_filter_with_listener(FilterFlags::empty(), filter) {
+        Ok(listener) => listener,
+        Err(_) => return 2,
+    };
+
+    let fds = [listener.as_fd()];
+    let mut space = [0; rustix::cmsg_space!(ScmRights(1))];
+    let mut control = SendAncillaryBuffer::new(&mut space);
+    if !control.push(SendAncillaryMessage::ScmRights(&fds)) {
+        return 3;
+    }
+    if sendmsg(
+        &sock,
+        &[IoSlice::new(b"x")],
+        &mut control,
+        SendFlags::empty(),
+    )
+    .is_err()
+    {
+        return 4;
+    }
+    drop(listener);
+
+    unsafe {
+        if libc::syscall(libc::SYS_getppid) as i64 != EMULATED {
+            return 5;
+        }
+        if libc::syscall(libc::SYS_getppid) != -1 || *libc::__errno_location() != libc::EPERM {
+            return 6;
+        }
+        let fd = libc::syscall(libc::SYS_getppid) as c_int;
+        if fd < 0 || libc::fcntl(fd, libc::F_GETFD) != libc::FD_CLOEXEC {
+            return 7;
+        }
+        if libc::syscall(libc::SYS_getppid) as i64 != parent_pid {
+            return 8;
+        }
+    }
+    0
+}
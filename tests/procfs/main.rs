@@ -4,3 +4,5 @@
 #![cfg(linux_kernel)]
 
 mod basic;
+#[cfg(feature = "alloc")]
+mod mountinfo;
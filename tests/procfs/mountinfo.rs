@@ -0,0 +1,33 @@
+use rustix::procfs::mountinfo;
+
+#[test]
+fn test_mountinfo() {
+    let info = mountinfo().unwrap();
+    assert!(!info.is_empty());
+    assert_eq!(info.iter().count(), info.len());
+
+    let mut ids = Vec::new();
+    for entry in &info {
+        assert!(entry.root().to_bytes().starts_with(b"/"));
+        assert!(entry.mount_point().to_bytes().starts_with(b"/"));
+        assert!(!entry.fs_type().to_bytes().is_empty());
+        ids.push(entry.mount_id());
+    }
+
+    // There's a mount at the root, and its parent is either itself or
+    // outside of our view.
+    let root = info
+        .iter()
+        .find(|entry| entry.mount_point().to_bytes() == b"/")
+        .unwrap();
+    assert!(root.parent_id() == root.mount_id() || !ids.contains(&root.parent_id()));
+
+    // The filesystem holding `/proc` is procfs, on the device that `stat`
+    // reports.
+    let proc_stat = rustix::fs::stat("/proc").unwrap();
+    assert!(info
+        .iter()
+        .any(|entry| entry.mount_point().to_bytes() == b"/proc"
+            && entry.fs_type().to_bytes() == b"proc"
+            && entry.dev() == proc_stat.st_dev));
+}
@@ -0,0 +1,67 @@
+use rustix::signal::{sigaction, SigAction, SigHandler, SigactionFlags, Signal};
+use std::sync::atomic::{AtomicI32, Ordering};
+
+static CAUGHT: AtomicI32 = AtomicI32::new(0);
+
+extern "C" fn handler(sig: i32) {
+    CAUGHT.store(sig, Ordering::SeqCst);
+}
+
+#[test]
+fn test_sigaction() {
+    unsafe {
+        // `SIGURG` is ignored by default, so nothing bad happens if another
+        // test sends it while we're changing its action.
+        let old = sigaction(Signal::Urg, None).unwrap();
+        assert!(matches!(old.handler, SigHandler::Default));
+
+        let mut action = SigAction::new(SigHandler::Handler(handler));
+        action.flags = SigactionFlags::RESTART;
+        action.mask.add(Signal::Usr2);
+        sigaction(Signal::Urg, Some(&action)).unwrap();
+
+        assert_eq!(libc::pthread_kill(libc::pthread_self(), libc::SIGURG), 0);
+        assert_eq!(CAUGHT.load(Ordering::SeqCst), libc::SIGURG);
+
+        let prev = sigaction(Signal::Urg, Some(&SigAction::new(SigHandler::Ignore))).unwrap();
+        match prev.handler {
+            SigHandler::Handler(f) => assert_eq!(f as *const (), handler as *const ()),
+            other => panic!("unexpected handler {:?}", other),
+        }
+        assert_eq!(prev.flags, SigactionFlags::RESTART);
+        assert!(prev.mask.contains(Signal::Usr2));
+
+        let prev = sigaction(Signal::Urg, Some(&old)).unwrap();
+        assert!(matches!(prev.handler, SigHandler::Ignore));
+    }
+}
+
+extern "C" fn siginfo_handler(_sig: i32, _info: *mut libc::siginfo_t, _context: *mut libc::c_void) {
+}
+
+#[test]
+fn test_sigaction_foreign_flags() {
+    unsafe {
+        // Install an action for `SIGWINCH`, which is ignored by default, with
+        // libc, which may add flags of its own, such as `SA_RESTORER`.
+        let mut raw = std::mem::zeroed::<libc::sigaction>();
+        raw.sa_sigaction = siginfo_handler as *const () as libc::sighandler_t;
+        raw.sa_flags = libc::SA_SIGINFO | libc::SA_RESTART | libc::SA_ONSTACK;
+        libc::sigemptyset(&mut raw.sa_mask);
+        let mut old = std::mem::zeroed::<libc::sigaction>();
+        assert_eq!(libc::sigaction(libc::SIGWINCH, &raw, &mut old), 0);
+
+        // Both backends report just the flags `SigactionFlags` knows about.
+        let action = sigaction(Signal::Winch, None).unwrap();
+        assert!(matches!(action.handler, SigHandler::SigInfo(_)));
+        assert_eq!(
+            action.flags,
+            SigactionFlags::RESTART | SigactionFlags::ONSTACK
+        );
+
+        assert_eq!(
+            libc::sigaction(libc::SIGWINCH, &old, std::ptr::null_mut()),
+            0
+        );
+    }
+}
@@ -0,0 +1,9 @@
+//! Tests for [`rustix::signal`].
+
+#![cfg(feature = "signal")]
+#![cfg(linux_kernel)]
+
+mod action;
+mod mask;
+mod sigset;
+mod wait;
@@ -0,0 +1,57 @@
+use rustix::signal::{sigpending, thread_sigmask, SigSet, SigmaskHow, Signal};
+
+#[test]
+fn test_thread_sigmask() {
+    let set: SigSet = [Signal::Usr2].into_iter().collect();
+
+    let old = thread_sigmask(SigmaskHow::Block, Some(&set)).unwrap();
+    let now = thread_sigmask(SigmaskHow::Block, None).unwrap();
+    assert!(now.contains(Signal::Usr2));
+
+    thread_sigmask(SigmaskHow::Unblock, Some(&set)).unwrap();
+    let now = thread_sigmask(SigmaskHow::Block, None).unwrap();
+    assert!(!now.contains(Signal::Usr2));
+
+    thread_sigmask(SigmaskHow::SetMask, Some(&old)).unwrap();
+    assert_eq!(thread_sigmask(SigmaskHow::Block, None).unwrap(), old);
+}
+
+#[test]
+fn test_sigpending() {
+    let set: SigSet = [Signal::Winch].into_iter().collect();
+    let old = thread_sigmask(SigmaskHow::Block, Some(&set)).unwrap();
+
+    unsafe {
+        assert_eq!(libc::pthread_kill(libc::pthread_self(), libc::SIGWINCH), 0);
+    }
+    assert!(sigpending().contains(Signal::Winch));
+
+    // `SIGWINCH` is ignored by default, so unblocking it discards it.
+    thread_sigmask(SigmaskHow::SetMask, Some(&old)).unwrap();
+    assert!(!sigpending().contains(Signal::Winch));
+}
+
+#[test]
+fn test_thread_sigmask_realtime() {
+    // Block only `SIGRTMIN`, which `Signal` can't represent.
+    let old = thread_sigmask(SigmaskHow::SetMask, Some(&SigSet::empty())).unwrap();
+    unsafe {
+        let mut raw = std::mem::zeroed::<libc::sigset_t>();
+        libc::sigemptyset(&mut raw);
+        libc::sigaddset(&mut raw, libc::SIGRTMIN());
+        assert_eq!(
+            libc::pthread_sigmask(libc::SIG_BLOCK, &raw, std::ptr::null_mut()),
+            0
+        );
+    }
+
+    // The mask read back isn't empty, and isn't equal to an empty set, even
+    // though it has no signals that `Signal` can represent.
+    let mask = thread_sigmask(SigmaskHow::Block, None).unwrap();
+    assert_eq!(mask.iter().count(), 0);
+    assert!(!mask.is_empty());
+    assert_ne!(mask, SigSet::empty());
+
+    thread_sigmask(SigmaskHow::SetMask, Some(&old)).unwrap();
+    assert_eq!(thread_sigmask(SigmaskHow::Block, None).unwrap(), old);
+}
@@ -0,0 +1,33 @@
+use rustix::signal::{SigSet, Signal};
+
+#[test]
+fn test_sigset_iter() {
+    let set: SigSet = [Signal::Term, Signal::Hup, Signal::Usr1]
+        .into_iter()
+        .collect();
+    assert_eq!(
+        set.iter().collect::<Vec<_>>(),
+        [Signal::Hup, Signal::Usr1, Signal::Term]
+    );
+    assert!(!set.is_empty());
+    assert!(SigSet::empty().is_empty());
+    assert_eq!(SigSet::default(), SigSet::empty());
+}
+
+#[test]
+fn test_sigset_full() {
+    let full = SigSet::full();
+    assert!(full.contains(Signal::Kill));
+    assert!(full.contains(Signal::Usr2));
+    assert!(full.contains(Signal::Sys));
+
+    let mut set = SigSet::empty();
+    set.extend(full.iter());
+    assert_eq!(set, full);
+}
+
+#[test]
+fn test_sigset_debug() {
+    let set: SigSet = [Signal::Int, Signal::Pipe].into_iter().collect();
+    assert_eq!(format!("{:?}", set), "{Int, Pipe}");
+}
@@ -0,0 +1,80 @@
+use rustix::io;
+use rustix::signal::{
+    sigtimedwait, sigwait, sigwaitinfo, thread_sigmask, SigSet, SigmaskHow, Signal, Timespec,
+};
+
+#[test]
+fn test_sigwait() {
+    let set: SigSet = [Signal::Usr1].into_iter().collect();
+    let old = thread_sigmask(SigmaskHow::Block, Some(&set)).unwrap();
+
+    let zero = Timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    assert_eq!(
+        sigtimedwait(&set, Some(&zero)).map(|info| info.signo()),
+        Err(io::Errno::AGAIN)
+    );
+
+    unsafe {
+        assert_eq!(libc::pthread_kill(libc::pthread_self(), libc::SIGUSR1), 0);
+    }
+    assert_eq!(sigwait(&set).unwrap(), Signal::Usr1);
+
+    unsafe {
+        assert_eq!(libc::pthread_kill(libc::pthread_self(), libc::SIGUSR1), 0);
+    }
+    let info = sigwaitinfo(&set).unwrap();
+    assert_eq!(info.signal(), Some(Signal::Usr1));
+    // This is `SI_TKILL`, though glibc reports it as `SI_USER`.
+    assert!(info.code() <= 0);
+    assert_eq!(
+        info.pid().map(|pid| pid.as_raw_nonzero().get() as u32),
+        Some(std::process::id())
+    );
+
+    unsafe {
+        assert_eq!(libc::pthread_kill(libc::pthread_self(), libc::SIGUSR1), 0);
+    }
+    let info = sigtimedwait(&set, Some(&zero)).unwrap();
+    assert_eq!(info.signo(), libc::SIGUSR1);
+
+    thread_sigmask(SigmaskHow::SetMask, Some(&old)).unwrap();
+}
+
+#[test]
+fn test_sigwait_unrepresentable() {
+    // Block and raise a real-time signal, which `Signal` can't represent.
+    let rt = libc::SIGRTMAX();
+    unsafe {
+        let mut raw = std::mem::zeroed::<libc::sigset_t>();
+        libc::sigemptyset(&mut raw);
+        libc::sigaddset(&mut raw, rt);
+        let mut old_raw = std::mem::zeroed::<libc::sigset_t>();
+        assert_eq!(
+            libc::pthread_sigmask(libc::SIG_BLOCK, &raw, &mut old_raw),
+            0
+        );
+        assert_eq!(libc::pthread_kill(libc::pthread_self(), rt), 0);
+
+        // The current mask, as returned by `thread_sigmask`, contains it.
+        let mask = thread_sigmask(SigmaskHow::Block, None).unwrap();
+
+        // `sigwait` refuses to wait on it, rather than accepting a signal it
+        // can't return.
+        assert_eq!(sigwait(&mask), Err(io::Errno::INVAL));
+
+        // The signal is still pending.
+        let zero = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        assert_eq!(libc::sigtimedwait(&raw, std::ptr::null_mut(), &zero), rt);
+
+        assert_eq!(
+            libc::pthread_sigmask(libc::SIG_SETMASK, &old_raw, std::ptr::null_mut()),
+            0
+        );
+    }
+}
@@ -18,4 +18,6 @@ mod membarrier;
 mod prctl;
 mod sched;
 #[cfg(linux_kernel)]
+mod seccomp;
+#[cfg(linux_kernel)]
 mod setns;
@@ -0,0 +1,64 @@
+use rustix::thread::seccomp::{self, Action, FilterFlags, SockFilter};
+use rustix::thread::set_no_new_privs;
+
+#[test]
+fn test_seccomp_action_raw() {
+    for action in [
+        Action::KillProcess,
+        Action::KillThread,
+        Action::Trap,
+        Action::Errno(22),
+        Action::UserNotif,
+        Action::Trace(7),
+        Action::Log,
+        Action::Allow,
+    ] {
+        assert_eq!(Action::from_raw(action.to_raw()), Some(action));
+    }
+    assert_eq!(Action::Allow.to_raw(), 0x7fff_0000);
+    assert_eq!(Action::from_raw(0x1234_0000), None);
+}
+
+#[test]
+fn test_seccomp_action_avail() {
+    assert!(seccomp::action_avail(Action::Allow).unwrap());
+    assert!(seccomp::action_avail(Action::Errno(1)).unwrap());
+}
+
+#[test]
+fn test_seccomp_set_mode_filter() {
+    const ERRNO: i32 = 133; // `EHWPOISON`, which `getppid` never fails with.
+
+    let filter = [
+        seccomp::load_arch(),
+        SockFilter::jump_eq(seccomp::AUDIT_ARCH, 1, 0),
+        SockFilter::ret(Action::Allow.to_raw()),
+        seccomp::load_syscall_nr(),
+        SockFilter::jump_eq(libc::SYS_getppid as u32, 0, 1),
+        SockFilter::ret(Action::Errno(ERRNO as u16).to_raw()),
+        SockFilter::ret(Action::Allow.to_raw()),
+    ];
+
+    // The filter applies only to the calling thread, so install it in a new
+    // thread to avoid affecting other tests.
+    std::thread::spawn(move || {
+        set_no_new_privs(true).unwrap();
+        seccomp::set_mode_filter(FilterFlags::empty(), &filter).unwrap();
+
+        unsafe {
+            assert_eq!(libc::syscall(libc::SYS_getppid), -1);
+        }
+        assert_eq!(std::io::Error::last_os_error().raw_os_error(), Some(ERRNO));
+        unsafe {
+            assert!(libc::syscall(libc::SYS_getpid) > 0);
+        }
+    })
+    .join()
+    .unwrap();
+
+    // An empty program is rejected.
+    assert_eq!(
+        seccomp::set_mode_filter(FilterFlags::empty(), &[]),
+        Err(rustix::io::Errno::INVAL)
+    );
+}